@@ -0,0 +1,170 @@
+//! Feed data-quality checks.
+//!
+//! Spoofed or misconfigured transponders show up as one ICAO address
+//! reported in two places at once, or as several addresses broadcasting the
+//! same callsign. Neither is visible on the map, so the checks here collect
+//! them into a short list for the debug panel.
+
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crate::Aircraft;
+use crate::data_sources::DataSourceManager;
+use crate::debug_panel::DebugPanelState;
+
+/// Seconds between anomaly scans
+const ANOMALY_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A data-quality problem spotted in the live feed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedAnomaly {
+    /// Sources place the same ICAO address this far apart
+    ConflictingPositions { icao: String, distance_nm: f64 },
+    /// Several ICAO addresses broadcast the same callsign
+    SharedCallsign { callsign: String, icaos: Vec<String> },
+}
+
+impl FeedAnomaly {
+    /// Stable key used to log each anomaly once while it persists
+    fn key(&self) -> String {
+        match self {
+            FeedAnomaly::ConflictingPositions { icao, .. } => format!("icao:{}", icao),
+            FeedAnomaly::SharedCallsign { callsign, .. } => format!("callsign:{}", callsign),
+        }
+    }
+
+    /// One-line description for the debug panel and log
+    pub fn describe(&self) -> String {
+        match self {
+            FeedAnomaly::ConflictingPositions { icao, distance_nm } => {
+                format!("{} reported {:.1} NM apart by different sources", icao, distance_nm)
+            }
+            FeedAnomaly::SharedCallsign { callsign, icaos } => {
+                format!("{} used by {}", callsign, icaos.join(", "))
+            }
+        }
+    }
+}
+
+/// Find ICAO conflicts (from the multi-source merge) and callsigns shared by
+/// more than one ICAO. `aircraft` yields (ICAO, callsign) pairs; blank
+/// callsigns are ignored. Results are sorted so the list doesn't reshuffle
+/// between scans.
+pub fn find_feed_anomalies<'a>(
+    aircraft: impl Iterator<Item = (&'a str, Option<&'a str>)>,
+    manager: &DataSourceManager,
+) -> Vec<FeedAnomaly> {
+    let mut conflicts: Vec<FeedAnomaly> = manager
+        .aircraft
+        .values()
+        .filter_map(|merged| {
+            merged.position_disagreement_nm.map(|distance_nm| FeedAnomaly::ConflictingPositions {
+                icao: merged.icao.clone(),
+                distance_nm,
+            })
+        })
+        .collect();
+    conflicts.sort_by(|a, b| a.key().cmp(&b.key()));
+
+    let mut by_callsign: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (icao, callsign) in aircraft {
+        let Some(callsign) = callsign.map(str::trim).filter(|c| !c.is_empty()) else {
+            continue;
+        };
+        by_callsign.entry(callsign.to_uppercase()).or_default().push(icao.to_string());
+    }
+    let shared = by_callsign.into_iter().filter(|(_, icaos)| icaos.len() > 1).map(|(callsign, mut icaos)| {
+        icaos.sort();
+        icaos.dedup();
+        FeedAnomaly::SharedCallsign { callsign, icaos }
+    });
+
+    conflicts.into_iter().chain(shared).collect()
+}
+
+/// System that rescans the live aircraft for anomalies every couple of
+/// seconds, publishing them to the debug panel and logging new ones.
+pub fn detect_feed_anomalies(
+    mut debug: Option<ResMut<DebugPanelState>>,
+    manager: Res<DataSourceManager>,
+    aircraft_query: Query<&Aircraft>,
+    mut last_check: Local<Option<Instant>>,
+    mut reported: Local<HashSet<String>>,
+) {
+    let Some(ref mut debug) = debug else {
+        return;
+    };
+    let now = Instant::now();
+    if last_check.is_some_and(|t| now.duration_since(t) < ANOMALY_CHECK_INTERVAL) {
+        return;
+    }
+    *last_check = Some(now);
+
+    let anomalies = find_feed_anomalies(
+        aircraft_query.iter().map(|a| (a.icao.as_str(), a.callsign.as_deref())),
+        &manager,
+    );
+
+    let current: HashSet<String> = anomalies.iter().map(FeedAnomaly::key).collect();
+    for anomaly in anomalies.iter().filter(|a| !reported.contains(&a.key())) {
+        warn!("Feed anomaly: {}", anomaly.describe());
+        debug.push_log(format!("Anomaly: {}", anomaly.describe()));
+    }
+    *reported = current;
+
+    if debug.anomalies != anomalies {
+        debug.anomalies = anomalies;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_sources::SourcedAircraftData;
+
+    fn report(source: &str, priority: u8, latitude: f64) -> SourcedAircraftData {
+        SourcedAircraftData {
+            icao: "A1B2C3".to_string(),
+            source: source.to_string(),
+            priority,
+            last_update: Instant::now(),
+            latitude: Some(latitude),
+            longitude: Some(-122.0),
+            altitude: None,
+            heading: None,
+            velocity: None,
+            vertical_rate: None,
+            callsign: None,
+            squawk: None,
+        }
+    }
+
+    #[test]
+    fn flags_position_conflicts_and_shared_callsigns() {
+        let mut manager = DataSourceManager::default();
+        manager.update_aircraft(report("local", 10, 47.0));
+        manager.update_aircraft(report("remote", 5, 47.5));
+
+        let aircraft = [
+            ("A1B2C3", Some("UAL1")),
+            ("ABCDEF", Some("ual1 ")),
+            ("123456", Some("DAL2")),
+            ("654321", Some("  ")),
+            ("FEDCBA", None),
+        ];
+        let anomalies = find_feed_anomalies(aircraft.into_iter(), &manager);
+        assert_eq!(anomalies.len(), 2);
+        assert!(matches!(
+            &anomalies[0],
+            FeedAnomaly::ConflictingPositions { icao, distance_nm } if icao == "A1B2C3" && *distance_nm > 29.0
+        ));
+        assert_eq!(
+            anomalies[1],
+            FeedAnomaly::SharedCallsign {
+                callsign: "UAL1".to_string(),
+                icaos: vec!["A1B2C3".to_string(), "ABCDEF".to_string()],
+            }
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
 
 use adsb_client::{
     Client as AdsbClient, ClientConfig, ConnectionConfig, ConnectionState, TrackerConfig,
@@ -7,6 +8,7 @@ use adsb_client::{
 
 use crate::{constants, config, MapState};
 use crate::debug_panel::DebugPanelState;
+use crate::event_log::{EventCategory, EventLog};
 
 /// Shared state for aircraft data from the ADS-B client.
 /// Updated by the background tokio thread and read by Bevy systems.
@@ -16,6 +18,10 @@ pub struct AdsbAircraftData {
     pub aircraft: Arc<Mutex<Vec<adsb_client::Aircraft>>>,
     /// Current connection state
     pub connection_state: Arc<Mutex<ConnectionState>>,
+    /// Address the running client thread is connected to
+    pub endpoint: String,
+    /// Signals the running client thread to stop
+    shutdown: Arc<Notify>,
 }
 
 impl AdsbAircraftData {
@@ -23,9 +29,30 @@ impl AdsbAircraftData {
         Self {
             aircraft: Arc::new(Mutex::new(Vec::new())),
             connection_state: Arc::new(Mutex::new(ConnectionState::Disconnected)),
+            endpoint: String::new(),
+            shutdown: Arc::new(Notify::new()),
         }
     }
 
+    /// Start a client thread for `endpoint`, stopping any previous one.
+    /// The new thread gets fresh shared state, so a late update from the old
+    /// thread can't leak into it; the status shows Connecting until the new
+    /// client reports in.
+    pub fn connect(&mut self, endpoint: String, center: (f64, f64)) {
+        self.shutdown.notify_one();
+        self.shutdown = Arc::new(Notify::new());
+        self.aircraft = Arc::new(Mutex::new(Vec::new()));
+        self.connection_state = Arc::new(Mutex::new(ConnectionState::Connecting));
+        self.endpoint = endpoint.clone();
+        spawn_client_thread(
+            endpoint,
+            center,
+            Arc::clone(&self.aircraft),
+            Arc::clone(&self.connection_state),
+            Arc::clone(&self.shutdown),
+        );
+    }
+
     /// Try to get a snapshot of all tracked aircraft without blocking.
     /// Returns `None` if the background thread currently holds the lock.
     pub fn try_get_aircraft(&self) -> Option<Vec<adsb_client::Aircraft>> {
@@ -46,28 +73,15 @@ impl AdsbAircraftData {
     }
 }
 
-/// Component to mark the connection status UI text
-#[derive(Component)]
-pub struct ConnectionStatusText;
-
-/// Setup the ADS-B client in a background thread with its own tokio runtime.
-pub fn setup_adsb_client(
-    mut commands: Commands,
-    map_state: Res<MapState>,
-    app_config: Res<config::AppConfig>,
+/// Run an ADS-B client in a background thread with its own tokio runtime
+/// until `shutdown` is notified.
+fn spawn_client_thread(
+    endpoint_url: String,
+    (center_lat, center_lon): (f64, f64),
+    aircraft_data: Arc<Mutex<Vec<adsb_client::Aircraft>>>,
+    connection_state: Arc<Mutex<ConnectionState>>,
+    shutdown: Arc<Notify>,
 ) {
-    let adsb_data = AdsbAircraftData::new();
-    let aircraft_data = Arc::clone(&adsb_data.aircraft);
-    let connection_state = Arc::clone(&adsb_data.connection_state);
-
-    // Get the center coordinates from map state
-    let center_lat = map_state.latitude;
-    let center_lon = map_state.longitude;
-
-    // Get endpoint URL from config
-    let endpoint_url = app_config.feed.endpoint_url.clone();
-
-    // Spawn a background thread with its own tokio runtime
     std::thread::spawn(move || {
         let rt = tokio::runtime::Builder::new_current_thread()
             .enable_all()
@@ -93,32 +107,85 @@ pub fn setup_adsb_client(
 
             // Processing loop
             loop {
-                if !client.process_next().await {
+                let alive = tokio::select! {
+                    _ = shutdown.notified() => {
+                        info!("Stopping ADS-B client for {}", endpoint_url);
+                        break;
+                    }
+                    alive = client.process_next() => alive,
+                };
+                if !alive {
                     warn!("ADS-B client connection closed, restarting...");
-                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-                    continue;
+                    tokio::select! {
+                        _ = shutdown.notified() => break,
+                        _ = tokio::time::sleep(std::time::Duration::from_secs(5)) => continue,
+                    }
                 }
 
                 if let Ok(mut state) = connection_state.lock() {
                     *state = client.connection_state();
                 }
 
+                // Normalize ICAO addresses so casing or padding differences
+                // never split one aircraft into two entities
+                let aircraft = super::sync::normalize_aircraft_icaos(client.get_aircraft());
                 if let Ok(mut data) = aircraft_data.lock() {
-                    *data = client.get_aircraft();
+                    *data = aircraft;
                 }
             }
         });
     });
+}
+
+/// Component to mark the connection status UI text
+#[derive(Component)]
+pub struct ConnectionStatusText;
+
+/// Setup the ADS-B client in a background thread with its own tokio runtime.
+pub fn setup_adsb_client(
+    mut commands: Commands,
+    map_state: Res<MapState>,
+    app_config: Res<config::AppConfig>,
+) {
+    let mut adsb_data = AdsbAircraftData::new();
+    adsb_data.connect(
+        app_config.feed.endpoint_url.clone(),
+        (map_state.latitude, map_state.longitude),
+    );
 
     commands.insert_resource(adsb_data);
     info!("ADS-B client background thread started");
 }
 
+/// Reconnect the primary feed when the endpoint is changed in settings,
+/// stopping the old client thread and starting one against the new address.
+pub fn reconnect_on_endpoint_change(
+    adsb_data: Option<ResMut<AdsbAircraftData>>,
+    app_config: Res<config::AppConfig>,
+    map_state: Res<MapState>,
+    mut events: ResMut<EventLog>,
+) {
+    let Some(mut adsb_data) = adsb_data else {
+        return;
+    };
+    if !app_config.is_changed() || adsb_data.endpoint == app_config.feed.endpoint_url {
+        return;
+    }
+
+    let endpoint = app_config.feed.endpoint_url.clone();
+    events.record(
+        EventCategory::Connection,
+        format!("ADS-B endpoint changed from {} to {}, reconnecting", adsb_data.endpoint, endpoint),
+    );
+    adsb_data.connect(endpoint, (map_state.latitude, map_state.longitude));
+}
+
 /// Update the connection status UI indicator
 pub fn update_connection_status(
     adsb_data: Option<Res<AdsbAircraftData>>,
     mut status_query: Query<(&mut Text, &mut TextColor), With<ConnectionStatusText>>,
     mut debug: Option<ResMut<DebugPanelState>>,
+    mut events: ResMut<EventLog>,
     mut prev_state: Local<String>,
     theme: Res<crate::theme::AppTheme>,
 ) {
@@ -135,6 +202,7 @@ pub fn update_connection_status(
         if let Some(ref mut dbg) = debug {
             dbg.push_log(format!("Connection: {}", state_label));
         }
+        events.record(EventCategory::Connection, format!("ADS-B connection: {}", state_label));
         *prev_state = state_label;
     }
 
@@ -1,5 +1,7 @@
 pub mod sync;
+pub mod anomaly;
 pub mod connection;
+pub mod synthetic;
 
 pub use sync::*;
 pub use connection::*;
@@ -24,8 +26,12 @@ impl Plugin for AdsbPlugin {
                 sync_aircraft_from_adsb,
                 update_aircraft_label_text.after(sync_aircraft_from_adsb),
                 apply_model_corrections.after(sync_aircraft_from_adsb),
+                anomaly::detect_feed_anomalies.after(sync_aircraft_from_adsb),
+                reconnect_on_endpoint_change.before(update_connection_status),
                 update_connection_status,
+                synthetic::update_synthetic_traffic.before(sync_aircraft_from_adsb),
             ),
-        );
+        )
+        .init_resource::<synthetic::SyntheticTraffic>();
     }
 }
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy::camera::visibility::RenderLayers;
 use crate::{constants, Aircraft, AircraftLabel, RenderCategory};
-use crate::aircraft::TrailHistory;
+use crate::aircraft::altitude::format_altitude;
+use crate::aircraft::{AircraftListState, AircraftTypeInfo, CameraFollowState, TrailHistory};
 use crate::aircraft::picking::{on_aircraft_click, on_aircraft_hover, on_aircraft_out};
 use crate::debug_panel::DebugPanelState;
 use super::connection::{AdsbAircraftData, ConnectionStatusText};
@@ -53,19 +54,42 @@ impl AircraftModelRegistry {
     }
 }
 
-/// Load aircraft 3D models and build the registry.
-/// The default GLB is loaded with MAIN_WORLD asset usage so mesh data
-/// is retained on the CPU for picking raycasts (not just uploaded to GPU).
-pub fn setup_aircraft_models(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// Load a GLB scene with MAIN_WORLD asset usage so mesh data is retained on
+/// the CPU for picking raycasts (not just uploaded to GPU).
+fn load_glb_scene(asset_server: &AssetServer, path: String) -> Handle<Scene> {
     use bevy::asset::RenderAssetUsages;
     use bevy::gltf::GltfLoaderSettings;
 
-    let default_model = asset_server.load_with_settings(
-        "airplane.glb#Scene0",
+    asset_server.load_with_settings(
+        format!("{}#Scene0", path),
         |settings: &mut GltfLoaderSettings| {
             settings.load_meshes = RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD;
         },
-    );
+    )
+}
+
+/// Asset path for a user model file name from `[aircraft_models]`, relative
+/// to `assets/`. Only plain `.glb` files inside `assets/models/` are accepted.
+fn custom_model_asset_path(file: &str) -> Option<String> {
+    let file = file.trim().trim_start_matches('/');
+    let is_glb = std::path::Path::new(file)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("glb"));
+    if !is_glb || file.split(['/', '\\']).any(|part| part == "..") {
+        return None;
+    }
+    Some(format!("models/{}", file))
+}
+
+/// Load aircraft 3D models and build the registry: the generic default
+/// model, the built-in B737 model, and any user GLBs mapped by type code
+/// in the `[aircraft_models]` config table, which take precedence.
+pub fn setup_aircraft_models(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    app_config: Res<crate::config::AppConfig>,
+) {
+    let default_model = load_glb_scene(&asset_server, "airplane.glb".to_string());
     let b737_model: Handle<Scene> = asset_server.load("models/b737/78349.obj");
 
     let mut type_models = HashMap::new();
@@ -97,6 +121,30 @@ pub fn setup_aircraft_models(mut commands: Commands, asset_server: Res<AssetServ
         corrections.insert(code.to_string(), b737_correction.clone());
     }
 
+    // User models follow the GLB convention, so they replace any built-in
+    // correction for their type codes. Files mapped to several types load once.
+    let mut custom_models: HashMap<String, Handle<Scene>> = HashMap::new();
+    for (code, file) in &app_config.aircraft_models {
+        let Some(asset_path) = custom_model_asset_path(file) else {
+            warn!("Ignoring aircraft model for {}: '{}' is not a .glb file in assets/models/", code, file);
+            continue;
+        };
+        if !crate::paths::assets_dir().join(&asset_path).is_file() {
+            warn!("Aircraft model for {} not found: assets/{}", code, asset_path);
+            continue;
+        }
+        let handle = custom_models
+            .entry(asset_path.clone())
+            .or_insert_with(|| load_glb_scene(&asset_server, asset_path))
+            .clone();
+        let code = code.trim().to_ascii_uppercase();
+        corrections.remove(&code);
+        type_models.insert(code, handle);
+    }
+    if !custom_models.is_empty() {
+        info!("Loaded {} custom aircraft model(s)", custom_models.len());
+    }
+
     commands.insert_resource(AircraftModelRegistry {
         default_model,
         type_models,
@@ -104,6 +152,75 @@ pub fn setup_aircraft_models(mut commands: Commands, asset_server: Res<AssetServ
     });
 }
 
+/// Normalize an ICAO 24-bit address to trimmed, uppercase hex.
+///
+/// readsb marks non-ICAO addresses (TIS-B, ADS-R) with a leading `~`, which
+/// is kept so they never collide with a real ICAO address. Returns `None`
+/// for anything else that is not exactly six hex digits, so malformed
+/// addresses are dropped rather than spawning stray aircraft.
+pub fn normalize_icao(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    let (prefix, hex) = match trimmed.strip_prefix('~') {
+        Some(hex) => ("~", hex),
+        None => ("", trimmed),
+    };
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(format!("{}{}", prefix, hex.to_ascii_uppercase()))
+}
+
+/// Normalize the ICAO of every aircraft in an ADS-B snapshot, dropping
+/// invalid addresses and collapsing reports that only differed by casing or
+/// whitespace (the most recently seen report wins).
+pub fn normalize_aircraft_icaos(aircraft: Vec<adsb_client::Aircraft>) -> Vec<adsb_client::Aircraft> {
+    let mut by_icao: HashMap<String, adsb_client::Aircraft> = HashMap::with_capacity(aircraft.len());
+    for mut ac in aircraft {
+        let Some(icao) = normalize_icao(&ac.icao) else {
+            debug!("Dropping aircraft with invalid ICAO address {:?}", ac.icao);
+            continue;
+        };
+        ac.icao = icao.clone();
+        match by_icao.get(&icao) {
+            Some(existing) if existing.last_seen >= ac.last_seen => {}
+            _ => {
+                by_icao.insert(icao, ac);
+            }
+        }
+    }
+    by_icao.into_values().collect()
+}
+
+/// Update the set of ICAOs evicted by the tracked-aircraft cap.
+///
+/// Eviction is sticky: an evicted aircraft stays evicted until it leaves
+/// the feed (or is pinned), so shifting `last_seen` order between frames
+/// can't despawn and respawn aircraft. When the aircraft not yet evicted
+/// exceed `cap`, the least recently updated of them are added to the set;
+/// pinned aircraft (selected or followed) are never evicted. A cap of 0
+/// disables eviction.
+fn update_cap_evictions(
+    mut candidates: Vec<(&str, chrono::DateTime<chrono::Utc>)>,
+    cap: usize,
+    pinned: &[&str],
+    evicted: &mut HashSet<String>,
+) {
+    if cap == 0 {
+        evicted.clear();
+        return;
+    }
+    let in_feed: HashSet<&str> = candidates.iter().map(|&(icao, _)| icao).collect();
+    evicted.retain(|icao| in_feed.contains(icao.as_str()) && !pinned.contains(&icao.as_str()));
+
+    candidates.retain(|(icao, _)| !evicted.contains(*icao));
+    if candidates.len() <= cap {
+        return;
+    }
+    candidates.sort_by_key(|&(icao, last_seen)| (!pinned.contains(&icao), std::cmp::Reverse(last_seen)));
+    let keep_count = cap.max(candidates.iter().filter(|(icao, _)| pinned.contains(icao)).count());
+    evicted.extend(candidates.into_iter().skip(keep_count).map(|(icao, _)| icao.to_string()));
+}
+
 /// Sync aircraft entities from the shared ADS-B data.
 /// This system runs every frame and updates Bevy entities to match the ADS-B client state.
 pub fn sync_aircraft_from_adsb(
@@ -111,10 +228,16 @@ pub fn sync_aircraft_from_adsb(
     model_registry: Option<Res<AircraftModelRegistry>>,
     adsb_data: Option<Res<AdsbAircraftData>>,
     mut aircraft_query: Query<(Entity, &mut Aircraft, &mut Transform)>,
+    synthetic_query: Query<(), With<super::synthetic::SyntheticAircraft>>,
     label_query: Query<(Entity, &AircraftLabel)>,
     mut debug: Option<ResMut<DebugPanelState>>,
     theme: Res<AppTheme>,
     type_db: Option<Res<crate::aircraft::AircraftTypeDatabase>>,
+    app_config: Res<crate::config::AppConfig>,
+    list_state: Res<AircraftListState>,
+    follow_state: Res<CameraFollowState>,
+    mut data_sources: Option<ResMut<crate::data_sources::DataSourceManager>>,
+    mut evicted: Local<HashSet<String>>,
 ) {
     let Some(adsb_data) = adsb_data else {
         return; // ADS-B client not yet initialized
@@ -129,12 +252,48 @@ pub fn sync_aircraft_from_adsb(
         return;
     };
 
-    // Build a map of existing aircraft entities by ICAO
+    // Enforce the tracked-aircraft cap: evict the oldest-updated aircraft
+    // that are neither selected nor followed, and keep them evicted while
+    // they stay in the feed
+    let pinned: Vec<&str> = [list_state.selected_icao.as_deref(), follow_state.following_icao.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    let candidates = adsb_aircraft
+        .iter()
+        .filter(|ac| ac.latitude.is_some() && ac.longitude.is_some())
+        .map(|ac| (ac.icao.as_str(), ac.last_seen))
+        .collect();
+    let was_engaged = !evicted.is_empty();
+    update_cap_evictions(candidates, app_config.feed.max_tracked_aircraft, &pinned, &mut evicted);
+    if evicted.is_empty() == was_engaged {
+        if !was_engaged {
+            warn!("Tracked aircraft cap ({}) reached; evicting oldest-updated aircraft", app_config.feed.max_tracked_aircraft);
+        } else {
+            info!("Tracked aircraft back under cap");
+        }
+    }
+
+    // Build a map of existing aircraft entities by ICAO. Synthetic traffic
+    // is managed by its own system and must not be despawned here.
     let mut existing_aircraft: HashMap<String, Entity> = aircraft_query
         .iter()
+        .filter(|(entity, _, _)| !synthetic_query.contains(*entity))
         .map(|(entity, aircraft, _)| (aircraft.icao.clone(), entity))
         .collect();
 
+    let altitude_source = app_config.altitude_display.source;
+
+    // Reports from the live connection feed the data source manager under
+    // the first enabled source. Reports older than that source's stale
+    // timeout are treated as gone so they don't respawn once pruned.
+    let live_source = data_sources
+        .as_deref()
+        .and_then(|manager| manager.live_feed_source())
+        .map(|s| (s.name.clone(), s.priority, std::time::Duration::from_secs(s.stale_timeout_secs)));
+    let now = std::time::Instant::now();
+    let now_utc = chrono::Utc::now();
+
     // Update or spawn aircraft
     for adsb_ac in &adsb_aircraft {
         if let Some(ref mut dbg) = debug {
@@ -149,12 +308,40 @@ pub fn sync_aircraft_from_adsb(
             continue;
         };
 
+        // Evicted aircraft are left in `existing_aircraft` and despawned below
+        if evicted.contains(&adsb_ac.icao) {
+            continue;
+        }
+
+        if let (Some(manager), Some((source, priority, stale_timeout))) = (data_sources.as_mut(), &live_source) {
+            let age = (now_utc - adsb_ac.last_seen).to_std().unwrap_or_default();
+            if age > *stale_timeout {
+                continue;
+            }
+            manager.update_aircraft(crate::data_sources::SourcedAircraftData {
+                icao: adsb_ac.icao.clone(),
+                source: source.clone(),
+                priority: *priority,
+                last_update: now.checked_sub(age).unwrap_or(now),
+                latitude: Some(lat),
+                longitude: Some(lon),
+                altitude: adsb_ac.altitude,
+                heading: adsb_ac.track.map(|t| t as f32),
+                velocity: adsb_ac.velocity,
+                vertical_rate: adsb_ac.vertical_rate,
+                callsign: adsb_ac.callsign.clone(),
+                squawk: adsb_ac.squawk.clone(),
+            });
+        }
+
         if let Some(&entity) = existing_aircraft.get(&adsb_ac.icao) {
             // Update existing aircraft
             if let Ok((_, mut aircraft, _)) = aircraft_query.get_mut(entity) {
                 aircraft.latitude = lat;
                 aircraft.longitude = lon;
-                aircraft.altitude = adsb_ac.altitude;
+                // The client only decodes barometric altitude so far; the
+                // geometric field stays empty until it exposes GNSS height
+                aircraft.set_altitudes(adsb_ac.altitude, None, altitude_source);
                 aircraft.heading = adsb_ac.track.map(|t| t as f32);
                 aircraft.velocity = adsb_ac.velocity;
                 aircraft.vertical_rate = adsb_ac.vertical_rate;
@@ -173,72 +360,37 @@ pub fn sync_aircraft_from_adsb(
                 let callsign = adsb_ac.callsign.as_deref().unwrap_or("?");
                 dbg.push_log(format!("New aircraft: {} ({})", adsb_ac.icao, callsign));
             }
-            // Spawn new aircraft with 3D model
-            let aircraft_name = adsb_ac.callsign.as_deref().unwrap_or(&adsb_ac.icao);
-
             // Look up type code for model selection
             let type_code = type_db
                 .as_ref()
                 .and_then(|db| db.lookup(&adsb_ac.icao))
                 .and_then(|info| info.type_code.clone());
 
-            let model_handle = model_registry.get_model(type_code.as_deref());
-            let correction = model_registry.get_correction(type_code.as_deref());
-
-            let mut entity_commands = commands.spawn((
-                    Name::new(format!("Aircraft: {}", aircraft_name)),
-                    SceneRoot(model_handle),
-                    Transform::from_xyz(0.0, 0.0, constants::AIRCRAFT_Z_LAYER),
-                    Pickable::default(),
-                    Aircraft {
-                        icao: adsb_ac.icao.clone(),
-                        callsign: adsb_ac.callsign.clone(),
-                        latitude: lat,
-                        longitude: lon,
-                        altitude: adsb_ac.altitude,
-                        heading: adsb_ac.track.map(|t| t as f32),
-                        velocity: adsb_ac.velocity,
-                        vertical_rate: adsb_ac.vertical_rate,
-                        squawk: adsb_ac.squawk.clone(),
-                        is_on_ground: adsb_ac.is_on_ground,
-                        alert: adsb_ac.alert,
-                        emergency: adsb_ac.emergency,
-                        spi: adsb_ac.spi,
-                        last_seen: adsb_ac.last_seen,
-                    },
-                    TrailHistory::default(),
-                ));
-            if let Some(corr) = correction {
-                entity_commands.insert(corr);
-            }
-            let aircraft_entity = entity_commands
-                .observe(on_aircraft_click)
-                .observe(on_aircraft_hover)
-                .observe(on_aircraft_out)
-                .id();
-
-            // Spawn label for this aircraft
-            let callsign_display = adsb_ac.callsign.as_deref().unwrap_or(&adsb_ac.icao);
-            let alt_display = adsb_ac
-                .altitude
-                .map(|a| format!("{} ft", a))
-                .unwrap_or_default();
-            let label_text = format!("{}\n{}", callsign_display, alt_display);
-
-            commands.spawn((
-                Name::new(format!("Label: {}", aircraft_name)),
-                Text2d::new(label_text),
-                TextFont {
-                    font_size: constants::BASE_FONT_SIZE,
-                    ..default()
+            spawn_aircraft_entity(
+                &mut commands,
+                &model_registry,
+                type_code.as_deref(),
+                Aircraft {
+                    icao: adsb_ac.icao.clone(),
+                    callsign: adsb_ac.callsign.clone(),
+                    latitude: lat,
+                    longitude: lon,
+                    altitude: altitude_source.select(adsb_ac.altitude, None),
+                    barometric_altitude: adsb_ac.altitude,
+                    geometric_altitude: None,
+                    heading: adsb_ac.track.map(|t| t as f32),
+                    velocity: adsb_ac.velocity,
+                    vertical_rate: adsb_ac.vertical_rate,
+                    squawk: adsb_ac.squawk.clone(),
+                    is_on_ground: adsb_ac.is_on_ground,
+                    alert: adsb_ac.alert,
+                    emergency: adsb_ac.emergency,
+                    spi: adsb_ac.spi,
+                    last_seen: adsb_ac.last_seen,
                 },
-                TextColor(theme.text_primary()),
-                Transform::from_xyz(0.0, 0.0, constants::LABEL_Z_LAYER),
-                AircraftLabel {
-                    aircraft_entity,
-                },
-                RenderLayers::layer(RenderCategory::LABELS),
-            ));
+                &app_config,
+                &theme,
+            );
         }
     }
 
@@ -261,6 +413,65 @@ pub fn sync_aircraft_from_adsb(
     }
 }
 
+/// Spawn an aircraft entity with its 3D model, picking observers and map
+/// label. Returns the aircraft entity.
+pub(crate) fn spawn_aircraft_entity(
+    commands: &mut Commands,
+    model_registry: &AircraftModelRegistry,
+    type_code: Option<&str>,
+    aircraft: Aircraft,
+    app_config: &crate::config::AppConfig,
+    theme: &AppTheme,
+) -> Entity {
+    let aircraft_name = aircraft.callsign.clone().unwrap_or_else(|| aircraft.icao.clone());
+    let label_text = aircraft_label_text(
+        &LabelFields {
+            icao: &aircraft.icao,
+            callsign: aircraft.callsign.as_deref(),
+            altitude: aircraft.altitude,
+            velocity: aircraft.velocity,
+            vertical_rate: aircraft.vertical_rate,
+            type_code: None,
+        },
+        app_config,
+    );
+
+    let mut entity_commands = commands.spawn((
+        Name::new(format!("Aircraft: {}", aircraft_name)),
+        SceneRoot(model_registry.get_model(type_code)),
+        Transform::from_xyz(0.0, 0.0, constants::AIRCRAFT_Z_LAYER),
+        Pickable::default(),
+        aircraft,
+        TrailHistory::default(),
+    ));
+    if let Some(corr) = model_registry.get_correction(type_code) {
+        entity_commands.insert(corr);
+    }
+    let aircraft_entity = entity_commands
+        .observe(on_aircraft_click)
+        .observe(on_aircraft_hover)
+        .observe(on_aircraft_out)
+        .id();
+
+    commands.spawn((
+        Name::new(format!("Label: {}", aircraft_name)),
+        Text2d::new(label_text),
+        TextFont {
+            font_size: constants::BASE_FONT_SIZE,
+            ..default()
+        },
+        TextColor(theme.text_primary()),
+        app_config.appearance.label_anchor.text_anchor(),
+        Transform::from_xyz(0.0, 0.0, constants::LABEL_Z_LAYER),
+        AircraftLabel {
+            aircraft_entity,
+        },
+        RenderLayers::layer(RenderCategory::LABELS),
+    ));
+
+    aircraft_entity
+}
+
 /// Apply model corrections to child mesh entities after scene loading.
 /// Runs every frame but only processes uncorrected entities (those with
 /// ModelCorrection but without ModelCorrectionApplied). Once children
@@ -268,7 +479,7 @@ pub fn sync_aircraft_from_adsb(
 pub fn apply_model_corrections(
     mut commands: Commands,
     parent_query: Query<(Entity, &ModelCorrection, &Children), Without<ModelCorrectionApplied>>,
-    mut transform_query: Query<&mut Transform>,
+    mut transform_query: Query<&mut Transform, Without<crate::aircraft::marker::ChevronMarker>>,
 ) {
     for (entity, correction, children) in parent_query.iter() {
         let mut applied = false;
@@ -289,17 +500,198 @@ pub fn apply_model_corrections(
 
 /// Update aircraft labels with current data
 pub fn update_aircraft_label_text(
-    aircraft_query: Query<&Aircraft>,
+    aircraft_query: Query<(&Aircraft, Option<&AircraftTypeInfo>)>,
     mut label_query: Query<(&AircraftLabel, &mut Text2d)>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
     for (label, mut text) in label_query.iter_mut() {
-        if let Ok(aircraft) = aircraft_query.get(label.aircraft_entity) {
-            let callsign_display = aircraft.callsign.as_deref().unwrap_or(&aircraft.icao);
-            let alt_display = aircraft
-                .altitude
-                .map(|a| format!("{} ft", a))
-                .unwrap_or_default();
-            **text = format!("{}\n{}", callsign_display, alt_display);
+        if let Ok((aircraft, type_info)) = aircraft_query.get(label.aircraft_entity) {
+            **text = aircraft_label_text(
+                &LabelFields {
+                    icao: &aircraft.icao,
+                    callsign: aircraft.callsign.as_deref(),
+                    altitude: aircraft.altitude,
+                    velocity: aircraft.velocity,
+                    vertical_rate: aircraft.vertical_rate,
+                    type_code: type_info.and_then(|t| t.type_code.as_deref()),
+                },
+                &app_config,
+            );
+        }
+    }
+}
+
+/// Per-aircraft values available to the label template.
+struct LabelFields<'a> {
+    icao: &'a str,
+    callsign: Option<&'a str>,
+    altitude: Option<i32>,
+    velocity: Option<f64>,
+    vertical_rate: Option<i32>,
+    type_code: Option<&'a str>,
+}
+
+/// Map label text: the user's label template, plus their note if set.
+fn aircraft_label_text(fields: &LabelFields, app_config: &crate::config::AppConfig) -> String {
+    let text = render_label_template(&app_config.appearance.label_template, fields, &app_config.altitude_display);
+    match app_config.aircraft_note(fields.icao) {
+        Some(note) => format!("{}\n{}", text, note),
+        None => text,
+    }
+}
+
+/// Expand `{callsign}`, `{icao}`, `{alt}`, `{spd}`, `{vrate}` and `{type}`
+/// tokens. `{callsign}` falls back to the ICAO address; other missing values
+/// expand to nothing, and lines left blank by missing values are dropped so
+/// the label doesn't grow empty rows. Unknown tokens are kept verbatim.
+fn render_label_template(
+    template: &str,
+    fields: &LabelFields,
+    altitude_display: &crate::aircraft::altitude::AltitudeDisplay,
+) -> String {
+    let mut lines = Vec::new();
+    for line in template.lines() {
+        let mut out = String::new();
+        let mut had_token = false;
+        let mut had_value = false;
+        let mut rest = line;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let Some(len) = rest[start..].find('}') else {
+                out.push_str(&rest[start..]);
+                rest = "";
+                break;
+            };
+            let token = &rest[start + 1..start + len];
+            let value = match token {
+                "callsign" => Some(fields.callsign.unwrap_or(fields.icao).to_string()),
+                "icao" => Some(fields.icao.to_string()),
+                "alt" => fields.altitude.map(|a| format_altitude(Some(a), altitude_display)),
+                "spd" => fields.velocity.map(|v| format!("{:03}kt", v as i32)),
+                "vrate" => fields.vertical_rate.map(|vr| format!("{:+}fpm", vr)),
+                "type" => fields.type_code.map(str::to_string),
+                _ => Some(rest[start..=start + len].to_string()),
+            };
+            had_token = true;
+            if let Some(value) = value {
+                had_value = true;
+                out.push_str(&value);
+            }
+            rest = &rest[start + len + 1..];
         }
+        out.push_str(rest);
+        if had_token && !had_value {
+            continue;
+        }
+        let trimmed = out.trim();
+        if !trimmed.is_empty() {
+            lines.push(trimmed.to_string());
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn fields<'a>(callsign: Option<&'a str>, altitude: Option<i32>) -> LabelFields<'a> {
+        LabelFields {
+            icao: "A1B2C3",
+            callsign,
+            altitude,
+            velocity: Some(452.7),
+            vertical_rate: Some(-800),
+            type_code: None,
+        }
+    }
+
+    #[test]
+    fn label_template_expands_tokens() {
+        let display = crate::aircraft::altitude::AltitudeDisplay::default();
+        let text = render_label_template("{callsign} {icao}\n{spd} {vrate}", &fields(Some("UAL1"), None), &display);
+        assert_eq!(text, "UAL1 A1B2C3\n452kt -800fpm");
+    }
+
+    #[test]
+    fn label_template_falls_back_for_missing_fields() {
+        let display = crate::aircraft::altitude::AltitudeDisplay::default();
+        // Callsign falls back to ICAO; a line made only of missing values is dropped
+        let text = render_label_template("{callsign}\n{alt}\n{type}", &fields(None, None), &display);
+        assert_eq!(text, "A1B2C3");
+        // Unknown tokens survive untouched
+        let text = render_label_template("{callsign} {foo}", &fields(Some("UAL1"), None), &display);
+        assert_eq!(text, "UAL1 {foo}");
+    }
+
+    #[test]
+    fn cap_keeps_pinned_and_most_recent() {
+        let now = Utc::now();
+        let candidates = vec![
+            ("old", now - Duration::seconds(30)),
+            ("new", now),
+            ("mid", now - Duration::seconds(10)),
+            ("followed", now - Duration::seconds(60)),
+        ];
+        let mut evicted = HashSet::new();
+        update_cap_evictions(candidates, 2, &["followed"], &mut evicted);
+        assert_eq!(evicted, HashSet::from(["old".to_string(), "mid".to_string()]));
+    }
+
+    #[test]
+    fn cap_not_applied_when_under_limit_or_disabled() {
+        let now = Utc::now();
+        let mut evicted = HashSet::new();
+        update_cap_evictions(vec![("a", now), ("b", now)], 2, &[], &mut evicted);
+        assert!(evicted.is_empty());
+        update_cap_evictions(vec![("a", now), ("b", now), ("c", now)], 0, &[], &mut evicted);
+        assert!(evicted.is_empty());
+    }
+
+    #[test]
+    fn eviction_is_sticky_across_reordered_updates() {
+        let now = Utc::now();
+        let mut evicted = HashSet::new();
+        update_cap_evictions(
+            vec![("a", now), ("b", now - Duration::seconds(5)), ("c", now - Duration::seconds(10))],
+            2,
+            &[],
+            &mut evicted,
+        );
+        assert_eq!(evicted, HashSet::from(["c".to_string()]));
+
+        // "c" is now the most recently updated, but the same two survive
+        update_cap_evictions(
+            vec![("a", now - Duration::seconds(10)), ("b", now - Duration::seconds(5)), ("c", now + Duration::seconds(1))],
+            2,
+            &[],
+            &mut evicted,
+        );
+        assert_eq!(evicted, HashSet::from(["c".to_string()]));
+
+        // Leaving the feed clears the eviction
+        update_cap_evictions(vec![("a", now), ("b", now)], 2, &[], &mut evicted);
+        assert!(evicted.is_empty());
+    }
+
+    #[test]
+    fn icao_is_trimmed_and_uppercased() {
+        assert_eq!(normalize_icao("a1b2c3").as_deref(), Some("A1B2C3"));
+        assert_eq!(normalize_icao("  A1b2C3\n").as_deref(), Some("A1B2C3"));
+        assert_eq!(normalize_icao("~a1b2c3").as_deref(), Some("~A1B2C3"));
+        assert_eq!(normalize_icao("~a1b2c").as_deref(), None);
+        assert_eq!(normalize_icao("~~a1b2c3").as_deref(), None);
+        assert_eq!(normalize_icao("A1B2C3D").as_deref(), None);
+        assert_eq!(normalize_icao("").as_deref(), None);
+        assert_eq!(normalize_icao("GHIJKL").as_deref(), None);
+    }
+
+    #[test]
+    fn custom_model_paths_stay_inside_models_dir() {
+        assert_eq!(custom_model_asset_path("c172.glb").as_deref(), Some("models/c172.glb"));
+        assert_eq!(custom_model_asset_path(" /ga/C172.GLB ").as_deref(), Some("models/ga/C172.GLB"));
+        assert_eq!(custom_model_asset_path("c172.obj"), None);
+        assert_eq!(custom_model_asset_path("../airplane.glb"), None);
     }
 }
@@ -0,0 +1,258 @@
+//! Synthetic traffic for development and demos.
+//!
+//! Spawns a deterministic set of aircraft flying back and forth along
+//! great-circle routes around the map center, with altitude, speed and
+//! heading that change over time. They go through the same spawn path as
+//! live ADS-B aircraft, so the list, trails, prediction, CPA and 3D view can
+//! be exercised without a feed or a recording.
+
+use bevy::prelude::*;
+
+use super::sync::{spawn_aircraft_entity, AircraftModelRegistry};
+use crate::geo::{destination_point, great_circle_intermediate, haversine_distance_nm, initial_bearing};
+use crate::theme::AppTheme;
+use crate::{Aircraft, AircraftLabel, MapState};
+
+/// Upper bound for the synthetic aircraft count slider
+pub const MAX_SYNTHETIC_AIRCRAFT: usize = 500;
+
+/// Synthetic ICAO addresses start here so they're easy to tell apart
+const SYNTHETIC_ICAO_BASE: u32 = 0xFF_0000;
+
+/// GNSS height above barometric altitude reported by synthetic traffic, so
+/// the altitude source setting has something to switch between
+const SYNTHETIC_GEOMETRIC_OFFSET_FT: i32 = 150;
+
+/// Developer toggle and size for synthetic traffic
+#[derive(Resource)]
+pub struct SyntheticTraffic {
+    pub enabled: bool,
+    pub count: usize,
+    /// Center the routes were generated around (set when enabled)
+    origin: Option<(f64, f64)>,
+    routes: Vec<SyntheticRoute>,
+}
+
+impl Default for SyntheticTraffic {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            count: 25,
+            origin: None,
+            routes: Vec::new(),
+        }
+    }
+}
+
+/// Marker for aircraft driven by `SyntheticTraffic`. ADS-B sync leaves them alone.
+#[derive(Component)]
+pub struct SyntheticAircraft;
+
+/// A route one synthetic aircraft flies back and forth along
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntheticRoute {
+    pub icao: String,
+    pub callsign: String,
+    pub start: (f64, f64),
+    pub end: (f64, f64),
+    pub cruise_altitude: i32,
+    pub cruise_speed_kts: f64,
+    /// Phase offset (0..1) so aircraft don't move in lockstep
+    pub phase: f64,
+}
+
+/// Position, altitude and motion of a synthetic aircraft at one instant
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntheticState {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: i32,
+    pub heading: f32,
+    pub velocity: f64,
+    pub vertical_rate: i32,
+}
+
+/// Small deterministic generator so the same count always yields the same traffic
+fn hash_unit(seed: u64) -> f64 {
+    let mut x = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15).wrapping_add(0x6A09_E667_F3BC_C909);
+    x ^= x >> 31;
+    x = x.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x ^= x >> 29;
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Generate `count` routes around `center`. Deterministic for a given input.
+pub fn generate_routes(center: (f64, f64), count: usize) -> Vec<SyntheticRoute> {
+    const AIRLINES: [&str; 6] = ["SYN", "TST", "DMO", "DEV", "SIM", "QAX"];
+    (0..count)
+        .map(|i| {
+            let r = |k: u64| hash_unit(i as u64 * 16 + k);
+            // Start within 120 NM of center, fly 150-450 NM on a random bearing
+            let start = destination_point(center.0, center.1, r(0) * 360.0, r(1) * 120.0);
+            let end = destination_point(start.0, start.1, r(2) * 360.0, 150.0 + r(3) * 300.0);
+            SyntheticRoute {
+                icao: format!("{:06X}", SYNTHETIC_ICAO_BASE + i as u32),
+                callsign: format!("{}{}", AIRLINES[i % AIRLINES.len()], 100 + i),
+                start,
+                end,
+                cruise_altitude: 3_000 + (r(4) * 36.0) as i32 * 1_000,
+                cruise_speed_kts: 180.0 + r(5) * 300.0,
+                phase: r(6),
+            }
+        })
+        .collect()
+}
+
+impl SyntheticRoute {
+    /// State at `elapsed_secs` since the traffic was enabled. Aircraft fly
+    /// to the end of the route and back; altitude and speed wander gently.
+    pub fn state_at(&self, elapsed_secs: f64) -> SyntheticState {
+        let length_nm = haversine_distance_nm(self.start.0, self.start.1, self.end.0, self.end.1).max(1.0);
+        // Speed wobbles ±10% over a few minutes
+        let speed_wave = (elapsed_secs / 180.0 + self.phase) * std::f64::consts::TAU;
+        let velocity = self.cruise_speed_kts * (1.0 + 0.1 * speed_wave.sin());
+
+        let leg_secs = length_nm / self.cruise_speed_kts * 3600.0;
+        let cycle = (elapsed_secs / leg_secs + self.phase * 2.0).rem_euclid(2.0);
+        let (fraction, outbound) = if cycle < 1.0 { (cycle, true) } else { (2.0 - cycle, false) };
+
+        let (latitude, longitude) =
+            great_circle_intermediate(self.start.0, self.start.1, self.end.0, self.end.1, fraction);
+        let toward = if outbound { self.end } else { self.start };
+        let heading = initial_bearing(latitude, longitude, toward.0, toward.1) as f32;
+
+        // Altitude drifts ±2000 ft around cruise over ten minutes
+        let alt_period_secs = 600.0;
+        let alt_wave = (elapsed_secs / alt_period_secs + self.phase) * std::f64::consts::TAU;
+        let altitude = self.cruise_altitude + (2_000.0 * alt_wave.sin()) as i32;
+        let vertical_rate = (2_000.0 * std::f64::consts::TAU / alt_period_secs * 60.0 * alt_wave.cos()) as i32;
+
+        SyntheticState {
+            latitude,
+            longitude,
+            altitude: altitude.max(500),
+            heading,
+            velocity,
+            vertical_rate,
+        }
+    }
+}
+
+/// Spawn, move and despawn synthetic aircraft to match `SyntheticTraffic`.
+pub fn update_synthetic_traffic(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut traffic: ResMut<SyntheticTraffic>,
+    mut enabled_at: Local<f64>,
+    map_state: Res<MapState>,
+    model_registry: Option<Res<AircraftModelRegistry>>,
+    app_config: Res<crate::config::AppConfig>,
+    theme: Res<AppTheme>,
+    mut aircraft_query: Query<(Entity, &mut Aircraft), With<SyntheticAircraft>>,
+    label_query: Query<(Entity, &AircraftLabel)>,
+) {
+    let wanted = if traffic.enabled { traffic.count.min(MAX_SYNTHETIC_AIRCRAFT) } else { 0 };
+
+    // Regenerate routes when toggled on or the count changes
+    if wanted == 0 {
+        traffic.origin = None;
+        traffic.routes.clear();
+    } else if traffic.origin.is_none() || traffic.routes.len() != wanted {
+        let origin = *traffic.origin.get_or_insert((map_state.latitude, map_state.longitude));
+        traffic.routes = generate_routes(origin, wanted);
+        *enabled_at = time.elapsed_secs_f64();
+    }
+
+    let elapsed = time.elapsed_secs_f64() - *enabled_at;
+    let altitude_source = app_config.altitude_display.source;
+    let mut existing: std::collections::HashMap<String, Entity> = aircraft_query
+        .iter()
+        .map(|(entity, aircraft)| (aircraft.icao.clone(), entity))
+        .collect();
+
+    for route in &traffic.routes {
+        let state = route.state_at(elapsed);
+        let geometric = Some(state.altitude + SYNTHETIC_GEOMETRIC_OFFSET_FT);
+        if let Some(entity) = existing.remove(&route.icao) {
+            if let Ok((_, mut aircraft)) = aircraft_query.get_mut(entity) {
+                aircraft.latitude = state.latitude;
+                aircraft.longitude = state.longitude;
+                aircraft.set_altitudes(Some(state.altitude), geometric, altitude_source);
+                aircraft.heading = Some(state.heading);
+                aircraft.velocity = Some(state.velocity);
+                aircraft.vertical_rate = Some(state.vertical_rate);
+                aircraft.last_seen = chrono::Utc::now();
+            }
+        } else if let Some(ref registry) = model_registry {
+            let entity = spawn_aircraft_entity(
+                &mut commands,
+                registry,
+                None,
+                Aircraft {
+                    icao: route.icao.clone(),
+                    callsign: Some(route.callsign.clone()),
+                    latitude: state.latitude,
+                    longitude: state.longitude,
+                    altitude: altitude_source.select(Some(state.altitude), geometric),
+                    barometric_altitude: Some(state.altitude),
+                    geometric_altitude: geometric,
+                    heading: Some(state.heading),
+                    velocity: Some(state.velocity),
+                    vertical_rate: Some(state.vertical_rate),
+                    squawk: Some("1200".to_string()),
+                    is_on_ground: Some(false),
+                    alert: None,
+                    emergency: None,
+                    spi: None,
+                    last_seen: chrono::Utc::now(),
+                },
+                &app_config,
+                &theme,
+            );
+            commands.entity(entity).insert(SyntheticAircraft);
+        }
+    }
+
+    // Anything left over is no longer wanted
+    for (_, entity) in existing {
+        for (label_entity, label) in label_query.iter() {
+            if label.aircraft_entity == entity {
+                commands.entity(label_entity).despawn();
+            }
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_are_deterministic() {
+        let a = generate_routes((37.7, -97.3), 10);
+        let b = generate_routes((37.7, -97.3), 10);
+        assert_eq!(a, b);
+        assert_eq!(a[3].icao, "FF0003");
+        assert!(a.iter().all(|r| (3_000..=39_000).contains(&r.cruise_altitude)));
+    }
+
+    #[test]
+    fn aircraft_fly_out_and_back_along_route() {
+        let mut route = generate_routes((37.7, -97.3), 1).remove(0);
+        route.phase = 0.0;
+        let length = haversine_distance_nm(route.start.0, route.start.1, route.end.0, route.end.1);
+        let leg_secs = length / route.cruise_speed_kts * 3600.0;
+
+        let start = route.state_at(0.0);
+        assert!(haversine_distance_nm(start.latitude, start.longitude, route.start.0, route.start.1) < 0.1);
+        let outbound = initial_bearing(route.start.0, route.start.1, route.end.0, route.end.1) as f32;
+        assert!((start.heading - outbound).abs() < 1.0);
+
+        let turned = route.state_at(leg_secs);
+        assert!(haversine_distance_nm(turned.latitude, turned.longitude, route.end.0, route.end.1) < 0.1);
+
+        let back = route.state_at(leg_secs * 2.0);
+        assert!(haversine_distance_nm(back.latitude, back.longitude, route.start.0, route.start.1) < 0.1);
+    }
+}
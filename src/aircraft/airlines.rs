@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Bundled ICAO airline designator table (`icao,name`).
+const BUNDLED_AIRLINES_CSV: &str = include_str!("../../assets/data/airlines.csv");
+
+/// Lookup from 3-letter ICAO airline designator to airline name.
+#[derive(Resource, Default)]
+pub struct AirlineDirectory {
+    names: HashMap<String, String>,
+}
+
+impl AirlineDirectory {
+    /// Parse an `icao,name` CSV. Rows without a 3-letter designator are skipped.
+    pub fn from_csv(data: &str) -> Self {
+        let mut names = HashMap::new();
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        for record in reader.records().flatten() {
+            let (Some(code), Some(name)) = (record.get(0), record.get(1)) else {
+                continue;
+            };
+            let code = code.trim().to_ascii_uppercase();
+            let name = name.trim();
+            if code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic()) && !name.is_empty() {
+                names.insert(code, name.to_string());
+            }
+        }
+        Self { names }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Airline name for a callsign such as "UAL123", if its prefix is known.
+    pub fn lookup(&self, callsign: &str) -> Option<&str> {
+        let code = airline_designator(callsign)?;
+        self.names.get(&code).map(String::as_str)
+    }
+}
+
+/// Extract the ICAO airline designator from an airline-style callsign:
+/// three letters followed by a flight number ("UAL123", "DLH4AB").
+/// Registrations like "N123AB" or "GABCD" yield `None`.
+pub fn airline_designator(callsign: &str) -> Option<String> {
+    let callsign = callsign.trim();
+    let bytes = callsign.as_bytes();
+    if bytes.len() < 4
+        || !bytes[..3].iter().all(u8::is_ascii_alphabetic)
+        || !bytes[3].is_ascii_digit()
+    {
+        return None;
+    }
+    Some(callsign[..3].to_ascii_uppercase())
+}
+
+/// Startup system that loads the bundled airline table.
+pub fn load_airline_directory(mut directory: ResMut<AirlineDirectory>) {
+    *directory = AirlineDirectory::from_csv(BUNDLED_AIRLINES_CSV);
+    info!("Loaded {} airline designators", directory.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn designator_requires_letters_then_flight_number() {
+        assert_eq!(airline_designator("UAL123").as_deref(), Some("UAL"));
+        assert_eq!(airline_designator("dlh4ab ").as_deref(), Some("DLH"));
+        assert_eq!(airline_designator("N123AB"), None);
+        assert_eq!(airline_designator("GABCD"), None);
+        assert_eq!(airline_designator("UAL"), None);
+    }
+
+    #[test]
+    fn bundled_table_resolves_common_callsigns() {
+        let directory = AirlineDirectory::from_csv(BUNDLED_AIRLINES_CSV);
+        assert_eq!(directory.lookup("UAL123"), Some("United Airlines"));
+        assert_eq!(directory.lookup("BAW9"), Some("British Airways"));
+        assert_eq!(directory.lookup("ZZZ1"), None);
+    }
+}
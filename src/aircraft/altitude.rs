@@ -1,22 +1,451 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
 use crate::geo::FL_THRESHOLD;
+use crate::Aircraft;
+use super::declutter::DeclutterFade;
+use super::picking::{HoverOutline, SelectionOutline};
+
+/// Which reported altitude becomes `Aircraft::altitude`, and so drives
+/// labels, colors, filters and 3D height.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AltitudeSource {
+    /// Pressure altitude (standard 29.92 inHg), what ATC and flight levels use
+    #[default]
+    Barometric,
+    /// GNSS height above the WGS84 ellipsoid
+    Geometric,
+}
+
+impl AltitudeSource {
+    pub const ALL: [AltitudeSource; 2] = [AltitudeSource::Barometric, AltitudeSource::Geometric];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AltitudeSource::Barometric => "Barometric",
+            AltitudeSource::Geometric => "Geometric (GNSS)",
+        }
+    }
+
+    /// The preferred altitude, falling back to the other one when the
+    /// aircraft doesn't report it.
+    pub fn select(&self, barometric: Option<i32>, geometric: Option<i32>) -> Option<i32> {
+        match self {
+            AltitudeSource::Barometric => barometric.or(geometric),
+            AltitudeSource::Geometric => geometric.or(barometric),
+        }
+    }
+}
+
+/// How altitudes are written in the aircraft list, detail and map labels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AltitudeDisplay {
+    /// Show altitudes at or above the transition altitude as flight levels
+    pub flight_levels: bool,
+    /// Transition altitude in feet (18,000 ft in the US)
+    pub transition_altitude: i32,
+    /// Altitude used for display, filtering and 3D placement
+    #[serde(default)]
+    pub source: AltitudeSource,
+}
+
+impl Default for AltitudeDisplay {
+    fn default() -> Self {
+        Self {
+            flight_levels: true,
+            transition_altitude: FL_THRESHOLD,
+            source: AltitudeSource::default(),
+        }
+    }
+}
+
+impl AltitudeDisplay {
+    /// Whether `alt` should be written as a flight level.
+    pub fn is_flight_level(&self, alt: i32) -> bool {
+        self.flight_levels && alt >= self.transition_altitude
+    }
+
+    /// Altitude value without a unit: "FL350" or "12,500".
+    pub fn value(&self, alt: i32) -> String {
+        if self.is_flight_level(alt) {
+            format!("FL{:03}", alt / 100)
+        } else {
+            group_thousands(alt)
+        }
+    }
+}
+
+fn group_thousands(value: i32) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if value < 0 {
+        out.push('-');
+    }
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
 
-/// Format altitude for display. At or above FL_THRESHOLD (18,000 ft),
-/// displays as flight level (e.g. "FL350"); below, as feet (e.g. "12500 ft").
-/// Returns "---" for None.
-pub fn format_altitude(alt: Option<i32>) -> String {
+/// Format altitude for display. At or above the transition altitude (when
+/// flight levels are enabled) displays as flight level (e.g. "FL350");
+/// otherwise as feet (e.g. "12,500 ft"). Returns "---" for None.
+pub fn format_altitude(alt: Option<i32>, display: &AltitudeDisplay) -> String {
     match alt {
-        Some(a) if a >= FL_THRESHOLD => format!("FL{:03}", a / 100),
-        Some(a) => format!("{} ft", a),
+        Some(a) if display.is_flight_level(a) => display.value(a),
+        Some(a) => format!("{} ft", display.value(a)),
         None => "---".to_string(),
     }
 }
 
 /// Format altitude with a vertical-rate indicator prefix.
 /// Suitable for compact list displays.
-pub fn format_altitude_with_indicator(alt: i32, indicator: &str) -> String {
-    if alt >= FL_THRESHOLD {
-        format!("{} FL{:03}", indicator, alt / 100)
-    } else {
-        format!("{} {}", indicator, alt)
+pub fn format_altitude_with_indicator(alt: i32, indicator: &str, display: &AltitudeDisplay) -> String {
+    format!("{} {}", indicator, display.value(alt))
+}
+
+// =============================================================================
+// Altitude color scheme
+// =============================================================================
+
+/// Built-in altitude color presets selectable from Settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AltitudeColorPreset {
+    /// Cyan through green, yellow and orange to purple (the original trail gradient)
+    #[default]
+    Classic,
+    /// Warm low-altitude colors shading to blue/magenta at cruise
+    FlightAware,
+    /// Dark grey at the surface to near-white at cruise
+    Grayscale,
+    /// User-edited bands
+    Custom,
+}
+
+impl AltitudeColorPreset {
+    pub const ALL: &'static [AltitudeColorPreset] = &[
+        AltitudeColorPreset::Classic,
+        AltitudeColorPreset::FlightAware,
+        AltitudeColorPreset::Grayscale,
+        AltitudeColorPreset::Custom,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AltitudeColorPreset::Classic => "Classic",
+            AltitudeColorPreset::FlightAware => "FlightAware",
+            AltitudeColorPreset::Grayscale => "Grayscale",
+            AltitudeColorPreset::Custom => "Custom",
+        }
+    }
+}
+
+/// One altitude band: aircraft at or above `min_ft` (and below the next
+/// band's threshold) use `color`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AltitudeBand {
+    pub min_ft: i32,
+    /// sRGB color as 8-bit components
+    pub color: [u8; 3],
+}
+
+impl AltitudeBand {
+    const fn new(min_ft: i32, color: [u8; 3]) -> Self {
+        Self { min_ft, color }
+    }
+}
+
+/// Shared altitude-to-color mapping used by the aircraft list, trails and
+/// aircraft markers so every view agrees on what color a given altitude is.
+///
+/// Loaded from `AppConfig::altitude_colors` and kept in sync as a resource.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AltitudeColorScheme {
+    pub preset: AltitudeColorPreset,
+    /// Bands sorted by ascending `min_ft`
+    pub bands: Vec<AltitudeBand>,
+    /// Color for aircraft without an altitude report
+    pub unknown_color: [u8; 3],
+    /// Blend linearly between band colors instead of stepping at thresholds
+    pub smooth: bool,
+    /// Tint 3D aircraft models with their band color
+    #[serde(default)]
+    pub tint_markers: bool,
+}
+
+impl Default for AltitudeColorScheme {
+    fn default() -> Self {
+        Self::from_preset(AltitudeColorPreset::Classic)
+    }
+}
+
+impl AltitudeColorScheme {
+    /// Build the scheme for a preset. `Custom` starts from the Classic bands.
+    pub fn from_preset(preset: AltitudeColorPreset) -> Self {
+        let (bands, smooth) = match preset {
+            AltitudeColorPreset::Classic | AltitudeColorPreset::Custom => (
+                vec![
+                    AltitudeBand::new(0, [0, 255, 255]),
+                    AltitudeBand::new(10_000, [0, 128, 0]),
+                    AltitudeBand::new(20_000, [255, 255, 0]),
+                    AltitudeBand::new(30_000, [255, 153, 0]),
+                    AltitudeBand::new(40_000, [204, 0, 255]),
+                ],
+                true,
+            ),
+            AltitudeColorPreset::FlightAware => (
+                vec![
+                    AltitudeBand::new(0, [255, 110, 0]),
+                    AltitudeBand::new(4_000, [255, 210, 0]),
+                    AltitudeBand::new(10_000, [110, 220, 0]),
+                    AltitudeBand::new(20_000, [0, 180, 255]),
+                    AltitudeBand::new(30_000, [60, 80, 255]),
+                    AltitudeBand::new(40_000, [200, 0, 255]),
+                ],
+                true,
+            ),
+            AltitudeColorPreset::Grayscale => (
+                vec![
+                    AltitudeBand::new(0, [90, 90, 90]),
+                    AltitudeBand::new(10_000, [140, 140, 140]),
+                    AltitudeBand::new(20_000, [185, 185, 185]),
+                    AltitudeBand::new(30_000, [220, 220, 220]),
+                    AltitudeBand::new(40_000, [250, 250, 250]),
+                ],
+                false,
+            ),
+        };
+        Self {
+            preset,
+            bands,
+            unknown_color: [100, 100, 100],
+            smooth,
+            tint_markers: false,
+        }
+    }
+
+    /// Sort bands by threshold. Called after editing so lookups can assume order.
+    pub fn normalize(&mut self) {
+        self.bands.sort_by_key(|b| b.min_ft);
+        self.bands.dedup_by_key(|b| b.min_ft);
+    }
+
+    /// Index of the band containing `altitude`, or `None` if unknown.
+    /// Altitudes below the first threshold fall into the first band.
+    pub fn band_index(&self, altitude: Option<i32>) -> Option<usize> {
+        let alt = altitude?;
+        if self.bands.is_empty() {
+            return None;
+        }
+        let idx = self.bands.iter().rposition(|b| alt >= b.min_ft).unwrap_or(0);
+        Some(idx)
+    }
+
+    /// sRGB color for an altitude as 8-bit components.
+    pub fn rgb(&self, altitude: Option<i32>) -> [u8; 3] {
+        let Some(idx) = self.band_index(altitude) else {
+            return self.unknown_color;
+        };
+        let band = &self.bands[idx];
+        if !self.smooth {
+            return band.color;
+        }
+        let Some(next) = self.bands.get(idx + 1) else {
+            return band.color;
+        };
+        let alt = altitude.unwrap_or(0).max(band.min_ft);
+        let span = (next.min_ft - band.min_ft).max(1) as f32;
+        let t = ((alt - band.min_ft) as f32 / span).clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        [
+            lerp(band.color[0], next.color[0]),
+            lerp(band.color[1], next.color[1]),
+            lerp(band.color[2], next.color[2]),
+        ]
+    }
+
+    /// Bevy color for an altitude (trails, gizmos, materials).
+    pub fn color(&self, altitude: Option<i32>) -> Color {
+        let [r, g, b] = self.rgb(altitude);
+        Color::srgb_u8(r, g, b)
+    }
+
+    /// Stepped band color regardless of `smooth`, used where a small fixed
+    /// palette is needed (e.g. one material per band).
+    pub fn band_color(&self, index: usize) -> Color {
+        let [r, g, b] = self.bands.get(index).map(|b| b.color).unwrap_or(self.unknown_color);
+        Color::srgb_u8(r, g, b)
+    }
+}
+
+// =============================================================================
+// Marker tinting
+// =============================================================================
+
+/// One material per altitude band for tinting aircraft models, plus the
+/// models' original materials so tinting can be switched off again.
+#[derive(Resource, Default)]
+pub struct AltitudeTintMaterials {
+    /// Band materials, rebuilt whenever the scheme changes
+    bands: Vec<Handle<StandardMaterial>>,
+    unknown: Option<Handle<StandardMaterial>>,
+    /// Original model materials keyed by mesh entity
+    originals: HashMap<Entity, Handle<StandardMaterial>>,
+}
+
+impl AltitudeTintMaterials {
+    fn is_tint(&self, handle: &Handle<StandardMaterial>) -> bool {
+        self.unknown.as_ref() == Some(handle) || self.bands.contains(handle)
+    }
+}
+
+fn tint_material(color: Color) -> StandardMaterial {
+    StandardMaterial {
+        base_color: color,
+        perceptual_roughness: 0.6,
+        ..default()
+    }
+}
+
+/// System that tints aircraft models with their altitude band color when
+/// `AltitudeColorScheme::tint_markers` is enabled. Selected and hovered
+/// aircraft are left to the outline material swap, and faded aircraft to
+/// `fade_declutter_materials`.
+pub fn tint_aircraft_markers(
+    scheme: Res<AltitudeColorScheme>,
+    mut tint: ResMut<AltitudeTintMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    aircraft_query: Query<(&Aircraft, &Children), (Without<SelectionOutline>, Without<HoverOutline>, Without<DeclutterFade>)>,
+    children_query: Query<&Children>,
+    mesh_query: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    if !scheme.tint_markers {
+        // Put original materials back once after tinting is switched off
+        if tint.originals.is_empty() {
+            return;
+        }
+        let originals = std::mem::take(&mut tint.originals);
+        for (entity, original) in originals {
+            if let Ok(current) = mesh_query.get(entity) {
+                if tint.is_tint(&current.0) {
+                    commands.entity(entity).insert(MeshMaterial3d(original));
+                }
+            }
+        }
+        return;
+    }
+
+    if scheme.is_changed() || tint.unknown.is_none() {
+        let bands = (0..scheme.bands.len())
+            .map(|i| materials.add(tint_material(scheme.band_color(i))))
+            .collect();
+        tint.bands = bands;
+        tint.unknown = Some(materials.add(tint_material(scheme.band_color(usize::MAX))));
+    }
+
+    for (aircraft, children) in aircraft_query.iter() {
+        let target = match scheme.band_index(aircraft.altitude) {
+            Some(idx) => tint.bands[idx].clone(),
+            None => tint.unknown.clone().unwrap_or_default(),
+        };
+        apply_tint_to_hierarchy(children, &children_query, &mesh_query, &mut tint, &target, &mut commands);
+    }
+
+    // Forget meshes that were despawned along with their aircraft
+    tint.originals.retain(|entity, _| mesh_query.get(*entity).is_ok());
+}
+
+/// Recursively assign the band material to all meshes in the hierarchy.
+fn apply_tint_to_hierarchy(
+    children: &Children,
+    children_query: &Query<&Children>,
+    mesh_query: &Query<&MeshMaterial3d<StandardMaterial>>,
+    tint: &mut AltitudeTintMaterials,
+    target: &Handle<StandardMaterial>,
+    commands: &mut Commands,
+) {
+    for child in children.iter() {
+        if let Ok(mat_handle) = mesh_query.get(child) {
+            if mat_handle.0 != *target {
+                if !tint.is_tint(&mat_handle.0) {
+                    tint.originals.entry(child).or_insert_with(|| mat_handle.0.clone());
+                }
+                commands.entity(child).insert(MeshMaterial3d(target.clone()));
+            }
+        }
+        if let Ok(grandchildren) = children_query.get(child) {
+            apply_tint_to_hierarchy(grandchildren, children_query, mesh_query, tint, target, commands);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_altitude_uses_flight_levels_above_transition() {
+        let display = AltitudeDisplay::default();
+        assert_eq!(format_altitude(Some(35_000), &display), "FL350");
+        assert_eq!(format_altitude(Some(18_000), &display), "FL180");
+        assert_eq!(format_altitude(Some(17_500), &display), "17,500 ft");
+        assert_eq!(format_altitude(Some(-150), &display), "-150 ft");
+        assert_eq!(format_altitude(None, &display), "---");
+
+        let europe = AltitudeDisplay { transition_altitude: 6_000, ..display };
+        assert_eq!(format_altitude(Some(7_000), &europe), "FL070");
+
+        let feet_only = AltitudeDisplay { flight_levels: false, ..display };
+        assert_eq!(format_altitude(Some(35_000), &feet_only), "35,000 ft");
+    }
+
+    #[test]
+    fn altitude_source_falls_back_to_other_reading() {
+        assert_eq!(AltitudeSource::Barometric.select(Some(10_000), Some(10_150)), Some(10_000));
+        assert_eq!(AltitudeSource::Geometric.select(Some(10_000), Some(10_150)), Some(10_150));
+        assert_eq!(AltitudeSource::Geometric.select(Some(10_000), None), Some(10_000));
+        assert_eq!(AltitudeSource::Barometric.select(None, Some(10_150)), Some(10_150));
+        assert_eq!(AltitudeSource::Barometric.select(None, None), None);
+    }
+
+    #[test]
+    fn band_index_picks_highest_threshold_below_altitude() {
+        let scheme = AltitudeColorScheme::from_preset(AltitudeColorPreset::Grayscale);
+        assert_eq!(scheme.band_index(None), None);
+        assert_eq!(scheme.band_index(Some(-200)), Some(0));
+        assert_eq!(scheme.band_index(Some(9_999)), Some(0));
+        assert_eq!(scheme.band_index(Some(10_000)), Some(1));
+        assert_eq!(scheme.band_index(Some(45_000)), Some(4));
+    }
+
+    #[test]
+    fn stepped_scheme_returns_band_color() {
+        let scheme = AltitudeColorScheme::from_preset(AltitudeColorPreset::Grayscale);
+        assert_eq!(scheme.rgb(Some(15_000)), [140, 140, 140]);
+        assert_eq!(scheme.rgb(None), scheme.unknown_color);
+    }
+
+    #[test]
+    fn smooth_scheme_blends_between_bands() {
+        let mut scheme = AltitudeColorScheme::from_preset(AltitudeColorPreset::Grayscale);
+        scheme.smooth = true;
+        assert_eq!(scheme.rgb(Some(5_000)), [115, 115, 115]);
+        // Above the last threshold the top color holds
+        assert_eq!(scheme.rgb(Some(50_000)), [250, 250, 250]);
+    }
+
+    #[test]
+    fn normalize_sorts_and_dedups_bands() {
+        let mut scheme = AltitudeColorScheme::default();
+        scheme.bands.reverse();
+        scheme.bands.push(AltitudeBand::new(0, [1, 2, 3]));
+        scheme.normalize();
+        let thresholds: Vec<i32> = scheme.bands.iter().map(|b| b.min_ft).collect();
+        assert_eq!(thresholds, vec![0, 10_000, 20_000, 30_000, 40_000]);
     }
 }
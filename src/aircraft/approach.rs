@@ -0,0 +1,347 @@
+use bevy::prelude::*;
+use bevy_slippy_tiles::*;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::{Aircraft, MapState};
+use crate::aviation::{AviationData, LoadingState, Runway};
+use crate::geo::{haversine_distance_nm, initial_bearing, CoordinateConverter};
+
+/// Aircraft further than this from the threshold are not on approach
+const APPROACH_MAX_DISTANCE_NM: f64 = 8.0;
+/// Maximum difference between aircraft track and runway heading
+const APPROACH_MAX_TRACK_ERROR_DEG: f64 = 15.0;
+/// Maximum angle off the extended centerline, seen from the threshold
+const APPROACH_MAX_CENTERLINE_DEG: f64 = 8.0;
+/// Maximum height above the threshold, per NM of distance (~3° glidepath
+/// is 318 ft/NM) plus a fixed allowance for intercepts from above
+const APPROACH_MAX_FT_PER_NM: f64 = 500.0;
+const APPROACH_HEIGHT_ALLOWANCE_FT: f64 = 1000.0;
+/// Aircraft must be descending at least this fast
+const APPROACH_MIN_DESCENT_FPM: i32 = 200;
+/// Seconds between approach re-evaluations
+const APPROACH_CHECK_INTERVAL_SECS: f32 = 1.0;
+/// Quick reject: runways further than this in latitude degrees are skipped
+/// before the great-circle checks
+const APPROACH_LAT_WINDOW_DEG: f64 = APPROACH_MAX_DISTANCE_NM / 60.0 + 0.02;
+
+/// Attached to aircraft aligned with and descending toward a runway
+/// threshold; removed once they no longer qualify.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct OnApproach {
+    pub airport_ident: String,
+    pub runway_ident: String,
+    pub threshold_latitude: f64,
+    pub threshold_longitude: f64,
+    pub distance_nm: f64,
+}
+
+impl OnApproach {
+    /// Short badge text, e.g. "APP KSEA 16R"
+    pub fn badge(&self) -> String {
+        format!("APP {} {}", self.airport_ident, self.runway_ident)
+    }
+}
+
+/// Resource controlling approach detection and the map highlight.
+/// `show_on_map` is synced from `OverlayConfig::show_approaches`.
+#[derive(Resource)]
+pub struct ApproachDetection {
+    /// Draw a line from each approaching aircraft to its runway threshold
+    pub show_on_map: bool,
+    last_check: Option<Instant>,
+    grid: RunwayGrid,
+}
+
+impl Default for ApproachDetection {
+    fn default() -> Self {
+        Self {
+            show_on_map: true,
+            last_check: None,
+            grid: RunwayGrid::default(),
+        }
+    }
+}
+
+/// Runway indices bucketed by the whole-degree cell of each threshold, so
+/// each aircraft is only checked against runways in its neighbourhood.
+/// Rebuilt whenever the runway count changes.
+#[derive(Default)]
+struct RunwayGrid {
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    runway_count: usize,
+}
+
+fn grid_cell(latitude: f64, longitude: f64) -> (i32, i32) {
+    (latitude.floor() as i32, longitude.rem_euclid(360.0).floor() as i32)
+}
+
+impl RunwayGrid {
+    fn build(runways: &[Runway]) -> Self {
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (index, runway) in runways.iter().enumerate() {
+            let mut keys: Vec<(i32, i32)> = runway_ends(runway)
+                .map(|end| grid_cell(end.latitude, end.longitude))
+                .collect();
+            keys.dedup();
+            for key in keys {
+                cells.entry(key).or_default().push(index);
+            }
+        }
+        Self { cells, runway_count: runways.len() }
+    }
+
+    /// Indices of runways with a threshold in the cell containing the
+    /// position or any of its eight neighbours. A degree of latitude is
+    /// 60 NM, well beyond `APPROACH_MAX_DISTANCE_NM`; a degree of longitude
+    /// shrinks toward the poles, where a few more runways may be missed.
+    fn nearby(&self, latitude: f64, longitude: f64) -> Vec<usize> {
+        let (lat, lon) = grid_cell(latitude, longitude);
+        let mut indices = Vec::new();
+        for dlat in -1..=1 {
+            for dlon in -1..=1 {
+                let key = (lat + dlat, (lon + dlon).rem_euclid(360));
+                if let Some(cell) = self.cells.get(&key) {
+                    indices.extend_from_slice(cell);
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+/// One landing direction of a runway: the threshold the aircraft touches
+/// down at and the heading it lands on.
+struct RunwayEnd<'a> {
+    ident: &'a str,
+    latitude: f64,
+    longitude: f64,
+    elevation_ft: i32,
+    heading: f64,
+}
+
+fn runway_ends(runway: &Runway) -> impl Iterator<Item = RunwayEnd<'_>> {
+    let le = match (&runway.le_ident, runway.le_latitude_deg, runway.le_longitude_deg, runway.le_heading_deg_t) {
+        (Some(ident), Some(latitude), Some(longitude), Some(heading)) => Some(RunwayEnd {
+            ident,
+            latitude,
+            longitude,
+            elevation_ft: runway.le_elevation_ft.unwrap_or(0),
+            heading,
+        }),
+        _ => None,
+    };
+    let he = match (&runway.he_ident, runway.he_latitude_deg, runway.he_longitude_deg, runway.he_heading_deg_t) {
+        (Some(ident), Some(latitude), Some(longitude), Some(heading)) => Some(RunwayEnd {
+            ident,
+            latitude,
+            longitude,
+            elevation_ft: runway.he_elevation_ft.unwrap_or(0),
+            heading,
+        }),
+        _ => None,
+    };
+    le.into_iter().chain(he)
+}
+
+fn angle_diff(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+/// Find the runway an aircraft is on approach to, if any: within range of
+/// the threshold, on the extended centerline, tracking the runway heading,
+/// descending and below a generous glidepath ceiling. The nearest
+/// qualifying threshold wins.
+pub fn find_approach<'a>(aircraft: &Aircraft, runways: impl Iterator<Item = &'a Runway>) -> Option<OnApproach> {
+    let heading = aircraft.heading? as f64;
+    let altitude = aircraft.altitude?;
+    if aircraft.vertical_rate? > -APPROACH_MIN_DESCENT_FPM || aircraft.is_on_ground == Some(true) {
+        return None;
+    }
+
+    let mut best: Option<OnApproach> = None;
+    for runway in runways {
+        if runway.is_closed() {
+            continue;
+        }
+        for end in runway_ends(runway) {
+            if (end.latitude - aircraft.latitude).abs() > APPROACH_LAT_WINDOW_DEG {
+                continue;
+            }
+            if angle_diff(heading, end.heading) > APPROACH_MAX_TRACK_ERROR_DEG {
+                continue;
+            }
+            let distance = haversine_distance_nm(aircraft.latitude, aircraft.longitude, end.latitude, end.longitude);
+            if distance > APPROACH_MAX_DISTANCE_NM || best.as_ref().is_some_and(|b| b.distance_nm <= distance) {
+                continue;
+            }
+            // The aircraft sits on the reciprocal of the runway heading as
+            // seen from the threshold
+            let from_threshold = initial_bearing(end.latitude, end.longitude, aircraft.latitude, aircraft.longitude);
+            if distance > 0.2 && angle_diff(from_threshold, end.heading + 180.0) > APPROACH_MAX_CENTERLINE_DEG {
+                continue;
+            }
+            let height = (altitude - end.elevation_ft) as f64;
+            if height > distance * APPROACH_MAX_FT_PER_NM + APPROACH_HEIGHT_ALLOWANCE_FT {
+                continue;
+            }
+            best = Some(OnApproach {
+                airport_ident: runway.airport_ident.clone(),
+                runway_ident: end.ident.to_string(),
+                threshold_latitude: end.latitude,
+                threshold_longitude: end.longitude,
+                distance_nm: distance,
+            });
+        }
+    }
+    best
+}
+
+/// System that flags aircraft on approach to a runway, re-evaluated about
+/// once per second.
+pub fn detect_approaches(
+    mut commands: Commands,
+    mut detection: ResMut<ApproachDetection>,
+    aviation_data: Res<AviationData>,
+    aircraft_query: Query<(Entity, &Aircraft, Option<&OnApproach>)>,
+) {
+    if aviation_data.loading_state != LoadingState::Ready {
+        return;
+    }
+
+    let now = Instant::now();
+    if detection.last_check.is_some_and(|t| now.duration_since(t).as_secs_f32() < APPROACH_CHECK_INTERVAL_SECS) {
+        return;
+    }
+    detection.last_check = Some(now);
+
+    if detection.grid.runway_count != aviation_data.runways.len() {
+        detection.grid = RunwayGrid::build(&aviation_data.runways);
+    }
+
+    for (entity, aircraft, current) in aircraft_query.iter() {
+        let nearby = detection.grid.nearby(aircraft.latitude, aircraft.longitude);
+        let runways = nearby.into_iter().map(|i| &aviation_data.runways[i]);
+        match (find_approach(aircraft, runways), current) {
+            (Some(approach), Some(current)) if approach == *current => {}
+            (Some(approach), _) => {
+                commands.entity(entity).insert(approach);
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<OnApproach>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// System to draw a line from each approaching aircraft to its runway threshold.
+pub fn draw_approach_lines(
+    mut gizmos: Gizmos,
+    detection: Res<ApproachDetection>,
+    tile_settings: Res<SlippyTilesSettings>,
+    map_state: Res<MapState>,
+    view3d_state: Res<crate::view3d::View3DState>,
+    aircraft_query: Query<(&Aircraft, &OnApproach)>,
+) {
+    if !detection.show_on_map {
+        return;
+    }
+    if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        return;
+    }
+
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let color = Color::srgba(0.3, 1.0, 0.6, 0.8);
+    for (aircraft, approach) in aircraft_query.iter() {
+        let from = converter.latlon_to_world(aircraft.latitude, aircraft.longitude);
+        let to = converter.latlon_to_world(approach.threshold_latitude, approach.threshold_longitude);
+        gizmos.line_2d(from, to, color);
+        gizmos.circle_2d(to, 4.0, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runway() -> Runway {
+        // North-south runway 16/34, 16 threshold at the north end
+        Runway {
+            id: 1,
+            airport_ref: 1,
+            airport_ident: "KTST".to_string(),
+            length_ft: Some(9000),
+            width_ft: Some(150),
+            surface: None,
+            lighted: None,
+            closed: None,
+            le_ident: Some("16".to_string()),
+            le_latitude_deg: Some(47.0),
+            le_longitude_deg: Some(-122.0),
+            le_elevation_ft: Some(400),
+            le_heading_deg_t: Some(180.0),
+            he_ident: Some("34".to_string()),
+            he_latitude_deg: Some(46.975),
+            he_longitude_deg: Some(-122.0),
+            he_elevation_ft: Some(400),
+            he_heading_deg_t: Some(0.0),
+        }
+    }
+
+    fn aircraft(latitude: f64, heading: f32, altitude: i32, vertical_rate: i32) -> Aircraft {
+        Aircraft {
+            icao: "ABCDEF".to_string(),
+            callsign: None,
+            latitude,
+            longitude: -122.0,
+            altitude: Some(altitude),
+            barometric_altitude: Some(altitude),
+            geometric_altitude: None,
+            heading: Some(heading),
+            velocity: Some(140.0),
+            vertical_rate: Some(vertical_rate),
+            squawk: None,
+            is_on_ground: Some(false),
+            alert: None,
+            emergency: None,
+            spi: None,
+            last_seen: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn flags_aligned_descending_aircraft() {
+        let runways = [runway()];
+        // 5 NM north of the 16 threshold, southbound, ~1,600 ft above field
+        let approach = find_approach(&aircraft(47.0833, 178.0, 2000, -700), runways.iter()).unwrap();
+        assert_eq!(approach.runway_ident, "16");
+        assert_eq!(approach.badge(), "APP KTST 16");
+        assert!((approach.distance_nm - 5.0).abs() < 0.1);
+
+        // Climbing out, wrong direction, or far too high
+        assert!(find_approach(&aircraft(47.0833, 178.0, 2000, 1500), runways.iter()).is_none());
+        assert!(find_approach(&aircraft(47.0833, 0.0, 2000, -700), runways.iter()).is_none());
+        assert!(find_approach(&aircraft(47.0833, 178.0, 9000, -700), runways.iter()).is_none());
+    }
+
+    #[test]
+    fn grid_only_returns_nearby_runways() {
+        let mut far = runway();
+        far.le_latitude_deg = Some(10.0);
+        far.he_latitude_deg = Some(9.975);
+        let mut antimeridian = runway();
+        antimeridian.le_longitude_deg = Some(179.9);
+        antimeridian.he_longitude_deg = Some(179.9);
+        let grid = RunwayGrid::build(&[runway(), far, antimeridian]);
+
+        assert_eq!(grid.nearby(47.05, -122.0), vec![0]);
+        assert_eq!(grid.nearby(10.0, -122.0), vec![1]);
+        // Neighbouring cells wrap across the antimeridian
+        assert_eq!(grid.nearby(47.0, -179.9), vec![2]);
+        assert!(grid.nearby(0.0, 0.0).is_empty());
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use chrono::{DateTime, Utc};
 
+use super::altitude::AltitudeSource;
+
 /// Component for aircraft entities
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -13,8 +15,13 @@ pub struct Aircraft {
     pub latitude: f64,
     /// Current longitude in degrees
     pub longitude: f64,
-    /// Altitude in feet
+    /// Altitude in feet from the configured `AltitudeSource`; everything
+    /// that shows, filters or places by altitude reads this one
     pub altitude: Option<i32>,
+    /// Barometric (pressure) altitude in feet, as reported
+    pub barometric_altitude: Option<i32>,
+    /// Geometric (GNSS) altitude in feet, as reported
+    pub geometric_altitude: Option<i32>,
     /// Track/heading in degrees (0-360)
     pub heading: Option<f32>,
     /// Ground speed in knots
@@ -36,6 +43,20 @@ pub struct Aircraft {
     pub last_seen: DateTime<Utc>,
 }
 
+impl Aircraft {
+    /// Set both reported altitudes and pick the effective one
+    pub fn set_altitudes(&mut self, barometric: Option<i32>, geometric: Option<i32>, source: AltitudeSource) {
+        self.barometric_altitude = barometric;
+        self.geometric_altitude = geometric;
+        self.altitude = source.select(barometric, geometric);
+    }
+
+    /// Geometric minus barometric altitude, when both are known
+    pub fn geometric_offset(&self) -> Option<i32> {
+        Some(self.geometric_altitude? - self.barometric_altitude?)
+    }
+}
+
 /// Component to link aircraft labels to their aircraft
 #[derive(Component)]
 pub struct AircraftLabel {
@@ -0,0 +1,317 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+use std::collections::HashMap;
+
+use crate::{Aircraft, AircraftLabel};
+use super::list_panel::AircraftListState;
+use super::picking::{HoverOutline, SelectionOutline};
+
+/// Opacity of out-of-band aircraft when dimming rather than hiding
+pub const DECLUTTER_DIM_OPACITY: f32 = 0.2;
+/// Seconds for a full fade between in-band and dimmed/hidden
+const DECLUTTER_FADE_SECS: f32 = 0.3;
+
+/// Map-level altitude band used to declutter busy airspace.
+///
+/// Independent of `AircraftFilters` (which only affects the list): aircraft
+/// outside the band keep their entities and list rows but their markers,
+/// labels and trails fade to a dim opacity on the map, or out entirely when
+/// `hide_completely` is set.
+#[derive(Resource)]
+pub struct AltitudeDeclutter {
+    pub enabled: bool,
+    pub min_altitude: i32,
+    pub max_altitude: i32,
+    /// Fade out-of-band aircraft out entirely instead of dimming them
+    pub hide_completely: bool,
+    /// Whether the controls popup is expanded in the aircraft pane
+    pub show_controls: bool,
+}
+
+impl Default for AltitudeDeclutter {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_altitude: 0,
+            max_altitude: 60000,
+            hide_completely: false,
+            show_controls: false,
+        }
+    }
+}
+
+impl AltitudeDeclutter {
+    /// Whether an aircraft at `altitude` falls outside the band.
+    /// Aircraft without an altitude report count as outside while the band is
+    /// active since they cannot be placed inside it.
+    pub fn hides(&self, altitude: Option<i32>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match altitude {
+            Some(alt) => alt < self.min_altitude || alt > self.max_altitude,
+            None => true,
+        }
+    }
+
+    /// Opacity an aircraft at `altitude` should settle at
+    pub fn target_opacity(&self, altitude: Option<i32>) -> f32 {
+        if !self.hides(altitude) {
+            1.0
+        } else if self.hide_completely {
+            0.0
+        } else {
+            DECLUTTER_DIM_OPACITY
+        }
+    }
+}
+
+/// Marker opacity for an aircraft leaving the list's range, `progress`
+/// through the grace period (see `AircraftListState::leaving_progress`):
+/// fades from full to the dim opacity, then returns to normal once the
+/// aircraft drops out of the list.
+pub fn leaving_opacity(progress: f32) -> f32 {
+    1.0 - progress.clamp(0.0, 1.0) * (1.0 - DECLUTTER_DIM_OPACITY)
+}
+
+/// Move `current` opacity toward `target` at the declutter fade rate
+pub fn step_fade(current: f32, target: f32, dt: f32) -> f32 {
+    let max_step = dt / DECLUTTER_FADE_SECS;
+    current + (target - current).clamp(-max_step, max_step)
+}
+
+/// Marker component on aircraft fully hidden by the altitude declutter band.
+/// Trail and label systems skip entities carrying it.
+#[derive(Component)]
+pub struct AltitudeHidden;
+
+/// Current declutter opacity of an aircraft that is faded or fading, either
+/// by the altitude band or because it is leaving the list's range. Removed
+/// once the aircraft is back at full opacity. Label, trail and
+/// model-material systems multiply their alpha by it.
+#[derive(Component)]
+pub struct DeclutterFade(pub f32);
+
+/// System that fades aircraft outside the declutter band, leaving the list's
+/// range or (optionally) squawking VFR, and hides them once fully faded in
+/// "hide completely" mode.
+pub fn apply_altitude_declutter(
+    mut commands: Commands,
+    time: Res<Time>,
+    declutter: Res<AltitudeDeclutter>,
+    list_state: Res<AircraftListState>,
+    app_config: Res<crate::config::AppConfig>,
+    mut aircraft_query: Query<(Entity, &Aircraft, &mut Visibility, Has<AltitudeHidden>, Option<&DeclutterFade>)>,
+    mut label_query: Query<(&AircraftLabel, &mut Visibility), Without<Aircraft>>,
+) {
+    let dt = time.delta_secs();
+    for (entity, aircraft, mut visibility, is_hidden, fade) in aircraft_query.iter_mut() {
+        let current = fade.map(|f| f.0).unwrap_or(1.0);
+        let leaving = list_state.leaving_progress(&aircraft.icao).map_or(1.0, leaving_opacity);
+        let appearance = &app_config.appearance;
+        let vfr = if appearance.dim_vfr_on_map && appearance.is_vfr_squawk(aircraft.squawk.as_deref()) {
+            DECLUTTER_DIM_OPACITY
+        } else {
+            1.0
+        };
+        let target = declutter.target_opacity(aircraft.altitude).min(leaving).min(vfr);
+        let opacity = step_fade(current, target, dt);
+
+        if opacity >= 1.0 {
+            if fade.is_some() {
+                commands.entity(entity).remove::<DeclutterFade>();
+            }
+        } else if opacity != current || fade.is_none() {
+            commands.entity(entity).insert(DeclutterFade(opacity));
+        }
+
+        let hide = target <= 0.0 && opacity <= 0.0;
+        if hide && !is_hidden {
+            commands.entity(entity).insert(AltitudeHidden);
+            *visibility = Visibility::Hidden;
+        } else if !hide && is_hidden {
+            commands.entity(entity).remove::<AltitudeHidden>();
+            *visibility = Visibility::Inherited;
+        }
+    }
+
+    // Labels are separate entities; hide them alongside their aircraft once
+    // fully faded (dimming is applied by dim_stale_aircraft). Restoring is
+    // left to update_aircraft_3d_transform, which owns label visibility
+    // across 2D/3D mode changes.
+    for (label, mut visibility) in label_query.iter_mut() {
+        let Ok((_, aircraft, _, _, fade)) = aircraft_query.get(label.aircraft_entity) else {
+            continue;
+        };
+        let faded_out = declutter.target_opacity(aircraft.altitude) <= 0.0
+            && fade.is_some_and(|f| f.0 <= 0.0);
+        if faded_out && *visibility != Visibility::Hidden {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+/// Per-mesh translucent copies of aircraft model materials used while an
+/// aircraft is faded, plus the materials they replaced.
+#[derive(Resource, Default)]
+pub struct DeclutterFadeMaterials {
+    faded: HashMap<Entity, (Handle<StandardMaterial>, Handle<StandardMaterial>)>,
+}
+
+/// System that applies `DeclutterFade` opacity to aircraft models by giving
+/// each faded mesh its own blended copy of its material, and restores the
+/// original once the aircraft is back at full opacity. Selected and hovered
+/// aircraft are left to the outline material swap.
+pub fn fade_declutter_materials(
+    mut fade_materials: ResMut<DeclutterFadeMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    aircraft_query: Query<(&Children, Option<&DeclutterFade>), (With<Aircraft>, Without<SelectionOutline>, Without<HoverOutline>)>,
+    children_query: Query<&Children>,
+    mesh_query: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    for (children, fade) in aircraft_query.iter() {
+        let mut meshes = Vec::new();
+        collect_meshes(children, &children_query, &mesh_query, &mut meshes);
+
+        for mesh in meshes {
+            let Ok(current) = mesh_query.get(mesh) else {
+                continue;
+            };
+            match fade {
+                Some(fade) => {
+                    let faded = match fade_materials.faded.get(&mesh) {
+                        Some((_, faded)) if *faded == current.0 => faded.clone(),
+                        _ => {
+                            let mut copy = materials.get(&current.0).cloned().unwrap_or_default();
+                            copy.alpha_mode = AlphaMode::Blend;
+                            let faded = materials.add(copy);
+                            fade_materials.faded.insert(mesh, (current.0.clone(), faded.clone()));
+                            commands.entity(mesh).insert(MeshMaterial3d(faded.clone()));
+                            faded
+                        }
+                    };
+                    // get_mut flags the asset for re-upload, so only take it
+                    // when the alpha actually moved
+                    let stale = materials.get(&faded).is_some_and(|m| m.base_color.alpha() != fade.0);
+                    if stale {
+                        if let Some(material) = materials.get_mut(&faded) {
+                            material.base_color.set_alpha(fade.0);
+                        }
+                    }
+                }
+                None => {
+                    if let Some((original, faded)) = fade_materials.faded.remove(&mesh) {
+                        if current.0 == faded {
+                            commands.entity(mesh).insert(MeshMaterial3d(original));
+                        }
+                        materials.remove(&faded);
+                    }
+                }
+            }
+        }
+    }
+
+    // Forget meshes that were despawned along with their aircraft
+    fade_materials.faded.retain(|entity, _| mesh_query.get(*entity).is_ok());
+}
+
+fn collect_meshes(
+    children: &Children,
+    children_query: &Query<&Children>,
+    mesh_query: &Query<&MeshMaterial3d<StandardMaterial>>,
+    out: &mut Vec<Entity>,
+) {
+    for child in children.iter() {
+        if mesh_query.get(child).is_ok() {
+            out.push(child);
+        }
+        if let Ok(grandchildren) = children_query.get(child) {
+            collect_meshes(grandchildren, children_query, mesh_query, out);
+        }
+    }
+}
+
+/// Render the map declutter controls (enable toggle + altitude band sliders).
+pub fn render_declutter_controls(ui: &mut egui::Ui, declutter: &mut AltitudeDeclutter, header_color: egui::Color32) {
+    ui.checkbox(&mut declutter.enabled,
+        egui::RichText::new("Only show altitude band on map")
+            .color(header_color)
+            .size(10.0));
+    ui.add_enabled_ui(declutter.enabled, |ui| {
+        ui.add(egui::Slider::new(&mut declutter.min_altitude, 0..=60000)
+            .step_by(500.0)
+            .text("Min ft"));
+        ui.add(egui::Slider::new(&mut declutter.max_altitude, 0..=60000)
+            .step_by(500.0)
+            .text("Max ft"));
+    });
+    if declutter.min_altitude > declutter.max_altitude {
+        declutter.max_altitude = declutter.min_altitude;
+    }
+    ui.add_enabled_ui(declutter.enabled, |ui| {
+        ui.checkbox(&mut declutter.hide_completely,
+            egui::RichText::new("Hide completely (instead of dimming)")
+                .color(header_color)
+                .size(10.0));
+    });
+    let effect = if declutter.hide_completely { "Hides" } else { "Dims" };
+    ui.label(egui::RichText::new(format!("{} markers and trails; the list is unaffected", effect))
+        .color(egui::Color32::GRAY)
+        .size(9.0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_band_hides_nothing() {
+        let declutter = AltitudeDeclutter::default();
+        assert!(!declutter.hides(Some(35_000)));
+        assert!(!declutter.hides(None));
+    }
+
+    #[test]
+    fn enabled_band_hides_outside_range_and_unknown() {
+        let declutter = AltitudeDeclutter {
+            enabled: true,
+            min_altitude: 0,
+            max_altitude: 5000,
+            ..Default::default()
+        };
+        assert!(!declutter.hides(Some(3000)));
+        assert!(!declutter.hides(Some(5000)));
+        assert!(declutter.hides(Some(5001)));
+        assert!(declutter.hides(None));
+    }
+
+    #[test]
+    fn out_of_band_dims_unless_hiding_completely() {
+        let mut declutter = AltitudeDeclutter {
+            enabled: true,
+            max_altitude: 5000,
+            ..Default::default()
+        };
+        assert_eq!(declutter.target_opacity(Some(3000)), 1.0);
+        assert_eq!(declutter.target_opacity(Some(9000)), DECLUTTER_DIM_OPACITY);
+        declutter.hide_completely = true;
+        assert_eq!(declutter.target_opacity(Some(9000)), 0.0);
+    }
+
+    #[test]
+    fn fade_steps_toward_target_without_overshoot() {
+        let half = step_fade(1.0, 0.0, DECLUTTER_FADE_SECS / 2.0);
+        assert!((half - 0.5).abs() < 1e-5);
+        assert_eq!(step_fade(half, 0.0, 1.0), 0.0);
+        assert_eq!(step_fade(0.9, 1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn leaving_aircraft_fade_to_dim_over_grace_period() {
+        assert_eq!(leaving_opacity(0.0), 1.0);
+        assert!((leaving_opacity(0.5) - (1.0 + DECLUTTER_DIM_OPACITY) / 2.0).abs() < 1e-6);
+        assert_eq!(leaving_opacity(1.0), DECLUTTER_DIM_OPACITY);
+        assert_eq!(leaving_opacity(3.0), DECLUTTER_DIM_OPACITY);
+    }
+}
@@ -57,10 +57,11 @@ pub fn render_detail_panel(
 /// System to toggle detail panel with D key
 pub fn toggle_detail_panel(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut detail_state: ResMut<DetailPanelState>,
     list_state: Res<AircraftListState>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyD) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleDetail, &keyboard) {
         if list_state.selected_icao.is_some() {
             detail_state.open = !detail_state.open;
             if detail_state.open && detail_state.track_start.is_none() {
@@ -1,14 +1,22 @@
 use bevy::prelude::*;
 use bevy_slippy_tiles::*;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
 use crate::{Aircraft, MapState};
+use crate::config::AppConfig;
+use crate::event_log::{EventCategory, EventLog};
 use crate::geo::CoordinateConverter;
+use super::{AircraftListState, CameraFollowState};
 
 /// Emergency squawk codes
 pub const SQUAWK_HIJACK: &str = "7500";      // Aircraft hijacking
 pub const SQUAWK_RADIO_FAIL: &str = "7600";  // Radio failure
 pub const SQUAWK_EMERGENCY: &str = "7700";   // General emergency
 
+/// How long the banner flashes after auto-follow jumps to an emergency
+const AUTO_FOLLOW_FLASH: Duration = Duration::from_secs(4);
+
 /// Resource to track active emergency alerts
 #[derive(Resource, Default)]
 pub struct EmergencyAlertState {
@@ -16,6 +24,26 @@ pub struct EmergencyAlertState {
     pub active_emergencies: Vec<EmergencyInfo>,
     /// Animation timer for pulsing effect
     pub pulse_timer: f32,
+    /// Every (ICAO, squawk) emergency seen this session, so auto-follow
+    /// jumps to each one only once
+    seen: HashSet<(String, String)>,
+    /// When auto-follow last switched to an emergency
+    auto_followed_at: Option<Instant>,
+}
+
+impl EmergencyAlertState {
+    /// Record the active emergencies as seen and return the ICAO of the
+    /// first one that wasn't seen before, if any.
+    pub fn take_new_emergency(&mut self) -> Option<String> {
+        let mut first_new = None;
+        for emergency in &self.active_emergencies {
+            let key = (emergency.icao.clone(), emergency.squawk.clone());
+            if self.seen.insert(key) && first_new.is_none() {
+                first_new = Some(emergency.icao.clone());
+            }
+        }
+        first_new
+    }
 }
 
 /// Information about an emergency aircraft
@@ -67,6 +95,7 @@ pub fn detect_emergencies(
     mut alert_state: ResMut<EmergencyAlertState>,
     aircraft_query: Query<&Aircraft>,
     time: Res<Time>,
+    mut events: ResMut<EventLog>,
 ) {
     // Update pulse timer
     alert_state.pulse_timer += time.delta_secs() * 3.0; // 3Hz pulse
@@ -74,12 +103,24 @@ pub fn detect_emergencies(
         alert_state.pulse_timer -= std::f32::consts::TAU;
     }
 
-    // Clear and rebuild emergency list
-    alert_state.active_emergencies.clear();
+    // Clear and rebuild emergency list, logging ones that weren't active
+    let previous = std::mem::take(&mut alert_state.active_emergencies);
 
     for aircraft in aircraft_query.iter() {
         if let Some(ref squawk) = aircraft.squawk {
             if let Some(emergency_type) = EmergencyType::from_squawk(squawk) {
+                if !previous.iter().any(|e| e.icao == aircraft.icao && &e.squawk == squawk) {
+                    events.record(
+                        EventCategory::Emergency,
+                        format!(
+                            "{} ({}) squawking {} {}",
+                            aircraft.callsign.as_deref().map(str::trim).unwrap_or("-"),
+                            aircraft.icao,
+                            squawk,
+                            emergency_type.description(),
+                        ),
+                    );
+                }
                 alert_state.active_emergencies.push(EmergencyInfo {
                     icao: aircraft.icao.clone(),
                     callsign: aircraft.callsign.clone(),
@@ -91,6 +132,28 @@ pub fn detect_emergencies(
     }
 }
 
+/// System that follows a newly detected emergency aircraft when
+/// `FeedConfig::auto_follow_emergencies` is on. Emergencies already active
+/// when the option is turned on count as seen and are not followed.
+pub fn auto_follow_emergencies(
+    app_config: Res<AppConfig>,
+    mut alert_state: ResMut<EmergencyAlertState>,
+    mut follow_state: ResMut<CameraFollowState>,
+    mut list_state: ResMut<AircraftListState>,
+) {
+    let Some(icao) = alert_state.take_new_emergency() else {
+        return;
+    };
+    if !app_config.feed.auto_follow_emergencies || follow_state.following_icao.as_deref() == Some(icao.as_str()) {
+        return;
+    }
+    info!("Auto-following emergency aircraft {}", icao);
+    follow_state.following_icao = Some(icao.clone());
+    list_state.selected_icao = Some(icao);
+    list_state.scroll_to_selected = true;
+    alert_state.auto_followed_at = Some(Instant::now());
+}
+
 /// System to draw flashing rings around emergency aircraft
 pub fn draw_emergency_rings(
     mut gizmos: Gizmos,
@@ -185,6 +248,27 @@ pub fn update_emergency_banner(
     });
 }
 
+/// System that flashes the banner for a few seconds after auto-follow
+/// jumps to an emergency, then settles back to the steady color.
+pub fn flash_emergency_banner(
+    alert_state: Res<EmergencyAlertState>,
+    theme: Res<crate::theme::AppTheme>,
+    mut banner_query: Query<&mut BackgroundColor, With<EmergencyBanner>>,
+) {
+    let flashing = alert_state
+        .auto_followed_at
+        .is_some_and(|t| t.elapsed() < AUTO_FOLLOW_FLASH);
+    let alpha = if flashing {
+        // Twice the ring pulse rate, dipping to near transparent
+        0.15 + 0.85 * ((alert_state.pulse_timer * 2.0).sin() + 1.0) / 2.0
+    } else {
+        0.9
+    };
+    for mut background in banner_query.iter_mut() {
+        background.set_if_neq(BackgroundColor(theme.text_error().with_alpha(alpha)));
+    }
+}
+
 /// System to update emergency banner text with current emergencies
 pub fn update_emergency_banner_text(
     alert_state: Res<EmergencyAlertState>,
@@ -212,3 +296,33 @@ pub fn update_emergency_banner_text(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emergency(icao: &str, squawk: &str) -> EmergencyInfo {
+        EmergencyInfo {
+            icao: icao.to_string(),
+            callsign: None,
+            squawk: squawk.to_string(),
+            emergency_type: EmergencyType::from_squawk(squawk).unwrap(),
+        }
+    }
+
+    #[test]
+    fn each_emergency_is_new_only_once() {
+        let mut state = EmergencyAlertState::default();
+        state.active_emergencies = vec![emergency("ABC123", "7700")];
+        assert_eq!(state.take_new_emergency().as_deref(), Some("ABC123"));
+        assert_eq!(state.take_new_emergency(), None);
+
+        // Another aircraft joins; the first stays seen
+        state.active_emergencies.push(emergency("DEF456", "7600"));
+        assert_eq!(state.take_new_emergency().as_deref(), Some("DEF456"));
+
+        // A different squawk from the same aircraft is a new emergency
+        state.active_emergencies = vec![emergency("ABC123", "7500")];
+        assert_eq!(state.take_new_emergency().as_deref(), Some("ABC123"));
+    }
+}
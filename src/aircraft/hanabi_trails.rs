@@ -3,8 +3,11 @@ use bevy::image::{ImageSampler, ImageAddressMode, ImageFilterMode, ImageSamplerD
 use bevy_hanabi::prelude::*;
 use bevy_slippy_tiles::SlippyTilesSettings;
 
+use super::{AircraftListState, CameraFollowState};
 use super::components::Aircraft;
-use super::trails::{altitude_color, TrailConfig, TrailRenderer};
+use super::altitude::AltitudeColorScheme;
+use super::declutter::AltitudeHidden;
+use super::trails::{TrailConfig, TrailRenderer};
 use crate::geo::CoordinateConverter;
 use crate::view3d::View3DState;
 use crate::MapState;
@@ -225,8 +228,11 @@ pub fn update_trail_particles(
     map_state: Res<MapState>,
     view3d_state: Res<View3DState>,
     trail_config: Res<TrailConfig>,
-    aircraft_query: Query<&Aircraft>,
-    mut effect_query: Query<(&TrailEffect, &mut Transform, &mut EffectProperties)>,
+    altitude_colors: Res<AltitudeColorScheme>,
+    list_state: Res<AircraftListState>,
+    follow_state: Res<CameraFollowState>,
+    aircraft_query: Query<(&Aircraft, Has<AltitudeHidden>)>,
+    mut effect_query: Query<(&TrailEffect, &mut Transform, &mut EffectProperties, &mut Visibility)>,
 ) {
     if !trail_config.enabled {
         return;
@@ -235,15 +241,27 @@ pub fn update_trail_particles(
     let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
     let is_3d = view3d_state.is_3d_active();
 
-    for (trail_effect, mut transform, mut properties) in effect_query.iter_mut() {
-        let Ok(aircraft) = aircraft_query.get(trail_effect.aircraft_entity) else {
+    for (trail_effect, mut transform, mut properties, mut visibility) in effect_query.iter_mut() {
+        let Ok((aircraft, altitude_hidden)) = aircraft_query.get(trail_effect.aircraft_entity) else {
             continue;
         };
 
+        // Hide the emitter while the aircraft is outside the map declutter band
+        // or excluded by the trail display mode
+        let shown = trail_config.display_mode.shows(
+            &aircraft.icao,
+            list_state.selected_icao.as_deref(),
+            follow_state.following_icao.as_deref(),
+        );
+        let target_visibility = if altitude_hidden || !shown { Visibility::Hidden } else { Visibility::Inherited };
+        if *visibility != target_visibility {
+            *visibility = target_visibility;
+        }
+
         // Convert aircraft lat/lon to world position
         let xy = converter.latlon_to_world(aircraft.latitude, aircraft.longitude);
         let z = if is_3d {
-            view3d_state.altitude_to_z(aircraft.altitude.unwrap_or(0))
+            view3d_state.aircraft_height(aircraft.altitude)
         } else {
             0.0
         };
@@ -272,7 +290,7 @@ pub fn update_trail_particles(
         };
 
         // Update spawn color based on current altitude
-        let color = altitude_color(aircraft.altitude);
+        let color = altitude_colors.color(aircraft.altitude);
         let linear = color.to_linear();
         let color_vec4 = Vec4::new(linear.red, linear.green, linear.blue, 1.0);
 
@@ -1,16 +1,34 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 use crate::MapState;
+use crate::data_sources::DataSourceManager;
+use crate::deeplink::{aircraft_details_text, DeepLink};
 use crate::geo::{haversine_distance_nm, CoordinateConverter};
 use crate::theme::{AppTheme, to_egui_color32, to_egui_color32_alpha};
 use crate::widgets::{DataStrip, ArcGauge, WidgetTheme};
-use super::{CameraFollowState, DetailPanelState, TrailHistory, SessionClock};
-use super::typeinfo::AircraftTypeInfo;
-use super::altitude::{format_altitude, format_altitude_with_indicator};
+use super::{AltitudeTrend, CameraFollowState, DetailPanelState, TrailHistory, SessionClock};
+use super::airlines::AirlineDirectory;
+use super::typeinfo::{AircraftTypeInfo, TypeLookupPending};
+use super::wake::WakeCategory;
+use super::declutter::{AltitudeDeclutter, render_declutter_controls};
+use super::route::{self, RouteOverlay};
+use super::approach::OnApproach;
+use super::altitude::{format_altitude, format_altitude_with_indicator, AltitudeColorScheme};
+
+/// How long an aircraft that leaves the max-distance filter stays listed
+/// (greyed, marked as leaving) before it drops out of the list
+pub const LEAVING_GRACE: Duration = Duration::from_secs(8);
+/// Opacity of list rows for aircraft that are leaving
+const LEAVING_ROW_OPACITY: f32 = 0.45;
+/// Opacity of list rows for VFR traffic when greyed out
+const VFR_ROW_OPACITY: f32 = 0.55;
 
 /// Sort criteria for aircraft list
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub enum SortCriteria {
     #[default]
     Distance,
@@ -30,10 +48,48 @@ impl SortCriteria {
             SortCriteria::Type => "Type",
         }
     }
+
+    /// Direction used until the user picks one: nearest, highest and
+    /// fastest first; names alphabetical.
+    pub fn default_ascending(&self) -> bool {
+        match self {
+            SortCriteria::Distance | SortCriteria::Callsign | SortCriteria::Type => true,
+            SortCriteria::Altitude | SortCriteria::Speed => false,
+        }
+    }
+}
+
+/// List filter on the VFR conspicuity squawk (`AppearanceConfig::vfr_squawk`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VfrFilter {
+    #[default]
+    All,
+    OnlyVfr,
+    HideVfr,
+}
+
+impl VfrFilter {
+    pub const ALL: [VfrFilter; 3] = [VfrFilter::All, VfrFilter::OnlyVfr, VfrFilter::HideVfr];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VfrFilter::All => "All traffic",
+            VfrFilter::OnlyVfr => "Only VFR",
+            VfrFilter::HideVfr => "Hide VFR",
+        }
+    }
+
+    pub fn allows(&self, is_vfr: bool) -> bool {
+        match self {
+            VfrFilter::All => true,
+            VfrFilter::OnlyVfr => is_vfr,
+            VfrFilter::HideVfr => !is_vfr,
+        }
+    }
 }
 
 /// Filter settings for aircraft list
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AircraftFilters {
     pub min_altitude: i32,
     pub max_altitude: i32,
@@ -46,6 +102,8 @@ pub struct AircraftFilters {
     pub include_ground_traffic: bool,
     /// Whether to only show aircraft with valid position data
     pub require_position: bool,
+    /// Show, isolate or hide aircraft squawking the VFR code
+    pub vfr: VfrFilter,
 }
 
 impl Default for AircraftFilters {
@@ -59,6 +117,7 @@ impl Default for AircraftFilters {
             callsign_prefix: String::new(),
             include_ground_traffic: true,
             require_position: true,
+            vfr: VfrFilter::All,
         }
     }
 }
@@ -74,6 +133,33 @@ pub struct AircraftListState {
     pub search_text: String,
     pub selected_icao: Option<String>,
     pub show_filter_popup: bool,
+    /// Per-aircraft note being edited in the inline detail card
+    pub note_edit: Option<NoteEdit>,
+    /// Scroll the list to the selected row on the next render (set by
+    /// keyboard cycling, cleared once the row has been shown)
+    pub scroll_to_selected: bool,
+    /// Aircraft that were listed and then crossed out of the max-distance
+    /// filter, with the time they left. Entries are dropped at the end of
+    /// the grace period, when the aircraft leaves the list.
+    pub leaving: HashMap<String, Instant>,
+}
+
+impl AircraftListState {
+    /// Progress through the leaving grace period (0 = just left, 1 = gone
+    /// from the list), or None while the aircraft is within range.
+    pub fn leaving_progress(&self, icao: &str) -> Option<f32> {
+        self.leaving.get(icao).map(|left| {
+            (left.elapsed().as_secs_f32() / LEAVING_GRACE.as_secs_f32()).min(1.0)
+        })
+    }
+}
+
+/// In-progress edit of the user's note for one aircraft. Applied to
+/// `AppConfig::aircraft_notes` and saved by `apply_aircraft_note_edit`.
+pub struct NoteEdit {
+    pub icao: String,
+    pub text: String,
+    pub submitted: bool,
 }
 
 impl Default for AircraftListState {
@@ -87,6 +173,9 @@ impl Default for AircraftListState {
             search_text: String::new(),
             selected_icao: None,
             show_filter_popup: false,
+            note_edit: None,
+            scroll_to_selected: false,
+            leaving: HashMap::new(),
         }
     }
 }
@@ -96,7 +185,7 @@ impl Default for AircraftListState {
 pub struct AircraftListButton;
 
 /// Cached aircraft data for display
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct AircraftDisplayData {
     pub icao: String,
     pub callsign: Option<String>,
@@ -109,6 +198,18 @@ pub struct AircraftDisplayData {
     pub type_code: Option<String>,
     pub manufacturer_model: Option<String>,
     pub registration: Option<String>,
+    /// Airline name resolved from the callsign's ICAO designator
+    pub airline: Option<String>,
+    /// User's note for this aircraft
+    pub note: Option<String>,
+    /// Smoothed climb/descent trend from the trail altitudes
+    pub trend: Option<AltitudeTrend>,
+    /// "APP <airport> <runway>" badge when on approach
+    pub approach: Option<String>,
+    /// Out of range and about to drop out of the list
+    pub leaving: bool,
+    /// Squawking the VFR conspicuity code
+    pub vfr: bool,
 }
 
 /// Resource holding sorted/filtered aircraft for display
@@ -117,28 +218,88 @@ pub struct AircraftDisplayList {
     pub aircraft: Vec<AircraftDisplayData>,
 }
 
-/// System to populate and sort the aircraft display list
+/// Sort and filter inputs of the last display list rebuild. Any change
+/// rebuilds immediately, bypassing the refresh interval.
+#[derive(PartialEq)]
+pub struct DisplayListQuery {
+    sort_by: SortCriteria,
+    sort_ascending: bool,
+    filters: AircraftFilters,
+    search_text: String,
+}
+
+impl DisplayListQuery {
+    fn from_state(list_state: &AircraftListState) -> Self {
+        Self {
+            sort_by: list_state.sort_by,
+            sort_ascending: list_state.sort_ascending,
+            filters: list_state.filters.clone(),
+            search_text: list_state.search_text.clone(),
+        }
+    }
+}
+
+/// System to populate and sort the aircraft display list.
+///
+/// Rebuilds at most once per `list_refresh_interval_ms` so large traffic
+/// counts don't re-filter and re-sort every frame; the panels keep drawing
+/// the cached list in between.
 pub fn update_aircraft_display_list(
-    list_state: Res<AircraftListState>,
+    mut list_state: ResMut<AircraftListState>,
     app_config: Res<crate::config::AppConfig>,
-    aircraft_query: Query<(&crate::Aircraft, Option<&AircraftTypeInfo>)>,
+    aircraft_query: Query<(&crate::Aircraft, Option<&AircraftTypeInfo>, Option<&TrailHistory>, Option<&OnApproach>)>,
+    airlines: Res<AirlineDirectory>,
     mut display_list: ResMut<AircraftDisplayList>,
+    mut last_rebuild: Local<Option<(Instant, DisplayListQuery)>>,
 ) {
+    let query = DisplayListQuery::from_state(&list_state);
+    let interval = Duration::from_millis(app_config.appearance.list_refresh_interval_ms);
+    let now = Instant::now();
+    if let Some((at, last_query)) = last_rebuild.as_ref() {
+        if now.duration_since(*at) < interval && *last_query == query && !app_config.is_changed() {
+            return;
+        }
+    }
+    *last_rebuild = Some((now, query));
+
     let center_lat = app_config.map.default_latitude;
     let center_lon = app_config.map.default_longitude;
     let search = list_state.search_text.to_lowercase();
 
+    // Start the grace period for listed aircraft that just moved out of
+    // range; forget aircraft that are back in range, no longer tracked or
+    // whose grace period is over
+    let listed: HashSet<&str> = display_list.aircraft.iter().map(|a| a.icao.as_str()).collect();
+    let max_distance = list_state.filters.max_distance;
+    let mut leaving = std::mem::take(&mut list_state.leaving);
+    let mut distances: HashMap<&str, f64> = HashMap::new();
+    for (a, _, _, _) in aircraft_query.iter() {
+        let distance = haversine_distance_nm(center_lat, center_lon, a.latitude, a.longitude);
+        distances.insert(a.icao.as_str(), distance);
+        if distance <= max_distance {
+            leaving.remove(&a.icao);
+        } else if listed.contains(a.icao.as_str()) && !leaving.contains_key(&a.icao) {
+            leaving.insert(a.icao.clone(), now);
+        }
+    }
+    leaving.retain(|icao, left| {
+        distances.contains_key(icao.as_str()) && now.duration_since(*left) < LEAVING_GRACE
+    });
+    list_state.leaving = leaving;
+
     // Get callsign prefix filter (lowercase for comparison)
     let callsign_prefix = list_state.filters.callsign_prefix.to_lowercase();
 
     // Collect and filter aircraft
     let mut aircraft: Vec<AircraftDisplayData> = aircraft_query
         .iter()
-        .filter_map(|(a, type_info)| {
-            let distance = haversine_distance_nm(center_lat, center_lon, a.latitude, a.longitude);
+        .filter_map(|(a, type_info, trail, approach)| {
+            let distance = distances[a.icao.as_str()];
 
-            // Apply filters
-            if distance > list_state.filters.max_distance {
+            // Apply filters; aircraft that just left range stay for the
+            // grace period
+            let leaving = distance > max_distance;
+            if leaving && !list_state.leaving.contains_key(&a.icao) {
                 return None;
             }
 
@@ -164,6 +325,11 @@ pub fn update_aircraft_display_list(
                 }
             }
 
+            let vfr = app_config.appearance.is_vfr_squawk(a.squawk.as_deref());
+            if !list_state.filters.vfr.allows(vfr) {
+                return None;
+            }
+
             // Apply callsign prefix filter
             if !callsign_prefix.is_empty() {
                 let matches_prefix = a.callsign.as_ref()
@@ -180,7 +346,14 @@ pub fn update_aircraft_display_list(
                     .map(|c| c.to_lowercase().contains(&search))
                     .unwrap_or(false);
                 let icao_match = a.icao.to_lowercase().contains(&search);
-                if !callsign_match && !icao_match {
+                let airline_match = a.callsign.as_deref()
+                    .and_then(|c| airlines.lookup(c))
+                    .map(|name| name.to_lowercase().contains(&search))
+                    .unwrap_or(false);
+                let note_match = app_config.aircraft_note(&a.icao)
+                    .map(|note| note.to_lowercase().contains(&search))
+                    .unwrap_or(false);
+                if !callsign_match && !icao_match && !airline_match && !note_match {
                     return None;
                 }
             }
@@ -197,6 +370,14 @@ pub fn update_aircraft_display_list(
                 type_code: type_info.and_then(|ti| ti.type_code.clone()),
                 manufacturer_model: type_info.and_then(|ti| ti.manufacturer_model.clone()),
                 registration: type_info.and_then(|ti| ti.registration.clone()),
+                airline: a.callsign.as_deref()
+                    .and_then(|c| airlines.lookup(c))
+                    .map(str::to_string),
+                note: app_config.aircraft_note(&a.icao).map(str::to_string),
+                trend: trail.and_then(TrailHistory::altitude_trend),
+                approach: approach.map(OnApproach::badge),
+                leaving,
+                vfr,
             })
         })
         .collect();
@@ -239,15 +420,33 @@ pub fn update_aircraft_display_list(
     display_list.aircraft = aircraft;
 }
 
-/// Helper function to get altitude color based on altitude value
-fn get_altitude_color(altitude: Option<i32>) -> (egui::Color32, &'static str) {
-    match altitude {
-        Some(alt) if alt >= 30000 => (egui::Color32::from_rgb(200, 100, 255), "▲"), // High - purple
-        Some(alt) if alt >= 20000 => (egui::Color32::from_rgb(255, 150, 50), "▲"),  // Medium-high - orange
-        Some(alt) if alt >= 10000 => (egui::Color32::from_rgb(200, 200, 100), "▲"), // Medium - yellow
-        Some(_) => (egui::Color32::from_rgb(100, 200, 200), "▼"),                   // Low - cyan
-        None => (egui::Color32::from_rgb(100, 100, 100), "─"),                      // Unknown - grey
-    }
+/// VFR traffic selector shared by both filter popups
+fn render_vfr_filter(ui: &mut egui::Ui, filter: &mut VfrFilter, header_color: egui::Color32) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("VFR:")
+            .color(header_color)
+            .size(10.0));
+        egui::ComboBox::from_id_salt("vfr_filter")
+            .selected_text(filter.label())
+            .show_ui(ui, |ui| {
+                for option in VfrFilter::ALL {
+                    ui.selectable_value(filter, option, option.label());
+                }
+            });
+    });
+}
+
+/// Helper function to get altitude color and trend glyph for the list.
+/// The color comes from the shared `AltitudeColorScheme` so the list matches
+/// trails and markers.
+fn get_altitude_color(scheme: &AltitudeColorScheme, altitude: Option<i32>) -> (egui::Color32, &'static str) {
+    let [r, g, b] = scheme.rgb(altitude);
+    let indicator = match altitude {
+        Some(alt) if alt >= 10000 => "▲",
+        Some(_) => "▼",
+        None => "─",
+    };
+    (egui::Color32::from_rgb(r, g, b), indicator)
 }
 
 /// System to render the aircraft list panel with stacked detail section.
@@ -264,9 +463,13 @@ pub fn render_aircraft_list_panel(
     map_state: Res<MapState>,
     app_config: Res<crate::config::AppConfig>,
     clock: Res<SessionClock>,
-    aircraft_query: Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>)>,
+    aircraft_query: Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>, Has<TypeLookupPending>)>,
     theme: Res<AppTheme>,
+    altitude_colors: Res<AltitudeColorScheme>,
+    data_sources: Res<DataSourceManager>,
+    mut routes: ResMut<RouteOverlay>,
 ) {
+    let altitude_colors = &*altitude_colors;
     if !list_state.expanded {
         return;
     }
@@ -283,6 +486,8 @@ pub fn render_aircraft_list_panel(
     let icao_color = egui::Color32::from_rgb(200, 220, 255);
     let callsign_color = egui::Color32::from_rgb(150, 220, 150);
     let callsign_selected_color = egui::Color32::from_rgb(255, 50, 50);
+    let note_color = egui::Color32::from_rgb(230, 200, 120);
+    let approach_color = egui::Color32::from_rgb(80, 230, 150);
     let metrics_color = egui::Color32::from_rgb(170, 170, 170);
     let range_color = egui::Color32::from_rgb(100, 200, 255);
     let status_active = egui::Color32::from_rgb(100, 255, 100);
@@ -402,6 +607,8 @@ pub fn render_aircraft_list_panel(
                                 .color(header_color)
                                 .size(10.0));
 
+                        render_vfr_filter(ui, &mut list_state.filters.vfr, header_color);
+
                         ui.add_space(4.0);
 
                         if ui.button("Close").clicked() {
@@ -429,7 +636,7 @@ pub fn render_aircraft_list_panel(
 
                     for aircraft in &display_list.aircraft {
                         let is_selected = list_state.selected_icao.as_ref() == Some(&aircraft.icao);
-                        let (alt_color, alt_indicator) = get_altitude_color(aircraft.altitude);
+                        let (alt_color, alt_indicator) = get_altitude_color(altitude_colors, aircraft.altitude);
 
                         // Animated expand/collapse
                         let anim_id = ui.id().with(&aircraft.icao).with("expand");
@@ -447,6 +654,11 @@ pub fn render_aircraft_list_panel(
 
                         let card_response = card_frame.show(ui, |ui| {
                             ui.spacing_mut().item_spacing.y = 2.0;
+                            if aircraft.leaving {
+                                ui.multiply_opacity(LEAVING_ROW_OPACITY);
+                            } else if aircraft.vfr && app_config.appearance.dim_vfr_in_list {
+                                ui.multiply_opacity(VFR_ROW_OPACITY);
+                            }
 
                             // Row 1: Chevron + Status + ICAO + Callsign + Altitude + Follow button
                             ui.horizontal(|ui| {
@@ -475,15 +687,42 @@ pub fn render_aircraft_list_panel(
                                     } else {
                                         callsign_color
                                     };
-                                    ui.label(egui::RichText::new(format!("{}", callsign.trim()))
+                                    let cs_label = ui.label(egui::RichText::new(format!("{}", callsign.trim()))
                                         .color(cs_color)
                                         .size(13.0)
                                         .strong());
+                                    if let Some(ref airline) = aircraft.airline {
+                                        cs_label.on_hover_text(airline);
+                                    }
+                                }
+
+                                // User note
+                                if let Some(ref note) = aircraft.note {
+                                    ui.label(egui::RichText::new(note)
+                                        .color(note_color)
+                                        .size(11.0)
+                                        .italics());
+                                }
+
+                                // On-approach badge
+                                if let Some(ref approach) = aircraft.approach {
+                                    ui.label(egui::RichText::new(approach)
+                                        .color(approach_color)
+                                        .size(10.0)
+                                        .monospace());
+                                }
+
+                                if aircraft.leaving {
+                                    ui.label(egui::RichText::new("LEAVING")
+                                        .color(header_color)
+                                        .size(10.0)
+                                        .monospace())
+                                        .on_hover_text("Out of range; leaves the list shortly");
                                 }
 
                                 // Altitude with indicator
                                 if let Some(alt) = aircraft.altitude {
-                                    let alt_text = format!("{}", format_altitude_with_indicator(alt, alt_indicator));
+                                    let alt_text = format_altitude_with_indicator(alt, alt_indicator, &app_config.altitude_display);
                                     ui.label(egui::RichText::new(alt_text)
                                         .color(alt_color)
                                         .size(12.0)
@@ -574,12 +813,17 @@ pub fn render_aircraft_list_panel(
                                 render_inline_detail(
                                     ui,
                                     &aircraft.icao,
+                                    aircraft.airline.as_deref(),
+                                    aircraft.approach.as_deref(),
                                     expand_t,
                                     &mut follow_state,
+                                    &mut list_state.note_edit,
                                     &app_config,
                                     &clock,
                                     &aircraft_query,
                                     &theme,
+                                    &data_sources,
+                                    &mut routes,
                                 );
                             }
                         });
@@ -621,14 +865,20 @@ pub fn render_aircraft_list_pane_content(
     display_list: &AircraftDisplayList,
     app_config: &crate::config::AppConfig,
     clock: &SessionClock,
-    aircraft_query: &Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>)>,
+    aircraft_query: &Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>, Has<TypeLookupPending>)>,
     theme: &AppTheme,
+    altitude_colors: &AltitudeColorScheme,
+    declutter: &mut AltitudeDeclutter,
+    data_sources: &DataSourceManager,
+    routes: &mut RouteOverlay,
 ) {
     let selected_bg = egui::Color32::from_rgba_unmultiplied(100, 140, 180, 26);
     let header_color = egui::Color32::from_rgb(150, 150, 150);
     let icao_color = egui::Color32::from_rgb(200, 220, 255);
     let callsign_color = egui::Color32::from_rgb(150, 220, 150);
     let callsign_selected_color = egui::Color32::from_rgb(255, 50, 50);
+    let note_color = egui::Color32::from_rgb(230, 200, 120);
+    let approach_color = egui::Color32::from_rgb(80, 230, 150);
     let metrics_color = egui::Color32::from_rgb(170, 170, 170);
     let range_color = egui::Color32::from_rgb(100, 200, 255);
 
@@ -665,6 +915,11 @@ pub fn render_aircraft_list_pane_content(
         if ui.button("Filter").clicked() {
             list_state.show_filter_popup = !list_state.show_filter_popup;
         }
+
+        let map_label = if declutter.enabled { "Map \u{25CF}" } else { "Map" };
+        if ui.button(map_label).on_hover_text("Map altitude band").clicked() {
+            declutter.show_controls = !declutter.show_controls;
+        }
     });
 
     // Search box
@@ -731,6 +986,8 @@ pub fn render_aircraft_list_pane_content(
                         .color(header_color)
                         .size(10.0));
 
+                render_vfr_filter(ui, &mut list_state.filters.vfr, header_color);
+
                 ui.add_space(4.0);
 
                 if ui.button("Close").clicked() {
@@ -740,6 +997,16 @@ pub fn render_aircraft_list_pane_content(
         ui.add_space(4.0);
     }
 
+    // Map declutter band (independent of the list filters above)
+    if declutter.show_controls {
+        egui::Frame::group(ui.style())
+            .fill(egui::Color32::from_rgba_unmultiplied(35, 40, 45, 230))
+            .show(ui, |ui| {
+                render_declutter_controls(ui, declutter, header_color);
+            });
+        ui.add_space(4.0);
+    }
+
     // Aircraft count
     ui.horizontal(|ui| {
         ui.label(egui::RichText::new(format!("TOTAL: {}", display_list.aircraft.len()))
@@ -758,7 +1025,7 @@ pub fn render_aircraft_list_pane_content(
 
             for aircraft in &display_list.aircraft {
                 let is_selected = list_state.selected_icao.as_ref() == Some(&aircraft.icao);
-                let (alt_color, alt_indicator) = get_altitude_color(aircraft.altitude);
+                let (alt_color, alt_indicator) = get_altitude_color(altitude_colors, aircraft.altitude);
 
                 // Animated expand/collapse
                 let anim_id = ui.id().with(&aircraft.icao).with("expand");
@@ -775,6 +1042,11 @@ pub fn render_aircraft_list_pane_content(
 
                 let card_response = card_frame.show(ui, |ui| {
                     ui.spacing_mut().item_spacing.y = 2.0;
+                    if aircraft.leaving {
+                        ui.multiply_opacity(LEAVING_ROW_OPACITY);
+                    } else if aircraft.vfr && app_config.appearance.dim_vfr_in_list {
+                        ui.multiply_opacity(VFR_ROW_OPACITY);
+                    }
 
                     // Row 1: Chevron + Status + ICAO + Callsign + Altitude + Follow button
                     ui.horizontal(|ui| {
@@ -796,14 +1068,39 @@ pub fn render_aircraft_list_pane_content(
                             } else {
                                 callsign_color
                             };
-                            ui.label(egui::RichText::new(format!("{}", callsign.trim()))
+                            let cs_label = ui.label(egui::RichText::new(format!("{}", callsign.trim()))
                                 .color(cs_color)
                                 .size(13.0)
                                 .strong());
+                            if let Some(ref airline) = aircraft.airline {
+                                cs_label.on_hover_text(airline);
+                            }
+                        }
+
+                        if let Some(ref note) = aircraft.note {
+                            ui.label(egui::RichText::new(note)
+                                .color(note_color)
+                                .size(11.0)
+                                .italics());
+                        }
+
+                        if let Some(ref approach) = aircraft.approach {
+                            ui.label(egui::RichText::new(approach)
+                                .color(approach_color)
+                                .size(10.0)
+                                .monospace());
+                        }
+
+                        if aircraft.leaving {
+                            ui.label(egui::RichText::new("LEAVING")
+                                .color(header_color)
+                                .size(10.0)
+                                .monospace())
+                                .on_hover_text("Out of range; leaves the list shortly");
                         }
 
                         if let Some(alt) = aircraft.altitude {
-                            let alt_text = format!("{}", format_altitude_with_indicator(alt, alt_indicator));
+                            let alt_text = format_altitude_with_indicator(alt, alt_indicator, &app_config.altitude_display);
                             ui.label(egui::RichText::new(alt_text)
                                 .color(alt_color)
                                 .size(12.0)
@@ -864,16 +1161,18 @@ pub fn render_aircraft_list_pane_content(
                             .monospace());
                     });
 
-                    // Row 3: Vertical rate + manufacturer/model (bottom right)
+                    // Row 3: Trend + vertical rate + manufacturer/model (bottom right).
+                    // The glyph and color follow the smoothed trail trend when
+                    // available so they don't flicker with the raw rate.
                     ui.horizontal(|ui| {
                         if let Some(vr) = aircraft.vertical_rate {
-                            let (vr_color, vr_symbol) = if vr > 100 {
-                                (egui::Color32::from_rgb(100, 255, 100), "\u{2191}")
-                            } else if vr < -100 {
-                                (egui::Color32::from_rgb(255, 150, 100), "\u{2193}")
-                            } else {
-                                (egui::Color32::from_rgb(150, 150, 150), "\u{2500}")
+                            let trend = aircraft.trend.unwrap_or_else(|| AltitudeTrend::from_rate(vr as f64));
+                            let vr_color = match trend {
+                                AltitudeTrend::Climbing => egui::Color32::from_rgb(100, 255, 100),
+                                AltitudeTrend::Descending => egui::Color32::from_rgb(255, 150, 100),
+                                AltitudeTrend::Level => egui::Color32::from_rgb(150, 150, 150),
                             };
+                            let vr_symbol = trend.glyph();
                             ui.label(egui::RichText::new(format!("{} {}ft/min", vr_symbol, vr))
                                 .color(vr_color)
                                 .size(10.0)
@@ -894,16 +1193,26 @@ pub fn render_aircraft_list_pane_content(
                         render_inline_detail(
                             ui,
                             &aircraft.icao,
+                            aircraft.airline.as_deref(),
+                            aircraft.approach.as_deref(),
                             expand_t,
                             follow_state,
+                            &mut list_state.note_edit,
                             app_config,
                             clock,
                             aircraft_query,
                             theme,
+                            data_sources,
+                            routes,
                         );
                     }
                 });
 
+                if is_selected && list_state.scroll_to_selected {
+                    card_response.response.scroll_to_me(Some(egui::Align::Center));
+                    list_state.scroll_to_selected = false;
+                }
+
                 // Handle click to select/deselect (toggle)
                 if card_response.response.interact(egui::Sense::click()).clicked() {
                     if is_selected {
@@ -927,20 +1236,67 @@ pub fn render_aircraft_list_pane_content(
         });
 }
 
+/// System that applies a submitted note edit to the config and saves it.
+pub fn apply_aircraft_note_edit(
+    mut list_state: ResMut<AircraftListState>,
+    mut app_config: ResMut<crate::config::AppConfig>,
+) {
+    if !list_state.note_edit.as_ref().is_some_and(|edit| edit.submitted) {
+        return;
+    }
+    let Some(edit) = list_state.note_edit.take() else {
+        return;
+    };
+    app_config.set_aircraft_note(&edit.icao, &edit.text);
+    crate::config::save_config(&app_config);
+}
+
+/// System that keeps the list sort in step with `AppConfig::list_sort`:
+/// restores the saved sort on launch, recalls each criterion's last-used
+/// direction when the criterion changes, and saves any change.
+pub fn persist_list_sort(
+    mut list_state: ResMut<AircraftListState>,
+    mut app_config: ResMut<crate::config::AppConfig>,
+    mut last: Local<Option<(SortCriteria, bool)>>,
+) {
+    let Some((prev_sort, prev_ascending)) = *last else {
+        list_state.sort_by = app_config.list_sort.sort_by;
+        list_state.sort_ascending = app_config.list_sort.is_ascending(list_state.sort_by);
+        *last = Some((list_state.sort_by, list_state.sort_ascending));
+        return;
+    };
+    if list_state.sort_by == prev_sort && list_state.sort_ascending == prev_ascending {
+        return;
+    }
+    if list_state.sort_by != prev_sort {
+        list_state.sort_ascending = app_config.list_sort.is_ascending(list_state.sort_by);
+    }
+    let (sort_by, ascending) = (list_state.sort_by, list_state.sort_ascending);
+    app_config.list_sort.sort_by = sort_by;
+    app_config.list_sort.ascending.insert(sort_by, ascending);
+    crate::config::save_config(&app_config);
+    *last = Some((sort_by, ascending));
+}
+
 /// Render inline detail content within an expanded aircraft card.
 ///
 /// `expand_t` is 0.0..1.0 animation progress; content is height-clipped accordingly.
 fn render_inline_detail(
     ui: &mut egui::Ui,
     selected_icao: &str,
+    airline: Option<&str>,
+    approach: Option<&str>,
     expand_t: f32,
     follow_state: &mut CameraFollowState,
+    note_edit: &mut Option<NoteEdit>,
     app_config: &crate::config::AppConfig,
     clock: &SessionClock,
-    aircraft_query: &Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>)>,
+    aircraft_query: &Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>, Has<TypeLookupPending>)>,
     theme: &AppTheme,
+    data_sources: &DataSourceManager,
+    routes: &mut RouteOverlay,
 ) {
-    let Some((aircraft, trail, type_info)) = aircraft_query.iter().find(|(a, _, _)| a.icao == selected_icao) else {
+    let Some((aircraft, trail, type_info, type_pending)) = aircraft_query.iter().find(|(a, _, _, _)| a.icao == selected_icao) else {
         return;
     };
 
@@ -979,7 +1335,7 @@ fn render_inline_detail(
                     ui.horizontal(|ui| {
                         ui.label(egui::RichText::new("Pos").color(wt.text_dim).size(10.0));
                         ui.label(
-                            egui::RichText::new(format!("{:.4}, {:.4}", aircraft.latitude, aircraft.longitude))
+                            egui::RichText::new(app_config.map.format_position(aircraft.latitude, aircraft.longitude))
                                 .color(wt.text).size(10.0).monospace(),
                         );
                     });
@@ -987,20 +1343,49 @@ fn render_inline_detail(
 
             ui.add_space(2.0);
 
+            // Contributing feeds (only meaningful with more than one source)
+            if data_sources.is_multi_source() {
+                if let Some(merged) = data_sources.merged(selected_icao) {
+                    DataStrip::new(&wt)
+                        .accent_left(wt.border, 2.0)
+                        .show(ui, |ui| {
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label(egui::RichText::new("Src").color(wt.text_dim).size(10.0));
+                                render_source_names(ui, merged, wt.accent, wt.text, 10.0);
+                            });
+                        });
+                    ui.add_space(1.0);
+                }
+            }
+
             // Key metrics as data strips
             let mut pairs: Vec<(&str, String, egui::Color32)> = Vec::new();
             pairs.push(("Dist", format!("{:.1}nm", distance_nm), wt.accent));
 
+            if let Some(approach) = approach {
+                let target = approach.strip_prefix("APP ").unwrap_or(approach);
+                pairs.push(("App", target.to_string(), egui::Color32::from_rgb(80, 230, 150)));
+            }
+
+            if let Some(airline) = airline {
+                pairs.push(("Airline", airline.to_string(), wt.text));
+            }
+
             if let Some(ti) = type_info {
                 if let Some(ref reg) = ti.registration {
                     pairs.push(("Reg", reg.clone(), wt.text));
                 }
                 if let Some(ref tc) = ti.type_code {
                     pairs.push(("Type", tc.clone(), wt.text));
+                    if let Some(wake) = WakeCategory::from_type_code(tc) {
+                        pairs.push(("Wake", wake.label().to_string(), wt.text));
+                    }
                 }
                 if let Some(ref op) = ti.operator {
                     pairs.push(("Oper", op.clone(), wt.text));
                 }
+            } else if type_pending {
+                pairs.push(("Type", "resolving\u{2026}".to_string(), wt.text_dim));
             }
 
             pairs.push(("Trk", format!("{}", trail.points.len()), wt.text));
@@ -1037,7 +1422,7 @@ fn render_inline_detail(
                     ui.add(ArcGauge::themed(alt_norm, &wt)
                         .size(60.0)
                         .label("ALT")
-                        .value_text(&format!("{}", alt))
+                        .value_text(&app_config.altitude_display.value(alt))
                         .tick_count(5)
                         .track_width(4.0)
                         .fill_width(4.0));
@@ -1071,6 +1456,110 @@ fn render_inline_detail(
 
             ui.add_space(2.0);
 
+            // User note (persisted by ICAO)
+            DataStrip::new(&wt)
+                .accent_left(wt.border, 2.0)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new("Note").color(wt.text_dim).size(10.0));
+                        match note_edit {
+                            Some(edit) if edit.icao == selected_icao => {
+                                let field = ui.add(egui::TextEdit::singleline(&mut edit.text)
+                                    .desired_width(140.0)
+                                    .char_limit(40)
+                                    .font(egui::TextStyle::Small));
+                                let enter = field.lost_focus()
+                                    && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                                if enter || ui.small_button("Save").clicked() {
+                                    edit.submitted = true;
+                                }
+                                if ui.small_button("Cancel").clicked() {
+                                    *note_edit = None;
+                                }
+                            }
+                            _ => {
+                                let current = app_config.aircraft_note(selected_icao);
+                                ui.label(egui::RichText::new(current.unwrap_or("\u{2014}"))
+                                    .color(wt.text)
+                                    .size(10.0)
+                                    .italics());
+                                if ui.small_button("Edit").clicked() {
+                                    *note_edit = Some(NoteEdit {
+                                        icao: selected_icao.to_string(),
+                                        text: current.unwrap_or_default().to_string(),
+                                        submitted: false,
+                                    });
+                                }
+                            }
+                        }
+                    });
+                });
+
+            ui.add_space(2.0);
+
+            // Destination airport (manual entry) with remaining route
+            DataStrip::new(&wt)
+                .accent_left(wt.border, 2.0)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new("Dest").color(wt.text_dim).size(10.0));
+                        match &mut routes.edit {
+                            Some(edit) if edit.icao == selected_icao => {
+                                let field = ui.add(egui::TextEdit::singleline(&mut edit.text)
+                                    .desired_width(60.0)
+                                    .char_limit(8)
+                                    .hint_text("ICAO")
+                                    .font(egui::TextStyle::Small));
+                                let enter = field.lost_focus()
+                                    && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                                if enter || ui.small_button("Set").clicked() {
+                                    edit.submitted = true;
+                                }
+                                if ui.small_button("Cancel").clicked() {
+                                    routes.edit = None;
+                                }
+                            }
+                            _ => {
+                                let destination = routes.destination(selected_icao).cloned();
+                                match &destination {
+                                    Some(dest) => {
+                                        let (distance, eta) = route::remaining_route(
+                                            aircraft.latitude,
+                                            aircraft.longitude,
+                                            aircraft.velocity,
+                                            dest,
+                                        );
+                                        let eta_text = eta.map(route::format_eta)
+                                            .unwrap_or_else(|| "---".to_string());
+                                        ui.label(egui::RichText::new(&dest.ident)
+                                            .color(wt.accent).size(10.0).monospace())
+                                            .on_hover_text(&dest.name);
+                                        ui.label(egui::RichText::new(format!("{:.0}nm ETA {}", distance, eta_text))
+                                            .color(wt.text).size(10.0).monospace());
+                                    }
+                                    None => {
+                                        ui.label(egui::RichText::new("\u{2014}").color(wt.text).size(10.0));
+                                    }
+                                }
+                                if ui.small_button("Edit").clicked() {
+                                    routes.edit = Some(route::DestinationEdit {
+                                        icao: selected_icao.to_string(),
+                                        text: destination.map(|d| d.ident).unwrap_or_default(),
+                                        submitted: false,
+                                    });
+                                }
+                            }
+                        }
+                    });
+                    if let Some((icao, error)) = &routes.lookup_error {
+                        if icao == selected_icao {
+                            ui.label(egui::RichText::new(error).color(egui::Color32::from_rgb(255, 120, 100)).size(9.0));
+                        }
+                    }
+                });
+
+            ui.add_space(2.0);
+
             // Follow/Unfollow button
             ui.horizontal(|ui| {
                 let is_following = follow_state.following_icao.as_deref() == Some(selected_icao);
@@ -1091,6 +1580,20 @@ fn render_inline_detail(
                         follow_state.following_icao = Some(selected_icao.to_string());
                     }
                 }
+
+                let link = DeepLink::for_aircraft(aircraft);
+                if ui.add(egui::Button::new(egui::RichText::new("Copy").color(wt.text).size(10.0)).small())
+                    .on_hover_text("Copy aircraft details")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(aircraft_details_text(aircraft, type_info, &link, app_config.map.coordinate_format));
+                }
+                if ui.add(egui::Button::new(egui::RichText::new("Link").color(wt.text).size(10.0)).small())
+                    .on_hover_text("Copy a link that opens AirJedi on this aircraft")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(link.to_string());
+                }
             });
         },
     );
@@ -1113,7 +1616,8 @@ fn render_detail_section(
     follow_state: &mut CameraFollowState,
     app_config: &crate::config::AppConfig,
     clock: &SessionClock,
-    aircraft_query: &Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>)>,
+    aircraft_query: &Query<(&crate::Aircraft, &TrailHistory, Option<&AircraftTypeInfo>, Has<TypeLookupPending>)>,
+    data_sources: &DataSourceManager,
 ) {
     let label_color = egui::Color32::from_rgb(150, 150, 150);
     let value_color = egui::Color32::from_rgb(220, 220, 220);
@@ -1121,7 +1625,7 @@ fn render_detail_section(
     let highlight_color = egui::Color32::from_rgb(100, 200, 255);
 
     // Find the selected aircraft
-    let Some((aircraft, trail, type_info)) = aircraft_query.iter().find(|(a, _, _)| a.icao == selected_icao) else {
+    let Some((aircraft, trail, type_info, type_pending)) = aircraft_query.iter().find(|(a, _, _, _)| a.icao == selected_icao) else {
         detail_state.open = false;
         detail_state.track_start = None;
         return;
@@ -1175,16 +1679,29 @@ fn render_detail_section(
                 .show(ui, |ui| {
                     ui.label(egui::RichText::new("Position").color(label_color).size(11.0));
                     ui.label(
-                        egui::RichText::new(format!("{:.4}, {:.4}", aircraft.latitude, aircraft.longitude))
+                        egui::RichText::new(app_config.map.format_position(aircraft.latitude, aircraft.longitude))
                             .color(value_color).size(11.0).monospace(),
                     );
                     ui.end_row();
 
                     ui.label(egui::RichText::new("Altitude").color(label_color).size(11.0));
-                    let alt_text = format_altitude(aircraft.altitude);
+                    let alt_text = format_altitude(aircraft.altitude, &app_config.altitude_display);
                     ui.label(egui::RichText::new(alt_text).color(value_color).size(11.0).monospace());
                     ui.end_row();
 
+                    // Show both readings only when the feed reports GNSS height
+                    if let Some(offset) = aircraft.geometric_offset() {
+                        ui.label(egui::RichText::new("Baro / Geom").color(label_color).size(11.0));
+                        let both_text = format!(
+                            "{} / {} ({:+} ft)",
+                            format_altitude(aircraft.barometric_altitude, &app_config.altitude_display),
+                            format_altitude(aircraft.geometric_altitude, &app_config.altitude_display),
+                            offset,
+                        );
+                        ui.label(egui::RichText::new(both_text).color(value_color).size(11.0).monospace());
+                        ui.end_row();
+                    }
+
                     ui.label(egui::RichText::new("Speed").color(label_color).size(11.0));
                     let speed_text = aircraft.velocity
                         .map(|v| format!("{} kts", v as i32))
@@ -1231,6 +1748,16 @@ fn render_detail_section(
                     ui.label(egui::RichText::new(dur_text).color(value_color).size(11.0).monospace());
                     ui.end_row();
 
+                    if data_sources.is_multi_source() {
+                        if let Some(merged) = data_sources.merged(selected_icao) {
+                            ui.label(egui::RichText::new("Sources").color(label_color).size(11.0));
+                            ui.horizontal_wrapped(|ui| {
+                                render_source_names(ui, merged, highlight_color, value_color, 11.0);
+                            });
+                            ui.end_row();
+                        }
+                    }
+
                     // Aircraft type info (from OpenSky database)
                     if let Some(ti) = type_info {
                         if let Some(ref reg) = ti.registration {
@@ -1253,6 +1780,10 @@ fn render_detail_section(
                             ui.label(egui::RichText::new(op).color(value_color).size(11.0).monospace());
                             ui.end_row();
                         }
+                    } else if type_pending {
+                        ui.label(egui::RichText::new("Aircraft").color(label_color).size(11.0));
+                        ui.label(egui::RichText::new("resolving\u{2026}").color(label_color).size(11.0).italics());
+                        ui.end_row();
                     }
                 });
 
@@ -1273,12 +1804,34 @@ fn render_detail_section(
         });
 }
 
+/// List the feeds reporting an aircraft, with the primary source highlighted.
+fn render_source_names(
+    ui: &mut egui::Ui,
+    merged: &crate::data_sources::MergedAircraftData,
+    primary_color: egui::Color32,
+    other_color: egui::Color32,
+    size: f32,
+) {
+    ui.spacing_mut().item_spacing.x = 6.0;
+    for source in &merged.sources {
+        let is_primary = *source == merged.primary_source;
+        let text = egui::RichText::new(source).size(size).monospace();
+        let text = if is_primary {
+            text.color(primary_color).strong()
+        } else {
+            text.color(other_color)
+        };
+        ui.label(text).on_hover_text(if is_primary { "Primary source" } else { "Contributing source" });
+    }
+}
+
 /// System to toggle aircraft list visibility
 pub fn toggle_aircraft_list(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut list_state: ResMut<AircraftListState>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyL) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleAircraftList, &keyboard) {
         list_state.expanded = !list_state.expanded;
     }
 }
@@ -0,0 +1,267 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::Aircraft;
+use crate::adsb::synthetic::SyntheticAircraft;
+use crate::recording::PlaybackState;
+use crate::theme::{AppTheme, to_egui_color32};
+use super::typeinfo::AircraftTypeInfo;
+
+const LOGBOOK_FILE: &str = "logbook.json";
+/// How often the live feed is folded into the logbook
+const LOGBOOK_UPDATE_INTERVAL: Duration = Duration::from_secs(5);
+/// Minimum time between logbook writes while it has unsaved changes
+const LOGBOOK_SAVE_INTERVAL: Duration = Duration::from_secs(60);
+/// Rows shown in the panel; search narrows the rest
+const LOGBOOK_MAX_ROWS: usize = 500;
+
+/// One distinct aircraft ever seen
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LogbookEntry {
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    /// Most recent non-empty callsign
+    #[serde(default)]
+    pub callsign: Option<String>,
+    #[serde(default)]
+    pub type_code: Option<String>,
+}
+
+/// Persisted log of every unique aircraft seen, keyed by ICAO, stored as
+/// `logbook.json` in the config directory. Unlike the session statistics it
+/// survives restarts.
+#[derive(Resource)]
+pub struct AircraftLogbook {
+    pub entries: BTreeMap<String, LogbookEntry>,
+    /// Panel search text (ICAO, callsign or type)
+    pub search: String,
+    dirty: bool,
+    last_update: Option<Instant>,
+    last_save: Instant,
+}
+
+impl Default for AircraftLogbook {
+    fn default() -> Self {
+        Self {
+            entries: load_logbook(),
+            search: String::new(),
+            dirty: false,
+            last_update: None,
+            last_save: Instant::now(),
+        }
+    }
+}
+
+impl AircraftLogbook {
+    /// Record a sighting. Returns true when the aircraft is new to the log.
+    pub fn observe(&mut self, icao: &str, callsign: Option<&str>, type_code: Option<&str>, now: DateTime<Utc>) -> bool {
+        let callsign = callsign.map(str::trim).filter(|c| !c.is_empty());
+        let is_new = !self.entries.contains_key(icao);
+        let entry = self.entries.entry(icao.to_string()).or_insert_with(|| LogbookEntry {
+            first_seen: now,
+            last_seen: now,
+            callsign: None,
+            type_code: None,
+        });
+        entry.last_seen = now;
+        if let Some(callsign) = callsign {
+            entry.callsign = Some(callsign.to_string());
+        }
+        if let Some(type_code) = type_code {
+            entry.type_code = Some(type_code.to_string());
+        }
+        self.dirty = true;
+        is_new
+    }
+
+    /// Entries matching the search text, most recently seen first
+    pub fn search_results(&self) -> Vec<(&String, &LogbookEntry)> {
+        let query = self.search.trim().to_uppercase();
+        let mut results: Vec<_> = self.entries.iter()
+            .filter(|(icao, entry)| {
+                query.is_empty()
+                    || icao.contains(&query)
+                    || entry.callsign.as_deref().is_some_and(|c| c.to_uppercase().contains(&query))
+                    || entry.type_code.as_deref().is_some_and(|t| t.to_uppercase().contains(&query))
+            })
+            .collect();
+        results.sort_by(|a, b| b.1.last_seen.cmp(&a.1.last_seen));
+        results
+    }
+}
+
+fn logbook_path() -> PathBuf {
+    let dir = crate::paths::config_dir();
+    crate::paths::ensure_dir(&dir);
+    dir.join(LOGBOOK_FILE)
+}
+
+fn load_logbook() -> BTreeMap<String, LogbookEntry> {
+    let path = logbook_path();
+    if !path.exists() {
+        return BTreeMap::new();
+    }
+    match std::fs::read_to_string(&path).map(|contents| serde_json::from_str(&contents)) {
+        Ok(Ok(entries)) => entries,
+        Ok(Err(e)) => {
+            warn!("Failed to parse aircraft logbook: {}", e);
+            BTreeMap::new()
+        }
+        Err(e) => {
+            warn!("Failed to read aircraft logbook: {}", e);
+            BTreeMap::new()
+        }
+    }
+}
+
+fn save_logbook(entries: &BTreeMap<String, LogbookEntry>) {
+    let path = logbook_path();
+    match serde_json::to_string(entries) {
+        Ok(contents) => {
+            if let Err(e) = std::fs::write(&path, contents) {
+                error!("Failed to write aircraft logbook: {}", e);
+            }
+        }
+        Err(e) => error!("Failed to serialize aircraft logbook: {}", e),
+    }
+}
+
+/// System that folds the live feed into the logbook every few seconds and
+/// writes it back to disk at most once a minute. Synthetic traffic and
+/// aircraft replayed from a recording are not logged.
+pub fn update_logbook(
+    mut logbook: ResMut<AircraftLogbook>,
+    playback: Res<PlaybackState>,
+    aircraft_query: Query<(&Aircraft, Option<&AircraftTypeInfo>), Without<SyntheticAircraft>>,
+) {
+    let now = Instant::now();
+    if logbook.last_update.is_some_and(|t| now.duration_since(t) < LOGBOOK_UPDATE_INTERVAL) {
+        return;
+    }
+    if playback.drives_aircraft() {
+        return;
+    }
+    logbook.last_update = Some(now);
+
+    let mut new_count = 0;
+    for (aircraft, type_info) in aircraft_query.iter() {
+        let type_code = type_info.and_then(|t| t.type_code.as_deref());
+        if logbook.observe(&aircraft.icao, aircraft.callsign.as_deref(), type_code, aircraft.last_seen) {
+            new_count += 1;
+        }
+    }
+    if new_count > 0 {
+        debug!("Logbook: {} new aircraft ({} total)", new_count, logbook.entries.len());
+    }
+
+    if logbook.dirty && now.duration_since(logbook.last_save) >= LOGBOOK_SAVE_INTERVAL {
+        save_logbook(&logbook.entries);
+        logbook.dirty = false;
+        logbook.last_save = now;
+    }
+}
+
+/// System that writes unsaved logbook changes when the app exits, so the
+/// last minute of sightings isn't lost.
+pub fn save_logbook_on_exit(
+    mut exit_events: MessageReader<AppExit>,
+    mut logbook: ResMut<AircraftLogbook>,
+) {
+    if exit_events.read().count() == 0 || !logbook.dirty {
+        return;
+    }
+    save_logbook(&logbook.entries);
+    logbook.dirty = false;
+}
+
+/// Render the logbook into a bare `egui::Ui` (for dock/tab usage).
+pub fn render_logbook_pane_content(
+    ui: &mut egui::Ui,
+    logbook: &mut AircraftLogbook,
+    theme: &AppTheme,
+) {
+    let label_color = to_egui_color32(theme.text_dim());
+    let value_color = to_egui_color32(theme.text_primary());
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Unique Aircraft:").color(label_color).size(11.0));
+        ui.label(egui::RichText::new(format!("{}", logbook.entries.len()))
+            .color(value_color)
+            .size(12.0)
+            .strong()
+            .monospace());
+    });
+    ui.add_space(4.0);
+    ui.add(egui::TextEdit::singleline(&mut logbook.search).hint_text("Search ICAO, callsign, type"));
+    ui.add_space(4.0);
+
+    let results = logbook.search_results();
+    if results.is_empty() {
+        ui.label(egui::RichText::new("No matching aircraft").color(label_color).size(10.0));
+        return;
+    }
+
+    egui::Grid::new("logbook_grid")
+        .num_columns(4)
+        .striped(true)
+        .spacing([10.0, 2.0])
+        .show(ui, |ui| {
+            for header in ["ICAO", "Callsign", "Type", "Last seen"] {
+                ui.label(egui::RichText::new(header).color(label_color).size(9.0));
+            }
+            ui.end_row();
+            for (icao, entry) in results.iter().take(LOGBOOK_MAX_ROWS) {
+                ui.label(egui::RichText::new(icao.as_str()).color(value_color).size(10.0).monospace());
+                ui.label(egui::RichText::new(entry.callsign.as_deref().unwrap_or("-")).color(value_color).size(10.0).monospace());
+                ui.label(egui::RichText::new(entry.type_code.as_deref().unwrap_or("-")).color(value_color).size(10.0).monospace());
+                ui.label(egui::RichText::new(entry.last_seen.format("%Y-%m-%d %H:%M").to_string())
+                    .color(label_color)
+                    .size(10.0)
+                    .monospace())
+                    .on_hover_text(format!("First seen {}", entry.first_seen.format("%Y-%m-%d %H:%M UTC")));
+                ui.end_row();
+            }
+        });
+    if results.len() > LOGBOOK_MAX_ROWS {
+        ui.label(egui::RichText::new(format!("{} more; refine the search", results.len() - LOGBOOK_MAX_ROWS))
+            .color(label_color)
+            .size(10.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observe_keeps_first_seen_and_best_details() {
+        let mut logbook = AircraftLogbook {
+            entries: BTreeMap::new(),
+            search: String::new(),
+            dirty: false,
+            last_update: None,
+            last_save: Instant::now(),
+        };
+        let t0 = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let t1 = DateTime::from_timestamp(1_700_000_600, 0).unwrap();
+
+        assert!(logbook.observe("A1B2C3", Some("UAL1  "), None, t0));
+        assert!(!logbook.observe("A1B2C3", Some("  "), Some("B738"), t1));
+        let entry = &logbook.entries["A1B2C3"];
+        assert_eq!(entry.first_seen, t0);
+        assert_eq!(entry.last_seen, t1);
+        assert_eq!(entry.callsign.as_deref(), Some("UAL1"));
+        assert_eq!(entry.type_code.as_deref(), Some("B738"));
+
+        logbook.observe("ABCDEF", None, Some("A320"), t0);
+        logbook.search = "b73".to_string();
+        let results = logbook.search_results();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "A1B2C3");
+    }
+}
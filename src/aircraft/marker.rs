@@ -0,0 +1,114 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::prelude::*;
+
+use crate::config::{AppConfig, MarkerShape};
+use crate::view3d::View3DState;
+use crate::Aircraft;
+
+/// Chevron mesh drawn in place of the aircraft model on the 2D map.
+///
+/// Spawned as a child of every aircraft so it inherits the marker position,
+/// heading rotation and scale; `apply_marker_shape` decides per aircraft
+/// whether the chevron or the model scene is visible.
+#[derive(Component)]
+pub struct ChevronMarker;
+
+/// Shared chevron mesh and material
+#[derive(Resource)]
+pub struct ChevronMarkerAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for ChevronMarkerAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(chevron_mesh());
+        let material = world.resource_mut::<Assets<StandardMaterial>>().add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.85, 0.3),
+            unlit: true,
+            cull_mode: None,
+            ..default()
+        });
+        Self { mesh, material }
+    }
+}
+
+/// Chevron in the model's XZ plane with the tip along +Z, matching the GLB
+/// convention (nose=+Z, up=+Y, ~4 units across) so `marker_rotation_2d`
+/// lays it flat on the map pointing along the track.
+fn chevron_mesh() -> Mesh {
+    let positions: Vec<[f32; 3]> = vec![
+        [0.0, 0.0, 2.0],   // tip
+        [-1.6, 0.0, -1.6], // left wing
+        [0.0, 0.0, -0.8],  // tail notch
+        [1.6, 0.0, -1.6],  // right wing
+    ];
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_indices(Indices::U32(vec![0, 1, 2, 0, 2, 3]))
+}
+
+/// Whether an aircraft is drawn as a chevron: only on the 2D map and only
+/// when the heading is known, since a chevron without a heading would point
+/// north regardless of track. Everything else keeps the model.
+pub fn shows_chevron(shape: MarkerShape, is_3d: bool, heading: Option<f32>) -> bool {
+    shape == MarkerShape::Chevron && !is_3d && heading.is_some()
+}
+
+/// System that gives newly spawned aircraft a (hidden) chevron child.
+pub fn attach_chevron_markers(
+    mut commands: Commands,
+    assets: Res<ChevronMarkerAssets>,
+    new_aircraft: Query<Entity, Added<Aircraft>>,
+) {
+    for entity in new_aircraft.iter() {
+        commands.entity(entity).with_child((
+            Name::new("Chevron Marker"),
+            ChevronMarker,
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::default(),
+            Visibility::Hidden,
+        ));
+    }
+}
+
+/// System that shows either the chevron or the model scene for each
+/// aircraft according to `AppearanceConfig::marker_shape`.
+pub fn apply_marker_shape(
+    app_config: Res<AppConfig>,
+    view3d_state: Res<View3DState>,
+    aircraft_query: Query<(&Aircraft, &Children)>,
+    chevron_query: Query<(), With<ChevronMarker>>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    let shape = app_config.appearance.marker_shape;
+    let is_3d = view3d_state.is_3d_active() || view3d_state.is_transitioning();
+
+    for (aircraft, children) in aircraft_query.iter() {
+        let chevron = shows_chevron(shape, is_3d, aircraft.heading);
+        for child in children.iter() {
+            let visible = chevron_query.contains(child) == chevron;
+            let target = if visible { Visibility::Inherited } else { Visibility::Hidden };
+            if let Ok(mut visibility) = visibility_query.get_mut(child) {
+                visibility.set_if_neq(target);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chevron_falls_back_to_model_without_heading() {
+        assert!(shows_chevron(MarkerShape::Chevron, false, Some(90.0)));
+        assert!(!shows_chevron(MarkerShape::Chevron, false, None));
+        assert!(!shows_chevron(MarkerShape::Chevron, true, Some(90.0)));
+        assert!(!shows_chevron(MarkerShape::Model, false, Some(90.0)));
+    }
+}
@@ -1,17 +1,24 @@
+pub mod airlines;
 pub mod altitude;
+pub mod approach;
 pub mod components;
 pub mod trails;
 pub mod trail_renderer;
+pub mod declutter;
 pub mod staleness;
 pub mod list_panel;
+pub mod logbook;
+pub mod marker;
 pub mod detail_panel;
 pub mod emergency;
 pub mod prediction;
+pub mod route;
 pub mod picking;
 pub mod stats_panel;
 pub mod typeinfo;
 pub mod typeloader;
 pub mod plugin;
+pub mod wake;
 #[cfg(feature = "hanabi")]
 pub mod hanabi_plugin;
 #[cfg(feature = "hanabi")]
@@ -20,11 +27,14 @@ pub mod hanabi_selection;
 pub mod hanabi_trails;
 
 pub use components::{Aircraft, AircraftLabel};
-pub use trails::{TrailHistory, TrailConfig, TrailRenderer, SessionClock, TrailRecordTimer};
+pub use trails::{AltitudeTrend, LostTrails, TrailHistory, TrailConfig, TrailDisplayMode, TrailFadeMode, TrailRenderer, SessionClock, TrailRecordTimer, simplify_track, TRAIL_SIMPLIFY_TOLERANCE_M};
 pub use list_panel::{AircraftListState, AircraftDisplayList, AircraftDisplayData};
 pub use detail_panel::{DetailPanelState, CameraFollowState};
 pub use stats_panel::StatsPanelState;
 pub use emergency::EmergencyAlertState;
 pub use prediction::PredictionConfig;
-pub use typeinfo::{AircraftTypeInfo, AircraftTypeDatabase};
+pub use route::RouteOverlay;
+pub use typeinfo::{AircraftTypeInfo, AircraftTypeDatabase, AircraftTypeDbStatus, TypeLookupPending};
+pub use airlines::AirlineDirectory;
 pub use plugin::AircraftPlugin;
+pub use wake::WakeCategory;
@@ -3,6 +3,7 @@ use bevy::picking::mesh_picking::ray_cast::MeshRayCast;
 
 use super::detail_panel::CameraFollowState;
 use super::list_panel::AircraftListState;
+use crate::inspector::InspectorState;
 use crate::Aircraft;
 
 /// Marker component added to aircraft entities when selected via click.
@@ -60,6 +61,7 @@ pub fn on_aircraft_click(
     aircraft_query: Query<&Aircraft>,
     mut list_state: ResMut<AircraftListState>,
     mut follow_state: ResMut<CameraFollowState>,
+    mut inspector_state: ResMut<InspectorState>,
 ) {
     let aircraft_entity = event.observer();
 
@@ -67,6 +69,7 @@ pub fn on_aircraft_click(
         info!("Aircraft clicked: {}", aircraft.icao);
         list_state.selected_icao = Some(aircraft.icao.clone());
         follow_state.following_icao = Some(aircraft.icao.clone());
+        inspector_state.selected_entity = Some(aircraft_entity);
     }
 }
 
@@ -100,12 +103,13 @@ pub fn on_aircraft_out(
     }
 }
 
-/// System that clears selection when ESC is pressed.
+/// System that clears selection when the cancel key (ESC by default) is pressed.
 pub fn deselect_on_escape(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut list_state: ResMut<AircraftListState>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::Cancel, &keyboard) {
         if list_state.selected_icao.is_some() {
             list_state.selected_icao = None;
         }
@@ -1,20 +1,31 @@
 use bevy::prelude::*;
 
 use super::{
-    SessionClock, TrailConfig, TrailRecordTimer,
+    LostTrails, SessionClock, TrailConfig, TrailRecordTimer,
     AircraftListState, AircraftDisplayList,
     DetailPanelState, CameraFollowState,
     EmergencyAlertState, PredictionConfig, StatsPanelState,
-    AircraftTypeDatabase,
+    AircraftTypeDatabase, AircraftTypeDbStatus,
     components::Aircraft,
 };
-use super::trail_renderer::{draw_trails, prune_trails};
-use super::trails::record_trail_points;
+use super::trail_renderer::{TrailGizmos, apply_trail_gizmo_config, draw_trails, prune_trails};
+use super::trails::{record_trail_points, retain_lost_trail, update_lost_trails};
 use super::staleness::dim_stale_aircraft;
-use super::list_panel::{toggle_aircraft_list, update_aircraft_display_list, highlight_selected_aircraft};
+use super::altitude::{AltitudeColorScheme, AltitudeTintMaterials, tint_aircraft_markers};
+use super::declutter::{AltitudeDeclutter, DeclutterFadeMaterials, apply_altitude_declutter, fade_declutter_materials};
+use super::list_panel::{toggle_aircraft_list, update_aircraft_display_list, highlight_selected_aircraft, apply_aircraft_note_edit, persist_list_sort};
 use super::detail_panel::{render_detail_panel, toggle_detail_panel, open_detail_on_selection, detect_aircraft_click};
-use super::emergency::{detect_emergencies, draw_emergency_rings, update_emergency_banner, update_emergency_banner_text};
+use super::emergency::{
+    auto_follow_emergencies, detect_emergencies, draw_emergency_rings, flash_emergency_banner, update_emergency_banner,
+    update_emergency_banner_text,
+};
 use super::prediction::draw_predictions;
+use super::route::{RouteOverlay, apply_destination_edit, draw_destination_routes};
+use super::logbook::{AircraftLogbook, save_logbook_on_exit, update_logbook};
+use super::marker::{ChevronMarkerAssets, attach_chevron_markers, apply_marker_shape};
+use super::approach::{ApproachDetection, detect_approaches, draw_approach_lines};
+use super::airlines::{AirlineDirectory, load_airline_directory};
+use super::wake::assign_wake_category;
 use super::typeloader::{start_aircraft_type_loading, poll_aircraft_type_loading, attach_aircraft_type_info};
 use super::picking::{setup_outline_materials, manage_selection_outline, swap_outline_materials, deselect_on_escape, clear_stale_selection, follow_aircraft_3d, pick_aircraft_3d};
 #[cfg(feature = "hanabi")]
@@ -30,21 +41,38 @@ impl Plugin for AircraftPlugin {
             .init_resource::<SessionClock>()
             .init_resource::<TrailConfig>()
             .init_resource::<TrailRecordTimer>()
+            .init_resource::<LostTrails>()
             .init_resource::<AircraftListState>()
             .init_resource::<AircraftDisplayList>()
             .init_resource::<DetailPanelState>()
             .init_resource::<CameraFollowState>()
             .init_resource::<EmergencyAlertState>()
             .init_resource::<PredictionConfig>()
+            .init_resource::<RouteOverlay>()
+            .init_resource::<ApproachDetection>()
+            .init_resource::<ChevronMarkerAssets>()
+            .init_resource::<AircraftLogbook>()
             .init_resource::<StatsPanelState>()
             .init_resource::<AircraftTypeDatabase>()
-            .add_systems(Startup, (start_aircraft_type_loading, setup_outline_materials))
+            .init_resource::<AircraftTypeDbStatus>()
+            .init_resource::<AirlineDirectory>()
+            .init_resource::<AltitudeColorScheme>()
+            .init_resource::<AltitudeTintMaterials>()
+            .init_resource::<AltitudeDeclutter>()
+            .init_resource::<DeclutterFadeMaterials>()
+            .init_gizmo_group::<TrailGizmos>()
+            .add_observer(retain_lost_trail)
+            .add_systems(Startup, (start_aircraft_type_loading, setup_outline_materials, load_airline_directory))
             .add_systems(Update, (
                 record_trail_points,
-                draw_trails.after(crate::ZoomSet::Change),
+                apply_trail_gizmo_config,
+                draw_trails.after(crate::ZoomSet::Change).after(apply_trail_gizmo_config),
                 prune_trails,
+                update_lost_trails,
                 toggle_aircraft_list,
+                persist_list_sort.before(update_aircraft_display_list),
                 update_aircraft_display_list,
+                apply_aircraft_note_edit,
                 highlight_selected_aircraft,
                 toggle_detail_panel,
                 open_detail_on_selection,
@@ -57,11 +85,21 @@ impl Plugin for AircraftPlugin {
                 dim_stale_aircraft,
             ))
             .add_systems(Update, render_detail_panel)
-            .add_systems(Update, (poll_aircraft_type_loading, attach_aircraft_type_info))
+            .add_systems(Update, (auto_follow_emergencies.after(detect_emergencies), flash_emergency_banner))
+            .add_systems(Update, apply_altitude_declutter.after(crate::view3d::update_aircraft_3d_transform))
+            .add_systems(Update, (poll_aircraft_type_loading, attach_aircraft_type_info.after(poll_aircraft_type_loading)))
+            .add_systems(Update, assign_wake_category)
+            .add_systems(Update, (apply_destination_edit, draw_destination_routes.after(crate::ZoomSet::Change)))
+            .add_systems(Update, update_logbook)
+            .add_systems(Last, save_logbook_on_exit)
+            .add_systems(Update, (attach_chevron_markers, apply_marker_shape.after(attach_chevron_markers)))
+            .add_systems(Update, (detect_approaches, draw_approach_lines.after(crate::ZoomSet::Change)))
             .add_systems(Update, (
                 manage_selection_outline,
                 #[cfg(not(feature = "hanabi"))]
                 swap_outline_materials.after(manage_selection_outline),
+                tint_aircraft_markers.after(manage_selection_outline),
+                fade_declutter_materials.after(tint_aircraft_markers).after(apply_altitude_declutter),
                 deselect_on_escape,
                 clear_stale_selection,
                 follow_aircraft_3d,
@@ -0,0 +1,215 @@
+use bevy::prelude::*;
+use bevy_slippy_tiles::*;
+use std::collections::HashMap;
+
+use crate::{Aircraft, MapState};
+use crate::aviation::{Airport, AviationData};
+use crate::geo::{haversine_distance_nm, CoordinateConverter};
+use super::trail_renderer::great_circle_subdivisions;
+
+/// Route overlay segments longer than this are subdivided along the great circle
+const ROUTE_SEGMENT_NM: f64 = 25.0;
+/// Below this groundspeed no ETA is shown
+const MIN_ETA_SPEED_KTS: f64 = 30.0;
+
+/// Destination airport assigned to an aircraft
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteDestination {
+    pub ident: String,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl RouteDestination {
+    pub fn from_airport(airport: &Airport) -> Self {
+        Self {
+            ident: airport.ident.clone(),
+            name: airport.name.clone(),
+            latitude: airport.latitude_deg,
+            longitude: airport.longitude_deg,
+        }
+    }
+}
+
+/// In-progress destination entry for one aircraft in the inline detail card.
+/// Resolved against the airport database by `apply_destination_edit`.
+pub struct DestinationEdit {
+    pub icao: String,
+    pub text: String,
+    pub submitted: bool,
+}
+
+/// Manually assigned destinations, drawn as a great-circle line from the
+/// aircraft to the airport with remaining distance and ETA in the detail card.
+#[derive(Resource)]
+pub struct RouteOverlay {
+    pub enabled: bool,
+    /// Destinations keyed by ICAO hex
+    destinations: HashMap<String, RouteDestination>,
+    pub edit: Option<DestinationEdit>,
+    /// Error from the last destination lookup, keyed by ICAO
+    pub lookup_error: Option<(String, String)>,
+}
+
+impl Default for RouteOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            destinations: HashMap::new(),
+            edit: None,
+            lookup_error: None,
+        }
+    }
+}
+
+impl RouteOverlay {
+    pub fn destination(&self, icao: &str) -> Option<&RouteDestination> {
+        self.destinations.get(icao)
+    }
+
+    pub fn set_destination(&mut self, icao: &str, destination: RouteDestination) {
+        self.destinations.insert(icao.to_string(), destination);
+    }
+
+    pub fn clear_destination(&mut self, icao: &str) {
+        self.destinations.remove(icao);
+    }
+}
+
+/// Find an airport by ICAO ident, GPS code or IATA code (case-insensitive)
+pub fn find_airport<'a>(airports: &'a [Airport], code: &str) -> Option<&'a Airport> {
+    let code = code.trim();
+    if code.is_empty() {
+        return None;
+    }
+    let matches = |field: Option<&str>| field.is_some_and(|f| f.eq_ignore_ascii_case(code));
+    airports.iter().find(|a| a.ident.eq_ignore_ascii_case(code))
+        .or_else(|| airports.iter().find(|a| matches(a.gps_code.as_deref())))
+        .or_else(|| airports.iter().find(|a| matches(a.iata_code.as_deref())))
+}
+
+/// Remaining great-circle distance (nm) and ETA (seconds) to `destination`.
+/// ETA is `None` when the groundspeed is unknown or too low to be meaningful.
+pub fn remaining_route(
+    latitude: f64,
+    longitude: f64,
+    groundspeed_kts: Option<f64>,
+    destination: &RouteDestination,
+) -> (f64, Option<f64>) {
+    let distance = haversine_distance_nm(latitude, longitude, destination.latitude, destination.longitude);
+    let eta = groundspeed_kts
+        .filter(|&gs| gs >= MIN_ETA_SPEED_KTS)
+        .map(|gs| distance / gs * 3600.0);
+    (distance, eta)
+}
+
+/// Format an ETA in seconds as "H:MM" (or "MM min" under an hour)
+pub fn format_eta(secs: f64) -> String {
+    let minutes = (secs / 60.0).round() as u64;
+    if minutes < 60 {
+        format!("{} min", minutes)
+    } else {
+        format!("{}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+/// System that resolves a submitted destination edit against the airport
+/// database. An empty entry clears the destination.
+pub fn apply_destination_edit(
+    mut overlay: ResMut<RouteOverlay>,
+    aviation_data: Res<AviationData>,
+) {
+    if !overlay.edit.as_ref().is_some_and(|edit| edit.submitted) {
+        return;
+    }
+    let Some(edit) = overlay.edit.take() else {
+        return;
+    };
+    overlay.lookup_error = None;
+
+    if edit.text.trim().is_empty() {
+        overlay.clear_destination(&edit.icao);
+        return;
+    }
+    match find_airport(&aviation_data.airports, &edit.text) {
+        Some(airport) => {
+            let destination = RouteDestination::from_airport(airport);
+            info!("Assigned destination {} to {}", destination.ident, edit.icao);
+            overlay.set_destination(&edit.icao, destination);
+        }
+        None => {
+            overlay.lookup_error = Some((edit.icao, format!("Unknown airport '{}'", edit.text.trim())));
+        }
+    }
+}
+
+/// System to draw the remaining great-circle route from each aircraft to its
+/// assigned destination airport.
+pub fn draw_destination_routes(
+    mut gizmos: Gizmos,
+    overlay: Res<RouteOverlay>,
+    tile_settings: Res<SlippyTilesSettings>,
+    map_state: Res<MapState>,
+    view3d_state: Res<crate::view3d::View3DState>,
+    aircraft_query: Query<&Aircraft>,
+) {
+    if !overlay.enabled || overlay.destinations.is_empty() {
+        return;
+    }
+    if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        return;
+    }
+
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let color = Color::srgba(1.0, 0.75, 0.3, 0.8);
+
+    for aircraft in aircraft_query.iter() {
+        let Some(destination) = overlay.destination(&aircraft.icao) else {
+            continue;
+        };
+
+        let mut points = vec![(aircraft.latitude, aircraft.longitude)];
+        points.extend(great_circle_subdivisions(
+            aircraft.latitude,
+            aircraft.longitude,
+            destination.latitude,
+            destination.longitude,
+            ROUTE_SEGMENT_NM,
+        ));
+        points.push((destination.latitude, destination.longitude));
+
+        let world: Vec<Vec2> = points.iter()
+            .map(|&(lat, lon)| converter.latlon_to_world(lat, lon))
+            .collect();
+        gizmos.linestrip_2d(world.iter().copied(), color);
+
+        if let Some(&airport_pos) = world.last() {
+            gizmos.circle_2d(airport_pos, 8.0, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_route_eta_uses_groundspeed() {
+        let destination = RouteDestination {
+            ident: "KSEA".to_string(),
+            name: "Seattle-Tacoma".to_string(),
+            latitude: 47.4490,
+            longitude: -122.3093,
+        };
+        // One degree of latitude south of the airport is ~60 nm
+        let (distance, eta) = remaining_route(46.4490, -122.3093, Some(240.0), &destination);
+        assert!((distance - 60.0).abs() < 0.5);
+        assert!((eta.unwrap() - 900.0).abs() < 10.0);
+
+        let (_, eta) = remaining_route(46.4490, -122.3093, Some(5.0), &destination);
+        assert!(eta.is_none());
+        assert_eq!(format_eta(900.0), "15 min");
+        assert_eq!(format_eta(5400.0), "1:30");
+    }
+}
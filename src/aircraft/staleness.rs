@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use chrono::Utc;
 
 use crate::{Aircraft, AircraftLabel};
+use super::declutter::DeclutterFade;
 
 /// Seconds before an aircraft starts dimming
 const STALE_START_SECS: f32 = 10.0;
@@ -30,15 +31,16 @@ pub fn aircraft_age_secs(aircraft: &Aircraft) -> f32 {
     (now - aircraft.last_seen).num_milliseconds().max(0) as f32 / 1000.0
 }
 
-/// System that dims stale aircraft labels based on time since last ADS-B update.
+/// System that dims stale aircraft labels based on time since last ADS-B update,
+/// combined with any altitude-declutter fade.
 pub fn dim_stale_aircraft(
-    aircraft_query: Query<&Aircraft>,
+    aircraft_query: Query<(&Aircraft, Option<&DeclutterFade>)>,
     mut label_query: Query<(&AircraftLabel, &mut TextColor)>,
 ) {
     for (label, mut text_color) in label_query.iter_mut() {
-        if let Ok(aircraft) = aircraft_query.get(label.aircraft_entity) {
+        if let Ok((aircraft, fade)) = aircraft_query.get(label.aircraft_entity) {
             let elapsed = aircraft_age_secs(aircraft);
-            let opacity = staleness_opacity(elapsed);
+            let opacity = staleness_opacity(elapsed) * fade.map_or(1.0, |f| f.0);
             text_color.0 = text_color.0.with_alpha(opacity);
         }
     }
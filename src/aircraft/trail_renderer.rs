@@ -1,26 +1,92 @@
 use bevy::prelude::*;
+use bevy::camera::visibility::RenderLayers;
+use bevy::gizmos::config::{GizmoConfigGroup, GizmoConfigStore};
 use bevy_slippy_tiles::*;
 
-use super::{TrailHistory, TrailConfig, SessionClock};
-use super::trails::{altitude_color, age_opacity, TrailRenderer};
+use std::collections::VecDeque;
+
+use super::{AircraftListState, CameraFollowState, LostTrails, TrailHistory, TrailConfig, SessionClock};
+use super::trails::{
+    age_opacity, length_opacity, simplify_track, TrailDisplayMode, TrailFadeMode, TrailPoint, TrailRenderer,
+    TRAIL_LENGTH_FADE_FRACTION, TRAIL_SIMPLIFY_TOLERANCE_M,
+};
+use super::altitude::AltitudeColorScheme;
+use super::declutter::{AltitudeHidden, DeclutterFade};
 use super::staleness::{staleness_opacity, aircraft_age_secs};
-use crate::{Aircraft, MapState};
-use crate::geo::CoordinateConverter;
+use crate::{Aircraft, MapState, RenderCategory};
+use crate::data_sources::DataSourceManager;
+use crate::geo::{great_circle_intermediate, haversine_distance_nm, CoordinateConverter};
 use crate::view3d::View3DState;
 
+/// Trail segments longer than this are subdivided along the great circle
+const GREAT_CIRCLE_SEGMENT_NM: f64 = 20.0;
+
+/// Ghost trails start at this fraction of a live trail's opacity
+const GHOST_TRAIL_OPACITY: f32 = 0.5;
+
+/// Intermediate great-circle points between two trail points, excluding
+/// the endpoints. Empty for segments short enough to draw as one line.
+pub fn great_circle_subdivisions(lat1: f64, lon1: f64, lat2: f64, lon2: f64, max_segment_nm: f64) -> Vec<(f64, f64)> {
+    let distance = haversine_distance_nm(lat1, lon1, lat2, lon2);
+    if distance <= max_segment_nm {
+        return Vec::new();
+    }
+    let segments = (distance / max_segment_nm).ceil() as usize;
+    (1..segments)
+        .map(|i| great_circle_intermediate(lat1, lon1, lat2, lon2, i as f64 / segments as f64))
+        .collect()
+}
+
+/// Gizmo group for flight trails, so trail line width can be configured
+/// independently of runways, navaids and other default gizmos.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct TrailGizmos;
+
+/// Apply the configured trail width (and the shared gizmo render layer) to
+/// the trail gizmo group.
+pub fn apply_trail_gizmo_config(
+    config: Res<TrailConfig>,
+    mut config_store: ResMut<GizmoConfigStore>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    let (gizmo_config, _) = config_store.config_mut::<TrailGizmos>();
+    gizmo_config.line.width = config.line_width;
+    gizmo_config.render_layers = RenderLayers::layer(RenderCategory::GIZMOS);
+}
+
+/// Shared inputs for drawing one trail
+struct TrailDrawContext<'a> {
+    config: &'a TrailConfig,
+    altitude_colors: &'a AltitudeColorScheme,
+    clock: &'a SessionClock,
+    converter: CoordinateConverter,
+    view3d_state: &'a View3DState,
+    sources: &'a DataSourceManager,
+    by_source: bool,
+    is_3d: bool,
+}
+
 /// System to draw flight trails using Gizmos.
 /// In 2D mode, draws flat trails. In 3D mode, draws trails at altitude using Vec3 positions.
 /// Skips drawing when the active renderer for the current mode is not Gizmo.
+/// Ghost trails of lost aircraft are drawn fading out when all trails are shown.
 pub fn draw_trails(
-    mut gizmos: Gizmos,
+    mut gizmos: Gizmos<TrailGizmos>,
     config: Res<TrailConfig>,
+    altitude_colors: Res<AltitudeColorScheme>,
     clock: Res<SessionClock>,
     tile_settings: Res<SlippyTilesSettings>,
     map_state: Res<MapState>,
     view3d_state: Res<View3DState>,
-    trail_query: Query<(&TrailHistory, &Aircraft)>,
+    list_state: Res<AircraftListState>,
+    follow_state: Res<CameraFollowState>,
+    sources: Res<DataSourceManager>,
+    lost_trails: Res<LostTrails>,
+    trail_query: Query<(&TrailHistory, &Aircraft, Option<&DeclutterFade>), Without<AltitudeHidden>>,
 ) {
-    if !config.enabled {
+    if !config.enabled || config.display_mode == TrailDisplayMode::None {
         return;
     }
 
@@ -33,53 +99,130 @@ pub fn draw_trails(
     // Gizmo trails draw in both 2D and 3D modes. In 3D, they render as
     // an overlay through Camera2d on the GIZMOS layer.
 
-    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let ctx = TrailDrawContext {
+        config: &config,
+        altitude_colors: &altitude_colors,
+        clock: &clock,
+        converter: CoordinateConverter::new(&tile_settings, map_state.zoom_level),
+        view3d_state: &view3d_state,
+        sources: &sources,
+        by_source: sources.trails_by_source(),
+        is_3d,
+    };
 
-    for (trail, aircraft) in trail_query.iter() {
-        let stale_opacity = staleness_opacity(aircraft_age_secs(aircraft));
+    let selected = list_state.selected_icao.as_deref();
+    let followed = follow_state.following_icao.as_deref();
 
-        if trail.points.len() < 2 {
+    for (trail, aircraft, fade) in trail_query.iter() {
+        if !config.display_mode.shows(&aircraft.icao, selected, followed) {
             continue;
         }
 
-        let mut prev_pos: Option<Vec3> = None;
-        let mut prev_color: Option<Color> = None;
-
-        for point in trail.points.iter() {
-            let opacity = age_opacity(
-                clock.age_secs(point.timestamp),
-                config.solid_duration_seconds,
-                config.fade_duration_seconds,
-            );
+        let stale_opacity = staleness_opacity(aircraft_age_secs(aircraft)) * fade.map_or(1.0, |f| f.0);
+        draw_trail_line(&mut gizmos, &ctx, &trail.points, trail.simplified_indices(), stale_opacity);
+    }
 
-            if opacity <= 0.0 {
-                prev_pos = None;
-                continue;
+    // Lost aircraft can't be selected or followed, so ghosts only show
+    // alongside everyone else's trails
+    if config.display_mode == TrailDisplayMode::All {
+        let now_secs = clock.now_secs();
+        for lost in lost_trails.trails.values() {
+            let opacity = lost.opacity(now_secs, config.ghost_duration_secs) * GHOST_TRAIL_OPACITY;
+            if opacity > 0.0 {
+                draw_trail_line(&mut gizmos, &ctx, &lost.points, None, opacity);
             }
+        }
+    }
+}
 
-            let xy = converter.latlon_to_world(point.lat, point.lon);
-            let z = if is_3d {
-                view3d_state.altitude_to_z(point.altitude.unwrap_or(0))
-            } else {
-                0.0
-            };
-            let pos = Vec3::new(xy.x, xy.y, z);
+/// Draw one trail, scaling every segment's alpha by `opacity_scale`.
+/// `simplified` is the cached `simplify_track` result when there is one.
+fn draw_trail_line(
+    gizmos: &mut Gizmos<TrailGizmos>,
+    ctx: &TrailDrawContext,
+    points: &VecDeque<TrailPoint>,
+    simplified: Option<&[usize]>,
+    opacity_scale: f32,
+) {
+    if points.len() < 2 {
+        return;
+    }
+
+    let mut prev_pos: Option<Vec3> = None;
+    let mut prev_color: Option<Color> = None;
+    let mut prev_latlon: Option<(f64, f64)> = None;
+
+    // Collapse nearly collinear runs (long cruise legs) before drawing
+    let computed;
+    let kept = match simplified {
+        Some(kept) => kept,
+        None => {
+            let track: Vec<_> = points.iter().map(|p| (p.lat, p.lon, p.altitude)).collect();
+            computed = simplify_track(&track, TRAIL_SIMPLIFY_TOLERANCE_M);
+            &computed
+        }
+    };
+
+    let count = points.len();
+    for (index, point) in kept.iter().map(|&i| (i, &points[i])) {
+        let opacity = match ctx.config.fade_mode {
+            TrailFadeMode::Age => age_opacity(
+                ctx.clock.age_secs(point.timestamp),
+                ctx.config.solid_duration_seconds,
+                ctx.config.fade_duration_seconds,
+            ),
+            TrailFadeMode::Length => length_opacity(index, count, TRAIL_LENGTH_FADE_FRACTION),
+        };
+
+        if opacity <= 0.0 {
+            prev_pos = None;
+            prev_latlon = None;
+            continue;
+        }
+
+        let xy = ctx.converter.latlon_to_world(point.lat, point.lon);
+        let z = if ctx.is_3d {
+            ctx.view3d_state.aircraft_height(point.altitude)
+        } else {
+            0.0
+        };
+        let pos = Vec3::new(xy.x, xy.y, z);
 
-            let base_color = altitude_color(point.altitude);
-            let color = base_color.with_alpha(opacity * stale_opacity);
+        // Points recorded before a second source came online keep the altitude color
+        let base_color = match point.source.as_deref() {
+            Some(source) if ctx.by_source => ctx.sources.source_color(source),
+            _ => ctx.altitude_colors.color(point.altitude),
+        };
+        let color = base_color.with_alpha(opacity * opacity_scale);
 
-            if let Some(prev) = prev_pos {
-                let draw_color = prev_color.unwrap_or(color);
-                if is_3d {
-                    gizmos.line(prev, pos, draw_color);
+        if let (Some(prev), Some((prev_lat, prev_lon))) = (prev_pos, prev_latlon) {
+            let draw_color = prev_color.unwrap_or(color);
+
+            // Long segments follow the great circle; z is interpolated linearly
+            let intermediates = great_circle_subdivisions(prev_lat, prev_lon, point.lat, point.lon, GREAT_CIRCLE_SEGMENT_NM);
+            let steps = intermediates.len() + 1;
+            let mut from = prev;
+            for (i, (lat, lon)) in intermediates.into_iter().enumerate() {
+                let xy = ctx.converter.latlon_to_world(lat, lon);
+                let t = (i + 1) as f32 / steps as f32;
+                let to = Vec3::new(xy.x, xy.y, prev.z + (pos.z - prev.z) * t);
+                if ctx.is_3d {
+                    gizmos.line(from, to, draw_color);
                 } else {
-                    gizmos.line_2d(prev.truncate(), pos.truncate(), draw_color);
+                    gizmos.line_2d(from.truncate(), to.truncate(), draw_color);
                 }
+                from = to;
+            }
+            if ctx.is_3d {
+                gizmos.line(from, pos, draw_color);
+            } else {
+                gizmos.line_2d(from.truncate(), pos.truncate(), draw_color);
             }
-
-            prev_pos = Some(pos);
-            prev_color = Some(color);
         }
+
+        prev_pos = Some(pos);
+        prev_color = Some(color);
+        prev_latlon = Some((point.lat, point.lon));
     }
 }
 
@@ -93,3 +236,32 @@ pub fn prune_trails(
         trail.prune(config.max_age_seconds, &clock);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_segments_are_not_subdivided() {
+        assert!(great_circle_subdivisions(37.6, -97.4, 37.7, -97.3, GREAT_CIRCLE_SEGMENT_NM).is_empty());
+    }
+
+    #[test]
+    fn long_segment_follows_great_circle() {
+        // New York to London: the great circle bows well north of the
+        // straight lat/lon line between the endpoints.
+        let (lat1, lon1, lat2, lon2) = (40.64, -73.78, 51.47, -0.45);
+        let points = great_circle_subdivisions(lat1, lon1, lat2, lon2, GREAT_CIRCLE_SEGMENT_NM);
+        let total = haversine_distance_nm(lat1, lon1, lat2, lon2);
+        assert_eq!(points.len(), (total / GREAT_CIRCLE_SEGMENT_NM).ceil() as usize - 1);
+
+        for &(lat, lon) in &points {
+            // Every point lies on the great circle between the endpoints
+            let via = haversine_distance_nm(lat1, lon1, lat, lon) + haversine_distance_nm(lat, lon, lat2, lon2);
+            assert!((via - total).abs() < 0.01, "point off great circle by {} nm", via - total);
+        }
+
+        let (mid_lat, _) = points[points.len() / 2];
+        assert!(mid_lat > (lat1 + lat2) / 2.0 + 5.0, "midpoint latitude {} should bow north", mid_lat);
+    }
+}
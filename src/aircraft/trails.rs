@@ -1,9 +1,12 @@
 use bevy::prelude::*;
+use bevy::math::DVec3;
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::time::Instant;
 
+use crate::geo::{haversine_distance_nm, FEET_TO_METERS, NM_TO_KM};
+
 /// Which renderer to use for aircraft trails.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TrailRenderer {
@@ -13,6 +16,71 @@ pub enum TrailRenderer {
     Particle,
 }
 
+/// Which aircraft have their trails drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrailDisplayMode {
+    #[default]
+    All,
+    SelectedOnly,
+    FollowedOnly,
+    None,
+}
+
+impl TrailDisplayMode {
+    pub const ALL: &'static [TrailDisplayMode] = &[
+        TrailDisplayMode::All,
+        TrailDisplayMode::SelectedOnly,
+        TrailDisplayMode::FollowedOnly,
+        TrailDisplayMode::None,
+    ];
+
+    /// Whether the trail for `icao` should be drawn given the current
+    /// selected and followed aircraft.
+    pub fn shows(&self, icao: &str, selected: Option<&str>, followed: Option<&str>) -> bool {
+        match self {
+            TrailDisplayMode::All => true,
+            TrailDisplayMode::SelectedOnly => selected == Some(icao),
+            TrailDisplayMode::FollowedOnly => followed == Some(icao),
+            TrailDisplayMode::None => false,
+        }
+    }
+}
+
+impl fmt::Display for TrailDisplayMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrailDisplayMode::All => write!(f, "All"),
+            TrailDisplayMode::SelectedOnly => write!(f, "Selected only"),
+            TrailDisplayMode::FollowedOnly => write!(f, "Followed only"),
+            TrailDisplayMode::None => write!(f, "None"),
+        }
+    }
+}
+
+/// How trail segments fade toward the tail.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrailFadeMode {
+    /// Older points are more transparent (suits slow traffic)
+    #[default]
+    Age,
+    /// Points toward the tail are more transparent regardless of age, so
+    /// fast jets keep a consistent-looking trail
+    Length,
+}
+
+impl TrailFadeMode {
+    pub const ALL: &'static [TrailFadeMode] = &[TrailFadeMode::Age, TrailFadeMode::Length];
+}
+
+impl fmt::Display for TrailFadeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrailFadeMode::Age => write!(f, "By age"),
+            TrailFadeMode::Length => write!(f, "By length"),
+        }
+    }
+}
+
 /// Resource providing a session-relative clock for serializable timestamps.
 /// Trail points store seconds since this clock's epoch (session start).
 #[derive(Resource)]
@@ -48,12 +116,52 @@ pub struct TrailPoint {
     pub altitude: Option<i32>,
     /// Seconds since session start (serializable replacement for Instant)
     pub timestamp: f64,
+    /// Primary data source when the point was recorded (multi-source only)
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+/// Trail history window used for the smoothed altitude trend
+pub const ALTITUDE_TREND_WINDOW_SECS: f64 = 60.0;
+
+/// Smoothed climb rate (ft/min) below which an aircraft is considered level
+const LEVEL_TREND_FPM: f64 = 250.0;
+
+/// Short-term climb/descent trend derived from trail altitudes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AltitudeTrend {
+    Climbing,
+    Level,
+    Descending,
+}
+
+impl AltitudeTrend {
+    pub fn from_rate(fpm: f64) -> Self {
+        if fpm >= LEVEL_TREND_FPM {
+            AltitudeTrend::Climbing
+        } else if fpm <= -LEVEL_TREND_FPM {
+            AltitudeTrend::Descending
+        } else {
+            AltitudeTrend::Level
+        }
+    }
+
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            AltitudeTrend::Climbing => "\u{2197}",
+            AltitudeTrend::Level => "\u{2192}",
+            AltitudeTrend::Descending => "\u{2198}",
+        }
+    }
 }
 
 /// Component storing trail history for an aircraft
 #[derive(Component, Default)]
 pub struct TrailHistory {
     pub points: VecDeque<TrailPoint>,
+    /// `simplify_track` result for `points`, refreshed when points are
+    /// added or pruned instead of on every draw
+    simplified: Vec<usize>,
 }
 
 impl fmt::Display for TrailRenderer {
@@ -83,6 +191,20 @@ pub struct TrailConfig {
     pub fade_duration_seconds: u64,
     pub renderer_2d: TrailRenderer,
     pub renderer_3d: TrailRenderer,
+    /// Gizmo trail line width in pixels
+    pub line_width: f32,
+    /// Minimum distance moved (meters) before another point is recorded
+    pub min_point_distance_m: f64,
+    /// Maximum seconds between recorded points, regardless of movement
+    pub max_point_interval_secs: u64,
+    /// Which aircraft's trails are drawn (points are recorded for all)
+    pub display_mode: TrailDisplayMode,
+    /// Fade older points or points toward the tail
+    pub fade_mode: TrailFadeMode,
+    /// Keep the trails of lost aircraft as fading ghosts
+    pub ghost_trails: bool,
+    /// Seconds a ghost trail takes to fade out after signal loss
+    pub ghost_duration_secs: u64,
 }
 
 impl Default for TrailConfig {
@@ -97,24 +219,90 @@ impl Default for TrailConfig {
             renderer_3d: TrailRenderer::Particle,
             #[cfg(not(feature = "hanabi"))]
             renderer_3d: TrailRenderer::Gizmo,
+            line_width: 2.0,
+            min_point_distance_m: 50.0,
+            max_point_interval_secs: 15,
+            display_mode: TrailDisplayMode::All,
+            fade_mode: TrailFadeMode::Age,
+            ghost_trails: false,
+            ghost_duration_secs: 120,
         }
     }
 }
 
 impl TrailHistory {
     /// Add a new point to the trail
-    pub fn add_point(&mut self, lat: f64, lon: f64, altitude: Option<i32>, clock: &SessionClock) {
+    pub fn add_point(&mut self, lat: f64, lon: f64, altitude: Option<i32>, source: Option<&str>, clock: &SessionClock) {
         self.points.push_back(TrailPoint {
             lat,
             lon,
             altitude,
             timestamp: clock.now_secs(),
+            source: source.map(str::to_string),
+        });
+        self.resimplify();
+    }
+
+    /// Whether a position should be recorded as a new point: always for an
+    /// empty trail, otherwise once the aircraft has moved at least
+    /// `min_distance_m` from the last point or `max_interval_secs` have passed.
+    pub fn should_record(
+        &self,
+        lat: f64,
+        lon: f64,
+        now_secs: f64,
+        min_distance_m: f64,
+        max_interval_secs: u64,
+    ) -> bool {
+        let Some(last) = self.points.back() else {
+            return true;
+        };
+        if now_secs - last.timestamp >= max_interval_secs as f64 {
+            return true;
+        }
+        let moved_m = haversine_distance_nm(last.lat, last.lon, lat, lon) * NM_TO_KM * 1000.0;
+        moved_m >= min_distance_m
+    }
+
+    /// Smoothed vertical speed in ft/min: the least-squares slope of the
+    /// altitudes recorded in the last `window_secs` of the trail. `None`
+    /// until there are at least three altitude samples spanning 10 seconds.
+    pub fn altitude_trend_fpm(&self, window_secs: f64) -> Option<f64> {
+        let newest = self.points.back()?.timestamp;
+        let samples: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .rev()
+            .take_while(|p| newest - p.timestamp <= window_secs)
+            .filter_map(|p| p.altitude.map(|alt| (p.timestamp, alt as f64)))
+            .collect();
+        if samples.len() < 3 {
+            return None;
+        }
+        let span = samples.first()?.0 - samples.last()?.0;
+        if span < 10.0 {
+            return None;
+        }
+
+        let n = samples.len() as f64;
+        let mean_t = samples.iter().map(|(t, _)| t).sum::<f64>() / n;
+        let mean_alt = samples.iter().map(|(_, a)| a).sum::<f64>() / n;
+        let (cov, var) = samples.iter().fold((0.0, 0.0), |(cov, var), (t, a)| {
+            let dt = t - mean_t;
+            (cov + dt * (a - mean_alt), var + dt * dt)
         });
+        Some(cov / var * 60.0)
+    }
+
+    /// Climbing / level / descending over the default trend window
+    pub fn altitude_trend(&self) -> Option<AltitudeTrend> {
+        self.altitude_trend_fpm(ALTITUDE_TREND_WINDOW_SECS).map(AltitudeTrend::from_rate)
     }
 
     /// Remove points older than max_age
     pub fn prune(&mut self, max_age_seconds: u64, clock: &SessionClock) {
         let cutoff = clock.now_secs() - max_age_seconds as f64;
+        let before = self.points.len();
         while let Some(front) = self.points.front() {
             if front.timestamp < cutoff {
                 self.points.pop_front();
@@ -122,33 +310,100 @@ impl TrailHistory {
                 break;
             }
         }
+        if self.points.len() != before {
+            self.resimplify();
+        }
+    }
+
+    /// Indices of the points to draw, cached from the last add or prune.
+    /// `None` if `points` was changed directly since then.
+    pub fn simplified_indices(&self) -> Option<&[usize]> {
+        let covers_points = self.simplified.first() == Some(&0)
+            && self.simplified.last().map(|&i| i + 1) == Some(self.points.len());
+        covers_points.then_some(self.simplified.as_slice())
+    }
+
+    fn resimplify(&mut self) {
+        let track: Vec<_> = self.points.iter().map(|p| (p.lat, p.lon, p.altitude)).collect();
+        self.simplified = simplify_track(&track, TRAIL_SIMPLIFY_TOLERANCE_M);
     }
 }
 
-/// Get color for altitude (cyan at low, purple at high)
-pub fn altitude_color(altitude: Option<i32>) -> Color {
-    let alt = altitude.unwrap_or(0).max(0) as f32;
+/// Perpendicular tolerance used when thinning trails for drawing and export.
+/// Points closer than this to the line through their neighbours add nothing visible.
+pub const TRAIL_SIMPLIFY_TOLERANCE_M: f64 = 30.0;
 
-    // Altitude ranges: 0-10k cyan, 10k-20k green, 20k-30k yellow, 30k-40k orange, 40k+ purple
-    let t = (alt / 40000.0).clamp(0.0, 1.0);
+/// Douglas–Peucker simplification of a track of `(lat, lon, altitude_ft)`
+/// points. Returns the indices of points to keep, always including the first
+/// and last. Altitude takes part in the distance so climbs and descents on a
+/// straight ground track are preserved for 3D trails.
+pub fn simplify_track(points: &[(f64, f64, Option<i32>)], tolerance_m: f64) -> Vec<usize> {
+    let n = points.len();
+    if n <= 2 || tolerance_m <= 0.0 {
+        return (0..n).collect();
+    }
 
-    if t < 0.25 {
-        // Cyan to green
-        let s = t / 0.25;
-        Color::srgb(0.0, 1.0 - s * 0.5, 1.0 - s)
-    } else if t < 0.5 {
-        // Green to yellow
-        let s = (t - 0.25) / 0.25;
-        Color::srgb(s, 0.5 + s * 0.5, 0.0)
-    } else if t < 0.75 {
-        // Yellow to orange
-        let s = (t - 0.5) / 0.25;
-        Color::srgb(1.0, 1.0 - s * 0.4, 0.0)
-    } else {
-        // Orange to purple
-        let s = (t - 0.75) / 0.25;
-        Color::srgb(1.0 - s * 0.2, 0.6 - s * 0.6, s)
+    // Project to a local flat frame in meters around the first point
+    let (lat0, lon0, _) = points[0];
+    let m_per_deg = 60.0 * NM_TO_KM * 1000.0;
+    let cos_lat = lat0.to_radians().cos();
+    let mut last_alt = 0.0;
+    let projected: Vec<DVec3> = points
+        .iter()
+        .map(|&(lat, lon, alt)| {
+            if let Some(alt) = alt {
+                last_alt = alt as f64 * FEET_TO_METERS;
+            }
+            let dlon = (lon - lon0 + 540.0).rem_euclid(360.0) - 180.0;
+            DVec3::new(dlon * cos_lat * m_per_deg, (lat - lat0) * m_per_deg, last_alt)
+        })
+        .collect();
+
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+    let mut stack = vec![(0, n - 1)];
+    while let Some((start, end)) = stack.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+        let a = projected[start];
+        let ab = projected[end] - a;
+        let len_sq = ab.length_squared();
+        let mut farthest = (start, 0.0);
+        for (i, p) in projected.iter().enumerate().take(end).skip(start + 1) {
+            let ap = *p - a;
+            let distance = if len_sq > 0.0 {
+                ap.cross(ab).length() / len_sq.sqrt()
+            } else {
+                ap.length()
+            };
+            if distance > farthest.1 {
+                farthest = (i, distance);
+            }
+        }
+        if farthest.1 > tolerance_m {
+            keep[farthest.0] = true;
+            stack.push((start, farthest.0));
+            stack.push((farthest.0, end));
+        }
     }
+
+    (0..n).filter(|&i| keep[i]).collect()
+}
+
+/// Fraction of the trail (oldest points) that fades in `TrailFadeMode::Length`
+pub const TRAIL_LENGTH_FADE_FRACTION: f32 = 0.25;
+
+/// Calculate opacity based on a point's position in the trail: the newest
+/// point (`index == count - 1`) is opaque and the oldest `fade_fraction` of
+/// the trail ramps down toward the tail.
+pub fn length_opacity(index: usize, count: usize, fade_fraction: f32) -> f32 {
+    if count == 0 || fade_fraction <= 0.0 {
+        return 1.0;
+    }
+    let fade_points = count as f32 * fade_fraction;
+    ((index + 1) as f32 / fade_points).min(1.0)
 }
 
 /// Calculate opacity based on age (seconds since the point was recorded).
@@ -166,7 +421,85 @@ pub fn age_opacity(age_secs: f64, solid_secs: u64, fade_secs: u64) -> f32 {
     }
 }
 
-/// Resource to track when we last recorded trail points
+/// Trail of an aircraft that dropped out of the feed
+pub struct LostTrail {
+    pub points: VecDeque<TrailPoint>,
+    /// Session seconds when the aircraft was removed
+    pub lost_at: f64,
+}
+
+impl LostTrail {
+    /// Ghost opacity, fading linearly from 1 at signal loss to 0 after
+    /// `duration_secs`
+    pub fn opacity(&self, now_secs: f64, duration_secs: u64) -> f32 {
+        if duration_secs == 0 {
+            return 0.0;
+        }
+        (1.0 - (now_secs - self.lost_at) / duration_secs as f64).clamp(0.0, 1.0) as f32
+    }
+}
+
+/// Trails of recently lost aircraft keyed by ICAO, drawn as fading ghosts
+/// until they expire or the aircraft is picked up again.
+#[derive(Resource, Default)]
+pub struct LostTrails {
+    pub trails: HashMap<String, LostTrail>,
+}
+
+impl LostTrails {
+    /// Drop ghosts older than `duration_secs`
+    pub fn expire(&mut self, now_secs: f64, duration_secs: u64) {
+        self.trails.retain(|_, lost| now_secs - lost.lost_at < duration_secs as f64);
+    }
+}
+
+/// Observer that keeps an aircraft's trail as a ghost when its entity is
+/// despawned, if ghost trails are enabled.
+pub fn retain_lost_trail(
+    remove: On<Remove, TrailHistory>,
+    config: Res<TrailConfig>,
+    clock: Res<SessionClock>,
+    mut lost: ResMut<LostTrails>,
+    query: Query<(&crate::Aircraft, &TrailHistory)>,
+) {
+    if !config.enabled || !config.ghost_trails {
+        return;
+    }
+    let Ok((aircraft, trail)) = query.get(remove.entity) else {
+        return;
+    };
+    if trail.points.len() < 2 {
+        return;
+    }
+    lost.trails.insert(aircraft.icao.clone(), LostTrail {
+        points: trail.points.clone(),
+        lost_at: clock.now_secs(),
+    });
+}
+
+/// System that expires ghost trails and drops the ghost of any aircraft
+/// that reappears, since its new trail takes over.
+pub fn update_lost_trails(
+    config: Res<TrailConfig>,
+    clock: Res<SessionClock>,
+    mut lost: ResMut<LostTrails>,
+    new_aircraft: Query<&crate::Aircraft, Added<crate::Aircraft>>,
+) {
+    if lost.trails.is_empty() {
+        return;
+    }
+    if !config.enabled || !config.ghost_trails {
+        lost.trails.clear();
+        return;
+    }
+    for aircraft in new_aircraft.iter() {
+        lost.trails.remove(&aircraft.icao);
+    }
+    lost.expire(clock.now_secs(), config.ghost_duration_secs);
+}
+
+/// Resource to track when we last sampled aircraft positions for trails.
+/// Each sample only records a point when `TrailHistory::should_record` allows it.
 #[derive(Resource)]
 pub struct TrailRecordTimer {
     pub last_record: Instant,
@@ -177,7 +510,7 @@ impl Default for TrailRecordTimer {
     fn default() -> Self {
         Self {
             last_record: Instant::now(),
-            interval_secs: 2.0, // Record position every 2 seconds
+            interval_secs: 2.0, // Sample positions every 2 seconds
         }
     }
 }
@@ -187,6 +520,7 @@ pub fn record_trail_points(
     mut timer: ResMut<TrailRecordTimer>,
     config: Res<TrailConfig>,
     clock: Res<SessionClock>,
+    sources: Res<crate::data_sources::DataSourceManager>,
     mut query: Query<(&crate::Aircraft, &mut TrailHistory)>,
 ) {
     if !config.enabled {
@@ -199,7 +533,144 @@ pub fn record_trail_points(
     }
     timer.last_record = now;
 
+    let now_secs = clock.now_secs();
+    // Only tag points when there is more than one feed to tell apart
+    let multi_source = sources.is_multi_source();
     for (aircraft, mut trail) in query.iter_mut() {
-        trail.add_point(aircraft.latitude, aircraft.longitude, aircraft.altitude, &clock);
+        if trail.should_record(
+            aircraft.latitude,
+            aircraft.longitude,
+            now_secs,
+            config.min_point_distance_m,
+            config.max_point_interval_secs,
+        ) {
+            let source = multi_source
+                .then(|| sources.merged(&aircraft.icao))
+                .flatten()
+                .map(|m| m.primary_source.as_str())
+                .filter(|s| !s.is_empty());
+            trail.add_point(aircraft.latitude, aircraft.longitude, aircraft.altitude, source, &clock);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trail_with_point(lat: f64, lon: f64, timestamp: f64) -> TrailHistory {
+        let mut trail = TrailHistory::default();
+        trail.points.push_back(TrailPoint { lat, lon, altitude: None, timestamp, source: None });
+        trail
+    }
+
+    #[test]
+    fn altitude_trend_ignores_single_sample_noise() {
+        let mut trail = TrailHistory::default();
+        // Steady 1200 ft/min climb sampled every 5 s, with one bad reading
+        for i in 0..12 {
+            let mut altitude = 10_000 + i * 100;
+            if i == 10 {
+                altitude -= 800;
+            }
+            trail.points.push_back(TrailPoint { lat: 40.0, lon: -74.0, altitude: Some(altitude), timestamp: i as f64 * 5.0, source: None });
+        }
+        let fpm = trail.altitude_trend_fpm(ALTITUDE_TREND_WINDOW_SECS).unwrap();
+        assert!(fpm > 800.0, "{fpm}");
+        assert_eq!(trail.altitude_trend(), Some(AltitudeTrend::Climbing));
+
+        let short = trail_with_point(40.0, -74.0, 0.0);
+        assert_eq!(short.altitude_trend(), None);
+    }
+
+    #[test]
+    fn display_mode_filters_by_selection() {
+        let selected = Some("A1B2C3");
+        let followed = Some("ABCDEF");
+        assert!(TrailDisplayMode::All.shows("000001", selected, followed));
+        assert!(TrailDisplayMode::SelectedOnly.shows("A1B2C3", selected, followed));
+        assert!(!TrailDisplayMode::SelectedOnly.shows("ABCDEF", selected, followed));
+        assert!(TrailDisplayMode::FollowedOnly.shows("ABCDEF", selected, followed));
+        assert!(!TrailDisplayMode::FollowedOnly.shows("ABCDEF", selected, None));
+        assert!(!TrailDisplayMode::None.shows("A1B2C3", selected, followed));
+    }
+
+    #[test]
+    fn simplification_thins_straight_runs() {
+        // Ten points due north along one meridian at constant altitude
+        let track: Vec<_> = (0..10).map(|i| (40.0 + i as f64 * 0.01, -74.0, Some(35_000))).collect();
+        assert_eq!(simplify_track(&track, TRAIL_SIMPLIFY_TOLERANCE_M), vec![0, 9]);
+    }
+
+    #[test]
+    fn simplification_preserves_turns_and_climbs() {
+        // North, then a 90° turn east
+        let mut track: Vec<_> = (0..5).map(|i| (40.0 + i as f64 * 0.01, -74.0, Some(10_000))).collect();
+        track.extend((1..5).map(|i| (40.04, -74.0 + i as f64 * 0.01, Some(10_000))));
+        assert_eq!(simplify_track(&track, TRAIL_SIMPLIFY_TOLERANCE_M), vec![0, 4, 8]);
+
+        // Straight ground track that levels off after a climb keeps the level-off point
+        let climb: Vec<_> = (0..6)
+            .map(|i| (40.0 + i as f64 * 0.01, -74.0, Some(if i < 3 { i * 3000 } else { 6000 })))
+            .collect();
+        assert_eq!(simplify_track(&climb, TRAIL_SIMPLIFY_TOLERANCE_M), vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn simplified_indices_follow_added_points() {
+        let clock = SessionClock::default();
+        let mut trail = TrailHistory::default();
+        for i in 0..10 {
+            trail.add_point(40.0 + i as f64 * 0.01, -74.0, Some(35_000), None, &clock);
+        }
+        assert_eq!(trail.simplified_indices(), Some(&[0, 9][..]));
+
+        // Direct edits invalidate the cache until the next add or prune
+        trail.points.pop_back();
+        assert_eq!(trail.simplified_indices(), None);
+        trail.add_point(40.09, -74.0, Some(35_000), None, &clock);
+        assert_eq!(trail.simplified_indices(), Some(&[0, 9][..]));
+    }
+
+    #[test]
+    fn length_opacity_fades_oldest_points() {
+        assert_eq!(length_opacity(99, 100, 0.25), 1.0);
+        assert_eq!(length_opacity(50, 100, 0.25), 1.0);
+        assert!((length_opacity(11, 100, 0.25) - 0.48).abs() < 1e-6);
+        assert!(length_opacity(0, 100, 0.25) > 0.0);
+        assert!(length_opacity(0, 100, 0.25) < 0.1);
+    }
+
+    #[test]
+    fn lost_trails_fade_and_expire() {
+        let mut lost = LostTrails::default();
+        let trail = trail_with_point(47.0, -122.0, 0.0);
+        lost.trails.insert("A1B2C3".to_string(), LostTrail { points: trail.points.clone(), lost_at: 100.0 });
+        lost.trails.insert("ABCDEF".to_string(), LostTrail { points: trail.points, lost_at: 10.0 });
+
+        assert_eq!(lost.trails["A1B2C3"].opacity(100.0, 120), 1.0);
+        assert_eq!(lost.trails["A1B2C3"].opacity(160.0, 120), 0.5);
+        assert_eq!(lost.trails["A1B2C3"].opacity(300.0, 120), 0.0);
+
+        lost.expire(140.0, 120);
+        assert!(lost.trails.contains_key("A1B2C3"));
+        assert!(!lost.trails.contains_key("ABCDEF"));
+    }
+
+    #[test]
+    fn empty_trail_always_records() {
+        let trail = TrailHistory::default();
+        assert!(trail.should_record(40.0, -74.0, 0.0, 50.0, 15));
+    }
+
+    #[test]
+    fn records_on_distance_or_max_interval() {
+        let trail = trail_with_point(40.0, -74.0, 100.0);
+        // ~11 m north, 4 s later: skipped
+        assert!(!trail.should_record(40.0001, -74.0, 104.0, 50.0, 15));
+        // ~111 m north: recorded
+        assert!(trail.should_record(40.001, -74.0, 104.0, 50.0, 15));
+        // Parked, but the max interval has elapsed: recorded
+        assert!(trail.should_record(40.0, -74.0, 115.0, 50.0, 15));
     }
 }
@@ -81,6 +81,48 @@ pub enum LoadingState {
     Failed,
 }
 
+impl LoadingState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LoadingState::NotStarted => "not started",
+            LoadingState::Downloading => "loading",
+            LoadingState::Ready => "ready",
+            LoadingState::Failed => "failed",
+        }
+    }
+}
+
+/// Marker on aircraft whose type lookup is waiting for the database to load.
+#[derive(Component)]
+pub struct TypeLookupPending;
+
+/// Progress of the aircraft type database, for display in the UI.
+#[derive(Resource, Default)]
+pub struct AircraftTypeDbStatus {
+    pub state: LoadingState,
+    /// Number of records loaded
+    pub entries: usize,
+    /// Aircraft currently waiting on a lookup
+    pub pending_lookups: usize,
+    pub error: Option<String>,
+}
+
+impl AircraftTypeDbStatus {
+    pub fn is_ready(&self) -> bool {
+        self.state == LoadingState::Ready
+    }
+
+    /// One-line summary, e.g. "ready (512034 entries)".
+    pub fn summary(&self) -> String {
+        match self.state {
+            LoadingState::Ready => format!("ready ({} entries)", self.entries),
+            LoadingState::Downloading => format!("loading ({} lookups pending)", self.pending_lookups),
+            LoadingState::Failed => format!("failed: {}", self.error.as_deref().unwrap_or("unknown error")),
+            LoadingState::NotStarted => LoadingState::NotStarted.label().to_string(),
+        }
+    }
+}
+
 /// Resource holding the aircraft type database keyed by lowercase ICAO24
 #[derive(Resource, Default)]
 pub struct AircraftTypeDatabase {
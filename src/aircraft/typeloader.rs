@@ -3,7 +3,9 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use crate::data::{cache_path, is_cache_fresh, download_file_blocking, DataFile};
-use super::typeinfo::{AircraftTypeRecord, AircraftTypeInfo, AircraftTypeDatabase, LoadingState};
+use super::typeinfo::{
+    AircraftTypeRecord, AircraftTypeInfo, AircraftTypeDatabase, AircraftTypeDbStatus, LoadingState, TypeLookupPending,
+};
 use super::components::Aircraft;
 
 /// Resource holding the shared handle to the background loading thread result.
@@ -81,8 +83,12 @@ pub fn start_aircraft_type_loading(
 /// Update system: polls the background thread and moves data into the resource when ready.
 pub fn poll_aircraft_type_loading(
     mut db: ResMut<AircraftTypeDatabase>,
+    mut status: ResMut<AircraftTypeDbStatus>,
     load_handle: Option<Res<AircraftTypeLoadHandle>>,
 ) {
+    if status.state != db.loading_state {
+        status.state = db.loading_state;
+    }
     if db.loading_state != LoadingState::Downloading {
         return;
     }
@@ -104,28 +110,64 @@ pub fn poll_aircraft_type_loading(
             let count = records.len();
             db.records = records;
             db.loading_state = LoadingState::Ready;
+            status.state = LoadingState::Ready;
+            status.entries = count;
             info!("Aircraft type database ready: {} entries", count);
         }
         Err(e) => {
             error!("Failed to load aircraft type database: {}", e);
             db.loading_state = LoadingState::Failed;
+            status.state = LoadingState::Failed;
+            status.error = Some(e);
         }
     }
 }
 
 /// Update system: attaches AircraftTypeInfo components to aircraft entities that don't have one yet.
+///
+/// While the database is still loading, unresolved aircraft are tagged with
+/// `TypeLookupPending` so the UI can show that type info is on its way.
 pub fn attach_aircraft_type_info(
     mut commands: Commands,
     db: Res<AircraftTypeDatabase>,
-    query: Query<(Entity, &Aircraft), Without<AircraftTypeInfo>>,
+    mut status: ResMut<AircraftTypeDbStatus>,
+    query: Query<(Entity, &Aircraft, Has<TypeLookupPending>), Without<AircraftTypeInfo>>,
 ) {
-    if db.loading_state != LoadingState::Ready {
-        return;
-    }
-
-    for (entity, aircraft) in query.iter() {
-        if let Some(type_info) = db.lookup(&aircraft.icao) {
-            commands.entity(entity).insert(type_info);
+    match db.loading_state {
+        LoadingState::NotStarted | LoadingState::Downloading => {
+            let mut pending = 0;
+            for (entity, _, is_pending) in query.iter() {
+                if !is_pending {
+                    commands.entity(entity).insert(TypeLookupPending);
+                }
+                pending += 1;
+            }
+            if status.pending_lookups != pending {
+                status.pending_lookups = pending;
+            }
+        }
+        LoadingState::Ready => {
+            for (entity, aircraft, is_pending) in query.iter() {
+                if let Some(type_info) = db.lookup(&aircraft.icao) {
+                    commands.entity(entity).insert(type_info);
+                }
+                if is_pending {
+                    commands.entity(entity).remove::<TypeLookupPending>();
+                }
+            }
+            if status.pending_lookups != 0 {
+                status.pending_lookups = 0;
+            }
+        }
+        LoadingState::Failed => {
+            for (entity, _, is_pending) in query.iter() {
+                if is_pending {
+                    commands.entity(entity).remove::<TypeLookupPending>();
+                }
+            }
+            if status.pending_lookups != 0 {
+                status.pending_lookups = 0;
+            }
         }
     }
 }
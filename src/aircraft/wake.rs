@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+use super::typeinfo::AircraftTypeInfo;
+
+/// ICAO wake-turbulence category.
+///
+/// The ADS-B feed doesn't expose the emitter category, so this is derived
+/// from the ICAO type designator resolved by the aircraft type database.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WakeCategory {
+    Light,
+    Medium,
+    Heavy,
+    Super,
+}
+
+/// Type designators certified above 560,000 kg MTOW (ICAO category J).
+const SUPER_TYPES: &[&str] = &["A388", "A124", "A225"];
+
+/// Type designators at or above 136,000 kg MTOW.
+const HEAVY_TYPES: &[&str] = &[
+    "A306", "A30B", "A310", "A332", "A333", "A337", "A338", "A339", "A342", "A343", "A345",
+    "A346", "A359", "A35K", "A400", "B741", "B742", "B743", "B744", "B748", "B74S", "B762",
+    "B763", "B764", "B772", "B773", "B778", "B779", "B77L", "B77W", "B788", "B789", "B78X",
+    "C5M", "C17", "DC10", "E3CF", "E3TF", "E6", "IL76", "IL96", "K35R", "KC10", "MD11",
+    "B52", "VC10",
+];
+
+/// Common type designators at or below 7,000 kg MTOW.
+const LIGHT_TYPES: &[&str] = &[
+    "BE20", "BE33", "BE35", "BE36", "BE55", "BE58", "BE9L", "C150", "C152", "C162", "C170",
+    "C172", "C177", "C180", "C182", "C185", "C206", "C208", "C210", "C25A", "C25B", "C310",
+    "C340", "C414", "C421", "C510", "C525", "C72R", "C77R", "C82R", "DA20", "DA40", "DA42",
+    "DA62", "E50P", "EC20", "EC35", "M20P", "M20T", "P28A", "P28B", "P28R", "P32R", "PA18",
+    "PA24", "PA31", "PA32", "PA34", "PA44", "PA46", "PC12", "R22", "R44", "R66", "S22T",
+    "SR20", "SR22", "TBM7", "TBM8", "TBM9", "B06", "AS50", "H500",
+];
+
+impl WakeCategory {
+    /// Category for an ICAO type designator. Types not listed as light,
+    /// heavy or super are treated as medium.
+    pub fn from_type_code(type_code: &str) -> Option<Self> {
+        let code = type_code.trim().to_ascii_uppercase();
+        if code.is_empty() {
+            return None;
+        }
+        let code = code.as_str();
+        Some(if SUPER_TYPES.contains(&code) {
+            WakeCategory::Super
+        } else if HEAVY_TYPES.contains(&code) {
+            WakeCategory::Heavy
+        } else if LIGHT_TYPES.contains(&code) {
+            WakeCategory::Light
+        } else {
+            WakeCategory::Medium
+        })
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WakeCategory::Light => "Light",
+            WakeCategory::Medium => "Medium",
+            WakeCategory::Heavy => "Heavy",
+            WakeCategory::Super => "Super",
+        }
+    }
+
+    /// Multiplier applied to the aircraft marker size.
+    pub fn icon_scale(&self) -> f32 {
+        match self {
+            WakeCategory::Light => 0.75,
+            WakeCategory::Medium => 1.0,
+            WakeCategory::Heavy => 1.3,
+            WakeCategory::Super => 1.5,
+        }
+    }
+}
+
+/// System that tags aircraft with their wake category once type info resolves.
+pub fn assign_wake_category(
+    mut commands: Commands,
+    query: Query<(Entity, &AircraftTypeInfo, Option<&WakeCategory>), Changed<AircraftTypeInfo>>,
+) {
+    for (entity, type_info, current) in query.iter() {
+        let category = type_info.type_code.as_deref().and_then(WakeCategory::from_type_code);
+        match (category, current) {
+            (Some(category), Some(current)) if category == *current => {}
+            (Some(category), _) => {
+                commands.entity(entity).insert(category);
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<WakeCategory>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categorizes_known_types() {
+        assert_eq!(WakeCategory::from_type_code("A388"), Some(WakeCategory::Super));
+        assert_eq!(WakeCategory::from_type_code("b77w"), Some(WakeCategory::Heavy));
+        assert_eq!(WakeCategory::from_type_code("C172"), Some(WakeCategory::Light));
+        assert_eq!(WakeCategory::from_type_code("B738"), Some(WakeCategory::Medium));
+        assert_eq!(WakeCategory::from_type_code(""), None);
+    }
+
+    #[test]
+    fn icon_scale_grows_with_category() {
+        assert!(WakeCategory::Light.icon_scale() < WakeCategory::Medium.icon_scale());
+        assert!(WakeCategory::Medium.icon_scale() < WakeCategory::Heavy.icon_scale());
+        assert!(WakeCategory::Heavy.icon_scale() < WakeCategory::Super.icon_scale());
+    }
+}
@@ -3,8 +3,9 @@
 //! Displays Class B/C/D airspace and restricted areas.
 //! This module provides data structures and stubs for airspace visualization.
 //!
-//! ## Data Sources (for future implementation)
-//! - FAA NASR (National Airspace System Resources)
+//! ## Data Sources
+//! - FAA NASR (National Airspace System Resources): Class B/C/D boundaries
+//!   from the subscription's `Class_Airspace` shapefile (see `nasr`)
 //! - OpenAIP (open aviation database)
 //! - FAA SUA (Special Use Airspace)
 //! - VATSIM data (for virtual airspace)
@@ -32,6 +33,8 @@ use crate::geo::CoordinateConverter;
 use crate::render_layers::RenderCategory;
 use crate::view3d::View3DState;
 
+pub mod nasr;
+
 /// Classification of airspace
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum AirspaceClass {
@@ -222,19 +225,45 @@ pub struct AirspaceData {
     pub loaded: bool,
     /// Whether data has changed and meshes need regeneration
     pub dirty: bool,
+    /// Path typed into the "Load FAA NASR…" field
+    pub nasr_path: String,
+    /// Error from the last file load, shown in the panel
+    pub load_error: Option<String>,
 }
 
 impl AirspaceData {
-    /// Load airspace data from a file
+    /// Load airspace data from a file, replacing what is loaded.
+    ///
+    /// Supported: FAA NASR `Class_Airspace.shp` (with its `.dbf` alongside).
     ///
-    /// TODO: Implement actual file parsing for:
-    /// - OpenAIP XML format
-    /// - GeoJSON format
-    /// - FAA NASR format
-    pub fn load_from_file(&mut self, _path: &std::path::Path) -> Result<(), String> {
-        // Stub implementation
-        warn!("Airspace loading not yet implemented");
-        Err("Airspace loading not yet implemented. Data sources to consider: OpenAIP, FAA NASR".to_string())
+    /// TODO: OpenAIP XML and GeoJSON files
+    pub fn load_from_file(&mut self, path: &std::path::Path) -> Result<(), String> {
+        let is_shapefile = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("shp"));
+        if !is_shapefile {
+            return Err(format!("Unsupported airspace file: {} (expected NASR .shp)", path.display()));
+        }
+
+        let airspaces = nasr::load_class_airspace(path)?;
+        if airspaces.is_empty() {
+            return Err(format!("No Class B/C/D airspace found in {}", path.display()));
+        }
+        info!("Loaded {} NASR airspace definitions from {}", airspaces.len(), path.display());
+        self.airspaces = airspaces;
+        self.loaded = true;
+        self.dirty = true;
+        self.source = Some("FAA NASR".to_string());
+        Ok(())
+    }
+
+    /// Load the file in `nasr_path`, recording any error for display.
+    pub fn load_nasr_from_path(&mut self) {
+        let path = std::path::PathBuf::from(self.nasr_path.trim());
+        self.load_error = self.load_from_file(&path).err();
+        if let Some(ref err) = self.load_error {
+            warn!("Airspace load failed: {}", err);
+        }
     }
 
     /// Load sample airspace data for testing - realistic KICT Class C tiers.
@@ -364,6 +393,8 @@ pub struct SpawnedAirspaces {
     pub ids: std::collections::HashSet<String>,
     pub was_3d: bool,
     pub last_zoom: Option<u8>,
+    /// Projection origin the spawned meshes were built against
+    pub last_origin: Option<(f64, f64)>,
 }
 
 /// Timer for periodic airspace mesh refresh.
@@ -379,6 +410,7 @@ impl Default for AirspaceRefreshTimer {
 /// System to toggle airspace display
 pub fn toggle_airspace_display(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut display_state: ResMut<AirspaceDisplayState>,
     mut contexts: EguiContexts,
 ) {
@@ -389,11 +421,9 @@ pub fn toggle_airspace_display(
     }
 
     // Shift+A - Toggle airspace boundaries
-    if keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight) {
-        if keyboard.just_pressed(KeyCode::KeyA) {
-            display_state.enabled = !display_state.enabled;
-            info!("Airspace display: {}", if display_state.enabled { "enabled" } else { "disabled" });
-        }
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleAirspace, &keyboard) {
+        display_state.enabled = !display_state.enabled;
+        info!("Airspace display: {}", if display_state.enabled { "enabled" } else { "disabled" });
     }
 }
 
@@ -777,8 +807,14 @@ pub fn update_airspace_meshes(
         airspace_data.load_sample_data();
     }
 
+    // Only the projection origin matters here; other settings writes (such
+    // as endpoint rotation) must not trigger a rebuild
+    let origin = (tile_settings.reference_latitude, tile_settings.reference_longitude);
+    let origin_changed = spawned.last_origin != Some(origin);
+
     let needs_refresh = timer.0.just_finished()
         || airspace_data.dirty
+        || origin_changed
         || (display_state.enabled && airspace_data.loaded && spawned.ids.is_empty());
 
     if !needs_refresh {
@@ -818,11 +854,13 @@ pub fn update_airspace_meshes(
         spawned.was_3d = is_3d;
     }
 
-    // Despawn all meshes when zoom level changes (positions are zoom-dependent)
-    if Some(map_state.zoom_level.to_u8()) != spawned.last_zoom {
+    // Despawn all meshes when zoom level or projection origin changes
+    // (positions are relative to both)
+    if Some(map_state.zoom_level.to_u8()) != spawned.last_zoom || origin_changed {
         despawn_all(&mut commands, &existing_query, &outline_query);
         spawned.ids.clear();
         spawned.last_zoom = Some(map_state.zoom_level.to_u8());
+        spawned.last_origin = Some(origin);
     }
 
     let camera_lat = map_state.latitude;
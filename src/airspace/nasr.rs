@@ -0,0 +1,378 @@
+//! FAA NASR class airspace importer.
+//!
+//! Reads the `Class_Airspace` shapefile shipped in the NASR 28-day
+//! subscription (`Additional_Data/Shape_Files/Class_Airspace.shp` plus its
+//! `.dbf` attribute table). Only the subset needed here is supported:
+//! polygon shapes (plain, Z and M variants) and character/numeric dBASE
+//! fields. Class B, C and D boundaries are kept; everything else is skipped.
+
+use std::path::Path;
+
+use super::{Airspace, AirspaceClass, AirspacePoint, AltitudeReference};
+
+/// Shapefile main-file magic number (big-endian)
+const SHP_FILE_CODE: i32 = 9994;
+const SHP_HEADER_LEN: usize = 100;
+
+/// Load Class B/C/D airspace from a NASR `Class_Airspace.shp` and the `.dbf`
+/// next to it.
+pub fn load_class_airspace(shp_path: &Path) -> Result<Vec<Airspace>, String> {
+    let dbf_path = shp_path.with_extension("dbf");
+    let shp = std::fs::read(shp_path)
+        .map_err(|e| format!("Failed to read {}: {}", shp_path.display(), e))?;
+    let dbf = std::fs::read(&dbf_path)
+        .map_err(|e| format!("Failed to read {}: {}", dbf_path.display(), e))?;
+    parse_class_airspace(&shp, &dbf)
+}
+
+/// Combine shapefile geometry with its attribute rows. Records are matched by
+/// position, as the shapefile spec requires.
+pub fn parse_class_airspace(shp: &[u8], dbf: &[u8]) -> Result<Vec<Airspace>, String> {
+    let rings = parse_polygons(shp)?;
+    let rows = parse_dbf(dbf)?;
+    if rings.len() != rows.len() {
+        return Err(format!(
+            "Shape and attribute record counts differ ({} vs {})",
+            rings.len(),
+            rows.len()
+        ));
+    }
+
+    Ok(rings
+        .into_iter()
+        .zip(rows)
+        .enumerate()
+        .filter_map(|(index, (ring, row))| row_to_airspace(index, ring?, &row))
+        .collect())
+}
+
+/// `index` is the record number, which keeps ids unique: NASR has one row
+/// per Class B/C shelf, all sharing the airport's IDENT.
+fn row_to_airspace(index: usize, ring: Vec<AirspacePoint>, row: &DbfRow) -> Option<Airspace> {
+    let class = match (row.get("CLASS"), row.get("LOCAL_TYPE")) {
+        (Some("B"), _) | (_, Some("CLASS_B")) => AirspaceClass::ClassB,
+        (Some("C"), _) | (_, Some("CLASS_C")) => AirspaceClass::ClassC,
+        (Some("D"), _) | (_, Some("CLASS_D")) => AirspaceClass::ClassD,
+        _ => return None,
+    };
+    if ring.len() < 3 {
+        return None;
+    }
+
+    let ident = row.get("IDENT").unwrap_or("UNKNOWN");
+    let name = row.get("NAME").unwrap_or(ident);
+    Some(Airspace {
+        id: format!("{}_{}_{}", ident, class.display_name().replace(' ', ""), index),
+        name: name.to_string(),
+        class,
+        floor: altitude_limit(row.get("LOWER_VAL"), row.get("LOWER_CODE"), AltitudeReference::Surface),
+        ceiling: altitude_limit(row.get("UPPER_VAL"), row.get("UPPER_CODE"), AltitudeReference::Unlimited),
+        boundary: ring,
+        controlling_agency: None,
+        frequency: None,
+        operating_times: None,
+    })
+}
+
+/// NASR limits are feet with a reference code. Negative values are
+/// sentinels ("up to but not including" the overlying airspace) and fall
+/// back to `missing`.
+fn altitude_limit(value: Option<&str>, code: Option<&str>, missing: AltitudeReference) -> AltitudeReference {
+    let value = value.and_then(|v| v.parse::<f64>().ok()).map(|v| v as i32);
+    match (value, code) {
+        (_, Some("SFC")) => AltitudeReference::Surface,
+        (_, Some("UNL")) => AltitudeReference::Unlimited,
+        (Some(ft), _) if ft < 0 => missing,
+        (Some(0), None) => AltitudeReference::Surface,
+        (Some(ft), Some("AGL")) => AltitudeReference::AGL(ft),
+        (Some(ft), Some("STD")) => AltitudeReference::FL((ft / 100) as u16),
+        (Some(ft), _) => AltitudeReference::MSL(ft),
+        (None, _) => missing,
+    }
+}
+
+fn read_i32_be(bytes: &[u8], at: usize) -> Option<i32> {
+    Some(i32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn read_i32_le(bytes: &[u8], at: usize) -> Option<i32> {
+    Some(i32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn read_f64_le(bytes: &[u8], at: usize) -> Option<f64> {
+    Some(f64::from_le_bytes(bytes.get(at..at + 8)?.try_into().ok()?))
+}
+
+/// Read a little-endian count or index, rejecting negative values
+fn read_count_le(bytes: &[u8], at: usize, what: &str) -> Result<usize, String> {
+    let raw = read_i32_le(bytes, at).ok_or_else(|| format!("Truncated {}", what))?;
+    usize::try_from(raw).map_err(|_| format!("Invalid {} {}", what, raw))
+}
+
+/// End offset of `count` items of `size` bytes starting at `start`, if it
+/// fits within `len` bytes
+fn span_end(start: usize, count: usize, size: usize, len: usize) -> Option<usize> {
+    count
+        .checked_mul(size)
+        .and_then(|bytes| bytes.checked_add(start))
+        .filter(|&end| end <= len)
+}
+
+/// Parse polygon records into their outer ring (the part with the most
+/// points). Null shapes yield `None` so positions stay aligned with the
+/// attribute table.
+fn parse_polygons(shp: &[u8]) -> Result<Vec<Option<Vec<AirspacePoint>>>, String> {
+    if read_i32_be(shp, 0) != Some(SHP_FILE_CODE) {
+        return Err("Not a shapefile (bad file code)".to_string());
+    }
+
+    let mut rings = Vec::new();
+    let mut at = SHP_HEADER_LEN;
+    while at + 8 <= shp.len() {
+        let words = read_i32_be(shp, at + 4).ok_or("Truncated record header")?;
+        let end = usize::try_from(words)
+            .ok()
+            .and_then(|words| span_end(at + 8, words, 2, shp.len()))
+            .ok_or_else(|| format!("Truncated shape record at byte {}", at))?;
+        let content = &shp[at + 8..end];
+        at = end;
+
+        let shape_type = read_i32_le(content, 0).ok_or("Empty shape record")?;
+        match shape_type {
+            0 => rings.push(None),
+            // Polygon, PolygonZ, PolygonM share the 2D layout up to the points
+            5 | 15 | 25 => rings.push(Some(parse_polygon_outer_ring(content)?)),
+            other => return Err(format!("Unsupported shape type {} (expected polygons)", other)),
+        }
+    }
+    Ok(rings)
+}
+
+fn parse_polygon_outer_ring(content: &[u8]) -> Result<Vec<AirspacePoint>, String> {
+    let num_parts = read_count_le(content, 36, "polygon part count")?;
+    let num_points = read_count_le(content, 40, "polygon point count")?;
+    let parts_at = 44;
+    let points_at = span_end(parts_at, num_parts, 4, content.len()).ok_or("Truncated polygon parts")?;
+    span_end(points_at, num_points, 16, content.len()).ok_or("Truncated polygon points")?;
+
+    let mut starts = Vec::with_capacity(num_parts + 1);
+    for i in 0..num_parts {
+        let start = read_count_le(content, parts_at + i * 4, "polygon part start")?;
+        if start > num_points {
+            return Err(format!("Polygon part starts past its {} points", num_points));
+        }
+        starts.push(start);
+    }
+    starts.push(num_points);
+
+    let (start, end) = starts
+        .windows(2)
+        .map(|w| (w[0], w[1]))
+        .max_by_key(|(s, e)| e.saturating_sub(*s))
+        .unwrap_or((0, 0));
+
+    (start..end)
+        .map(|i| -> Result<AirspacePoint, String> {
+            let x = read_f64_le(content, points_at + i * 16).ok_or("Truncated polygon points")?;
+            let y = read_f64_le(content, points_at + i * 16 + 8).ok_or("Truncated polygon points")?;
+            Ok(AirspacePoint { latitude: y, longitude: x })
+        })
+        .collect()
+}
+
+/// One dBASE record as (field name, trimmed value) pairs
+struct DbfRow(Vec<(String, String)>);
+
+impl DbfRow {
+    fn get(&self, field: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, value)| value.as_str())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+fn parse_dbf(dbf: &[u8]) -> Result<Vec<DbfRow>, String> {
+    let header = dbf.get(..32).ok_or("Truncated dBASE header")?;
+    let num_records = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+    let header_len = u16::from_le_bytes(header[8..10].try_into().unwrap()) as usize;
+    let record_len = u16::from_le_bytes(header[10..12].try_into().unwrap()) as usize;
+
+    // Field descriptors: 32 bytes each, terminated by 0x0D
+    let mut fields = Vec::new();
+    let mut at = 32;
+    while at < header_len && dbf.get(at) != Some(&0x0D) {
+        let desc = dbf.get(at..at + 32).ok_or("Truncated dBASE field descriptor")?;
+        let name_end = desc[..11].iter().position(|&b| b == 0).unwrap_or(11);
+        let name = String::from_utf8_lossy(&desc[..name_end]).trim().to_string();
+        fields.push((name, desc[16] as usize));
+        at += 32;
+    }
+
+    if num_records > 0 && record_len == 0 {
+        return Err("dBASE records have zero length".to_string());
+    }
+    span_end(header_len, num_records, record_len, dbf.len())
+        .ok_or_else(|| format!("dBASE table is shorter than its {} records", num_records))?;
+
+    let mut rows = Vec::with_capacity(num_records);
+    for i in 0..num_records {
+        let start = header_len + i * record_len;
+        let record = dbf
+            .get(start..start + record_len)
+            .ok_or_else(|| format!("Truncated dBASE record {}", i))?;
+        // First byte is the deletion flag; values follow in field order
+        let mut offset = 1;
+        let mut values = Vec::with_capacity(fields.len());
+        for (name, len) in &fields {
+            let raw = record.get(offset..offset + len).unwrap_or_default();
+            values.push((name.clone(), String::from_utf8_lossy(raw).trim().to_string()));
+            offset += len;
+        }
+        rows.push(DbfRow(values));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a polygon shapefile; each shape is a list of (lon, lat) parts
+    fn polygon_shp(shapes: &[Vec<Vec<(f64, f64)>>]) -> Vec<u8> {
+        let mut records = Vec::new();
+        for (n, parts) in shapes.iter().enumerate() {
+            let mut content = Vec::new();
+            content.extend(5i32.to_le_bytes());
+            content.extend([0u8; 32]); // bbox, unused by the reader
+            let num_points: usize = parts.iter().map(Vec::len).sum();
+            content.extend((parts.len() as i32).to_le_bytes());
+            content.extend((num_points as i32).to_le_bytes());
+            let mut start = 0;
+            for part in parts {
+                content.extend((start as i32).to_le_bytes());
+                start += part.len();
+            }
+            for &(x, y) in parts.iter().flatten() {
+                content.extend(x.to_le_bytes());
+                content.extend(y.to_le_bytes());
+            }
+            records.extend((n as i32 + 1).to_be_bytes());
+            records.extend(((content.len() / 2) as i32).to_be_bytes());
+            records.extend(content);
+        }
+        let mut shp = vec![0u8; SHP_HEADER_LEN];
+        shp[0..4].copy_from_slice(&SHP_FILE_CODE.to_be_bytes());
+        shp[24..28].copy_from_slice(&(((SHP_HEADER_LEN + records.len()) / 2) as i32).to_be_bytes());
+        shp[28..32].copy_from_slice(&1000i32.to_le_bytes());
+        shp[32..36].copy_from_slice(&5i32.to_le_bytes());
+        shp.extend(records);
+        shp
+    }
+
+    fn dbf(fields: &[(&str, usize)], rows: &[&[&str]]) -> Vec<u8> {
+        let header_len = 32 + fields.len() * 32 + 1;
+        let record_len = 1 + fields.iter().map(|(_, len)| len).sum::<usize>();
+        let mut out = vec![0u8; 32];
+        out[0] = 3;
+        out[4..8].copy_from_slice(&(rows.len() as u32).to_le_bytes());
+        out[8..10].copy_from_slice(&(header_len as u16).to_le_bytes());
+        out[10..12].copy_from_slice(&(record_len as u16).to_le_bytes());
+        for (name, len) in fields {
+            let mut desc = [0u8; 32];
+            desc[..name.len()].copy_from_slice(name.as_bytes());
+            desc[11] = b'C';
+            desc[16] = *len as u8;
+            out.extend(desc);
+        }
+        out.push(0x0D);
+        for row in rows {
+            out.push(b' ');
+            for ((_, len), value) in fields.iter().zip(row.iter()) {
+                out.extend(format!("{:<width$}", value, width = len).as_bytes());
+            }
+        }
+        out
+    }
+
+    const FIELDS: &[(&str, usize)] = &[
+        ("IDENT", 4),
+        ("NAME", 40),
+        ("CLASS", 1),
+        ("UPPER_VAL", 6),
+        ("UPPER_CODE", 3),
+        ("LOWER_VAL", 6),
+        ("LOWER_CODE", 3),
+    ];
+
+    #[test]
+    fn parses_class_c_with_floor_and_ceiling() {
+        let square = vec![(-97.43, 37.65), (-97.20, 37.65), (-97.20, 37.72), (-97.43, 37.72), (-97.43, 37.65)];
+        let shp = polygon_shp(&[vec![square.clone()], vec![square]]);
+        let dbf = dbf(FIELDS, &[
+            &["ICT", "WICHITA CLASS C", "C", "5300", "MSL", "0", "SFC"],
+            &["ICT", "WICHITA CLASS E2", "E", "-9998", "", "0", "SFC"],
+        ]);
+
+        let airspaces = parse_class_airspace(&shp, &dbf).unwrap();
+        assert_eq!(airspaces.len(), 1, "class E is outside the imported subset");
+        let c = &airspaces[0];
+        assert_eq!(c.class, AirspaceClass::ClassC);
+        assert_eq!(c.name, "WICHITA CLASS C");
+        assert!(matches!(c.floor, AltitudeReference::Surface));
+        assert!(matches!(c.ceiling, AltitudeReference::MSL(5300)));
+        assert_eq!(c.boundary.len(), 5);
+        assert!(c.contains_point(37.68, -97.30));
+    }
+
+    #[test]
+    fn uses_largest_part_and_rejects_mismatched_tables() {
+        let hole = vec![(-97.3, 37.68), (-97.29, 37.68), (-97.29, 37.69), (-97.3, 37.68)];
+        let outer = vec![(-98.0, 37.0), (-97.0, 37.0), (-97.0, 38.0), (-98.0, 38.0), (-98.0, 37.0)];
+        let shp = polygon_shp(&[vec![hole, outer]]);
+        let table = dbf(FIELDS, &[&["ICT", "SHELF", "B", "10000", "MSL", "-9998", ""]]);
+
+        let airspaces = parse_class_airspace(&shp, &table).unwrap();
+        assert_eq!(airspaces[0].boundary.len(), 5);
+        assert!(matches!(airspaces[0].floor, AltitudeReference::Surface));
+
+        let empty = dbf(FIELDS, &[]);
+        assert!(parse_class_airspace(&shp, &empty).is_err());
+    }
+
+    #[test]
+    fn corrupt_counts_are_errors_not_panics() {
+        let square = vec![(-97.43, 37.65), (-97.20, 37.65), (-97.20, 37.72), (-97.43, 37.72), (-97.43, 37.65)];
+        let shp = polygon_shp(&[vec![square]]);
+        let record = SHP_HEADER_LEN + 8;
+        for (offset, value) in [(36, -1i32), (36, i32::MAX), (40, -5), (40, i32::MAX), (44, 1_000)] {
+            let mut corrupt = shp.clone();
+            corrupt[record + offset..record + offset + 4].copy_from_slice(&value.to_le_bytes());
+            assert!(parse_polygons(&corrupt).is_err(), "offset {} value {}", offset, value);
+        }
+        let mut negative_len = shp.clone();
+        negative_len[SHP_HEADER_LEN + 4..SHP_HEADER_LEN + 8].copy_from_slice(&(-2i32).to_be_bytes());
+        assert!(parse_polygons(&negative_len).is_err());
+
+        let mut table = dbf(FIELDS, &[&["ICT", "WICHITA CLASS C", "C", "5300", "MSL", "0", "SFC"]]);
+        table[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(parse_dbf(&table).is_err());
+    }
+
+    #[test]
+    fn shelves_of_one_airport_get_distinct_ids() {
+        let inner = vec![(-97.35, 37.65), (-97.25, 37.65), (-97.25, 37.72), (-97.35, 37.72), (-97.35, 37.65)];
+        let outer = vec![(-97.50, 37.55), (-97.10, 37.55), (-97.10, 37.80), (-97.50, 37.80), (-97.50, 37.55)];
+        let shp = polygon_shp(&[vec![inner], vec![outer]]);
+        let table = dbf(FIELDS, &[
+            &["ICT", "WICHITA CLASS C", "C", "5300", "MSL", "0", "SFC"],
+            &["ICT", "WICHITA CLASS C", "C", "5300", "MSL", "3000", "MSL"],
+        ]);
+
+        let airspaces = parse_class_airspace(&shp, &table).unwrap();
+        assert_eq!(airspaces.len(), 2);
+        assert_ne!(airspaces[0].id, airspaces[1].id);
+        assert!(airspaces.iter().all(|a| a.id.starts_with("ICT_ClassC")));
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
@@ -26,6 +27,34 @@ pub enum LoadingState {
     Failed,
 }
 
+/// Outcome of the most recent aviation data load, for user-facing feedback.
+#[derive(Resource, Default)]
+pub struct AviationLoadStatus {
+    pub airports: usize,
+    pub runways: usize,
+    pub navaids: usize,
+    /// Error message when loading failed
+    pub error: Option<String>,
+    /// Set once the user has closed the missing-data warning
+    pub warning_dismissed: bool,
+    /// Set by the UI to re-run the loader (optionally forcing a fresh download)
+    pub reload_requested: Option<bool>,
+}
+
+impl AviationLoadStatus {
+    /// Whether the missing-data warning should be shown for `state`.
+    pub fn needs_attention(&self, state: LoadingState) -> bool {
+        if self.warning_dismissed {
+            return false;
+        }
+        match state {
+            LoadingState::Failed => true,
+            LoadingState::Ready => self.airports == 0,
+            _ => false,
+        }
+    }
+}
+
 /// Internal result type from the background loading thread.
 struct LoadedData {
     airports: Vec<Airport>,
@@ -125,6 +154,12 @@ pub fn start_aviation_data_loading(
     aviation_data.loading_state = LoadingState::Downloading;
     info!("Starting aviation data loading in background thread...");
 
+    commands.insert_resource(spawn_loader_thread(false));
+}
+
+/// Spawn the background download + parse thread. With `force_download`,
+/// cached files are re-fetched even if they are still fresh.
+fn spawn_loader_thread(force_download: bool) -> AviationLoadHandle {
     let result_handle: Arc<Mutex<Option<Result<LoadedData, String>>>> =
         Arc::new(Mutex::new(None));
     let handle = result_handle.clone();
@@ -133,7 +168,7 @@ pub fn start_aviation_data_loading(
         // Download phase
         let files = [DataFile::Airports, DataFile::Runways, DataFile::Navaids];
         for file in &files {
-            if !is_cache_fresh(file.filename()) {
+            if force_download || !is_cache_fresh(file.filename()) {
                 if let Err(e) = download_file_blocking(file) {
                     let Ok(mut lock) = handle.lock() else {
                         error!("Failed to acquire lock for aviation data loading");
@@ -162,13 +197,37 @@ pub fn start_aviation_data_loading(
         *lock = Some(result);
     });
 
-    commands.insert_resource(AviationLoadHandle(result_handle));
+    AviationLoadHandle(result_handle)
+}
+
+/// Re-run the loader when the user asks for it from the missing-data warning.
+pub fn handle_aviation_reload(
+    mut commands: Commands,
+    mut aviation_data: ResMut<AviationData>,
+    mut status: ResMut<AviationLoadStatus>,
+) {
+    let Some(force_download) = status.reload_requested.take() else {
+        return;
+    };
+    if matches!(
+        aviation_data.loading_state,
+        LoadingState::Downloading | LoadingState::Parsing
+    ) {
+        return;
+    }
+
+    info!("Reloading aviation data (force download: {})", force_download);
+    status.error = None;
+    status.warning_dismissed = false;
+    aviation_data.loading_state = LoadingState::Downloading;
+    commands.insert_resource(spawn_loader_thread(force_download));
 }
 
 /// Update system: polls the background thread and moves data into the ECS
 /// resource when loading is complete.
 pub fn poll_aviation_data_loading(
     mut aviation_data: ResMut<AviationData>,
+    mut status: ResMut<AviationLoadStatus>,
     load_handle: Option<Res<AviationLoadHandle>>,
 ) {
     // Only poll while we're in the loading states
@@ -203,6 +262,13 @@ pub fn poll_aviation_data_loading(
             aviation_data.navaids = data.navaids;
             aviation_data.build_runway_index();
             aviation_data.loading_state = LoadingState::Ready;
+            status.airports = airport_count;
+            status.runways = runway_count;
+            status.navaids = navaid_count;
+            status.error = None;
+            if airport_count == 0 {
+                warn!("Aviation data loaded but contains no airports");
+            }
             info!(
                 "Aviation data ready: {} airports, {} runways, {} navaids",
                 airport_count, runway_count, navaid_count
@@ -210,7 +276,75 @@ pub fn poll_aviation_data_loading(
         }
         Err(e) => {
             error!("Failed to load aviation data: {}", e);
+            status.error = Some(e);
             aviation_data.loading_state = LoadingState::Failed;
         }
     }
 }
+
+/// Show a one-time warning when no airports could be loaded, with options to
+/// retry the download or import the CSVs manually.
+pub fn render_aviation_data_warning(
+    mut contexts: EguiContexts,
+    aviation_data: Res<AviationData>,
+    mut status: ResMut<AviationLoadStatus>,
+) {
+    if !status.needs_attention(aviation_data.loading_state) {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new("Aviation Data Missing")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(320.0)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .open(&mut open)
+        .show(ctx, |ui| {
+            match &status.error {
+                Some(err) => {
+                    ui.label("Airport, runway and navaid data could not be loaded.");
+                    ui.label(egui::RichText::new(err).size(11.0).color(egui::Color32::LIGHT_RED));
+                }
+                None => {
+                    ui.label("The airport database loaded but contains no airports.");
+                }
+            }
+            ui.label(
+                egui::RichText::new("Airport markers and 3D ground elevation are unavailable until it is loaded.")
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+
+            ui.separator();
+            ui.label("To import manually, place these files in:");
+            let dir = cache_path("");
+            ui.horizontal(|ui| {
+                ui.monospace(dir.display().to_string());
+                if ui.small_button("Copy").clicked() {
+                    ui.ctx().copy_text(dir.display().to_string());
+                }
+            });
+            for file in [DataFile::Airports, DataFile::Runways, DataFile::Navaids] {
+                ui.label(egui::RichText::new(format!("  {}", file.filename())).size(11.0));
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Download").clicked() {
+                    status.reload_requested = Some(true);
+                }
+                if ui.button("Reload from disk").clicked() {
+                    status.reload_requested = Some(false);
+                }
+            });
+        });
+
+    if !open {
+        status.warning_dismissed = true;
+    }
+}
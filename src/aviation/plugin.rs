@@ -4,7 +4,9 @@ use super::{
     AviationData, AirportRenderState, RunwayRenderState, NavaidRenderState,
     spawn_airports, update_airport_positions, update_airport_visibility,
     draw_runways, draw_navaids,
+    AviationLoadStatus,
     start_aviation_data_loading, poll_aviation_data_loading,
+    handle_aviation_reload, render_aviation_data_warning,
 };
 use crate::ZoomSet;
 
@@ -14,17 +16,23 @@ impl Plugin for AviationPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<AviationData>()
+            .init_resource::<AviationLoadStatus>()
             .init_resource::<AirportRenderState>()
             .init_resource::<RunwayRenderState>()
             .init_resource::<NavaidRenderState>()
             .add_systems(Startup, start_aviation_data_loading)
             .add_systems(Update, (
                 poll_aviation_data_loading,
+                handle_aviation_reload,
                 spawn_airports,
                 update_airport_positions.after(ZoomSet::Change),
                 update_airport_visibility,
                 draw_runways.after(ZoomSet::Change),
                 draw_navaids.after(ZoomSet::Change),
-            ));
+            ))
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                render_aviation_data_warning.after(crate::dock::render_dock_tree),
+            );
     }
 }
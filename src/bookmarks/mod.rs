@@ -4,9 +4,15 @@ use bevy_slippy_tiles::ZoomLevel;
 
 use crate::config::{AppConfig, AircraftBookmark, LocationBookmark, save_config};
 use crate::aircraft::AircraftListState;
+use crate::event_log::{EventCategory, EventLog};
+use crate::geo::CoordinateConverter;
 use crate::theme::{AppTheme, to_egui_color32, to_egui_color32_alpha};
 use crate::{MapState, ZoomState, Aircraft};
 
+/// Ring around bookmarked aircraft; wider than the selection ring so both
+/// show when a watched aircraft is selected
+const WATCHLIST_RING_RADIUS: f32 = 22.0;
+
 pub struct BookmarksPlugin;
 
 impl Plugin for BookmarksPlugin {
@@ -15,6 +21,8 @@ impl Plugin for BookmarksPlugin {
             .add_systems(Update, (
                 toggle_bookmarks_panel,
                 highlight_bookmarked_aircraft,
+                draw_watchlist_rings.after(crate::ZoomSet::Change),
+                log_watchlist_sightings,
             ));
     }
 }
@@ -334,6 +342,7 @@ fn render_aircraft_bookmarks(
 /// Toggle bookmarks panel with 'B' key
 pub fn toggle_bookmarks_panel(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut panel_state: ResMut<BookmarksPanelState>,
     mut contexts: EguiContexts,
 ) {
@@ -344,7 +353,7 @@ pub fn toggle_bookmarks_panel(
         }
     }
 
-    if keyboard.just_pressed(KeyCode::KeyB) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleBookmarks, &keyboard) {
         panel_state.open = !panel_state.open;
     }
 }
@@ -367,3 +376,44 @@ pub fn highlight_bookmarked_aircraft(
     // The actual highlighting will be handled in the list_panel render function
     // by checking if the ICAO is in the bookmarks list
 }
+
+/// System that records an event when a watched aircraft appears in the feed.
+pub fn log_watchlist_sightings(
+    app_config: Res<AppConfig>,
+    mut events: ResMut<EventLog>,
+    new_aircraft: Query<&Aircraft, Added<Aircraft>>,
+) {
+    for aircraft in new_aircraft.iter() {
+        let Some(bookmark) = app_config.bookmarks.aircraft.iter().find(|b| b.icao == aircraft.icao) else {
+            continue;
+        };
+        let name = aircraft.callsign.as_deref().or(bookmark.callsign.as_deref()).map(str::trim).unwrap_or("-");
+        events.record(EventCategory::Watchlist, format!("Watched aircraft {} ({}) in range", name, aircraft.icao));
+    }
+}
+
+/// System to ring every visible bookmarked aircraft on the 2D map, so
+/// watched aircraft stand out without opening the list.
+pub fn draw_watchlist_rings(
+    mut gizmos: Gizmos,
+    app_config: Res<AppConfig>,
+    tile_settings: Res<bevy_slippy_tiles::SlippyTilesSettings>,
+    map_state: Res<MapState>,
+    view3d_state: Res<crate::view3d::View3DState>,
+    aircraft_query: Query<(&Aircraft, &Visibility)>,
+) {
+    let watched = &app_config.bookmarks.aircraft;
+    if watched.is_empty() || view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        return;
+    }
+
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let color = Color::srgb(1.0, 0.3, 0.9);
+    for (aircraft, visibility) in aircraft_query.iter() {
+        if *visibility == Visibility::Hidden || !watched.iter().any(|b| b.icao == aircraft.icao) {
+            continue;
+        }
+        let pos = converter.latlon_to_world(aircraft.latitude, aircraft.longitude);
+        gizmos.circle_2d(pos, WATCHLIST_RING_RADIUS, color);
+    }
+}
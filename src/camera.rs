@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 use bevy_slippy_tiles::*;
 
+use crate::aircraft::WakeCategory;
 use crate::constants;
 use crate::geo;
 use crate::map::{MapState, ZoomState};
 use crate::view3d;
-use crate::{clamp_latitude, clamp_longitude, Aircraft, AircraftLabel, ZoomDebugLogger, ZoomSet};
+use crate::{clamp_latitude, normalize_longitude, Aircraft, AircraftLabel, ZoomDebugLogger, ZoomSet};
 
 // =============================================================================
 // Constants
@@ -88,6 +89,7 @@ fn follow_aircraft(
     follow_state: Res<crate::aircraft::CameraFollowState>,
     aircraft_query: Query<&Aircraft>,
     time: Res<Time>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
     let Some(ref following_icao) = follow_state.following_icao else {
         return;
@@ -103,10 +105,17 @@ fn follow_aircraft(
     let t = (lerp_speed * time.delta_secs()).min(1.0);
 
     let new_lat = map_state.latitude + (aircraft.latitude - map_state.latitude) * t as f64;
-    let new_lon = map_state.longitude + (aircraft.longitude - map_state.longitude) * t as f64;
+    // With wrapping on, chase the aircraft the short way across the date line
+    let wrap = app_config.map.wrap_antimeridian;
+    let dlon = if wrap {
+        crate::geo::longitude_delta(map_state.longitude, aircraft.longitude)
+    } else {
+        aircraft.longitude - map_state.longitude
+    };
+    let new_lon = map_state.longitude + dlon * t as f64;
 
     map_state.latitude = clamp_latitude(new_lat);
-    map_state.longitude = clamp_longitude(new_lon);
+    map_state.longitude = normalize_longitude(new_lon, wrap);
 }
 
 fn update_camera_position(
@@ -115,6 +124,10 @@ fn update_camera_position(
     mut camera_query: Query<&mut Transform, With<MapCamera>>,
     logger: Option<Res<ZoomDebugLogger>>,
     view3d_state: Res<view3d::View3DState>,
+    app_config: Res<crate::config::AppConfig>,
+    follow_state: Res<crate::aircraft::CameraFollowState>,
+    aircraft_query: Query<&Aircraft>,
+    time: Res<Time>,
 ) {
     // Don't fight with update_3d_camera during 3D mode or transitions
     if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
@@ -144,8 +157,7 @@ fn update_camera_position(
             zoom_level
         );
 
-        let offset_x = center_pixel.0 - reference_pixel.0;
-        let offset_y = center_pixel.1 - reference_pixel.1;
+        let (offset_x, offset_y) = camera_offset(reference_pixel, center_pixel, zoom_level);
 
         if let Some(ref log) = logger {
             if map_state.is_changed() {
@@ -158,9 +170,44 @@ fn update_camera_position(
 
         camera_transform.translation.x = offset_x as f32;
         camera_transform.translation.y = offset_y as f32;
+
+        // Track-up: rotate the camera so the followed aircraft's heading points
+        // up on screen. Returns to north-up when not following.
+        let followed_heading = if app_config.map.track_up {
+            follow_state
+                .following_icao
+                .as_ref()
+                .and_then(|icao| aircraft_query.iter().find(|a| &a.icao == icao))
+                .and_then(|a| a.heading)
+        } else {
+            None
+        };
+        let target_rotation = followed_heading
+            .map(|heading| Quat::from_rotation_z((-heading).to_radians()))
+            .unwrap_or(Quat::IDENTITY);
+        if camera_transform.rotation != target_rotation {
+            let rotate_speed = 3.0;
+            let t = (rotate_speed * time.delta_secs()).min(1.0);
+            camera_transform.rotation = camera_transform.rotation.slerp(target_rotation, t);
+            if camera_transform.rotation.angle_between(target_rotation) < 0.001 {
+                camera_transform.rotation = target_rotation;
+            }
+        }
     }
 }
 
+/// Camera offset from the projection reference, in world pixels. Like
+/// `CoordinateConverter`, x is taken on the world copy nearest the
+/// reference, so the camera stays with tiles and aircraft when the center
+/// longitude wraps across the antimeridian.
+pub(crate) fn camera_offset(reference_pixel: (f64, f64), center_pixel: (f64, f64), zoom_level: ZoomLevel) -> (f64, f64) {
+    let world_width = geo::world_width_pixels(constants::DEFAULT_TILE_SIZE, zoom_level);
+    (
+        geo::wrap_pixel_offset(center_pixel.0 - reference_pixel.0, world_width),
+        center_pixel.1 - reference_pixel.1,
+    )
+}
+
 /// Sync Camera3d transform and projection to match Camera2d in 2D mode.
 /// In 3D mode, update_3d_camera handles both cameras directly.
 fn sync_aircraft_camera(
@@ -196,35 +243,49 @@ fn sync_aircraft_camera(
 /// In 2D mode, scale inversely with camera zoom for constant screen size.
 /// In 3D perspective mode, use a fixed world-space scale and let perspective
 /// projection handle apparent size (closer = bigger, farther = smaller).
-fn scale_aircraft_and_labels(
+pub(crate) fn scale_aircraft_and_labels(
     zoom_state: Res<ZoomState>,
     view3d_state: Res<crate::view3d::View3DState>,
-    mut aircraft_query: Query<&mut Transform, (With<Aircraft>, Without<AircraftLabel>)>,
+    app_config: Res<crate::config::AppConfig>,
+    mut aircraft_query: Query<(&mut Transform, Option<&WakeCategory>), (With<Aircraft>, Without<AircraftLabel>)>,
     mut label_query: Query<(&mut Transform, &mut TextFont), With<AircraftLabel>>,
     new_aircraft: Query<(), Added<Aircraft>>,
+    changed_wake: Query<(), Changed<WakeCategory>>,
 ) {
-    // Update scales when zoom changes, mode changes, or new aircraft are spawned
-    if !zoom_state.is_changed() && !view3d_state.is_changed() && new_aircraft.is_empty() {
+    // Update scales when zoom, mode or marker size changes, new aircraft are
+    // spawned, or an aircraft's wake category resolves
+    if !zoom_state.is_changed()
+        && !view3d_state.is_changed()
+        && !app_config.is_changed()
+        && new_aircraft.is_empty()
+        && changed_wake.is_empty()
+    {
         return;
     }
 
+    let marker_size = app_config.appearance.marker_size;
+    let wake_scale = |wake: Option<&WakeCategory>| match wake {
+        Some(wake) if app_config.appearance.scale_by_wake_category => wake.icon_scale(),
+        _ => 1.0,
+    };
+
     if view3d_state.is_3d_active() {
         // 3D perspective: fixed world-space scale. Perspective projection
         // naturally makes distant aircraft smaller and nearby aircraft larger.
         // Scale up significantly so aircraft are visible at altitude distances.
-        let scale = constants::AIRCRAFT_MODEL_SCALE * 10.0;
-        for mut transform in aircraft_query.iter_mut() {
-            transform.scale = Vec3::splat(scale);
+        let scale = marker_size * 10.0;
+        for (mut transform, wake) in aircraft_query.iter_mut() {
+            transform.scale = Vec3::splat(scale * wake_scale(wake));
         }
     } else {
         // 2D orthographic: scale inversely with zoom for constant screen size
-        let scale = constants::AIRCRAFT_MODEL_SCALE / zoom_state.camera_zoom;
-        for mut transform in aircraft_query.iter_mut() {
-            transform.scale = Vec3::splat(scale);
+        let scale = marker_size / zoom_state.camera_zoom;
+        for (mut transform, wake) in aircraft_query.iter_mut() {
+            transform.scale = Vec3::splat(scale * wake_scale(wake));
         }
     }
 
-    // Labels are always 2D (hidden in 3D mode by update_aircraft_3d_transform)
+    // Labels use the 2D scale; in 3D, billboard_labels_3d rescales them by depth
     let label_scale = 1.0 / zoom_state.camera_zoom;
     for (mut transform, mut text_font) in label_query.iter_mut() {
         transform.scale = Vec3::splat(label_scale);
@@ -245,32 +306,98 @@ pub(crate) fn update_aircraft_positions(
 
         transform.translation.x = pos.x;
         transform.translation.y = pos.y;
-        // Apply rotation for 3D model orientation:
-        // GLB model has nose along +Z, wings along X, height along Y.
-        // First rotate 180 around Z to flip the model right-side up (top faces camera).
-        // Then rotate -90 around X to tilt nose from +Z to +Y (north on screen).
-        // Finally heading rotation around Z orients the aircraft to its track angle.
-        let base_rot = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)
-            * Quat::from_rotation_z(std::f32::consts::PI);
-        if let Some(heading) = aircraft.heading {
-            transform.rotation = Quat::from_rotation_z((-heading).to_radians()) * base_rot;
-        } else {
-            transform.rotation = base_rot;
-        }
+        transform.rotation = marker_rotation_2d(aircraft.heading);
+    }
+}
+
+/// Rotation of an aircraft model on the 2D map for the given heading.
+///
+/// GLB model has nose along +Z, wings along X, height along Y.
+/// First rotate 180 around Z to flip the model right-side up (top faces camera).
+/// Then rotate -90 around X to tilt nose from +Z to +Y (north on screen).
+/// Finally heading rotation around Z orients the aircraft to its track angle.
+pub(crate) fn marker_rotation_2d(heading: Option<f32>) -> Quat {
+    let base_rot = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)
+        * Quat::from_rotation_z(std::f32::consts::PI);
+    match heading {
+        Some(heading) => Quat::from_rotation_z((-heading).to_radians()) * base_rot,
+        None => base_rot,
     }
 }
 
-fn update_aircraft_labels(
+/// 2D Z of an aircraft marker: the aircraft layer plus a small bias that
+/// grows with altitude, so higher traffic draws over lower traffic where
+/// markers overlap. Unknown altitude sorts with ground traffic.
+pub(crate) fn aircraft_z_2d(altitude: Option<i32>) -> f32 {
+    let fraction = (altitude.unwrap_or(0) as f32 / constants::AIRCRAFT_Z_BIAS_CEILING_FT).clamp(0.0, 1.0);
+    constants::AIRCRAFT_Z_LAYER + fraction * constants::AIRCRAFT_Z_BIAS_MAX
+}
+
+pub(crate) fn update_aircraft_labels(
     zoom_state: Res<ZoomState>,
+    view3d_state: Res<view3d::View3DState>,
+    app_config: Res<crate::config::AppConfig>,
+    camera_query: Query<&Transform, (With<MapCamera>, Without<Aircraft>, Without<AircraftLabel>)>,
     aircraft_query: Query<&Transform, With<Aircraft>>,
-    mut label_query: Query<(&AircraftLabel, &mut Transform), Without<Aircraft>>,
+    mut label_query: Query<(&AircraftLabel, &mut Transform, &mut bevy::sprite::Anchor), (Without<Aircraft>, Without<MapCamera>)>,
 ) {
-    let world_space_offset = constants::LABEL_SCREEN_OFFSET / zoom_state.camera_zoom;
+    let appearance = &app_config.appearance;
+    let world_space_offset = appearance.label_anchor.offset(appearance.label_offset) / zoom_state.camera_zoom;
+    let text_anchor = appearance.label_anchor.text_anchor();
+
+    // Counter-rotate labels with the 2D camera (track-up) so they stay upright
+    // and keep their screen-space offset from the marker.
+    let camera_rotation = if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        Quat::IDENTITY
+    } else {
+        camera_query.single().map(|t| t.rotation).unwrap_or(Quat::IDENTITY)
+    };
+    let offset = camera_rotation * world_space_offset.extend(0.0);
 
-    for (label, mut label_transform) in label_query.iter_mut() {
+    for (label, mut label_transform, mut anchor) in label_query.iter_mut() {
+        if *anchor != text_anchor {
+            *anchor = text_anchor;
+        }
         if let Ok(aircraft_transform) = aircraft_query.get(label.aircraft_entity) {
-            label_transform.translation.x = aircraft_transform.translation.x + world_space_offset;
-            label_transform.translation.y = aircraft_transform.translation.y + world_space_offset;
+            label_transform.translation.x = aircraft_transform.translation.x + offset.x;
+            label_transform.translation.y = aircraft_transform.translation.y + offset.y;
+            label_transform.rotation = camera_rotation;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(lat: f64, lon: f64, zoom: ZoomLevel) -> (f64, f64) {
+        world_coords_to_world_pixel(
+            &LatitudeLongitudeCoordinates { latitude: lat, longitude: lon },
+            constants::DEFAULT_TILE_SIZE,
+            zoom,
+        )
+    }
+
+    #[test]
+    fn camera_offset_stays_continuous_across_the_antimeridian() {
+        let zoom = ZoomLevel::L8;
+        let reference = pixel(0.0, 179.5, zoom);
+        let before = camera_offset(reference, pixel(0.0, 179.9, zoom), zoom);
+        // normalize_longitude wraps the pan past 180 to -179.9
+        let after = camera_offset(reference, pixel(0.0, -179.9, zoom), zoom);
+        let step = geo::world_width_pixels(constants::DEFAULT_TILE_SIZE, zoom) * 0.2 / 360.0;
+        assert!((after.0 - before.0 - step).abs() < 1e-6);
+        assert_eq!(after.1, before.1);
+    }
+
+    #[test]
+    fn higher_aircraft_stack_above_lower_below_labels() {
+        let ground = aircraft_z_2d(None);
+        let low = aircraft_z_2d(Some(3_000));
+        let high = aircraft_z_2d(Some(35_000));
+        assert_eq!(ground, constants::AIRCRAFT_Z_LAYER);
+        assert!(ground < low && low < high);
+        assert!(aircraft_z_2d(Some(90_000)) < constants::LABEL_Z_LAYER);
+        assert_eq!(aircraft_z_2d(Some(-500)), ground);
+    }
+}
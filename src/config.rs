@@ -2,10 +2,14 @@ use bevy::prelude::*;
 use bevy_egui::{egui, EguiPlugin};
 use bevy_slippy_tiles::{SlippyTilesSettings, TileFormat};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
-use crate::aircraft::TrailRenderer;
+use crate::aircraft::{TrailDisplayMode, TrailFadeMode, TrailRenderer};
+use crate::aircraft::list_panel::SortCriteria;
+use crate::aircraft::altitude::{AltitudeBand, AltitudeColorPreset, AltitudeColorScheme, AltitudeDisplay, AltitudeSource};
+use crate::geo::{format_from_receiver, format_latlon, CoordinateFormat, NorthReference, PositionReadout};
 use crate::theme::{AppTheme, ThemeRegistry};
 
 const CONFIG_FILE: &str = "config.toml";
@@ -18,6 +22,8 @@ pub enum BasemapStyle {
     CartoLight,
     OpenStreetMap,
     EsriSatellite,
+    /// User-supplied tile server (see `TileServerConfig`)
+    Custom,
 }
 
 impl BasemapStyle {
@@ -28,6 +34,8 @@ impl BasemapStyle {
             BasemapStyle::CartoLight => "https://basemaps.cartocdn.com/light_all",
             BasemapStyle::OpenStreetMap => "https://tile.openstreetmap.org",
             BasemapStyle::EsriSatellite => "https://server.arcgisonline.com/ArcGIS/rest/services/World_Imagery/MapServer/tile",
+            // Resolved from MapConfig::tile_server instead
+            BasemapStyle::Custom => "",
         }
     }
 
@@ -38,6 +46,7 @@ impl BasemapStyle {
             BasemapStyle::CartoLight => "CartoDB Light",
             BasemapStyle::OpenStreetMap => "OpenStreetMap",
             BasemapStyle::EsriSatellite => "ESRI Satellite",
+            BasemapStyle::Custom => "Custom",
         }
     }
 
@@ -60,7 +69,7 @@ impl BasemapStyle {
     pub fn native_tile_pixels(&self) -> u32 {
         match self {
             BasemapStyle::CartoDark | BasemapStyle::CartoLight => 512,
-            BasemapStyle::OpenStreetMap | BasemapStyle::EsriSatellite => 256,
+            BasemapStyle::OpenStreetMap | BasemapStyle::EsriSatellite | BasemapStyle::Custom => 256,
         }
     }
 
@@ -71,10 +80,303 @@ impl BasemapStyle {
             BasemapStyle::CartoLight,
             BasemapStyle::OpenStreetMap,
             BasemapStyle::EsriSatellite,
+            BasemapStyle::Custom,
         ]
     }
 }
 
+/// How the 3D sky is drawn behind tiles and aircraft.
+///
+/// The gradient sky dome is the safe path that replaced Bevy's `Atmosphere`
+/// after its multi-camera HDR bugs on Metal. Backends without those bugs can
+/// use a plain clear-color sky instead, skipping the dome mesh entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SkyRendering {
+    /// Sky dome on Metal (macOS), clear color elsewhere
+    #[default]
+    Auto,
+    GradientDome,
+    ClearColor,
+}
+
+impl SkyRendering {
+    pub const ALL: [SkyRendering; 3] = [SkyRendering::Auto, SkyRendering::GradientDome, SkyRendering::ClearColor];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SkyRendering::Auto => "Auto",
+            SkyRendering::GradientDome => "Gradient dome",
+            SkyRendering::ClearColor => "Clear color",
+        }
+    }
+
+    /// Whether to draw the sky dome, given whether the GPU backend is Metal.
+    pub fn uses_sky_dome(&self, metal_backend: bool) -> bool {
+        match self {
+            SkyRendering::Auto => metal_backend,
+            SkyRendering::GradientDome => true,
+            SkyRendering::ClearColor => false,
+        }
+    }
+}
+
+/// Shape of aircraft markers on the 2D map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MarkerShape {
+    /// Per-type 3D aircraft model
+    #[default]
+    Model,
+    /// Flat chevron pointing along the track; aircraft without a heading
+    /// keep the model
+    Chevron,
+}
+
+impl MarkerShape {
+    pub const ALL: [MarkerShape; 2] = [MarkerShape::Model, MarkerShape::Chevron];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MarkerShape::Model => "Aircraft model",
+            MarkerShape::Chevron => "Chevron",
+        }
+    }
+}
+
+/// Corner of the aircraft marker that map labels sit off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LabelAnchor {
+    #[default]
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+impl LabelAnchor {
+    pub const ALL: [LabelAnchor; 4] = [
+        LabelAnchor::TopRight,
+        LabelAnchor::TopLeft,
+        LabelAnchor::BottomRight,
+        LabelAnchor::BottomLeft,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LabelAnchor::TopRight => "Top right",
+            LabelAnchor::TopLeft => "Top left",
+            LabelAnchor::BottomRight => "Bottom right",
+            LabelAnchor::BottomLeft => "Bottom left",
+        }
+    }
+
+    /// Screen-space direction from the marker to the label (x right, y up)
+    pub fn direction(&self) -> Vec2 {
+        match self {
+            LabelAnchor::TopRight => Vec2::new(1.0, 1.0),
+            LabelAnchor::TopLeft => Vec2::new(-1.0, 1.0),
+            LabelAnchor::BottomRight => Vec2::new(1.0, -1.0),
+            LabelAnchor::BottomLeft => Vec2::new(-1.0, -1.0),
+        }
+    }
+
+    /// Screen-space label offset for a distance of `distance_px` along each axis
+    pub fn offset(&self, distance_px: f32) -> Vec2 {
+        self.direction() * distance_px
+    }
+
+    /// Text anchor at the corner facing the marker, so the label grows
+    /// away from it (e.g. a top-left label is anchored at its bottom right)
+    pub fn text_anchor(&self) -> bevy::sprite::Anchor {
+        use bevy::sprite::Anchor;
+        match self {
+            LabelAnchor::TopRight => Anchor::BOTTOM_LEFT,
+            LabelAnchor::TopLeft => Anchor::BOTTOM_RIGHT,
+            LabelAnchor::BottomRight => Anchor::TOP_LEFT,
+            LabelAnchor::BottomLeft => Anchor::TOP_RIGHT,
+        }
+    }
+}
+
+/// How aggressively map tiles are requested and kept. Scales the download
+/// radius, the number of offset requests for the 3D distance bands and the
+/// tile entity budget. `Medium` matches the original tuned values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TileDetail {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl TileDetail {
+    pub const ALL: [TileDetail; 3] = [TileDetail::Low, TileDetail::Medium, TileDetail::High];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TileDetail::Low => "Low",
+            TileDetail::Medium => "Medium",
+            TileDetail::High => "High",
+        }
+    }
+
+    fn scale(&self) -> f32 {
+        match self {
+            TileDetail::Low => 0.6,
+            TileDetail::Medium => 1.0,
+            TileDetail::High => 1.4,
+        }
+    }
+
+    /// Scale a tile request radius, never dropping below 2 tiles
+    pub fn scale_radius(&self, radius: u8) -> u8 {
+        ((radius as f32 * self.scale()).round() as u8).max(2)
+    }
+
+    /// Scale a tile entity budget
+    pub fn scale_budget(&self, budget: usize) -> usize {
+        (budget as f32 * self.scale()) as usize
+    }
+
+    /// Forward distances (in tiles) swept by the 3D horizon bands
+    pub fn horizon_sweeps(&self) -> &'static [f64] {
+        match self {
+            TileDetail::Low => &[3.0],
+            TileDetail::Medium => &[2.0, 5.0, 8.0],
+            TileDetail::High => &[2.0, 5.0, 8.0, 11.0],
+        }
+    }
+
+    /// Whether the 3D bands also request tiles to either side of the look direction
+    pub fn side_requests(&self) -> bool {
+        !matches!(self, TileDetail::Low)
+    }
+}
+
+/// Custom tile server definition used by `BasemapStyle::Custom`.
+///
+/// The URL template must end in `/{z}/{x}/{y}` or `/{z}/{y}/{x}`, optionally
+/// followed by a `.png` or `.jpg` extension. The part before the tile
+/// coordinates may contain `{s}` (replaced round-robin from `subdomains`) and
+/// `{apikey}` (replaced with `api_key`), e.g.
+/// `https://{s}.tile.example.com/{apikey}/{z}/{x}/{y}.png`. A query string
+/// may follow the extension and may also use `{apikey}`, e.g.
+/// `https://tile.example.com/{z}/{x}/{y}.png?key={apikey}`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TileServerConfig {
+    pub url_template: String,
+    #[serde(default)]
+    pub subdomains: Vec<String>,
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Pixel size of the tiles the server returns
+    #[serde(default = "TileServerConfig::default_tile_pixels")]
+    pub tile_pixels: u32,
+}
+
+impl TileServerConfig {
+    fn default_tile_pixels() -> u32 {
+        256
+    }
+}
+
+impl Default for TileServerConfig {
+    fn default() -> Self {
+        Self {
+            url_template: String::new(),
+            subdomains: Vec::new(),
+            api_key: None,
+            tile_pixels: Self::default_tile_pixels(),
+        }
+    }
+}
+
+/// A tile source resolved into what `SlippyTilesSettings` needs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedTileSource {
+    /// Endpoint bases, one per subdomain, rotated across tile requests
+    pub endpoints: Vec<String>,
+    pub tile_format: TileFormat,
+    pub reverse_axes: bool,
+    pub native_tile_pixels: u32,
+    /// Query string (without the `?`) appended to every tile URL
+    pub query: Option<String>,
+}
+
+impl TileServerConfig {
+    /// Validate the URL template and expand it into endpoint bases.
+    pub fn resolve(&self) -> Result<ResolvedTileSource, String> {
+        let template = self.url_template.trim();
+        if !(template.starts_with("http://") || template.starts_with("https://")) {
+            return Err("Tile URL must start with http:// or https://".to_string());
+        }
+        let expand_api_key = |text: &str| -> Result<String, String> {
+            if !text.contains("{apikey}") {
+                return Ok(text.to_string());
+            }
+            match self.api_key.as_deref().map(str::trim).filter(|k| !k.is_empty()) {
+                Some(key) => Ok(text.replace("{apikey}", key)),
+                None => Err("Tile URL uses {apikey} but no API key is set".to_string()),
+            }
+        };
+
+        let (template, query) = match template.split_once('?') {
+            Some((path, query)) => (path, Some(query.trim())),
+            None => (template, None),
+        };
+        let query = match query.filter(|q| !q.is_empty()) {
+            Some(q) if ["{z}", "{x}", "{y}", "{s}"].iter().any(|p| q.contains(p)) => {
+                return Err("Tile URL query string may only use {apikey}".to_string());
+            }
+            Some(q) => Some(expand_api_key(q)?),
+            None => None,
+        };
+
+        let (path, tile_format) = if let Some(p) = template.strip_suffix(".png") {
+            (p, TileFormat::Png)
+        } else if let Some(p) = template.strip_suffix(".jpg").or_else(|| template.strip_suffix(".jpeg")) {
+            (p, TileFormat::Jpg)
+        } else {
+            (template, TileFormat::Png)
+        };
+
+        let (base, reverse_axes) = if let Some(b) = path.strip_suffix("/{z}/{x}/{y}") {
+            (b, false)
+        } else if let Some(b) = path.strip_suffix("/{z}/{y}/{x}") {
+            (b, true)
+        } else {
+            return Err("Tile URL must end with /{z}/{x}/{y} or /{z}/{y}/{x}".to_string());
+        };
+        if base.contains("{z}") || base.contains("{x}") || base.contains("{y}") {
+            return Err("Tile URL contains {z}/{x}/{y} more than once".to_string());
+        }
+
+        let base = expand_api_key(base)?;
+
+        let endpoints = if base.contains("{s}") {
+            let subdomains: Vec<&str> = self
+                .subdomains
+                .iter()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
+            if subdomains.is_empty() {
+                return Err("Tile URL uses {s} but no subdomains are set".to_string());
+            }
+            subdomains.iter().map(|s| base.replace("{s}", s)).collect()
+        } else {
+            vec![base]
+        };
+
+        Ok(ResolvedTileSource {
+            endpoints,
+            tile_format,
+            reverse_axes,
+            native_tile_pixels: self.tile_pixels,
+            query,
+        })
+    }
+}
+
 #[derive(Resource, Serialize, Deserialize, Clone, Debug)]
 pub struct AppConfig {
     pub feed: FeedConfig,
@@ -89,6 +391,174 @@ pub struct AppConfig {
     pub appearance: AppearanceConfig,
     #[serde(default)]
     pub data_ingest: DataIngestConfig,
+    #[serde(default)]
+    pub altitude_colors: AltitudeColorScheme,
+    #[serde(default)]
+    pub altitude_display: AltitudeDisplay,
+    /// Aircraft list sort, remembered across launches
+    #[serde(default)]
+    pub list_sort: ListSortConfig,
+    /// 3D camera input sensitivities, edited in the 3D View tab
+    #[serde(default)]
+    pub camera_3d: CameraControlsConfig,
+    /// 3D fog curve, edited in the 3D View tab
+    #[serde(default)]
+    pub fog: FogConfig,
+    #[serde(default)]
+    pub power: PowerConfig,
+    /// Short user notes keyed by ICAO address, shown on map labels and in the list
+    #[serde(default)]
+    pub aircraft_notes: BTreeMap<String, String>,
+    /// Custom 3D models keyed by ADS-B type code, as GLB file names under
+    /// `assets/models/` (e.g. `C172 = "c172.glb"`). Loaded at startup.
+    #[serde(default)]
+    pub aircraft_models: BTreeMap<String, String>,
+}
+
+/// Aircraft list sort order and the last direction used for each criterion.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ListSortConfig {
+    #[serde(default)]
+    pub sort_by: SortCriteria,
+    /// Criteria without an entry use `SortCriteria::default_ascending`
+    #[serde(default)]
+    pub ascending: BTreeMap<SortCriteria, bool>,
+}
+
+/// How far the 3D camera moves per unit of mouse, trackpad or wheel input.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CameraControlsConfig {
+    /// Degrees of yaw and pitch per pixel of Shift+drag
+    #[serde(default = "CameraControlsConfig::default_orbit_sensitivity")]
+    pub orbit_sensitivity: f32,
+    /// Ground distance per pixel of drag, as a fraction of the camera distance
+    #[serde(default = "CameraControlsConfig::default_pan_sensitivity")]
+    pub pan_sensitivity: f32,
+    /// Degrees of pitch per point of Shift+scroll
+    #[serde(default = "CameraControlsConfig::default_pitch_scroll_sensitivity")]
+    pub pitch_scroll_sensitivity: f32,
+    /// Feet of camera altitude per scroll line
+    #[serde(default = "CameraControlsConfig::default_altitude_scroll_sensitivity")]
+    pub altitude_scroll_sensitivity: f32,
+}
+
+impl CameraControlsConfig {
+    fn default_orbit_sensitivity() -> f32 {
+        0.3
+    }
+
+    fn default_pan_sensitivity() -> f32 {
+        0.003
+    }
+
+    fn default_pitch_scroll_sensitivity() -> f32 {
+        0.05
+    }
+
+    fn default_altitude_scroll_sensitivity() -> f32 {
+        1000.0
+    }
+}
+
+impl Default for CameraControlsConfig {
+    fn default() -> Self {
+        Self {
+            orbit_sensitivity: Self::default_orbit_sensitivity(),
+            pan_sensitivity: Self::default_pan_sensitivity(),
+            pitch_scroll_sensitivity: Self::default_pitch_scroll_sensitivity(),
+            altitude_scroll_sensitivity: Self::default_altitude_scroll_sensitivity(),
+        }
+    }
+}
+
+/// How the 3D fog thickens with distance. Mirrored into `View3DState`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FogConfig {
+    #[serde(default)]
+    pub curve: crate::view3d::FogCurve,
+    /// Linear fog: fraction of the visibility range where the fog starts
+    #[serde(default = "FogConfig::default_start_fraction")]
+    pub start_fraction: f32,
+    /// Exponential fog: density multiplier
+    #[serde(default = "FogConfig::default_density_scale")]
+    pub density_scale: f32,
+}
+
+impl FogConfig {
+    fn default_start_fraction() -> f32 {
+        crate::view3d::FOG_START_FRACTION
+    }
+
+    fn default_density_scale() -> f32 {
+        1.0
+    }
+}
+
+impl Default for FogConfig {
+    fn default() -> Self {
+        Self {
+            curve: crate::view3d::FogCurve::default(),
+            start_fraction: Self::default_start_fraction(),
+            density_scale: Self::default_density_scale(),
+        }
+    }
+}
+
+/// Low-power idle mode: redraw less often while the window is unfocused or
+/// the feed has gone quiet.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PowerConfig {
+    #[serde(default)]
+    pub idle_mode: bool,
+    /// Seconds without any aircraft update before the app idles while focused
+    #[serde(default = "PowerConfig::default_idle_after_secs")]
+    pub idle_after_secs: u64,
+    /// Longest gap between redraws while idle; input still wakes the app at once
+    #[serde(default = "PowerConfig::default_idle_frame_interval_ms")]
+    pub idle_frame_interval_ms: u64,
+}
+
+impl PowerConfig {
+    fn default_idle_after_secs() -> u64 {
+        60
+    }
+
+    fn default_idle_frame_interval_ms() -> u64 {
+        1000
+    }
+}
+
+impl Default for PowerConfig {
+    fn default() -> Self {
+        Self {
+            idle_mode: false,
+            idle_after_secs: Self::default_idle_after_secs(),
+            idle_frame_interval_ms: Self::default_idle_frame_interval_ms(),
+        }
+    }
+}
+
+impl ListSortConfig {
+    pub fn is_ascending(&self, criteria: SortCriteria) -> bool {
+        self.ascending.get(&criteria).copied().unwrap_or(criteria.default_ascending())
+    }
+}
+
+impl AppConfig {
+    /// The user's note for an aircraft, if any.
+    pub fn aircraft_note(&self, icao: &str) -> Option<&str> {
+        self.aircraft_notes.get(icao).map(String::as_str)
+    }
+
+    /// Set or clear (when blank) the note for an aircraft.
+    pub fn set_aircraft_note(&mut self, icao: &str, note: &str) {
+        let note = note.trim();
+        if note.is_empty() {
+            self.aircraft_notes.remove(icao);
+        } else {
+            self.aircraft_notes.insert(icao.to_string(), note.to_string());
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -96,6 +566,25 @@ pub struct OverlayConfig {
     pub show_airports: bool,
     pub show_runways: bool,
     pub show_navaids: bool,
+    /// Draw fixed distance rings around the receiver
+    #[serde(default)]
+    pub show_range_rings: bool,
+    /// Range ring radii in nautical miles
+    #[serde(default = "OverlayConfig::default_range_ring_radii")]
+    pub range_ring_radii_nm: Vec<f64>,
+    /// Highlight aircraft on approach with a line to the runway threshold
+    #[serde(default = "OverlayConfig::default_show_approaches")]
+    pub show_approaches: bool,
+}
+
+impl OverlayConfig {
+    fn default_range_ring_radii() -> Vec<f64> {
+        vec![50.0, 100.0, 150.0]
+    }
+
+    fn default_show_approaches() -> bool {
+        true
+    }
 }
 
 impl Default for OverlayConfig {
@@ -104,6 +593,9 @@ impl Default for OverlayConfig {
             show_airports: true,
             show_runways: true,
             show_navaids: false,
+            show_range_rings: false,
+            range_ring_radii_nm: Self::default_range_ring_radii(),
+            show_approaches: Self::default_show_approaches(),
         }
     }
 }
@@ -116,9 +608,39 @@ pub struct TrailsConfig {
     pub renderer_2d: TrailRenderer,
     #[serde(default = "TrailsConfig::default_renderer_3d")]
     pub renderer_3d: TrailRenderer,
+    /// Record a new trail point once the aircraft has moved this far (meters)
+    #[serde(default = "TrailsConfig::default_min_point_distance_m")]
+    pub min_point_distance_m: f64,
+    /// Record a point at least this often even if the aircraft hasn't moved
+    #[serde(default = "TrailsConfig::default_max_point_interval_secs")]
+    pub max_point_interval_secs: u64,
+    /// Which aircraft's trails are drawn
+    #[serde(default)]
+    pub display_mode: TrailDisplayMode,
+    /// Fade trails by point age or by position along the trail
+    #[serde(default)]
+    pub fade_mode: TrailFadeMode,
+    /// Keep a fading ghost of a lost aircraft's trail
+    #[serde(default)]
+    pub ghost_trails: bool,
+    /// Seconds a ghost trail lingers after signal loss
+    #[serde(default = "TrailsConfig::default_ghost_duration_secs")]
+    pub ghost_duration_secs: u64,
 }
 
 impl TrailsConfig {
+    fn default_min_point_distance_m() -> f64 {
+        50.0
+    }
+
+    fn default_max_point_interval_secs() -> u64 {
+        15
+    }
+
+    fn default_ghost_duration_secs() -> u64 {
+        120
+    }
+
     fn default_renderer_3d() -> TrailRenderer {
         #[cfg(feature = "hanabi")]
         { TrailRenderer::Particle }
@@ -134,6 +656,12 @@ impl Default for TrailsConfig {
             max_age_seconds: 300,
             renderer_2d: TrailRenderer::default(),
             renderer_3d: Self::default_renderer_3d(),
+            min_point_distance_m: Self::default_min_point_distance_m(),
+            max_point_interval_secs: Self::default_max_point_interval_secs(),
+            display_mode: TrailDisplayMode::default(),
+            fade_mode: TrailFadeMode::default(),
+            ghost_trails: false,
+            ghost_duration_secs: Self::default_ghost_duration_secs(),
         }
     }
 }
@@ -176,12 +704,113 @@ pub struct BookmarksConfig {
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AppearanceConfig {
     pub theme: String,
+    /// Base on-screen size of aircraft markers in pixels
+    #[serde(default = "AppearanceConfig::default_marker_size")]
+    pub marker_size: f32,
+    /// Gizmo trail line width in pixels
+    #[serde(default = "AppearanceConfig::default_trail_width")]
+    pub trail_width: f32,
+    /// Scale aircraft markers by wake-turbulence category
+    #[serde(default = "AppearanceConfig::default_scale_by_wake_category")]
+    pub scale_by_wake_category: bool,
+    /// 2D aircraft marker shape
+    #[serde(default)]
+    pub marker_shape: MarkerShape,
+    /// Map label template; tokens: {callsign} {icao} {alt} {spd} {vrate} {type}
+    #[serde(default = "AppearanceConfig::default_label_template")]
+    pub label_template: String,
+    /// Label distance from the marker along each axis, in screen pixels
+    #[serde(default = "AppearanceConfig::default_label_offset")]
+    pub label_offset: f32,
+    /// Which corner of the marker labels sit off
+    #[serde(default)]
+    pub label_anchor: LabelAnchor,
+    /// 3D sky rendering path
+    #[serde(default)]
+    pub sky_rendering: SkyRendering,
+    /// Multiplier on the 2D day/night tint alpha (0, the default, disables it;
+    /// 1 is full strength)
+    #[serde(default = "AppearanceConfig::default_day_night_tint_intensity")]
+    pub day_night_tint_intensity: f32,
+    /// sRGB color of the 2D night tint
+    #[serde(default = "AppearanceConfig::default_night_tint_color")]
+    pub night_tint_color: [u8; 3],
+    /// Minimum interval between aircraft list rebuilds in milliseconds
+    /// (0 = every frame). Sort, filter and search changes apply immediately.
+    #[serde(default = "AppearanceConfig::default_list_refresh_interval_ms")]
+    pub list_refresh_interval_ms: u64,
+    /// VFR conspicuity code (1200 in the US, 7000 in Europe)
+    #[serde(default = "AppearanceConfig::default_vfr_squawk")]
+    pub vfr_squawk: String,
+    /// Grey out aircraft squawking the VFR code in the aircraft list
+    #[serde(default)]
+    pub dim_vfr_in_list: bool,
+    /// Fade aircraft squawking the VFR code on the map
+    #[serde(default)]
+    pub dim_vfr_on_map: bool,
+}
+
+impl AppearanceConfig {
+    fn default_marker_size() -> f32 {
+        crate::constants::AIRCRAFT_MODEL_SCALE
+    }
+
+    fn default_trail_width() -> f32 {
+        2.0
+    }
+
+    fn default_scale_by_wake_category() -> bool {
+        true
+    }
+
+    fn default_label_template() -> String {
+        "{callsign}\n{alt}".to_string()
+    }
+
+    fn default_label_offset() -> f32 {
+        crate::constants::LABEL_SCREEN_OFFSET
+    }
+
+    fn default_day_night_tint_intensity() -> f32 {
+        0.0
+    }
+
+    fn default_night_tint_color() -> [u8; 3] {
+        [5, 10, 40]
+    }
+
+    fn default_list_refresh_interval_ms() -> u64 {
+        250
+    }
+
+    fn default_vfr_squawk() -> String {
+        "1200".to_string()
+    }
+
+    /// Whether `squawk` is the configured VFR conspicuity code
+    pub fn is_vfr_squawk(&self, squawk: Option<&str>) -> bool {
+        squawk.is_some_and(|s| s.trim() == self.vfr_squawk)
+    }
 }
 
 impl Default for AppearanceConfig {
     fn default() -> Self {
         Self {
             theme: "Catppuccin Mocha".to_string(),
+            marker_size: Self::default_marker_size(),
+            trail_width: Self::default_trail_width(),
+            scale_by_wake_category: Self::default_scale_by_wake_category(),
+            label_template: Self::default_label_template(),
+            label_offset: Self::default_label_offset(),
+            label_anchor: LabelAnchor::default(),
+            marker_shape: MarkerShape::default(),
+            sky_rendering: SkyRendering::default(),
+            day_night_tint_intensity: Self::default_day_night_tint_intensity(),
+            night_tint_color: Self::default_night_tint_color(),
+            list_refresh_interval_ms: Self::default_list_refresh_interval_ms(),
+            vfr_squawk: Self::default_vfr_squawk(),
+            dim_vfr_in_list: false,
+            dim_vfr_on_map: false,
         }
     }
 }
@@ -190,6 +819,18 @@ impl Default for AppearanceConfig {
 pub struct FeedConfig {
     pub endpoint_url: String,
     pub refresh_interval_ms: u64,
+    /// Maximum number of aircraft entities kept on the map (0 = unlimited)
+    #[serde(default = "FeedConfig::default_max_tracked_aircraft")]
+    pub max_tracked_aircraft: usize,
+    /// Follow an aircraft as soon as it starts squawking 7500/7600/7700
+    #[serde(default)]
+    pub auto_follow_emergencies: bool,
+}
+
+impl FeedConfig {
+    fn default_max_tracked_aircraft() -> usize {
+        2000
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -199,6 +840,43 @@ pub struct MapConfig {
     pub default_zoom: u8,
     #[serde(default)]
     pub basemap_style: BasemapStyle,
+    /// Tile server used when `basemap_style` is `Custom`
+    #[serde(default)]
+    pub tile_server: TileServerConfig,
+    /// Rotate the 2D map so the followed aircraft's track points up
+    #[serde(default)]
+    pub track_up: bool,
+    /// North reference for bearing readouts (measurement, coverage)
+    #[serde(default)]
+    pub north_reference: NorthReference,
+    /// How positions are displayed (detail panel, cursor readout, measurement)
+    #[serde(default)]
+    pub coordinate_format: CoordinateFormat,
+    /// Lat/lon, bearing/distance from the home location, or both
+    #[serde(default)]
+    pub position_readout: PositionReadout,
+    /// Tile detail / bandwidth level
+    #[serde(default)]
+    pub tile_detail: TileDetail,
+    /// Pan continuously across the antimeridian instead of stopping at ±180°
+    #[serde(default)]
+    pub wrap_antimeridian: bool,
+    /// Snap the 2D zoom to a whole-pixel tile scale once scrolling stops
+    #[serde(default)]
+    pub snap_zoom: bool,
+    /// Cap on horizon sweep distances per 3D band (fewer sweeps, fewer
+    /// far-away tile requests)
+    #[serde(default = "MapConfig::default_max_horizon_sweeps")]
+    pub max_horizon_sweeps: u8,
+    /// Cap on tiles requested per 3D refresh tick across all bands
+    #[serde(default = "MapConfig::default_tile_budget_3d")]
+    pub tile_budget_3d: usize,
+    /// Fade newly loaded 2D tiles in rather than showing them at full opacity
+    #[serde(default = "MapConfig::default_tile_fade")]
+    pub tile_fade: bool,
+    /// 2D tile fade-in rate, in opacity per second
+    #[serde(default = "MapConfig::default_tile_fade_speed")]
+    pub tile_fade_speed: f32,
 }
 
 /// Configuration for a single data ingest provider.
@@ -285,18 +963,40 @@ impl Default for AppConfig {
                 // Raw TCP address for ADS-B connection (host:port format)
                 endpoint_url: "192.168.1.10:30003".to_string(),
                 refresh_interval_ms: 1000,
+                max_tracked_aircraft: FeedConfig::default_max_tracked_aircraft(),
+                auto_follow_emergencies: false,
             },
             map: MapConfig {
                 default_latitude: 37.6872,
                 default_longitude: -97.3301,
                 default_zoom: 10,
                 basemap_style: BasemapStyle::default(),
+                tile_server: TileServerConfig::default(),
+                track_up: false,
+                north_reference: NorthReference::default(),
+                coordinate_format: CoordinateFormat::default(),
+                position_readout: PositionReadout::default(),
+                tile_detail: TileDetail::default(),
+                wrap_antimeridian: false,
+                snap_zoom: false,
+                max_horizon_sweeps: MapConfig::default_max_horizon_sweeps(),
+                tile_budget_3d: MapConfig::default_tile_budget_3d(),
+                tile_fade: MapConfig::default_tile_fade(),
+                tile_fade_speed: MapConfig::default_tile_fade_speed(),
             },
             overlays: OverlayConfig::default(),
             trails: TrailsConfig::default(),
             bookmarks: BookmarksConfig::default(),
             appearance: AppearanceConfig::default(),
             data_ingest: DataIngestConfig::default(),
+            altitude_colors: AltitudeColorScheme::default(),
+            altitude_display: AltitudeDisplay::default(),
+            list_sort: ListSortConfig::default(),
+            camera_3d: CameraControlsConfig::default(),
+            fog: FogConfig::default(),
+            power: PowerConfig::default(),
+            aircraft_notes: BTreeMap::new(),
+            aircraft_models: BTreeMap::new(),
         }
     }
 }
@@ -352,44 +1052,142 @@ pub struct SettingsUiState {
     pub open: bool,
     pub endpoint_url: String,
     pub refresh_interval_ms: String,
+    pub max_tracked_aircraft: String,
+    pub auto_follow_emergencies: bool,
     pub default_latitude: String,
     pub default_longitude: String,
     pub default_zoom: String,
     // Map settings
     pub basemap_style: BasemapStyle,
+    pub tile_url_template: String,
+    /// Comma-separated subdomains for `{s}`
+    pub tile_subdomains: String,
+    pub tile_api_key: String,
+    pub tile_pixels: String,
+    pub track_up: bool,
+    pub north_reference: NorthReference,
+    pub coordinate_format: CoordinateFormat,
+    pub position_readout: PositionReadout,
+    pub tile_detail: TileDetail,
+    pub wrap_antimeridian: bool,
+    pub snap_zoom: bool,
+    pub max_horizon_sweeps: u8,
+    pub tile_budget_3d: usize,
+    pub tile_fade: bool,
+    pub tile_fade_speed: f32,
     // Overlay settings
     pub show_airports: bool,
     pub show_runways: bool,
     pub show_navaids: bool,
+    pub show_range_rings: bool,
+    pub show_approaches: bool,
+    /// Comma-separated ring radii in NM
+    pub range_ring_radii: String,
     // Trail settings
     pub trails_enabled: bool,
     pub trails_max_age: String,
     pub trails_renderer_2d: TrailRenderer,
     pub trails_renderer_3d: TrailRenderer,
+    pub trails_min_distance: String,
+    pub trails_max_interval: String,
+    pub trails_display_mode: TrailDisplayMode,
+    pub trails_fade_mode: TrailFadeMode,
+    pub trails_ghost: bool,
+    pub trails_ghost_duration: u64,
+    // Display size settings
+    pub marker_size: f32,
+    pub trail_width: f32,
+    pub scale_by_wake_category: bool,
+    pub marker_shape: MarkerShape,
+    pub label_template: String,
+    pub label_offset: f32,
+    pub label_anchor: LabelAnchor,
+    pub sky_rendering: SkyRendering,
+    pub day_night_tint_intensity: f32,
+    pub night_tint_color: [u8; 3],
+    pub list_refresh_interval_ms: u64,
+    pub vfr_squawk: String,
+    pub dim_vfr_in_list: bool,
+    pub dim_vfr_on_map: bool,
     pub data_ingest: DataIngestConfig,
+    pub altitude_colors: AltitudeColorScheme,
+    pub altitude_display: AltitudeDisplay,
+    pub power: PowerConfig,
     pub error_message: Option<String>,
     pub layout_reset_requested: bool,
+    /// Showing the confirmation for "Reset All to Defaults"
+    pub full_reset_confirm: bool,
+    /// Reset layout, open panels, theme and key bindings on the next dock frame
+    pub full_reset_requested: bool,
 }
 
 impl SettingsUiState {
     pub fn populate_from_config(&mut self, config: &AppConfig) {
         self.endpoint_url = config.feed.endpoint_url.clone();
         self.refresh_interval_ms = config.feed.refresh_interval_ms.to_string();
+        self.max_tracked_aircraft = config.feed.max_tracked_aircraft.to_string();
+        self.auto_follow_emergencies = config.feed.auto_follow_emergencies;
         self.default_latitude = config.map.default_latitude.to_string();
         self.default_longitude = config.map.default_longitude.to_string();
         self.default_zoom = config.map.default_zoom.to_string();
         // Map settings
         self.basemap_style = config.map.basemap_style;
+        self.tile_url_template = config.map.tile_server.url_template.clone();
+        self.tile_subdomains = config.map.tile_server.subdomains.join(",");
+        self.tile_api_key = config.map.tile_server.api_key.clone().unwrap_or_default();
+        self.tile_pixels = config.map.tile_server.tile_pixels.to_string();
+        self.track_up = config.map.track_up;
+        self.north_reference = config.map.north_reference;
+        self.coordinate_format = config.map.coordinate_format;
+        self.position_readout = config.map.position_readout;
+        self.tile_detail = config.map.tile_detail;
+        self.wrap_antimeridian = config.map.wrap_antimeridian;
+        self.snap_zoom = config.map.snap_zoom;
+        self.max_horizon_sweeps = config.map.max_horizon_sweeps;
+        self.tile_budget_3d = config.map.tile_budget_3d;
+        self.tile_fade = config.map.tile_fade;
+        self.tile_fade_speed = config.map.tile_fade_speed;
         // Overlay settings
         self.show_airports = config.overlays.show_airports;
         self.show_runways = config.overlays.show_runways;
         self.show_navaids = config.overlays.show_navaids;
+        self.show_range_rings = config.overlays.show_range_rings;
+        self.show_approaches = config.overlays.show_approaches;
+        self.range_ring_radii = config.overlays.range_ring_radii_nm
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
         // Trail settings
         self.trails_enabled = config.trails.enabled;
         self.trails_max_age = config.trails.max_age_seconds.to_string();
         self.trails_renderer_2d = config.trails.renderer_2d;
         self.trails_renderer_3d = config.trails.renderer_3d;
+        self.trails_min_distance = format!("{}", config.trails.min_point_distance_m);
+        self.trails_max_interval = config.trails.max_point_interval_secs.to_string();
+        self.trails_display_mode = config.trails.display_mode;
+        self.trails_fade_mode = config.trails.fade_mode;
+        self.trails_ghost = config.trails.ghost_trails;
+        self.trails_ghost_duration = config.trails.ghost_duration_secs;
+        // Display size settings
+        self.marker_size = config.appearance.marker_size;
+        self.trail_width = config.appearance.trail_width;
+        self.scale_by_wake_category = config.appearance.scale_by_wake_category;
+        self.label_template = config.appearance.label_template.clone();
+        self.label_offset = config.appearance.label_offset;
+        self.label_anchor = config.appearance.label_anchor;
+        self.marker_shape = config.appearance.marker_shape;
+        self.sky_rendering = config.appearance.sky_rendering;
+        self.day_night_tint_intensity = config.appearance.day_night_tint_intensity;
+        self.night_tint_color = config.appearance.night_tint_color;
+        self.list_refresh_interval_ms = config.appearance.list_refresh_interval_ms;
+        self.vfr_squawk = config.appearance.vfr_squawk.clone();
+        self.dim_vfr_in_list = config.appearance.dim_vfr_in_list;
+        self.dim_vfr_on_map = config.appearance.dim_vfr_on_map;
         self.data_ingest = config.data_ingest.clone();
+        self.altitude_colors = config.altitude_colors.clone();
+        self.altitude_display = config.altitude_display;
+        self.power = config.power.clone();
         self.error_message = None;
     }
 
@@ -415,6 +1213,13 @@ impl SettingsUiState {
             return Err("Refresh interval must be 100-60000 ms".to_string());
         }
 
+        // Validate tracked aircraft cap
+        let max_tracked_aircraft: usize = self.max_tracked_aircraft.trim().parse()
+            .map_err(|_| "Max tracked aircraft must be a number")?;
+        if max_tracked_aircraft != 0 && max_tracked_aircraft < 10 {
+            return Err("Max tracked aircraft must be 0 (unlimited) or at least 10".to_string());
+        }
+
         // Validate latitude
         let lat: f64 = self.default_latitude.trim().parse()
             .map_err(|_| "Latitude must be a number")?;
@@ -436,6 +1241,39 @@ impl SettingsUiState {
             return Err("Zoom must be 0-19".to_string());
         }
 
+        // Validate range ring radii
+        let mut range_ring_radii_nm = Vec::new();
+        for part in self.range_ring_radii.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let radius: f64 = part.parse()
+                .map_err(|_| format!("Range ring radius '{}' is not a number", part))?;
+            if !(1.0..=1000.0).contains(&radius) {
+                return Err("Range ring radii must be 1-1000 NM".to_string());
+            }
+            range_ring_radii_nm.push(radius);
+        }
+        range_ring_radii_nm.sort_by(|a, b| a.total_cmp(b));
+        range_ring_radii_nm.dedup();
+
+        // Validate custom tile server
+        let tile_pixels: u32 = self.tile_pixels.trim().parse()
+            .map_err(|_| "Tile size must be a number")?;
+        if tile_pixels != 256 && tile_pixels != 512 {
+            return Err("Tile size must be 256 or 512".to_string());
+        }
+        let tile_server = TileServerConfig {
+            url_template: self.tile_url_template.trim().to_string(),
+            subdomains: self.tile_subdomains
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            api_key: Some(self.tile_api_key.trim().to_string()).filter(|k| !k.is_empty()),
+            tile_pixels,
+        };
+        if self.basemap_style == BasemapStyle::Custom {
+            tile_server.resolve()?;
+        }
+
         // Validate trails max age
         let trails_max_age: u64 = self.trails_max_age.trim().parse()
             .map_err(|_| "Trail max age must be a number")?;
@@ -443,31 +1281,120 @@ impl SettingsUiState {
             return Err("Trail max age must be 30-3600 seconds".to_string());
         }
 
+        // Validate trail point recording thresholds
+        let trails_min_distance: f64 = self.trails_min_distance.trim().parse()
+            .map_err(|_| "Trail point distance must be a number")?;
+        if !(0.0..=5000.0).contains(&trails_min_distance) {
+            return Err("Trail point distance must be 0-5000 meters".to_string());
+        }
+        let trails_max_interval: u64 = self.trails_max_interval.trim().parse()
+            .map_err(|_| "Trail point interval must be a number")?;
+        if trails_max_interval < 2 || trails_max_interval > 120 {
+            return Err("Trail point interval must be 2-120 seconds".to_string());
+        }
+
+        // Validate display sizes
+        if !(2.0..=32.0).contains(&self.marker_size) {
+            return Err("Marker size must be 2-32 px".to_string());
+        }
+        if !(0.5..=10.0).contains(&self.trail_width) {
+            return Err("Trail width must be 0.5-10 px".to_string());
+        }
+        if self.label_template.trim().is_empty() {
+            return Err("Label template must not be empty".to_string());
+        }
+        if !(0.0..=80.0).contains(&self.label_offset) {
+            return Err("Label offset must be 0-80 px".to_string());
+        }
+        let vfr_squawk = self.vfr_squawk.trim();
+        if vfr_squawk.len() != 4 || !vfr_squawk.chars().all(|c| ('0'..='7').contains(&c)) {
+            return Err("VFR squawk must be four octal digits (e.g. 1200, 7000)".to_string());
+        }
+
+        // Validate altitude color bands
+        if self.altitude_colors.bands.is_empty() {
+            return Err("At least one altitude color band is required".to_string());
+        }
+        let mut altitude_colors = self.altitude_colors.clone();
+        altitude_colors.normalize();
+
+        // Validate transition altitude
+        if !(1000..=60000).contains(&self.altitude_display.transition_altitude) {
+            return Err("Transition altitude must be 1000-60000 ft".to_string());
+        }
+
         Ok(AppConfig {
             feed: FeedConfig {
                 endpoint_url: endpoint.to_string(),
                 refresh_interval_ms: refresh_ms,
+                max_tracked_aircraft,
+                auto_follow_emergencies: self.auto_follow_emergencies,
             },
             map: MapConfig {
                 default_latitude: lat,
                 default_longitude: lon,
                 default_zoom: zoom,
                 basemap_style: self.basemap_style,
+                tile_server,
+                track_up: self.track_up,
+                north_reference: self.north_reference,
+                coordinate_format: self.coordinate_format,
+                position_readout: self.position_readout,
+                tile_detail: self.tile_detail,
+                wrap_antimeridian: self.wrap_antimeridian,
+                snap_zoom: self.snap_zoom,
+                max_horizon_sweeps: self.max_horizon_sweeps,
+                tile_budget_3d: self.tile_budget_3d,
+                tile_fade: self.tile_fade,
+                tile_fade_speed: self.tile_fade_speed,
             },
             overlays: OverlayConfig {
                 show_airports: self.show_airports,
                 show_runways: self.show_runways,
                 show_navaids: self.show_navaids,
+                show_range_rings: self.show_range_rings,
+                show_approaches: self.show_approaches,
+                range_ring_radii_nm,
             },
             trails: TrailsConfig {
                 enabled: self.trails_enabled,
                 max_age_seconds: trails_max_age,
                 renderer_2d: self.trails_renderer_2d,
                 renderer_3d: self.trails_renderer_3d,
+                min_point_distance_m: trails_min_distance,
+                max_point_interval_secs: trails_max_interval,
+                display_mode: self.trails_display_mode,
+                fade_mode: self.trails_fade_mode,
+                ghost_trails: self.trails_ghost,
+                ghost_duration_secs: self.trails_ghost_duration,
             },
             bookmarks: BookmarksConfig::default(),
-            appearance: AppearanceConfig::default(),
+            appearance: AppearanceConfig {
+                marker_size: self.marker_size,
+                trail_width: self.trail_width,
+                scale_by_wake_category: self.scale_by_wake_category,
+                label_template: self.label_template.trim().to_string(),
+                label_offset: self.label_offset,
+                label_anchor: self.label_anchor,
+                marker_shape: self.marker_shape,
+                sky_rendering: self.sky_rendering,
+                day_night_tint_intensity: self.day_night_tint_intensity,
+                night_tint_color: self.night_tint_color,
+                list_refresh_interval_ms: self.list_refresh_interval_ms,
+                vfr_squawk: vfr_squawk.to_string(),
+                dim_vfr_in_list: self.dim_vfr_in_list,
+                dim_vfr_on_map: self.dim_vfr_on_map,
+                ..AppearanceConfig::default()
+            },
             data_ingest: self.data_ingest.clone(),
+            altitude_colors,
+            altitude_display: self.altitude_display,
+            list_sort: ListSortConfig::default(),
+            camera_3d: CameraControlsConfig::default(),
+            fog: FogConfig::default(),
+            power: self.power.clone(),
+            aircraft_notes: BTreeMap::new(),
+            aircraft_models: BTreeMap::new(),
         })
     }
 }
@@ -481,6 +1408,7 @@ pub fn render_settings_pane_content(
     app_config: &mut AppConfig,
     app_theme: &mut AppTheme,
     theme_registry: &ThemeRegistry,
+    tracked_aircraft: usize,
 ) {
     ui.heading("Settings");
     ui.separator();
@@ -504,14 +1432,126 @@ pub fn render_settings_pane_content(
 
     ui.add_space(12.0);
 
+    // Display size section
+    ui.collapsing("Display Size", |ui| {
+        ui.add(egui::Slider::new(&mut ui_state.marker_size, 2.0..=32.0)
+            .step_by(0.5)
+            .suffix(" px")
+            .text("Aircraft marker"));
+        ui.add(egui::Slider::new(&mut ui_state.trail_width, 0.5..=10.0)
+            .step_by(0.5)
+            .suffix(" px")
+            .text("Trail width"));
+        ui.checkbox(&mut ui_state.scale_by_wake_category, "Size markers by wake category");
+        ui.horizontal(|ui| {
+            ui.label("2D marker:");
+            egui::ComboBox::from_id_salt("marker_shape")
+                .selected_text(ui_state.marker_shape.label())
+                .show_ui(ui, |ui| {
+                    for shape in MarkerShape::ALL {
+                        ui.selectable_value(&mut ui_state.marker_shape, shape, shape.label());
+                    }
+                });
+        });
+        ui.add(egui::Slider::new(&mut ui_state.list_refresh_interval_ms, 0..=1000)
+            .step_by(50.0)
+            .suffix(" ms")
+            .text("List refresh"));
+        ui.label(egui::RichText::new("How often the aircraft list re-sorts; 0 = every frame")
+            .size(10.0)
+            .color(egui::Color32::GRAY));
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label("VFR squawk:");
+            ui.add(egui::TextEdit::singleline(&mut ui_state.vfr_squawk).desired_width(48.0))
+                .on_hover_text("VFR conspicuity code: 1200 in the US, 7000 in Europe");
+        });
+        ui.checkbox(&mut ui_state.dim_vfr_in_list, "Grey out VFR traffic in the list");
+        ui.checkbox(&mut ui_state.dim_vfr_on_map, "Fade VFR traffic on the map");
+        ui.add_space(8.0);
+
+        ui.label("Label template:");
+        ui.add(egui::TextEdit::multiline(&mut ui_state.label_template)
+            .desired_rows(2)
+            .font(egui::TextStyle::Monospace));
+        ui.label(egui::RichText::new("{callsign} {icao} {alt} {spd} {vrate} {type}")
+            .size(10.0)
+            .color(egui::Color32::GRAY));
+        ui.horizontal(|ui| {
+            ui.label("Label position:");
+            egui::ComboBox::from_id_salt("label_anchor")
+                .selected_text(ui_state.label_anchor.label())
+                .show_ui(ui, |ui| {
+                    for anchor in LabelAnchor::ALL {
+                        ui.selectable_value(&mut ui_state.label_anchor, anchor, anchor.label());
+                    }
+                });
+        });
+        ui.add(egui::Slider::new(&mut ui_state.label_offset, 0.0..=80.0)
+            .step_by(1.0)
+            .suffix(" px")
+            .text("Label offset"));
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label("3D sky:");
+            egui::ComboBox::from_id_salt("sky_rendering")
+                .selected_text(ui_state.sky_rendering.label())
+                .show_ui(ui, |ui| {
+                    for sky in SkyRendering::ALL {
+                        ui.selectable_value(&mut ui_state.sky_rendering, sky, sky.label());
+                    }
+                });
+        });
+        ui.label(egui::RichText::new("Auto keeps the sky dome on macOS (Metal) and uses a plain sky elsewhere")
+            .size(10.0)
+            .color(egui::Color32::GRAY));
+        ui.add_space(8.0);
+
+        ui.add(egui::Slider::new(&mut ui_state.day_night_tint_intensity, 0.0..=2.0)
+            .step_by(0.05)
+            .text("2D day/night tint"))
+            .on_hover_text("Tints the 2D map by the sun's elevation; 0 turns it off");
+        ui.horizontal(|ui| {
+            ui.label("Night tint color:");
+            ui.color_edit_button_srgb(&mut ui_state.night_tint_color);
+        });
+        ui.label(egui::RichText::new("0 disables the map tint; the golden-hour cue scales with it")
+            .size(10.0)
+            .color(egui::Color32::GRAY));
+    });
+
+    ui.add_space(12.0);
+
     // Feed section
     ui.collapsing("Feed", |ui| {
         ui.label("Endpoint (host:port):");
-        ui.text_edit_singleline(&mut ui_state.endpoint_url);
+        ui.text_edit_singleline(&mut ui_state.endpoint_url)
+            .on_hover_text("Saving a new endpoint reconnects the feed without a restart");
         ui.add_space(8.0);
 
         ui.label("Refresh Interval (ms):");
         ui.text_edit_singleline(&mut ui_state.refresh_interval_ms);
+        ui.add_space(8.0);
+
+        ui.label("Max Tracked Aircraft (0 = unlimited):");
+        ui.text_edit_singleline(&mut ui_state.max_tracked_aircraft);
+        let cap = app_config.feed.max_tracked_aircraft;
+        let at_cap = cap != 0 && tracked_aircraft >= cap;
+        ui.label(
+            egui::RichText::new(if cap == 0 {
+                format!("Tracking {} aircraft", tracked_aircraft)
+            } else {
+                format!("Tracking {} / {} aircraft", tracked_aircraft, cap)
+            })
+            .size(10.0)
+            .color(if at_cap { egui::Color32::from_rgb(255, 180, 60) } else { egui::Color32::GRAY }),
+        );
+        ui.add_space(8.0);
+
+        ui.checkbox(&mut ui_state.auto_follow_emergencies, "Auto-follow emergency aircraft")
+            .on_hover_text("Center on an aircraft as soon as it squawks 7500, 7600 or 7700 (once per emergency)");
     });
 
     ui.add_space(12.0);
@@ -530,6 +1570,18 @@ pub fn render_settings_pane_content(
                     );
                 }
             });
+        if ui_state.basemap_style == BasemapStyle::Custom {
+            ui.add_space(4.0);
+            ui.label("Tile URL template:");
+            ui.text_edit_singleline(&mut ui_state.tile_url_template)
+                .on_hover_text("e.g. https://{s}.tile.example.com/{z}/{x}/{y}.png?key={apikey}");
+            ui.label("Subdomains for {s} (comma-separated):");
+            ui.text_edit_singleline(&mut ui_state.tile_subdomains);
+            ui.label("API key for {apikey}:");
+            ui.add(egui::TextEdit::singleline(&mut ui_state.tile_api_key).password(true));
+            ui.label("Tile size (256 or 512):");
+            ui.text_edit_singleline(&mut ui_state.tile_pixels);
+        }
         ui.add_space(8.0);
 
         ui.label("Default Latitude:");
@@ -542,6 +1594,86 @@ pub fn render_settings_pane_content(
 
         ui.label("Default Zoom (0-19):");
         ui.text_edit_singleline(&mut ui_state.default_zoom);
+        ui.add_space(8.0);
+
+        ui.checkbox(&mut ui_state.track_up, "Track up when following (2D)")
+            .on_hover_text("Rotate the map so the followed aircraft's heading points up");
+        ui.checkbox(&mut ui_state.wrap_antimeridian, "Wrap across the date line")
+            .on_hover_text("Pan continuously across 180\u{00B0} longitude instead of stopping at the edge");
+        ui.checkbox(&mut ui_state.snap_zoom, "Snap zoom to tile level")
+            .on_hover_text("When scrolling stops, ease the zoom to a whole-pixel tile scale for crisp tiles");
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Bearings:");
+            egui::ComboBox::from_id_salt("north_reference")
+                .selected_text(ui_state.north_reference.label())
+                .show_ui(ui, |ui| {
+                    for reference in NorthReference::ALL {
+                        ui.selectable_value(&mut ui_state.north_reference, reference, reference.label());
+                    }
+                });
+        })
+        .response
+        .on_hover_text("Magnetic bearings apply the local declination from a coarse field model");
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Coordinates:");
+            egui::ComboBox::from_id_salt("coordinate_format")
+                .selected_text(ui_state.coordinate_format.label())
+                .show_ui(ui, |ui| {
+                    for format in CoordinateFormat::ALL {
+                        ui.selectable_value(&mut ui_state.coordinate_format, format, format.label());
+                    }
+                });
+        });
+        ui.horizontal(|ui| {
+            ui.label("Position readout:");
+            egui::ComboBox::from_id_salt("position_readout")
+                .selected_text(ui_state.position_readout.label())
+                .show_ui(ui, |ui| {
+                    for readout in PositionReadout::ALL {
+                        ui.selectable_value(&mut ui_state.position_readout, readout, readout.label());
+                    }
+                });
+        })
+        .response
+        .on_hover_text("Bearing and distance are measured from the default map location");
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Tile detail:");
+            egui::ComboBox::from_id_salt("tile_detail")
+                .selected_text(ui_state.tile_detail.label())
+                .show_ui(ui, |ui| {
+                    for detail in TileDetail::ALL {
+                        ui.selectable_value(&mut ui_state.tile_detail, detail, detail.label());
+                    }
+                });
+        })
+        .response
+        .on_hover_text("Lower detail requests fewer tiles, for slow machines or metered connections");
+        ui.add(egui::Slider::new(&mut ui_state.max_horizon_sweeps, 0..=4)
+            .text("3D horizon sweeps"))
+            .on_hover_text("Forward distances swept by the coarse horizon bands in 3D; 0 disables them");
+        ui.add(egui::Slider::new(&mut ui_state.tile_budget_3d, 200..=4000)
+            .step_by(100.0)
+            .suffix(" tiles")
+            .text("3D tile budget"))
+            .on_hover_text("Most tiles requested per 3D refresh; far bands are dropped first");
+        ui.checkbox(&mut ui_state.tile_fade, "Fade in new tiles")
+            .on_hover_text("Off shows 2D tiles at full opacity as soon as they load");
+        ui.add_enabled(
+            ui_state.tile_fade,
+            egui::Slider::new(
+                &mut ui_state.tile_fade_speed,
+                crate::constants::TILE_FADE_SPEED_MIN..=crate::constants::TILE_FADE_SPEED_MAX,
+            )
+                .step_by(0.5)
+                .suffix("/s")
+                .text("Tile fade speed"),
+        );
     });
 
     ui.add_space(12.0);
@@ -551,6 +1683,12 @@ pub fn render_settings_pane_content(
         ui.checkbox(&mut ui_state.show_airports, "Show Airports");
         ui.checkbox(&mut ui_state.show_runways, "Show Runways");
         ui.checkbox(&mut ui_state.show_navaids, "Show Navaids");
+        ui.checkbox(&mut ui_state.show_range_rings, "Show Range Rings");
+        ui.add_enabled_ui(ui_state.show_range_rings, |ui| {
+            ui.label("Ring radii (NM, comma-separated):");
+            ui.text_edit_singleline(&mut ui_state.range_ring_radii);
+        });
+        ui.checkbox(&mut ui_state.show_approaches, "Highlight Aircraft on Approach");
     });
 
     ui.add_space(12.0);
@@ -560,9 +1698,49 @@ pub fn render_settings_pane_content(
         ui.checkbox(&mut ui_state.trails_enabled, "Enable Trails");
         ui.add_space(8.0);
         ui.add_enabled_ui(ui_state.trails_enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Show trails for:");
+                egui::ComboBox::from_id_salt("trail_display_mode")
+                    .selected_text(ui_state.trails_display_mode.to_string())
+                    .show_ui(ui, |ui| {
+                        for &mode in TrailDisplayMode::ALL {
+                            ui.selectable_value(&mut ui_state.trails_display_mode, mode, mode.to_string());
+                        }
+                    });
+            });
+            ui.horizontal(|ui| {
+                ui.label("Fade trails:");
+                egui::ComboBox::from_id_salt("trail_fade_mode")
+                    .selected_text(ui_state.trails_fade_mode.to_string())
+                    .show_ui(ui, |ui| {
+                        for &mode in TrailFadeMode::ALL {
+                            ui.selectable_value(&mut ui_state.trails_fade_mode, mode, mode.to_string());
+                        }
+                    });
+            });
+            ui.label(egui::RichText::new("By length keeps fast aircraft trails consistent")
+                .color(egui::Color32::GRAY)
+                .size(10.0));
+            ui.add_space(8.0);
             ui.label("Max Age (seconds):");
             ui.text_edit_singleline(&mut ui_state.trails_max_age);
             ui.add_space(8.0);
+            ui.label("Record point after moving (meters):");
+            ui.text_edit_singleline(&mut ui_state.trails_min_distance);
+            ui.label("Record point at least every (seconds):");
+            ui.text_edit_singleline(&mut ui_state.trails_max_interval);
+            ui.label(egui::RichText::new("Parked and holding aircraft record fewer points")
+                .color(egui::Color32::GRAY)
+                .size(10.0));
+            ui.add_space(8.0);
+            ui.checkbox(&mut ui_state.trails_ghost, "Keep trails after signal loss")
+                .on_hover_text("Lost aircraft leave a fading ghost trail showing where they went");
+            ui.add_enabled_ui(ui_state.trails_ghost, |ui| {
+                ui.add(egui::Slider::new(&mut ui_state.trails_ghost_duration, 10..=900)
+                    .suffix(" s")
+                    .text("Ghost trail fades over"));
+            });
+            ui.add_space(8.0);
             ui.horizontal(|ui| {
                 ui.label("2D Renderer:");
                 egui::ComboBox::from_id_salt("trail_renderer_2d")
@@ -588,6 +1766,61 @@ pub fn render_settings_pane_content(
 
     ui.add_space(12.0);
 
+    // Altitude color section
+    ui.collapsing("Altitude Colors", |ui| {
+        render_altitude_color_settings(ui, &mut ui_state.altitude_colors);
+    });
+
+    ui.add_space(12.0);
+
+    // Altitude display section
+    ui.collapsing("Altitude Display", |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Altitude source:");
+            egui::ComboBox::from_id_salt("altitude_source")
+                .selected_text(ui_state.altitude_display.source.label())
+                .show_ui(ui, |ui| {
+                    for source in AltitudeSource::ALL {
+                        ui.selectable_value(&mut ui_state.altitude_display.source, source, source.label());
+                    }
+                });
+        })
+        .response
+        .on_hover_text("Falls back to the other reading when an aircraft doesn't report the chosen one");
+        ui.checkbox(&mut ui_state.altitude_display.flight_levels, "Show flight levels above transition altitude");
+        ui.add_enabled_ui(ui_state.altitude_display.flight_levels, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Transition altitude:");
+                ui.add(egui::DragValue::new(&mut ui_state.altitude_display.transition_altitude)
+                    .range(1000..=60000)
+                    .speed(100)
+                    .suffix(" ft"));
+            });
+        });
+    });
+
+    ui.add_space(12.0);
+
+    // Power section
+    ui.collapsing("Power", |ui| {
+        ui.checkbox(&mut ui_state.power.idle_mode, "Idle when unfocused or quiet")
+            .on_hover_text("Redraw less often while the window is in the background or no aircraft have updated for a while");
+        ui.add_enabled_ui(ui_state.power.idle_mode, |ui| {
+            ui.add(egui::Slider::new(&mut ui_state.power.idle_after_secs, 10..=600)
+                .suffix(" s")
+                .text("Quiet feed after"));
+            ui.add(egui::Slider::new(&mut ui_state.power.idle_frame_interval_ms, 250..=5000)
+                .step_by(250.0)
+                .suffix(" ms")
+                .text("Idle redraw interval"));
+        });
+        ui.label(egui::RichText::new("Mouse and keyboard input wake the app immediately")
+            .size(10.0)
+            .color(egui::Color32::GRAY));
+    });
+
+    ui.add_space(12.0);
+
     // Layout section
     ui.collapsing("Layout", |ui| {
         if ui.button("Reset Dock Layout").clicked() {
@@ -598,6 +1831,27 @@ pub fn render_settings_pane_content(
                 .size(10.0)
                 .color(egui::Color32::GRAY),
         );
+        ui.add_space(8.0);
+        if !ui_state.full_reset_confirm {
+            if ui.button("Reset All to Defaults\u{2026}").clicked() {
+                ui_state.full_reset_confirm = true;
+            }
+        } else {
+            ui.label(
+                egui::RichText::new("Reset dock layout, open panels, theme and key bindings? Bookmarks and recordings are kept.")
+                    .size(10.0)
+                    .color(egui::Color32::from_rgb(255, 200, 100)),
+            );
+            ui.horizontal(|ui| {
+                if ui.button("Reset").clicked() {
+                    ui_state.full_reset_requested = true;
+                    ui_state.full_reset_confirm = false;
+                }
+                if ui.button("Cancel").clicked() {
+                    ui_state.full_reset_confirm = false;
+                }
+            });
+        }
     });
 
     ui.add_space(16.0);
@@ -619,6 +1873,11 @@ pub fn render_settings_pane_content(
             match ui_state.validate_and_build() {
                 Ok(mut new_config) => {
                     new_config.bookmarks = app_config.bookmarks.clone();
+                    new_config.list_sort = app_config.list_sort.clone();
+                    new_config.camera_3d = app_config.camera_3d.clone();
+                    new_config.fog = app_config.fog.clone();
+                    new_config.aircraft_notes = app_config.aircraft_notes.clone();
+                    new_config.aircraft_models = app_config.aircraft_models.clone();
                     new_config.appearance.theme = app_theme.name().to_string();
                     save_config(&new_config);
                     *app_config = new_config;
@@ -634,12 +1893,72 @@ pub fn render_settings_pane_content(
     });
 }
 
+/// Preset picker and band editor for the shared altitude color scheme.
+/// Editing any band switches the preset to Custom.
+fn render_altitude_color_settings(ui: &mut egui::Ui, scheme: &mut AltitudeColorScheme) {
+    ui.horizontal(|ui| {
+        ui.label("Preset:");
+        egui::ComboBox::from_id_salt("altitude_color_preset")
+            .selected_text(scheme.preset.label())
+            .show_ui(ui, |ui| {
+                for &preset in AltitudeColorPreset::ALL {
+                    if ui.selectable_label(scheme.preset == preset, preset.label()).clicked()
+                        && scheme.preset != preset
+                    {
+                        let tint_markers = scheme.tint_markers;
+                        *scheme = AltitudeColorScheme::from_preset(preset);
+                        scheme.tint_markers = tint_markers;
+                    }
+                }
+            });
+    });
+    ui.checkbox(&mut scheme.smooth, "Blend between bands");
+    ui.checkbox(&mut scheme.tint_markers, "Tint aircraft models");
+    ui.add_space(8.0);
+
+    let mut edited = false;
+    let mut remove: Option<usize> = None;
+    for (i, band) in scheme.bands.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            edited |= ui.color_edit_button_srgb(&mut band.color).changed();
+            edited |= ui
+                .add(egui::DragValue::new(&mut band.min_ft)
+                    .range(-2000..=60000)
+                    .speed(100)
+                    .prefix("\u{2265} ")
+                    .suffix(" ft"))
+                .changed();
+            if ui.small_button("\u{2212}").clicked() {
+                remove = Some(i);
+            }
+        });
+    }
+    if let Some(i) = remove {
+        scheme.bands.remove(i);
+        edited = true;
+    }
+    ui.horizontal(|ui| {
+        if ui.small_button("Add band").clicked() {
+            let next_ft = scheme.bands.last().map(|b| b.min_ft + 10_000).unwrap_or(0);
+            let color = scheme.bands.last().map(|b| b.color).unwrap_or([255, 255, 255]);
+            scheme.bands.push(AltitudeBand { min_ft: next_ft, color });
+            edited = true;
+        }
+        ui.color_edit_button_srgb(&mut scheme.unknown_color);
+        ui.label("No altitude");
+    });
+    if edited {
+        scheme.preset = AltitudeColorPreset::Custom;
+    }
+}
+
 pub fn toggle_settings_panel(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut ui_state: ResMut<SettingsUiState>,
     app_config: Res<AppConfig>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::Cancel, &keyboard) {
         ui_state.open = !ui_state.open;
         if ui_state.open {
             ui_state.populate_from_config(&app_config);
@@ -653,7 +1972,10 @@ pub fn sync_config_to_render_states(
     mut airport_state: Option<ResMut<crate::aviation::AirportRenderState>>,
     mut runway_state: Option<ResMut<crate::aviation::RunwayRenderState>>,
     mut navaid_state: Option<ResMut<crate::aviation::NavaidRenderState>>,
+    mut approach_detection: Option<ResMut<crate::aircraft::approach::ApproachDetection>>,
     mut trail_config: Option<ResMut<crate::aircraft::TrailConfig>>,
+    mut altitude_colors: Option<ResMut<AltitudeColorScheme>>,
+    mut view3d_state: Option<ResMut<crate::view3d::View3DState>>,
 ) {
     if !app_config.is_changed() {
         return;
@@ -668,28 +1990,121 @@ pub fn sync_config_to_render_states(
     if let Some(ref mut state) = navaid_state {
         state.show_navaids = app_config.overlays.show_navaids;
     }
+    if let Some(ref mut detection) = approach_detection {
+        detection.show_on_map = app_config.overlays.show_approaches;
+    }
     if let Some(ref mut config) = trail_config {
         config.enabled = app_config.trails.enabled;
         config.max_age_seconds = app_config.trails.max_age_seconds;
         config.renderer_2d = app_config.trails.renderer_2d;
         config.renderer_3d = app_config.trails.renderer_3d;
+        config.min_point_distance_m = app_config.trails.min_point_distance_m;
+        config.max_point_interval_secs = app_config.trails.max_point_interval_secs;
+        config.display_mode = app_config.trails.display_mode;
+        config.fade_mode = app_config.trails.fade_mode;
+        config.ghost_trails = app_config.trails.ghost_trails;
+        config.ghost_duration_secs = app_config.trails.ghost_duration_secs;
+        config.line_width = app_config.appearance.trail_width;
+    }
+    if let Some(ref mut scheme) = altitude_colors {
+        if **scheme != app_config.altitude_colors {
+            **scheme = app_config.altitude_colors.clone();
+        }
+    }
+    if let Some(ref mut state) = view3d_state {
+        let fog = &app_config.fog;
+        if state.fog_curve != fog.curve
+            || state.fog_start_fraction != fog.start_fraction
+            || state.fog_density_scale != fog.density_scale
+        {
+            state.fog_curve = fog.curve;
+            state.fog_start_fraction = fog.start_fraction;
+            state.fog_density_scale = fog.density_scale;
+        }
+    }
+}
+
+impl MapConfig {
+    fn default_max_horizon_sweeps() -> u8 {
+        4
+    }
+
+    fn default_tile_budget_3d() -> usize {
+        1000
+    }
+
+    fn default_tile_fade() -> bool {
+        true
+    }
+
+    fn default_tile_fade_speed() -> f32 {
+        crate::constants::TILE_FADE_SPEED
+    }
+
+    /// Format a position for the detail panel and cursor readouts in the
+    /// chosen readout mode; receiver-relative readouts measure from the
+    /// default (home) location.
+    pub fn format_position(&self, lat: f64, lon: f64) -> String {
+        let receiver = (self.default_latitude, self.default_longitude);
+        match self.position_readout {
+            PositionReadout::LatLon => format_latlon(lat, lon, self.coordinate_format),
+            PositionReadout::FromReceiver => format_from_receiver(lat, lon, receiver, self.north_reference),
+            PositionReadout::Both => format!(
+                "{} ({})",
+                format_latlon(lat, lon, self.coordinate_format),
+                format_from_receiver(lat, lon, receiver, self.north_reference),
+            ),
+        }
+    }
+
+    /// Resolve the active basemap into endpoint(s) and tile format. Falls
+    /// back to the default basemap if a custom server is misconfigured.
+    pub fn tile_source(&self) -> ResolvedTileSource {
+        let builtin = |style: BasemapStyle| ResolvedTileSource {
+            endpoints: vec![style.endpoint_url().to_string()],
+            tile_format: style.tile_format(),
+            reverse_axes: style.reverse_axes(),
+            native_tile_pixels: style.native_tile_pixels(),
+            query: None,
+        };
+        if self.basemap_style != BasemapStyle::Custom {
+            return builtin(self.basemap_style);
+        }
+        self.tile_server.resolve().unwrap_or_else(|e| {
+            warn!("Invalid custom tile server ({}), using default basemap", e);
+            builtin(BasemapStyle::default())
+        })
     }
 }
 
-/// Resource to track the last applied basemap style for change detection
+/// Resource to track the last applied basemap for change detection
 #[derive(Resource)]
 pub struct CurrentBasemapState {
     pub style: BasemapStyle,
+    pub source: ResolvedTileSource,
+    /// Index of the next endpoint (subdomain) to hand out
+    pub next_endpoint: usize,
+    /// Bumped whenever the tile source changes so tile systems can reset
+    pub revision: u32,
 }
 
-impl Default for CurrentBasemapState {
-    fn default() -> Self {
+impl CurrentBasemapState {
+    pub fn new(map: &MapConfig) -> Self {
         Self {
-            style: BasemapStyle::default(),
+            style: map.basemap_style,
+            source: map.tile_source(),
+            next_endpoint: 0,
+            revision: 0,
         }
     }
 }
 
+impl Default for CurrentBasemapState {
+    fn default() -> Self {
+        Self::new(&AppConfig::default().map)
+    }
+}
+
 /// System to detect basemap changes and update SlippyTilesSettings
 pub fn apply_basemap_changes(
     app_config: Res<AppConfig>,
@@ -701,24 +2116,49 @@ pub fn apply_basemap_changes(
     }
 
     let new_style = app_config.map.basemap_style;
-    if current_state.style != new_style {
+    let new_source = app_config.map.tile_source();
+    if current_state.style != new_style || current_state.source != new_source {
         info!("Basemap changed from {:?} to {:?}", current_state.style, new_style);
         current_state.style = new_style;
-        tile_settings.endpoint = new_style.endpoint_url().to_string();
-        tile_settings.tile_format = new_style.tile_format();
-        tile_settings.reverse_axes = new_style.reverse_axes();
+        current_state.next_endpoint = 0;
+        current_state.revision = current_state.revision.wrapping_add(1);
+        tile_settings.endpoint = new_source.endpoints[0].clone();
+        tile_settings.tile_format = new_source.tile_format;
+        tile_settings.reverse_axes = new_source.reverse_axes;
+        tile_settings.query = new_source.query.clone();
+        current_state.source = new_source;
         // Note: Tile cache clearing happens in main.rs when cache is cleared
     }
 }
 
+/// Rotate the tile endpoint across configured subdomains so downloads are
+/// spread over the provider's hosts. The tile downloader reads the endpoint
+/// when it processes requests, so rotating once per frame in which tiles
+/// are requested distributes batches round-robin.
+pub fn rotate_tile_endpoint(
+    mut current_state: ResMut<CurrentBasemapState>,
+    mut tile_settings: ResMut<SlippyTilesSettings>,
+    download_events: MessageReader<bevy_slippy_tiles::DownloadSlippyTilesMessage>,
+) {
+    if current_state.source.endpoints.len() < 2 || download_events.is_empty() {
+        return;
+    }
+    let state = &mut *current_state;
+    state.next_endpoint = (state.next_endpoint + 1) % state.source.endpoints.len();
+    // Rotation isn't a settings change; flagging one would make every
+    // origin-dependent system rebuild on each frame that requests tiles
+    let next = &state.source.endpoints[state.next_endpoint];
+    if tile_settings.endpoint != *next {
+        tile_settings.bypass_change_detection().endpoint = next.clone();
+    }
+}
+
 pub struct ConfigPlugin;
 
 impl Plugin for ConfigPlugin {
     fn build(&self, app: &mut App) {
         let config = load_config();
-        let initial_basemap = CurrentBasemapState {
-            style: config.map.basemap_style,
-        };
+        let initial_basemap = CurrentBasemapState::new(&config.map);
 
         let registry = ThemeRegistry::new();
         let initial_theme = registry
@@ -731,6 +2171,124 @@ impl Plugin for ConfigPlugin {
             .insert_resource(registry)
             .insert_resource(initial_theme)
             .init_resource::<SettingsUiState>()
-            .add_systems(Update, (toggle_settings_panel, sync_config_to_render_states, apply_basemap_changes));
+            .add_systems(Update, (
+                toggle_settings_panel,
+                sync_config_to_render_states,
+                apply_basemap_changes,
+                rotate_tile_endpoint.after(apply_basemap_changes),
+            ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(template: &str) -> TileServerConfig {
+        TileServerConfig {
+            url_template: template.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tile_template_expands_subdomains_and_api_key() {
+        let config = TileServerConfig {
+            subdomains: vec!["a".into(), "b".into(), "c".into()],
+            api_key: Some("secret".into()),
+            ..server("https://{s}.tiles.example.com/{apikey}/{z}/{x}/{y}.jpg")
+        };
+        let source = config.resolve().unwrap();
+        assert_eq!(source.endpoints, vec![
+            "https://a.tiles.example.com/secret".to_string(),
+            "https://b.tiles.example.com/secret".to_string(),
+            "https://c.tiles.example.com/secret".to_string(),
+        ]);
+        assert_eq!(source.tile_format, TileFormat::Jpg);
+        assert!(!source.reverse_axes);
+    }
+
+    #[test]
+    fn tile_template_detects_reversed_axes() {
+        let source = server("https://example.com/tile/{z}/{y}/{x}").resolve().unwrap();
+        assert_eq!(source.endpoints, vec!["https://example.com/tile".to_string()]);
+        assert!(source.reverse_axes);
+    }
+
+    #[test]
+    fn tile_template_keeps_query_string_with_api_key() {
+        let config = TileServerConfig {
+            api_key: Some("secret".into()),
+            ..server("https://tiles.example.com/{z}/{x}/{y}.png?apikey={apikey}&style=dark")
+        };
+        let source = config.resolve().unwrap();
+        assert_eq!(source.endpoints, vec!["https://tiles.example.com".to_string()]);
+        assert_eq!(source.tile_format, TileFormat::Png);
+        assert_eq!(source.query.as_deref(), Some("apikey=secret&style=dark"));
+
+        let plain = server("https://tiles.example.com/{z}/{x}/{y}.png?key=abc").resolve().unwrap();
+        assert_eq!(plain.query.as_deref(), Some("key=abc"));
+        assert!(server("https://tiles.example.com/{z}/{x}/{y}.png").resolve().unwrap().query.is_none());
+    }
+
+    #[test]
+    fn tile_template_rejects_malformed_urls() {
+        assert!(server("https://example.com/{x}/{y}/{z}.png").resolve().is_err());
+        assert!(server("example.com/{z}/{x}/{y}.png").resolve().is_err());
+        assert!(server("https://example.com/{z}/{x}/{y}.png?z={z}").resolve().is_err());
+        assert!(server("https://example.com/{z}/{x}/{y}.png?key={apikey}").resolve().is_err());
+        assert!(server("https://{s}.example.com/{z}/{x}/{y}.png").resolve().is_err());
+        assert!(server("https://example.com/{apikey}/{z}/{x}/{y}.png").resolve().is_err());
+    }
+
+    #[test]
+    fn auto_sky_keeps_dome_only_on_metal() {
+        assert!(SkyRendering::Auto.uses_sky_dome(true));
+        assert!(!SkyRendering::Auto.uses_sky_dome(false));
+        assert!(SkyRendering::GradientDome.uses_sky_dome(false));
+        assert!(!SkyRendering::ClearColor.uses_sky_dome(true));
+    }
+
+    #[test]
+    fn list_sort_remembers_direction_per_criterion() {
+        let mut sort = ListSortConfig::default();
+        assert!(sort.is_ascending(SortCriteria::Distance));
+        assert!(!sort.is_ascending(SortCriteria::Altitude));
+
+        sort.sort_by = SortCriteria::Altitude;
+        sort.ascending.insert(SortCriteria::Altitude, true);
+        let text = toml::to_string_pretty(&sort).unwrap();
+        let restored: ListSortConfig = toml::from_str(&text).unwrap();
+        assert_eq!(restored.sort_by, SortCriteria::Altitude);
+        assert!(restored.is_ascending(SortCriteria::Altitude));
+        assert!(!restored.is_ascending(SortCriteria::Speed));
+    }
+
+    #[test]
+    fn fog_config_fills_in_missing_fields() {
+        let fog: FogConfig = toml::from_str("curve = \"ExponentialSquared\"").unwrap();
+        assert_eq!(fog.curve, crate::view3d::FogCurve::ExponentialSquared);
+        assert_eq!(fog.start_fraction, crate::view3d::FOG_START_FRACTION);
+        assert_eq!(fog.density_scale, 1.0);
+    }
+
+    #[test]
+    fn label_anchor_offsets_toward_its_corner() {
+        assert_eq!(LabelAnchor::default().offset(25.0), Vec2::new(25.0, 25.0));
+        assert_eq!(LabelAnchor::BottomLeft.offset(10.0), Vec2::new(-10.0, -10.0));
+        assert_eq!(LabelAnchor::TopLeft.offset(0.0), Vec2::ZERO);
+        assert_eq!(LabelAnchor::TopLeft.text_anchor(), bevy::sprite::Anchor::BOTTOM_RIGHT);
+        let appearance: AppearanceConfig = toml::from_str("theme = \"dark\"").unwrap();
+        assert_eq!(appearance.label_offset, crate::constants::LABEL_SCREEN_OFFSET);
+    }
+
+    #[test]
+    fn vfr_squawk_matches_configured_code() {
+        let mut appearance = AppearanceConfig::default();
+        assert!(appearance.is_vfr_squawk(Some("1200")));
+        assert!(!appearance.is_vfr_squawk(Some("7000")));
+        assert!(!appearance.is_vfr_squawk(None));
+        appearance.vfr_squawk = "7000".to_string();
+        assert!(appearance.is_vfr_squawk(Some("7000 ")));
     }
 }
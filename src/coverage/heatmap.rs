@@ -0,0 +1,298 @@
+//! Traffic density heatmap.
+//!
+//! Accumulates aircraft position samples into a lat/lon grid around the
+//! receiver and shows it as a translucent blue-to-red texture on the map.
+//! Where coverage tracks the furthest range per sector, the heatmap shows
+//! where traffic actually flows.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::visibility::RenderLayers;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_slippy_tiles::SlippyTilesSettings;
+use std::time::{Duration, Instant};
+
+use crate::geo::{world_width_pixels, wrap_longitude, CoordinateConverter};
+use crate::render_layers::RenderCategory;
+use crate::MapState;
+use super::CoverageState;
+
+/// Grid cells per side
+const GRID_CELLS: usize = 160;
+/// Cell width in degrees; the grid spans 8 degrees of longitude and
+/// latitude around the receiver
+const CELL_DEGREES: f64 = 0.05;
+/// Seconds between position samples, so parked or slow traffic doesn't
+/// swamp the map
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+/// Above the tint overlay and tiles, below aircraft
+const HEATMAP_Z: f32 = 5.5;
+/// Opacity of the densest cells
+const MAX_ALPHA: f32 = 0.6;
+
+/// Marker for the heatmap sprite
+#[derive(Component)]
+pub struct HeatmapOverlay;
+
+/// Position sample counts on a grid centered on the receiver. Columns are
+/// evenly spaced in longitude and rows evenly spaced in Mercator y, so the
+/// texture stretches linearly onto the map without misregistering rows.
+#[derive(Resource)]
+pub struct DensityHeatmap {
+    /// Whether samples are collected and the overlay is drawn
+    pub enabled: bool,
+    /// South-west corner of the grid (latitude, longitude)
+    origin: (f64, f64),
+    counts: Vec<u32>,
+    max_count: u32,
+    pub total_samples: u64,
+    last_sample: Option<Instant>,
+    /// Texture needs regenerating
+    dirty: bool,
+}
+
+impl Default for DensityHeatmap {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            origin: (0.0, 0.0),
+            counts: vec![0; GRID_CELLS * GRID_CELLS],
+            max_count: 0,
+            total_samples: 0,
+            last_sample: None,
+            dirty: true,
+        }
+    }
+}
+
+impl DensityHeatmap {
+    /// Clear all samples and recenter the grid on `center`.
+    pub fn reset(&mut self, center: (f64, f64)) {
+        let half_span = GRID_CELLS as f64 * CELL_DEGREES / 2.0;
+        self.origin = (center.0 - half_span, center.1 - half_span);
+        self.counts.fill(0);
+        self.max_count = 0;
+        self.total_samples = 0;
+        self.dirty = true;
+    }
+
+    /// Grid cell (column, row) containing a position, if inside the grid
+    fn cell(&self, latitude: f64, longitude: f64) -> Option<(usize, usize)> {
+        let (south, _, north, _) = self.bounds();
+        if !(south..north).contains(&latitude) {
+            return None;
+        }
+        let col = (wrap_longitude(longitude - self.origin.1) / CELL_DEGREES).floor();
+        let (y_south, y_north) = (mercator_y(south), mercator_y(north));
+        let row = ((mercator_y(latitude) - y_south) / (y_north - y_south) * GRID_CELLS as f64).floor();
+        let range = 0.0..GRID_CELLS as f64;
+        (range.contains(&col) && range.contains(&row)).then_some((col as usize, row as usize))
+    }
+
+    /// Add one position sample. Returns false when it falls outside the grid.
+    pub fn observe(&mut self, latitude: f64, longitude: f64) -> bool {
+        let Some((col, row)) = self.cell(latitude, longitude) else {
+            return false;
+        };
+        let count = &mut self.counts[row * GRID_CELLS + col];
+        *count += 1;
+        self.max_count = self.max_count.max(*count);
+        self.total_samples += 1;
+        self.dirty = true;
+        true
+    }
+
+    pub fn count_at(&self, latitude: f64, longitude: f64) -> u32 {
+        self.cell(latitude, longitude)
+            .map_or(0, |(col, row)| self.counts[row * GRID_CELLS + col])
+    }
+
+    /// Geographic bounds as (south, west, north, east)
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let span = GRID_CELLS as f64 * CELL_DEGREES;
+        (self.origin.0, self.origin.1, self.origin.0 + span, self.origin.1 + span)
+    }
+
+    /// RGBA pixels, top row first. Density is log-scaled so a few busy
+    /// airways don't wash out everything else.
+    fn pixels(&self) -> Vec<u8> {
+        let mut data = vec![0u8; GRID_CELLS * GRID_CELLS * 4];
+        let log_max = (self.max_count as f32).ln_1p();
+        if log_max <= 0.0 {
+            return data;
+        }
+        for row in 0..GRID_CELLS {
+            // Image rows run north to south, grid rows south to north
+            let image_row = GRID_CELLS - 1 - row;
+            for col in 0..GRID_CELLS {
+                let count = self.counts[row * GRID_CELLS + col];
+                if count == 0 {
+                    continue;
+                }
+                let t = (count as f32).ln_1p() / log_max;
+                let color = density_color(t);
+                let idx = (image_row * GRID_CELLS + col) * 4;
+                data[idx..idx + 4].copy_from_slice(&color);
+            }
+        }
+        data
+    }
+}
+
+/// Web Mercator y (unscaled) of a latitude
+fn mercator_y(latitude: f64) -> f64 {
+    let lat = latitude.clamp(-85.0511, 85.0511).to_radians();
+    (std::f64::consts::FRAC_PI_4 + lat / 2.0).tan().ln()
+}
+
+/// Blue (sparse) through green and yellow to red (dense), fading in with
+/// density. `t` is 0..1.
+fn density_color(t: f32) -> [u8; 4] {
+    let hue = 240.0 * (1.0 - t.clamp(0.0, 1.0));
+    let rgb = Color::hsl(hue, 1.0, 0.5).to_srgba();
+    let alpha = MAX_ALPHA * (0.35 + 0.65 * t);
+    [
+        (rgb.red * 255.0) as u8,
+        (rgb.green * 255.0) as u8,
+        (rgb.blue * 255.0) as u8,
+        (alpha * 255.0) as u8,
+    ]
+}
+
+fn heatmap_image(data: Vec<u8>) -> Image {
+    let mut image = Image::new(
+        Extent3d {
+            width: GRID_CELLS as u32,
+            height: GRID_CELLS as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+/// Spawn the (hidden) heatmap sprite with an empty texture.
+pub fn setup_heatmap_overlay(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let image = images.add(heatmap_image(vec![0; GRID_CELLS * GRID_CELLS * 4]));
+    commands.spawn((
+        Name::new("Density Heatmap"),
+        HeatmapOverlay,
+        Sprite {
+            image,
+            custom_size: Some(Vec2::ONE),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, HEATMAP_Z),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        RenderLayers::layer(RenderCategory::OVERLAYS_2D),
+    ));
+}
+
+/// System that samples aircraft positions into the heatmap every few seconds.
+pub fn update_heatmap_from_aircraft(
+    mut heatmap: ResMut<DensityHeatmap>,
+    coverage: Res<CoverageState>,
+    aircraft_query: Query<&crate::Aircraft>,
+) {
+    if !heatmap.enabled {
+        return;
+    }
+    let now = Instant::now();
+    if heatmap.last_sample.is_some_and(|t| now.duration_since(t) < SAMPLE_INTERVAL) {
+        return;
+    }
+    if heatmap.last_sample.is_none() && heatmap.total_samples == 0 {
+        heatmap.reset(coverage.receiver_location);
+    }
+    heatmap.last_sample = Some(now);
+
+    for aircraft in aircraft_query.iter() {
+        heatmap.observe(aircraft.latitude, aircraft.longitude);
+    }
+}
+
+/// System that regenerates the heatmap texture when samples change and
+/// keeps the sprite stretched over the grid's bounds at the current zoom.
+pub fn update_heatmap_overlay(
+    mut heatmap: ResMut<DensityHeatmap>,
+    tile_settings: Res<SlippyTilesSettings>,
+    map_state: Res<MapState>,
+    mut images: ResMut<Assets<Image>>,
+    mut overlay_query: Query<(&Sprite, &mut Transform, &mut Visibility), With<HeatmapOverlay>>,
+) {
+    let Ok((sprite, mut transform, mut visibility)) = overlay_query.single_mut() else {
+        return;
+    };
+    let show = heatmap.enabled && heatmap.total_samples > 0;
+    visibility.set_if_neq(if show { Visibility::Inherited } else { Visibility::Hidden });
+    if !show {
+        return;
+    }
+
+    if heatmap.dirty {
+        if let Some(image) = images.get_mut(&sprite.image) {
+            image.data = Some(heatmap.pixels());
+        }
+        heatmap.dirty = false;
+    }
+
+    let (south, west, north, east) = heatmap.bounds();
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let south_west = converter.latlon_to_world(south, west);
+    let north_west = converter.latlon_to_world(north, west);
+    // Mercator x is linear in longitude; sizing from the span rather than
+    // the east corner keeps the width right across the antimeridian
+    let world_width = world_width_pixels(crate::constants::DEFAULT_TILE_SIZE, map_state.zoom_level);
+    let width = (world_width * (east - west) / 360.0) as f32;
+    let height = north_west.y - south_west.y;
+    transform.translation.x = south_west.x + width / 2.0;
+    transform.translation.y = south_west.y + height / 2.0;
+    // A signed height keeps the image's top row on the north edge
+    transform.scale = Vec3::new(width, height, 1.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observe_bins_positions_around_the_center() {
+        let mut heatmap = DensityHeatmap::default();
+        heatmap.reset((47.0, -122.0));
+        assert!(heatmap.observe(47.01, -122.01));
+        assert!(heatmap.observe(47.02, -122.02));
+        assert!(heatmap.observe(48.0, -121.0));
+        assert!(!heatmap.observe(60.0, -122.0));
+        assert_eq!(heatmap.count_at(47.01, -122.01), 2);
+        assert_eq!(heatmap.count_at(48.0, -121.0), 1);
+        assert_eq!(heatmap.max_count, 2);
+        assert_eq!(heatmap.total_samples, 3);
+
+        heatmap.reset((0.0, 179.0));
+        assert!(heatmap.observe(0.0, -179.0));
+        assert_eq!(heatmap.count_at(0.0, -179.0), 1);
+    }
+
+    #[test]
+    fn rows_are_evenly_spaced_in_mercator_y() {
+        let mut heatmap = DensityHeatmap::default();
+        heatmap.reset((47.0, -122.0));
+        let (south, west, north, _) = heatmap.bounds();
+        let mid_y = (mercator_y(south) + mercator_y(north)) / 2.0;
+        let mid_lat = (2.0 * mid_y.exp().atan() - std::f64::consts::FRAC_PI_2).to_degrees();
+
+        let half = GRID_CELLS / 2;
+        assert_eq!(heatmap.cell(mid_lat + 1e-6, west), Some((0, half)));
+        assert_eq!(heatmap.cell(mid_lat - 1e-6, west), Some((0, half - 1)));
+        // The latitude midpoint sits south of the Mercator midpoint
+        assert!(heatmap.cell(47.0, west).unwrap().1 < half);
+        assert_eq!(heatmap.cell(south, west), Some((0, 0)));
+        assert_eq!(heatmap.cell(north - 1e-6, west), Some((0, GRID_CELLS - 1)));
+    }
+}
@@ -4,16 +4,31 @@
 //! Uses a sector-based approach: divides the area around the receiver into
 //! 36 sectors (10 degrees each) and tracks the maximum range observed in each.
 
+pub mod heatmap;
+
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
+use bevy_slippy_tiles::SlippyTilesSettings;
 use std::collections::HashMap;
 
-use crate::geo::{haversine_distance_nm, initial_bearing};
+use crate::aircraft::WakeCategory;
+use crate::geo::{destination_point, haversine_distance_nm, initial_bearing, CoordinateConverter, NorthReference};
+use crate::MapState;
 
 /// Number of sectors to divide the coverage area into
 const NUM_SECTORS: usize = 36;
 /// Degrees per sector
 const DEGREES_PER_SECTOR: f64 = 360.0 / NUM_SECTORS as f64;
+/// Number of weakest/strongest sectors listed in the analysis
+const RANKED_SECTOR_COUNT: usize = 3;
+/// Sectors below this fraction of the median max range count as weak
+const GAP_RANGE_FRACTION: f64 = 0.5;
+/// Minimum run of consecutive weak sectors reported as a gap
+const MIN_GAP_SECTORS: usize = 2;
+/// Minimum sectors with data before the analysis is meaningful
+const MIN_ANALYSIS_SECTORS: usize = 6;
+/// Radio horizon coefficient in NM per square root of feet (4/3 earth radius)
+const RADIO_HORIZON_NM_PER_SQRT_FT: f64 = 1.23;
 
 /// A single coverage sector tracking max range
 #[derive(Clone, Debug, Default)]
@@ -53,6 +68,8 @@ impl CoverageSector {
 pub struct CoverageState {
     /// Whether coverage visualization is enabled
     pub enabled: bool,
+    /// Restricts which aircraft count toward coverage
+    pub filter: CoverageFilter,
     /// Receiver location (latitude, longitude)
     pub receiver_location: (f64, f64),
     /// Coverage sectors indexed by sector number (0-35)
@@ -65,19 +82,63 @@ pub struct CoverageState {
     pub show_stats: bool,
     /// Overall maximum range observed
     pub overall_max_range_nm: f64,
+    /// Receiver antenna height above ground in feet
+    pub antenna_height_ft: f64,
+    /// Target altitude used for the theoretical radio horizon, in feet
+    pub horizon_target_altitude_ft: f64,
+    /// Whether to draw the theoretical radio horizon against the polygon
+    pub show_horizon: bool,
 }
 
 impl Default for CoverageState {
     fn default() -> Self {
         Self {
             enabled: false,
+            filter: CoverageFilter::default(),
             receiver_location: (37.6872, -97.3301), // Default: Wichita, KS
             sectors: std::array::from_fn(|_| CoverageSector::default()),
             observed_aircraft: HashMap::new(),
             show_polygon: true,
             show_stats: false,
             overall_max_range_nm: 0.0,
+            antenna_height_ft: 30.0,
+            horizon_target_altitude_ft: 35_000.0,
+            show_horizon: true,
+        }
+    }
+}
+
+/// Line-of-sight radio horizon in NM between an antenna and a target at the
+/// given heights above ground, using the standard 4/3 earth-radius model.
+pub fn radio_horizon_nm(antenna_height_ft: f64, target_altitude_ft: f64) -> f64 {
+    RADIO_HORIZON_NM_PER_SQRT_FT * (antenna_height_ft.max(0.0).sqrt() + target_altitude_ft.max(0.0).sqrt())
+}
+
+/// Optional filter on the aircraft feeding the coverage map, e.g. only
+/// airborne jets above 10,000 ft to reflect line-of-sight reception limits.
+#[derive(Clone, Debug, Default)]
+pub struct CoverageFilter {
+    /// Ignore aircraft below this altitude in feet (0 = no minimum)
+    pub min_altitude_ft: i32,
+    /// Wake categories to count; empty counts every category
+    pub categories: Vec<WakeCategory>,
+}
+
+impl CoverageFilter {
+    pub fn is_active(&self) -> bool {
+        self.min_altitude_ft > 0 || !self.categories.is_empty()
+    }
+
+    /// Whether an aircraft with this altitude and category counts.
+    /// Unknown values are rejected while the corresponding filter is set.
+    pub fn accepts(&self, altitude: Option<i32>, category: Option<WakeCategory>) -> bool {
+        if self.min_altitude_ft > 0 && altitude.is_none_or(|alt| alt < self.min_altitude_ft) {
+            return false;
+        }
+        if !self.categories.is_empty() && category.is_none_or(|c| !self.categories.contains(&c)) {
+            return false;
         }
+        true
     }
 }
 
@@ -116,14 +177,16 @@ impl CoverageState {
         )
     }
 
-    /// Record an aircraft observation
+    /// Record an aircraft observation, skipping aircraft rejected by `filter`
     pub fn observe_aircraft(
         &mut self,
         icao: &str,
         latitude: f64,
         longitude: f64,
+        altitude: Option<i32>,
+        category: Option<WakeCategory>,
     ) {
-        if !self.enabled {
+        if !self.enabled || !self.filter.accepts(altitude, category) {
             return;
         }
 
@@ -182,6 +245,31 @@ impl CoverageState {
         points
     }
 
+    /// Theoretical radio horizon for the configured antenna and target heights
+    pub fn horizon_nm(&self) -> f64 {
+        radio_horizon_nm(self.antenna_height_ft, self.horizon_target_altitude_ft)
+    }
+
+    /// Horizon ring points at each sector's center bearing, matching the
+    /// vertices of `get_polygon_points` for a sector-by-sector comparison
+    pub fn get_horizon_points(&self) -> Vec<(f64, f64)> {
+        let (lat, lon) = self.receiver_location;
+        let horizon = self.horizon_nm();
+        (0..NUM_SECTORS)
+            .map(|i| {
+                let bearing = i as f64 * DEGREES_PER_SECTOR + DEGREES_PER_SECTOR / 2.0;
+                destination_point(lat, lon, bearing, horizon)
+            })
+            .collect()
+    }
+
+    /// Number of sectors with data whose max range reaches at least half the
+    /// radio horizon. Sectors well short of it point at terrain blockage.
+    pub fn sectors_near_horizon(&self) -> usize {
+        let threshold = self.horizon_nm() * GAP_RANGE_FRACTION;
+        self.sectors.iter().filter(|s| s.max_range_nm >= threshold).count()
+    }
+
     /// Get coverage statistics
     pub fn get_stats(&self) -> CoverageStats {
         let active_sectors = self.sectors.iter().filter(|s| s.max_range_nm > 0.0).count();
@@ -203,6 +291,150 @@ impl CoverageState {
     }
 }
 
+/// A sector's bearing range and reach, for ranked listings
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectorSummary {
+    /// Sector start bearing in degrees
+    pub start_bearing: f64,
+    pub max_range_nm: f64,
+}
+
+/// A contiguous run of weak sectors, likely shadowed by terrain or buildings
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageGap {
+    /// Bearing where the gap starts, in degrees
+    pub start_bearing: f64,
+    /// Bearing where the gap ends, in degrees (may be less than start when it wraps north)
+    pub end_bearing: f64,
+    /// Number of sectors in the run
+    pub sector_count: usize,
+    /// Best max range seen inside the gap
+    pub max_range_nm: f64,
+}
+
+/// Where coverage is weakest and strongest, derived from sector max ranges
+#[derive(Debug, Clone, Default)]
+pub struct CoverageAnalysis {
+    pub weakest: Vec<SectorSummary>,
+    pub strongest: Vec<SectorSummary>,
+    pub gaps: Vec<CoverageGap>,
+    /// Ranges below this count as weak
+    pub gap_threshold_nm: f64,
+}
+
+impl CoverageState {
+    /// Rank sectors by max range and find contiguous weak runs.
+    /// Returns `None` until enough sectors have data to be meaningful.
+    pub fn analyze(&self) -> Option<CoverageAnalysis> {
+        let ranges: Vec<f64> = self.sectors.iter().map(|s| s.max_range_nm).collect();
+        analyze_sector_ranges(&ranges)
+    }
+}
+
+fn analyze_sector_ranges(ranges: &[f64]) -> Option<CoverageAnalysis> {
+    let mut active: Vec<f64> = ranges.iter().copied().filter(|r| *r > 0.0).collect();
+    if active.len() < MIN_ANALYSIS_SECTORS {
+        return None;
+    }
+    active.sort_by(|a, b| a.total_cmp(b));
+    let median = active[active.len() / 2];
+    let gap_threshold_nm = median * GAP_RANGE_FRACTION;
+
+    let summary = |i: usize| SectorSummary {
+        start_bearing: i as f64 * DEGREES_PER_SECTOR,
+        max_range_nm: ranges[i],
+    };
+    // Sectors with no data are unknown rather than weak, so rank active ones only
+    let mut ranked: Vec<usize> = (0..ranges.len()).filter(|&i| ranges[i] > 0.0).collect();
+    ranked.sort_by(|&a, &b| ranges[a].total_cmp(&ranges[b]));
+    let weakest = ranked.iter().take(RANKED_SECTOR_COUNT).map(|&i| summary(i)).collect();
+    let strongest = ranked.iter().rev().take(RANKED_SECTOR_COUNT).map(|&i| summary(i)).collect();
+
+    // Walk the ring starting just after a strong sector so runs that wrap
+    // through north are found in one piece.
+    let n = ranges.len();
+    let is_weak = |i: usize| ranges[i % n] > 0.0 && ranges[i % n] < gap_threshold_nm;
+    let start = (0..n).find(|&i| !is_weak(i)).unwrap_or(0) + 1;
+    let mut gaps = Vec::new();
+    let mut run_start: Option<usize> = None;
+    for offset in 0..=n {
+        let i = start + offset;
+        if offset < n && is_weak(i) {
+            if run_start.is_none() {
+                run_start = Some(i);
+            }
+        } else if let Some(first) = run_start.take() {
+            let count = i - first;
+            if count >= MIN_GAP_SECTORS {
+                gaps.push(CoverageGap {
+                    start_bearing: (first % n) as f64 * DEGREES_PER_SECTOR,
+                    end_bearing: (i % n) as f64 * DEGREES_PER_SECTOR,
+                    sector_count: count,
+                    max_range_nm: (first..i).map(|j| ranges[j % n]).fold(0.0, f64::max),
+                });
+            }
+        }
+    }
+    gaps.sort_by(|a, b| b.sector_count.cmp(&a.sector_count));
+
+    Some(CoverageAnalysis { weakest, strongest, gaps, gap_threshold_nm })
+}
+
+/// Render weakest/strongest sectors and detected gaps.
+pub fn render_sector_analysis(ui: &mut egui::Ui, coverage: &CoverageState, north: NorthReference) {
+    let Some(analysis) = coverage.analyze() else {
+        ui.label(egui::RichText::new("Collecting data for sector analysis...")
+            .size(11.0)
+            .color(egui::Color32::GRAY));
+        return;
+    };
+
+    // Sector bounds are true bearings; convert at the receiver for display
+    let (rx_lat, rx_lon) = coverage.receiver_location;
+    let bearing_range = |start: f64, end: f64| format!(
+        "{:03.0}°-{:03.0}°{}",
+        north.bearing(start, rx_lat, rx_lon),
+        north.bearing(end, rx_lat, rx_lon),
+        north.suffix(),
+    );
+    let sector_label = |s: &SectorSummary| format!(
+        "{}  {:.1} NM",
+        bearing_range(s.start_bearing, s.start_bearing + DEGREES_PER_SECTOR),
+        s.max_range_nm,
+    );
+
+    ui.label(egui::RichText::new("Weakest Sectors").strong());
+    for sector in &analysis.weakest {
+        ui.label(egui::RichText::new(sector_label(sector)).monospace());
+    }
+    ui.add_space(4.0);
+    ui.label(egui::RichText::new("Strongest Sectors").strong());
+    for sector in &analysis.strongest {
+        ui.label(egui::RichText::new(sector_label(sector)).monospace());
+    }
+    ui.add_space(4.0);
+
+    ui.label(egui::RichText::new("Coverage Gaps").strong());
+    if analysis.gaps.is_empty() {
+        ui.label(egui::RichText::new("No contiguous gaps detected").color(egui::Color32::GRAY));
+    }
+    for gap in &analysis.gaps {
+        ui.colored_label(
+            egui::Color32::from_rgb(255, 180, 60),
+            egui::RichText::new(format!(
+                "{}  ({} sectors, best {:.1} NM)",
+                bearing_range(gap.start_bearing, gap.end_bearing), gap.sector_count, gap.max_range_nm,
+            )).monospace(),
+        );
+    }
+    ui.label(egui::RichText::new(format!(
+        "Gap = runs below {:.1} NM (half the median range)",
+        analysis.gap_threshold_nm,
+    ))
+    .size(10.0)
+    .color(egui::Color32::GRAY));
+}
+
 /// Summary statistics for coverage
 #[derive(Debug, Clone)]
 pub struct CoverageStats {
@@ -221,6 +453,7 @@ pub struct CoveragePolygon;
 /// System to toggle coverage mode with keyboard
 pub fn toggle_coverage_mode(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut coverage: ResMut<CoverageState>,
     mut contexts: EguiContexts,
 ) {
@@ -231,7 +464,7 @@ pub fn toggle_coverage_mode(
     }
 
     // V - Toggle coverage visualization
-    if keyboard.just_pressed(KeyCode::KeyV) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleCoverage, &keyboard) {
         coverage.enabled = !coverage.enabled;
         if coverage.enabled {
             info!("Coverage tracking enabled");
@@ -241,24 +474,62 @@ pub fn toggle_coverage_mode(
     }
 
     // Shift+V - Toggle coverage stats panel
-    if keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight) {
-        if keyboard.just_pressed(KeyCode::KeyV) {
-            coverage.show_stats = !coverage.show_stats;
-        }
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleCoverageStats, &keyboard) {
+        coverage.show_stats = !coverage.show_stats;
     }
 }
 
 /// System to update coverage from aircraft positions
 pub fn update_coverage_from_aircraft(
     mut coverage: ResMut<CoverageState>,
-    aircraft_query: Query<&crate::Aircraft>,
+    aircraft_query: Query<(&crate::Aircraft, Option<&WakeCategory>)>,
+) {
+    if !coverage.enabled {
+        return;
+    }
+
+    for (aircraft, category) in aircraft_query.iter() {
+        coverage.observe_aircraft(
+            &aircraft.icao,
+            aircraft.latitude,
+            aircraft.longitude,
+            aircraft.altitude,
+            category.copied(),
+        );
+    }
+}
+
+/// System to draw the observed coverage polygon and the theoretical radio
+/// horizon ring around the receiver.
+pub fn draw_coverage_overlay(
+    mut gizmos: Gizmos,
+    coverage: Res<CoverageState>,
+    tile_settings: Res<SlippyTilesSettings>,
+    map_state: Res<MapState>,
 ) {
     if !coverage.enabled {
         return;
     }
 
-    for aircraft in aircraft_query.iter() {
-        coverage.observe_aircraft(&aircraft.icao, aircraft.latitude, aircraft.longitude);
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let to_world = |points: Vec<(f64, f64)>| -> Vec<Vec2> {
+        points.iter().map(|&(lat, lon)| converter.latlon_to_world(lat, lon)).collect()
+    };
+
+    if coverage.show_polygon && coverage.overall_max_range_nm > 0.0 {
+        let polygon = to_world(coverage.get_polygon_points());
+        gizmos.linestrip_2d(
+            polygon.iter().chain(polygon.first()).copied(),
+            Color::srgba(0.3, 0.9, 0.4, 0.8),
+        );
+    }
+
+    if coverage.show_horizon {
+        let horizon = to_world(coverage.get_horizon_points());
+        gizmos.linestrip_2d(
+            horizon.iter().chain(horizon.first()).copied(),
+            Color::srgba(0.6, 0.7, 1.0, 0.5),
+        );
     }
 }
 
@@ -266,6 +537,7 @@ pub fn update_coverage_from_aircraft(
 pub fn render_coverage_stats_panel(
     mut contexts: EguiContexts,
     mut coverage: ResMut<CoverageState>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
     let Ok(ctx) = contexts.ctx_mut() else {
         return;
@@ -320,6 +592,10 @@ pub fn render_coverage_stats_panel(
 
             ui.separator();
 
+            render_sector_analysis(ui, &coverage, app_config.map.north_reference);
+
+            ui.separator();
+
             ui.horizontal(|ui| {
                 let enable_text = if coverage.enabled { "Disable" } else { "Enable" };
                 if ui.button(enable_text).clicked() {
@@ -351,10 +627,100 @@ pub struct CoveragePlugin;
 impl Plugin for CoveragePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CoverageState>()
+            .init_resource::<heatmap::DensityHeatmap>()
+            .add_systems(Startup, heatmap::setup_heatmap_overlay)
             .add_systems(Update, (
                 toggle_coverage_mode,
                 update_coverage_from_aircraft,
+                draw_coverage_overlay,
+                heatmap::update_heatmap_from_aircraft,
+                heatmap::update_heatmap_overlay
+                    .after(heatmap::update_heatmap_from_aircraft)
+                    .after(crate::ZoomSet::Change),
             ));
         // Coverage stats panel is rendered via the consolidated Tools window (tools_window.rs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_few_sectors_skip_analysis() {
+        let mut ranges = [0.0; NUM_SECTORS];
+        ranges[0] = 100.0;
+        assert!(analyze_sector_ranges(&ranges).is_none());
+    }
+
+    #[test]
+    fn ranks_sectors_and_finds_gap_wrapping_north() {
+        let mut ranges = [150.0; NUM_SECTORS];
+        ranges[10] = 200.0;
+        // Blocked 340°-020°: sectors 34, 35, 0, 1
+        for i in [34, 35, 0, 1] {
+            ranges[i] = 20.0;
+        }
+        ranges[1] = 30.0;
+        // Single weak sector is below the run length and not reported
+        ranges[20] = 10.0;
+
+        let analysis = analyze_sector_ranges(&ranges).unwrap();
+        assert_eq!(analysis.weakest[0].start_bearing, 200.0);
+        assert_eq!(analysis.strongest[0].start_bearing, 100.0);
+        assert_eq!(analysis.gaps.len(), 1);
+        let gap = &analysis.gaps[0];
+        assert_eq!(gap.sector_count, 4);
+        assert_eq!(gap.start_bearing, 340.0);
+        assert_eq!(gap.end_bearing, 20.0);
+        assert_eq!(gap.max_range_nm, 30.0);
+    }
+
+    #[test]
+    fn sectors_without_data_are_not_gaps() {
+        let mut ranges = [150.0; NUM_SECTORS];
+        ranges[10..16].fill(0.0);
+
+        let analysis = analyze_sector_ranges(&ranges).unwrap();
+        assert!(analysis.gaps.is_empty());
+    }
+
+    #[test]
+    fn filter_keeps_only_high_jets() {
+        let mut coverage = CoverageState {
+            enabled: true,
+            filter: CoverageFilter {
+                min_altitude_ft: 10_000,
+                categories: vec![WakeCategory::Medium, WakeCategory::Heavy],
+            },
+            ..Default::default()
+        };
+        coverage.observe_aircraft("AAAAAA", 38.0, -97.3301, Some(35_000), Some(WakeCategory::Heavy));
+        coverage.observe_aircraft("BBBBBB", 38.0, -97.3301, Some(4_500), Some(WakeCategory::Medium));
+        coverage.observe_aircraft("CCCCCC", 38.0, -97.3301, Some(12_000), Some(WakeCategory::Light));
+        coverage.observe_aircraft("DDDDDD", 38.0, -97.3301, Some(30_000), None);
+        coverage.observe_aircraft("EEEEEE", 38.0, -97.3301, None, Some(WakeCategory::Heavy));
+
+        assert_eq!(coverage.get_stats().unique_aircraft, 1);
+    }
+
+    #[test]
+    fn radio_horizon_matches_rule_of_thumb() {
+        // 100 ft antenna, 10,000 ft target: 1.23 * (10 + 100) = 135.3 NM
+        assert!((radio_horizon_nm(100.0, 10_000.0) - 135.3).abs() < 1e-9);
+        assert_eq!(radio_horizon_nm(-5.0, 0.0), 0.0);
+
+        let mut coverage = CoverageState {
+            antenna_height_ft: 100.0,
+            horizon_target_altitude_ft: 10_000.0,
+            ..Default::default()
+        };
+        coverage.sectors[0].observe(120.0);
+        coverage.sectors[1].observe(40.0);
+        assert_eq!(coverage.sectors_near_horizon(), 1);
+
+        let (lat, lon) = coverage.get_horizon_points()[9];
+        let (rx_lat, rx_lon) = coverage.receiver_location;
+        assert!((haversine_distance_nm(rx_lat, rx_lon, lat, lon) - 135.3).abs() < 0.5);
+    }
+}
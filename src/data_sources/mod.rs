@@ -8,6 +8,24 @@ use bevy_egui::{egui, EguiContexts};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::adsb::normalize_icao;
+use crate::geo::haversine_distance_nm;
+
+/// Distance (NM) between two sources' positions for the same aircraft above
+/// which the aircraft is flagged as having a position disagreement.
+pub const POSITION_DISAGREEMENT_NM: f64 = 2.0;
+
+/// Trail colors for sources in configuration order, chosen to stay distinct
+/// from each other and from the altitude gradient's greens and blues.
+const SOURCE_COLORS: [Color; 6] = [
+    Color::srgb(1.0, 0.55, 0.1),
+    Color::srgb(0.85, 0.3, 1.0),
+    Color::srgb(0.2, 0.95, 0.95),
+    Color::srgb(1.0, 0.95, 0.3),
+    Color::srgb(1.0, 0.35, 0.45),
+    Color::srgb(0.6, 1.0, 0.4),
+];
+
 /// Configuration for a single data source
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataSourceConfig {
@@ -21,6 +39,16 @@ pub struct DataSourceConfig {
     pub priority: u8,
     /// Optional: receiver location for this feed
     pub receiver_location: Option<(f64, f64)>,
+    /// Seconds without an update before this source's data for an aircraft
+    /// is dropped from the merge
+    #[serde(default = "DataSourceConfig::default_stale_timeout_secs")]
+    pub stale_timeout_secs: u64,
+}
+
+impl DataSourceConfig {
+    fn default_stale_timeout_secs() -> u64 {
+        60
+    }
 }
 
 impl Default for DataSourceConfig {
@@ -31,6 +59,7 @@ impl Default for DataSourceConfig {
             enabled: true,
             priority: 100,
             receiver_location: None,
+            stale_timeout_secs: Self::default_stale_timeout_secs(),
         }
     }
 }
@@ -97,6 +126,8 @@ pub struct DataSourceManager {
     pub aircraft: HashMap<String, MergedAircraftData>,
     /// Whether to show source indicator on aircraft
     pub show_source_indicator: bool,
+    /// Color trail segments by the source that reported them (multi-source only)
+    pub color_trails_by_source: bool,
     /// Whether to show data source panel
     pub show_panel: bool,
 }
@@ -108,6 +139,7 @@ impl Default for DataSourceManager {
             states: HashMap::new(),
             aircraft: HashMap::new(),
             show_source_indicator: false,
+            color_trails_by_source: false,
             show_panel: false,
         }
     }
@@ -131,6 +163,84 @@ pub struct MergedAircraftData {
     pub sources: Vec<String>,
     /// Primary source name (highest priority with data)
     pub primary_source: String,
+    /// Latest report from each contributing source (source name -> data)
+    pub contributions: HashMap<String, SourcedAircraftData>,
+    /// Set when a source reports a position more than
+    /// `POSITION_DISAGREEMENT_NM` from the merged position of another source.
+    /// Holds the offending distance in NM.
+    pub position_disagreement_nm: Option<f64>,
+}
+
+impl MergedAircraftData {
+    fn new(icao: String) -> Self {
+        Self {
+            icao,
+            callsign: None,
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: None,
+            heading: None,
+            velocity: None,
+            vertical_rate: None,
+            squawk: None,
+            sources: Vec::new(),
+            primary_source: String::new(),
+            contributions: HashMap::new(),
+            position_disagreement_nm: None,
+        }
+    }
+
+    /// Rebuild the merged fields from the remaining per-source contributions.
+    ///
+    /// Contributions are applied from lowest to highest priority (ties broken
+    /// by recency), so each field ends up with the value from the best source
+    /// that reports it.
+    fn recompute(&mut self) {
+        let mut ordered: Vec<&SourcedAircraftData> = self.contributions.values().collect();
+        ordered.sort_by(|a, b| a.priority.cmp(&b.priority).then(a.last_update.cmp(&b.last_update)));
+
+        self.callsign = None;
+        self.altitude = None;
+        self.heading = None;
+        self.velocity = None;
+        self.vertical_rate = None;
+        self.squawk = None;
+
+        for data in &ordered {
+            if let (Some(lat), Some(lon)) = (data.latitude, data.longitude) {
+                self.latitude = lat;
+                self.longitude = lon;
+            }
+            if data.altitude.is_some() {
+                self.altitude = data.altitude;
+            }
+            if data.heading.is_some() {
+                self.heading = data.heading;
+            }
+            if data.velocity.is_some() {
+                self.velocity = data.velocity;
+            }
+            if data.vertical_rate.is_some() {
+                self.vertical_rate = data.vertical_rate;
+            }
+            if data.callsign.is_some() {
+                self.callsign = data.callsign.clone();
+            }
+            if data.squawk.is_some() {
+                self.squawk = data.squawk.clone();
+            }
+        }
+
+        // A single remaining source can't disagree with itself
+        if ordered.len() < 2 {
+            self.position_disagreement_nm = None;
+        }
+
+        self.sources = ordered.iter().rev().map(|d| d.source.clone()).collect();
+        if let Some(primary) = self.sources.first() {
+            self.primary_source = primary.clone();
+        }
+    }
 }
 
 impl DataSourceManager {
@@ -151,64 +261,124 @@ impl DataSourceManager {
     /// Update aircraft data from a source
     ///
     /// This handles merging data from multiple sources for the same aircraft.
-    pub fn update_aircraft(&mut self, data: SourcedAircraftData) {
-        let entry = self.aircraft.entry(data.icao.clone()).or_insert_with(|| {
-            MergedAircraftData {
-                icao: data.icao.clone(),
-                callsign: None,
-                latitude: 0.0,
-                longitude: 0.0,
-                altitude: None,
-                heading: None,
-                velocity: None,
-                vertical_rate: None,
-                squawk: None,
-                sources: Vec::new(),
-                primary_source: data.source.clone(),
+    /// Each source's latest report is kept so it can be dropped independently
+    /// when that source goes stale.
+    pub fn update_aircraft(&mut self, mut data: SourcedAircraftData) {
+        // Key by normalized ICAO so sources disagreeing on casing merge
+        let Some(icao) = normalize_icao(&data.icao) else {
+            return;
+        };
+        data.icao = icao;
+
+        let entry = self
+            .aircraft
+            .entry(data.icao.clone())
+            .or_insert_with(|| MergedAircraftData::new(data.icao.clone()));
+
+        // Fields a source omits in this message carry over from its previous report
+        if let Some(prev) = entry.contributions.get(&data.source) {
+            data.latitude = data.latitude.or(prev.latitude);
+            data.longitude = data.longitude.or(prev.longitude);
+            data.altitude = data.altitude.or(prev.altitude);
+            data.heading = data.heading.or(prev.heading);
+            data.velocity = data.velocity.or(prev.velocity);
+            data.vertical_rate = data.vertical_rate.or(prev.vertical_rate);
+            if data.callsign.is_none() {
+                data.callsign = prev.callsign.clone();
+            }
+            if data.squawk.is_none() {
+                data.squawk = prev.squawk.clone();
             }
-        });
-
-        // Track which sources report this aircraft
-        if !entry.sources.contains(&data.source) {
-            entry.sources.push(data.source.clone());
         }
 
-        // Find priority of current primary source
-        let primary_priority = self.sources
-            .iter()
-            .find(|s| s.name == entry.primary_source)
-            .map(|s| s.priority)
-            .unwrap_or(0);
+        // Compare against the merged position when it comes from another source.
+        // Comparing against our own previous fix would just measure movement.
+        if entry.primary_source != data.source && !entry.contributions.is_empty() {
+            if let (Some(lat), Some(lon)) = (data.latitude, data.longitude) {
+                let distance = haversine_distance_nm(entry.latitude, entry.longitude, lat, lon);
+                entry.position_disagreement_nm =
+                    (distance > POSITION_DISAGREEMENT_NM).then_some(distance);
+            }
+        }
 
-        // Update if this source has higher or equal priority
-        if data.priority >= primary_priority {
-            entry.primary_source = data.source.clone();
+        entry.contributions.insert(data.source.clone(), data);
+        entry.recompute();
+    }
 
-            if let Some(lat) = data.latitude {
-                entry.latitude = lat;
-            }
-            if let Some(lon) = data.longitude {
-                entry.longitude = lon;
-            }
-            if data.altitude.is_some() {
-                entry.altitude = data.altitude;
-            }
-            if data.heading.is_some() {
-                entry.heading = data.heading;
-            }
-            if data.velocity.is_some() {
-                entry.velocity = data.velocity;
-            }
-            if data.vertical_rate.is_some() {
-                entry.vertical_rate = data.vertical_rate;
-            }
-            if data.callsign.is_some() {
-                entry.callsign = data.callsign.clone();
+    /// Drop each source's contribution once it has gone longer than that
+    /// source's `stale_timeout_secs` without an update, recomputing the merged
+    /// record from the remaining sources. Aircraft left with no contributing
+    /// source are removed. Returns the ICAOs that were removed.
+    pub fn prune_stale_sources(&mut self, now: std::time::Instant) -> Vec<String> {
+        let timeouts: HashMap<&str, std::time::Duration> = self
+            .sources
+            .iter()
+            .map(|s| (s.name.as_str(), std::time::Duration::from_secs(s.stale_timeout_secs)))
+            .collect();
+        let default_timeout =
+            std::time::Duration::from_secs(DataSourceConfig::default_stale_timeout_secs());
+
+        let mut removed = Vec::new();
+        for (icao, merged) in self.aircraft.iter_mut() {
+            let before = merged.contributions.len();
+            merged.contributions.retain(|source, data| {
+                let timeout = timeouts.get(source.as_str()).copied().unwrap_or(default_timeout);
+                now.saturating_duration_since(data.last_update) <= timeout
+            });
+            if merged.contributions.is_empty() {
+                removed.push(icao.clone());
+            } else if merged.contributions.len() != before {
+                merged.recompute();
             }
-            if data.squawk.is_some() {
-                entry.squawk = data.squawk.clone();
+        }
+        for icao in &removed {
+            self.aircraft.remove(icao);
+        }
+
+        // Refresh per-source aircraft counts
+        for state in self.states.values_mut() {
+            state.aircraft_count = 0;
+        }
+        for merged in self.aircraft.values() {
+            for source in merged.contributions.keys() {
+                if let Some(state) = self.states.get_mut(source) {
+                    state.aircraft_count += 1;
+                }
             }
         }
+
+        removed
+    }
+
+    /// Source the live ADS-B connection's reports are attributed to: the
+    /// first enabled source in configuration order.
+    pub fn live_feed_source(&self) -> Option<&DataSourceConfig> {
+        self.sources.iter().find(|s| s.enabled)
+    }
+
+    /// Whether more than one source is enabled, i.e. aircraft may be merged
+    /// from several feeds.
+    pub fn is_multi_source(&self) -> bool {
+        self.sources.iter().filter(|s| s.enabled).count() > 1
+    }
+
+    /// Whether trails should currently be colored by source
+    pub fn trails_by_source(&self) -> bool {
+        self.color_trails_by_source && self.is_multi_source()
+    }
+
+    /// Legend color for a source, by its position in the configured list.
+    /// Sources no longer configured fall back to gray.
+    pub fn source_color(&self, name: &str) -> Color {
+        self.sources
+            .iter()
+            .position(|s| s.name == name)
+            .map_or(Color::srgb(0.6, 0.6, 0.6), |i| SOURCE_COLORS[i % SOURCE_COLORS.len()])
+    }
+
+    /// Merged record for an aircraft, if any source is reporting it.
+    pub fn merged(&self, icao: &str) -> Option<&MergedAircraftData> {
+        self.aircraft.get(icao)
     }
 
     /// Get source statistics
@@ -238,6 +408,7 @@ pub struct DataSourceStats {
 /// System to toggle data sources panel
 pub fn toggle_data_sources_panel(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut manager: ResMut<DataSourceManager>,
     mut contexts: EguiContexts,
 ) {
@@ -248,10 +419,8 @@ pub fn toggle_data_sources_panel(
     }
 
     // Shift+D - Toggle data sources panel
-    if keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight) {
-        if keyboard.just_pressed(KeyCode::KeyD) {
-            manager.show_panel = !manager.show_panel;
-        }
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleDataSources, &keyboard) {
+        manager.show_panel = !manager.show_panel;
     }
 }
 
@@ -334,24 +503,246 @@ pub fn render_data_sources_panel(
 
             // Note about implementation status
             ui.label(
-                egui::RichText::new("Note: Multi-source connection requires\nmodifications to the adsb_client integration")
+                egui::RichText::new("Note: only the first enabled source is connected.\nMerging, disagreement badges and trail colors by\nsource need the other feeds wired into adsb_client")
                     .size(11.0)
                     .color(egui::Color32::GRAY)
             );
 
             ui.horizontal(|ui| {
                 ui.checkbox(&mut manager.show_source_indicator, "Show source on aircraft");
+                ui.checkbox(&mut manager.color_trails_by_source, "Color trails by source");
             });
         });
 }
 
+/// System to draw a warning badge on aircraft whose sources disagree on position.
+/// 2D only; the badge is an amber triangle above-left of the marker.
+pub fn draw_position_disagreement_badges(
+    mut gizmos: Gizmos,
+    manager: Res<DataSourceManager>,
+    zoom_state: Res<crate::ZoomState>,
+    view3d_state: Res<crate::view3d::View3DState>,
+    aircraft_query: Query<(&crate::Aircraft, &Transform, &Visibility)>,
+) {
+    if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        return;
+    }
+    if !manager.aircraft.values().any(|m| m.position_disagreement_nm.is_some()) {
+        return;
+    }
+
+    let color = Color::srgb(1.0, 0.75, 0.1);
+    let size = 6.0 / zoom_state.camera_zoom;
+    let offset = Vec2::new(-14.0, 14.0) / zoom_state.camera_zoom;
+
+    for (aircraft, transform, visibility) in aircraft_query.iter() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        let flagged = manager
+            .merged(&aircraft.icao)
+            .is_some_and(|m| m.position_disagreement_nm.is_some());
+        if !flagged {
+            continue;
+        }
+
+        let center = transform.translation.truncate() + offset;
+        let top = center + Vec2::new(0.0, size);
+        let left = center + Vec2::new(-size, -size * 0.8);
+        let right = center + Vec2::new(size, -size * 0.8);
+        gizmos.linestrip_2d([top, right, left, top], color);
+        gizmos.line_2d(center + Vec2::new(0.0, size * 0.4), center + Vec2::new(0.0, -size * 0.2), color);
+    }
+}
+
+/// System to age out per-source aircraft data from feeds that have stopped
+/// reporting, despawning aircraft that no live source still reports.
+pub fn prune_stale_source_data(
+    mut commands: Commands,
+    mut manager: ResMut<DataSourceManager>,
+    time: Res<Time>,
+    mut timer: Local<f32>,
+    aircraft_query: Query<(Entity, &crate::Aircraft), Without<crate::adsb::synthetic::SyntheticAircraft>>,
+    label_query: Query<(Entity, &crate::AircraftLabel)>,
+) {
+    *timer += time.delta_secs();
+    if *timer < 1.0 {
+        return;
+    }
+    *timer = 0.0;
+
+    if manager.aircraft.is_empty() {
+        return;
+    }
+    let removed = manager.prune_stale_sources(std::time::Instant::now());
+    if removed.is_empty() {
+        return;
+    }
+    debug!("Dropped {} aircraft with no live data source", removed.len());
+
+    for (entity, aircraft) in aircraft_query.iter() {
+        if !removed.contains(&aircraft.icao) {
+            continue;
+        }
+        for (label_entity, label) in label_query.iter() {
+            if label.aircraft_entity == entity {
+                commands.entity(label_entity).despawn();
+                break;
+            }
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
 /// Plugin for multiple data sources
 pub struct DataSourcesPlugin;
 
 impl Plugin for DataSourcesPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DataSourceManager>()
-            .add_systems(Update, toggle_data_sources_panel);
+            .add_systems(Update, (
+                toggle_data_sources_panel,
+                prune_stale_source_data.after(crate::adsb::sync_aircraft_from_adsb),
+                draw_position_disagreement_badges.after(crate::ZoomSet::Change),
+            ));
         // Data sources panel is rendered via the consolidated Tools window (tools_window.rs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn manager_with_sources() -> DataSourceManager {
+        let mut manager = DataSourceManager {
+            sources: Vec::new(),
+            ..Default::default()
+        };
+        manager.add_source(DataSourceConfig {
+            name: "primary".to_string(),
+            priority: 200,
+            stale_timeout_secs: 30,
+            ..Default::default()
+        });
+        manager.add_source(DataSourceConfig {
+            name: "backup".to_string(),
+            priority: 100,
+            stale_timeout_secs: 30,
+            ..Default::default()
+        });
+        manager
+    }
+
+    fn report(source: &str, priority: u8, at: Instant, altitude: i32) -> SourcedAircraftData {
+        SourcedAircraftData {
+            icao: "A1B2C3".to_string(),
+            source: source.to_string(),
+            priority,
+            last_update: at,
+            latitude: Some(37.0),
+            longitude: Some(-97.0),
+            altitude: Some(altitude),
+            heading: None,
+            velocity: None,
+            vertical_rate: None,
+            callsign: None,
+            squawk: None,
+        }
+    }
+
+    #[test]
+    fn higher_priority_source_wins_merge() {
+        let mut manager = manager_with_sources();
+        let now = Instant::now();
+        manager.update_aircraft(report("primary", 200, now, 10_000));
+        manager.update_aircraft(report("backup", 100, now, 9_000));
+
+        let merged = &manager.aircraft["A1B2C3"];
+        assert_eq!(merged.altitude, Some(10_000));
+        assert_eq!(merged.primary_source, "primary");
+        assert_eq!(merged.sources, vec!["primary".to_string(), "backup".to_string()]);
+    }
+
+    #[test]
+    fn mixed_case_and_padded_icaos_merge_into_one_aircraft() {
+        let mut manager = manager_with_sources();
+        let now = Instant::now();
+        let mut lower = report("primary", 200, now, 10_000);
+        lower.icao = "a1b2c3".to_string();
+        let mut padded = report("backup", 100, now, 9_000);
+        padded.icao = " A1B2C3 ".to_string();
+        let mut invalid = report("backup", 100, now, 9_000);
+        invalid.icao = "A1B2".to_string();
+        manager.update_aircraft(lower);
+        manager.update_aircraft(padded);
+        manager.update_aircraft(invalid);
+
+        assert_eq!(manager.aircraft.len(), 1);
+        assert_eq!(manager.aircraft["A1B2C3"].sources.len(), 2);
+    }
+
+    #[test]
+    fn stale_source_falls_back_to_remaining_source() {
+        let mut manager = manager_with_sources();
+        let start = Instant::now();
+        manager.update_aircraft(report("primary", 200, start, 10_000));
+        manager.update_aircraft(report("backup", 100, start + Duration::from_secs(40), 9_000));
+
+        let removed = manager.prune_stale_sources(start + Duration::from_secs(45));
+        assert!(removed.is_empty());
+        let merged = &manager.aircraft["A1B2C3"];
+        assert_eq!(merged.altitude, Some(9_000));
+        assert_eq!(merged.primary_source, "backup");
+    }
+
+    #[test]
+    fn distant_positions_from_different_sources_are_flagged() {
+        let mut manager = manager_with_sources();
+        let now = Instant::now();
+        manager.update_aircraft(report("primary", 200, now, 10_000));
+
+        let mut far = report("backup", 100, now, 10_000);
+        far.latitude = Some(37.1); // ~6 NM north
+        manager.update_aircraft(far);
+        assert!(manager.aircraft["A1B2C3"].position_disagreement_nm.is_some());
+
+        manager.update_aircraft(report("backup", 100, now, 10_000));
+        assert!(manager.aircraft["A1B2C3"].position_disagreement_nm.is_none());
+    }
+
+    #[test]
+    fn aircraft_seen_only_by_dead_source_is_removed() {
+        let mut manager = manager_with_sources();
+        let start = Instant::now();
+        manager.update_aircraft(report("backup", 100, start, 9_000));
+
+        let removed = manager.prune_stale_sources(start + Duration::from_secs(31));
+        assert_eq!(removed, vec!["A1B2C3".to_string()]);
+        assert!(manager.aircraft.is_empty());
+    }
+
+    #[test]
+    fn live_feed_is_attributed_to_first_enabled_source() {
+        let mut manager = manager_with_sources();
+        assert_eq!(manager.live_feed_source().map(|s| s.name.as_str()), Some("primary"));
+
+        manager.sources[0].enabled = false;
+        assert_eq!(manager.live_feed_source().map(|s| s.name.as_str()), Some("backup"));
+
+        manager.sources[1].enabled = false;
+        assert!(manager.live_feed_source().is_none());
+    }
+
+    #[test]
+    fn trail_source_colors_need_two_enabled_sources() {
+        let mut manager = manager_with_sources();
+        manager.color_trails_by_source = true;
+        assert!(manager.trails_by_source());
+        assert_ne!(manager.source_color("primary"), manager.source_color("backup"));
+        assert_eq!(manager.source_color("gone"), Color::srgb(0.6, 0.6, 0.6));
+
+        manager.sources[1].enabled = false;
+        assert!(!manager.trails_by_source());
+    }
+}
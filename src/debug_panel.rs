@@ -1,18 +1,24 @@
 /// Debug/Metrics floating window.
 ///
 /// Provides a runtime debug panel with scrollable log messages and live metrics
-/// (FPS, aircraft count, message rate, connection state, map state).
+/// (FPS, frame time, entity counts, message rate, connection state, map state).
 
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
+use bevy_slippy_tiles::MapTile;
 use std::collections::VecDeque;
 
 use crate::adsb::AdsbAircraftData;
+use crate::adsb::anomaly::FeedAnomaly;
+use crate::adsb::synthetic::{SyntheticTraffic, MAX_SYNTHETIC_AIRCRAFT};
 use crate::theme::{AppTheme, to_egui_color32, to_egui_color32_alpha};
 use crate::ui_panels::{PanelId, UiPanelManager};
 use crate::{Aircraft, MapState, ZoomState};
 
 const MAX_LOG_MESSAGES: usize = 200;
+/// Number of frame-time samples kept for the rolling plot
+const FRAME_TIME_HISTORY: usize = 240;
 
 /// Resource holding debug panel state, log ring buffer, and live metrics.
 #[derive(Resource, Reflect)]
@@ -26,6 +32,28 @@ pub struct DebugPanelState {
     pub positions_rejected: u64,
     pub message_rate: f64,
     pub fps: f32,
+    /// Smoothed frame time in milliseconds
+    pub frame_time_ms: f32,
+    /// Recent raw frame times (ms) for the rolling plot, oldest first
+    #[reflect(ignore)]
+    pub frame_times: VecDeque<f32>,
+    /// Live `MapTile` entity count
+    pub tile_count: usize,
+    /// Tile download radius `compute_tile_radius` yields for the current view
+    pub tile_radius: u8,
+    /// Download requests issued by the last 3D tile refresh tick
+    pub tile_requests_3d: usize,
+    /// Tiles covered by those requests (before the downloader's dedup)
+    pub tiles_requested_3d: usize,
+    /// Bands dropped by the budget or deferred until the near band loads
+    pub tile_bands_skipped_3d: usize,
+    /// Aircraft type database status summary
+    pub type_db_status: String,
+    /// Show the zoom readout overlay on the map viewport
+    pub show_zoom_overlay: bool,
+    /// Data-quality problems from the last feed scan
+    #[reflect(ignore)]
+    pub anomalies: Vec<FeedAnomaly>,
     // Rate computation internals
     #[reflect(ignore)]
     last_rate_time: f64,
@@ -43,6 +71,16 @@ impl Default for DebugPanelState {
             positions_rejected: 0,
             message_rate: 0.0,
             fps: 0.0,
+            frame_time_ms: 0.0,
+            frame_times: VecDeque::with_capacity(FRAME_TIME_HISTORY),
+            tile_count: 0,
+            tile_radius: 0,
+            tile_requests_3d: 0,
+            tiles_requested_3d: 0,
+            tile_bands_skipped_3d: 0,
+            type_db_status: String::new(),
+            show_zoom_overlay: false,
+            anomalies: Vec::new(),
             last_rate_time: 0.0,
             last_rate_count: 0,
         }
@@ -58,6 +96,14 @@ impl DebugPanelState {
         let now = chrono_timestamp();
         self.log_messages.push_back(format!("[{}] {}", now, msg.into()));
     }
+
+    /// Record a raw frame time sample for the rolling plot.
+    pub fn push_frame_time(&mut self, ms: f32) {
+        if self.frame_times.len() >= FRAME_TIME_HISTORY {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(ms);
+    }
 }
 
 /// Simple HH:MM:SS timestamp from std SystemTime.
@@ -73,20 +119,56 @@ fn chrono_timestamp() -> String {
     format!("{:02}:{:02}:{:02}", h, m, s)
 }
 
-/// Update live metrics each frame (FPS, aircraft count, message rate).
+/// Update live metrics each frame (FPS, frame time, entity counts, message rate, tile radius).
 pub fn update_debug_metrics(
     time: Res<Time>,
     mut debug: ResMut<DebugPanelState>,
+    diagnostics: Option<Res<DiagnosticsStore>>,
     aircraft_query: Query<(), With<Aircraft>>,
+    tile_query: Query<(), With<MapTile>>,
+    zoom_state: Res<ZoomState>,
+    view3d_state: Res<crate::view3d::View3DState>,
+    window_query: Query<&Window>,
+    type_db_status: Option<Res<crate::aircraft::AircraftTypeDbStatus>>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
-    // FPS from delta
+    // FPS and frame time: smoothed values from Bevy diagnostics, falling back
+    // to the raw frame delta when the diagnostics plugin isn't present.
     let dt = time.delta_secs();
-    if dt > 0.0 {
+    let smoothed = diagnostics.as_ref().and_then(|store| {
+        let fps = store.get(&FrameTimeDiagnosticsPlugin::FPS)?.smoothed()?;
+        let frame_time = store.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)?.smoothed()?;
+        Some((fps as f32, frame_time as f32))
+    });
+    if let Some((fps, frame_time_ms)) = smoothed {
+        debug.fps = fps;
+        debug.frame_time_ms = frame_time_ms;
+    } else if dt > 0.0 {
         debug.fps = 1.0 / dt;
+        debug.frame_time_ms = dt * 1000.0;
+    }
+    if dt > 0.0 {
+        debug.push_frame_time(dt * 1000.0);
     }
 
-    // Aircraft count
+    // Entity counts
     debug.aircraft_count = aircraft_query.iter().count();
+    debug.tile_count = tile_query.iter().count();
+
+    // Tile radius the tile systems would request for the current view
+    if let Ok(window) = window_query.single() {
+        debug.tile_radius = crate::tiles::compute_tile_radius(
+            window.width(),
+            window.height(),
+            zoom_state.camera_zoom,
+            Some(&view3d_state),
+            app_config.map.tile_detail,
+        );
+    }
+
+    if let Some(status) = type_db_status.filter(|s| s.is_changed()) {
+        debug.type_db_status = status.summary();
+    }
 
     // Message rate: compute once per second
     let elapsed = time.elapsed_secs_f64();
@@ -138,10 +220,22 @@ pub fn render_debug_panel_ui(
                             ui.label(format!("{:.0}", debug.fps));
                             ui.end_row();
 
+                            ui.label("Frame time:");
+                            ui.label(format!("{:.2} ms", debug.frame_time_ms));
+                            ui.end_row();
+
                             ui.label("Aircraft:");
                             ui.label(format!("{}", debug.aircraft_count));
                             ui.end_row();
 
+                            ui.label("Tile entities:");
+                            ui.label(format!("{}", debug.tile_count));
+                            ui.end_row();
+
+                            ui.label("Type DB:");
+                            ui.label(&debug.type_db_status);
+                            ui.end_row();
+
                             ui.label("Msgs processed:");
                             ui.label(format!("{}", debug.messages_processed));
                             ui.end_row();
@@ -170,7 +264,20 @@ pub fn render_debug_panel_ui(
                                 ui.label(format!("{:.3}", zs.camera_zoom));
                                 ui.end_row();
                             }
+
+                            ui.label("Tile radius:");
+                            ui.label(format!("{}", debug.tile_radius));
+                            ui.end_row();
+
+                            ui.label("3D requests/tick:");
+                            ui.label(format!(
+                                "{} ({} tiles, {} skipped)",
+                                debug.tile_requests_3d, debug.tiles_requested_3d, debug.tile_bands_skipped_3d
+                            ));
+                            ui.end_row();
                         });
+                    render_frame_time_plot(ui, &debug.frame_times);
+                    ui.checkbox(&mut debug.show_zoom_overlay, "Zoom overlay on map");
                 });
 
             ui.separator();
@@ -214,6 +321,7 @@ pub fn render_debug_pane_content(
     debug: &mut DebugPanelState,
     map_state: Option<&MapState>,
     zoom_state: Option<&ZoomState>,
+    synthetic: Option<&mut SyntheticTraffic>,
 ) {
     // -- Metrics section --
     egui::CollapsingHeader::new("Metrics")
@@ -227,10 +335,22 @@ pub fn render_debug_pane_content(
                     ui.label(format!("{:.0}", debug.fps));
                     ui.end_row();
 
+                    ui.label("Frame time:");
+                    ui.label(format!("{:.2} ms", debug.frame_time_ms));
+                    ui.end_row();
+
                     ui.label("Aircraft:");
                     ui.label(format!("{}", debug.aircraft_count));
                     ui.end_row();
 
+                    ui.label("Tile entities:");
+                    ui.label(format!("{}", debug.tile_count));
+                    ui.end_row();
+
+                    ui.label("Type DB:");
+                    ui.label(&debug.type_db_status);
+                    ui.end_row();
+
                     ui.label("Msgs processed:");
                     ui.label(format!("{}", debug.messages_processed));
                     ui.end_row();
@@ -259,11 +379,69 @@ pub fn render_debug_pane_content(
                         ui.label(format!("{:.3}", zs.camera_zoom));
                         ui.end_row();
                     }
+
+                    ui.label("Tile radius:");
+                    ui.label(format!("{}", debug.tile_radius));
+                    ui.end_row();
+
+                    ui.label("3D requests/tick:");
+                    ui.label(format!(
+                        "{} ({} tiles, {} skipped)",
+                        debug.tile_requests_3d, debug.tiles_requested_3d, debug.tile_bands_skipped_3d
+                    ));
+                    ui.end_row();
                 });
+            render_frame_time_plot(ui, &debug.frame_times);
+            ui.checkbox(&mut debug.show_zoom_overlay, "Zoom overlay on map");
         });
 
     ui.separator();
 
+    // -- Feed anomalies section --
+    let anomalies_header = if debug.anomalies.is_empty() {
+        "Feed Anomalies".to_string()
+    } else {
+        format!("Feed Anomalies ({})", debug.anomalies.len())
+    };
+    egui::CollapsingHeader::new(anomalies_header)
+        .id_salt("debug_feed_anomalies")
+        .default_open(!debug.anomalies.is_empty())
+        .show(ui, |ui| {
+            if debug.anomalies.is_empty() {
+                ui.label(
+                    egui::RichText::new("No duplicate ICAOs or shared callsigns")
+                        .color(egui::Color32::GRAY)
+                        .size(9.0),
+                );
+            }
+            for anomaly in &debug.anomalies {
+                ui.label(
+                    egui::RichText::new(anomaly.describe())
+                        .color(egui::Color32::from_rgb(255, 190, 40))
+                        .size(11.0),
+                );
+            }
+        });
+
+    ui.separator();
+
+    // -- Synthetic traffic section --
+    if let Some(synthetic) = synthetic {
+        egui::CollapsingHeader::new("Synthetic Traffic")
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.checkbox(&mut synthetic.enabled, "Spawn synthetic aircraft");
+                ui.add(egui::Slider::new(&mut synthetic.count, 1..=MAX_SYNTHETIC_AIRCRAFT).text("Count"));
+                ui.label(
+                    egui::RichText::new("Routes are generated around the map center when enabled")
+                        .color(egui::Color32::GRAY)
+                        .size(9.0),
+                );
+            });
+
+        ui.separator();
+    }
+
     // -- Build Info section --
     egui::CollapsingHeader::new("Build Info")
         .default_open(false)
@@ -334,6 +512,46 @@ pub fn render_debug_pane_content(
         });
 }
 
+/// Rolling frame-time plot with 60 FPS (16.7 ms) and 30 FPS (33.3 ms)
+/// reference lines. The vertical scale grows to fit spikes.
+fn render_frame_time_plot(ui: &mut egui::Ui, frame_times: &VecDeque<f32>) {
+    let height = 48.0;
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), height), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(80));
+
+    let max_ms = frame_times.iter().copied().fold(40.0_f32, f32::max);
+    let y_for = |ms: f32| rect.bottom() - (ms / max_ms).min(1.0) * rect.height();
+
+    for (ms, color) in [
+        (1000.0 / 60.0, egui::Color32::from_rgb(80, 160, 80)),
+        (1000.0 / 30.0, egui::Color32::from_rgb(180, 140, 60)),
+    ] {
+        let y = y_for(ms);
+        painter.hline(rect.x_range(), y, egui::Stroke::new(1.0, color.gamma_multiply(0.6)));
+    }
+
+    if frame_times.len() >= 2 {
+        let step = rect.width() / (FRAME_TIME_HISTORY - 1) as f32;
+        let start_x = rect.right() - step * (frame_times.len() - 1) as f32;
+        let points: Vec<egui::Pos2> = frame_times
+            .iter()
+            .enumerate()
+            .map(|(i, &ms)| egui::pos2(start_x + step * i as f32, y_for(ms)))
+            .collect();
+        painter.add(egui::Shape::line(points, egui::Stroke::new(1.0, egui::Color32::LIGHT_BLUE)));
+    }
+
+    painter.text(
+        rect.left_top() + egui::vec2(4.0, 2.0),
+        egui::Align2::LEFT_TOP,
+        format!("{:.0} ms", max_ms),
+        egui::FontId::monospace(9.0),
+        egui::Color32::GRAY,
+    );
+}
+
 /// Render the debug panel as a floating egui window (Bevy system).
 pub fn render_debug_panel(
     mut contexts: EguiContexts,
@@ -377,6 +595,53 @@ pub fn render_debug_panel(
     }
 }
 
+/// Compact map overlay showing tile zoom, camera zoom and tile radius so the
+/// two-tier zoom system can be watched live while reproducing zoom issues.
+pub fn render_zoom_overlay(
+    mut contexts: EguiContexts,
+    debug: Res<DebugPanelState>,
+    map_state: Res<MapState>,
+    zoom_state: Res<ZoomState>,
+    dock_state: Res<crate::dock::DockTreeState>,
+    theme: Res<AppTheme>,
+) {
+    if !debug.show_zoom_overlay {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let Some(map_rect) = dock_state.map_viewport_rect else {
+        return;
+    };
+
+    let bg_color = to_egui_color32_alpha(theme.bg_secondary(), 200);
+    let text_color = to_egui_color32(theme.text_primary());
+
+    egui::Area::new(egui::Id::new("zoom_overlay"))
+        .fixed_pos(egui::pos2(map_rect.left() + 12.0, map_rect.bottom() - 70.0))
+        .order(egui::Order::Middle)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::NONE
+                .fill(bg_color)
+                .corner_radius(egui::CornerRadius::same(4))
+                .inner_margin(6.0)
+                .show(ui, |ui| {
+                    ui.spacing_mut().item_spacing.y = 2.0;
+                    for line in [
+                        format!("tile zoom   {}", map_state.zoom_level.to_u8()),
+                        format!("camera zoom {:.3}", zoom_state.camera_zoom),
+                        format!("tile radius {}", debug.tile_radius),
+                    ] {
+                        ui.label(egui::RichText::new(line).size(11.0).monospace().color(text_color));
+                    }
+                });
+        });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -483,4 +748,108 @@ mod tests {
         harness.get_by_label("Camera zoom:");
         harness.get_by_label("1.000");
     }
+
+    #[test]
+    fn test_debug_panel_renders_tile_radius() {
+        let mut debug = DebugPanelState::default();
+        debug.open = true;
+        debug.tile_radius = 7;
+
+        let map_state = MapState {
+            latitude: 51.5074,
+            longitude: -0.1278,
+            zoom_level: ZoomLevel::L12,
+        };
+        let mut zoom_state = ZoomState::new();
+        zoom_state.camera_zoom = 2.5;
+
+        let harness = Harness::new_state(
+            move |ctx, state: &mut DebugPanelState| {
+                render_debug_panel_ui(
+                    ctx,
+                    state,
+                    Some(&map_state),
+                    Some(&zoom_state),
+                    egui::Color32::from_rgba_unmultiplied(30, 30, 46, 240),
+                    egui::Color32::from_rgb(69, 71, 90),
+                );
+            },
+            debug,
+        );
+
+        // Distinct values, so each readout must come from its own source
+        harness.get_by_label("Tile zoom:");
+        harness.get_by_label("12");
+        harness.get_by_label("Camera zoom:");
+        harness.get_by_label("2.500");
+        harness.get_by_label("Tile radius:");
+        harness.get_by_label("7");
+    }
+
+    #[test]
+    fn test_debug_panel_tile_radius_without_zoom_state() {
+        let mut debug = DebugPanelState::default();
+        debug.open = true;
+        debug.tile_radius = 7;
+
+        let harness = Harness::new_state(
+            |ctx, state: &mut DebugPanelState| {
+                render_debug_panel_ui(
+                    ctx,
+                    state,
+                    None,
+                    None,
+                    egui::Color32::from_rgba_unmultiplied(30, 30, 46, 240),
+                    egui::Color32::from_rgb(69, 71, 90),
+                );
+            },
+            debug,
+        );
+
+        // Unknown map and zoom state drop their rows; the radius still shows
+        harness.get_by_label("Tile radius:");
+        harness.get_by_label("7");
+        assert!(harness.query_by_label("Tile zoom:").is_none());
+        assert!(harness.query_by_label("Camera zoom:").is_none());
+    }
+
+    #[test]
+    fn test_debug_panel_renders_frame_time_and_tile_count() {
+        let mut debug = DebugPanelState::default();
+        debug.open = true;
+        debug.frame_time_ms = 16.67;
+        debug.tile_count = 42;
+        for _ in 0..10 {
+            debug.push_frame_time(16.67);
+        }
+
+        let harness = Harness::new_state(
+            |ctx, state: &mut DebugPanelState| {
+                render_debug_panel_ui(
+                    ctx,
+                    state,
+                    None,
+                    None,
+                    egui::Color32::from_rgba_unmultiplied(30, 30, 46, 240),
+                    egui::Color32::from_rgb(69, 71, 90),
+                );
+            },
+            debug,
+        );
+
+        harness.get_by_label("Frame time:");
+        harness.get_by_label("16.67 ms");
+        harness.get_by_label("Tile entities:");
+        harness.get_by_label("42");
+    }
+
+    #[test]
+    fn frame_time_history_is_bounded() {
+        let mut debug = DebugPanelState::default();
+        for i in 0..(FRAME_TIME_HISTORY + 10) {
+            debug.push_frame_time(i as f32);
+        }
+        assert_eq!(debug.frame_times.len(), FRAME_TIME_HISTORY);
+        assert_eq!(debug.frame_times.front().copied(), Some(10.0));
+    }
 }
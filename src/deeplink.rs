@@ -0,0 +1,199 @@
+/// Shareable deep links to an aircraft or map location.
+///
+/// Links look like `airjedi://icao=ABC123&lat=47.4490&lon=-122.3093&zoom=10`;
+/// every field is optional. Passing one as a command-line argument recenters
+/// the map on launch and selects the aircraft once it appears in the feed.
+
+use bevy::prelude::*;
+use bevy_slippy_tiles::ZoomLevel;
+use std::time::{Duration, Instant};
+
+use crate::adsb::normalize_icao;
+use crate::aircraft::{AircraftListState, AircraftTypeInfo};
+use crate::geo::{format_latlon, CoordinateFormat};
+use crate::{Aircraft, MapState, ZoomState};
+
+pub const SCHEME: &str = "airjedi://";
+
+/// Zoom level written into aircraft links
+const AIRCRAFT_LINK_ZOOM: u8 = 10;
+/// Give up waiting for a linked aircraft after this long
+const AIRCRAFT_WAIT: Duration = Duration::from_secs(120);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeepLink {
+    pub icao: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub zoom: Option<u8>,
+}
+
+impl DeepLink {
+    /// Parse an `airjedi://` link. Unknown keys are ignored; a link without
+    /// any usable field is rejected.
+    pub fn parse(text: &str) -> Option<Self> {
+        let query = text.trim().strip_prefix(SCHEME)?.trim_end_matches('/');
+        let mut link = DeepLink::default();
+        for pair in query.split('&') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            match key {
+                "icao" => link.icao = normalize_icao(value),
+                "lat" => link.latitude = value.parse().ok().filter(|lat: &f64| lat.abs() <= 90.0),
+                "lon" => link.longitude = value.parse().ok().filter(|lon: &f64| lon.abs() <= 180.0),
+                "zoom" => link.zoom = value.parse().ok(),
+                _ => {}
+            }
+        }
+        (link.icao.is_some() || link.location().is_some()).then_some(link)
+    }
+
+    pub fn location(&self) -> Option<(f64, f64)> {
+        self.latitude.zip(self.longitude)
+    }
+
+    /// Link to an aircraft at its current position
+    pub fn for_aircraft(aircraft: &Aircraft) -> Self {
+        Self {
+            icao: Some(aircraft.icao.clone()),
+            latitude: Some(aircraft.latitude),
+            longitude: Some(aircraft.longitude),
+            zoom: Some(AIRCRAFT_LINK_ZOOM),
+        }
+    }
+
+    /// First `airjedi://` argument on the command line, if any
+    pub fn from_args(args: impl Iterator<Item = String>) -> Option<Self> {
+        args.filter(|arg| arg.starts_with(SCHEME)).find_map(|arg| Self::parse(&arg))
+    }
+}
+
+impl std::fmt::Display for DeepLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut fields = Vec::new();
+        if let Some(ref icao) = self.icao {
+            fields.push(format!("icao={}", icao));
+        }
+        if let Some((lat, lon)) = self.location() {
+            fields.push(format!("lat={:.4}", lat));
+            fields.push(format!("lon={:.4}", lon));
+        }
+        if let Some(zoom) = self.zoom {
+            fields.push(format!("zoom={}", zoom));
+        }
+        write!(f, "{}{}", SCHEME, fields.join("&"))
+    }
+}
+
+/// Plain-text summary of an aircraft for the clipboard
+pub fn aircraft_details_text(
+    aircraft: &Aircraft,
+    type_info: Option<&AircraftTypeInfo>,
+    link: &DeepLink,
+    coordinate_format: CoordinateFormat,
+) -> String {
+    let mut lines = vec![format!("ICAO: {}", aircraft.icao)];
+    if let Some(callsign) = aircraft.callsign.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+        lines.push(format!("Callsign: {}", callsign));
+    }
+    lines.push(format!("Position: {}", format_latlon(aircraft.latitude, aircraft.longitude, coordinate_format)));
+    if let Some(altitude) = aircraft.altitude {
+        lines.push(format!("Altitude: {} ft", altitude));
+    }
+    if let Some(type_code) = type_info.and_then(|t| t.type_code.as_deref()) {
+        lines.push(format!("Type: {}", type_code));
+    }
+    lines.push(format!("Link: {}", link));
+    lines.join("\n")
+}
+
+/// Deep link passed on the command line, applied once the map is up.
+#[derive(Resource, Default)]
+pub struct PendingDeepLink {
+    link: Option<DeepLink>,
+    recentered: bool,
+    started: Option<Instant>,
+}
+
+impl PendingDeepLink {
+    pub fn from_args() -> Self {
+        let link = DeepLink::from_args(std::env::args().skip(1));
+        if let Some(ref link) = link {
+            info!("Opening deep link {}", link);
+        }
+        Self { link, ..default() }
+    }
+}
+
+/// System that recenters the map on a pending deep link and selects its
+/// aircraft once it shows up in the feed.
+pub fn apply_deep_link(
+    mut pending: ResMut<PendingDeepLink>,
+    mut map_state: ResMut<MapState>,
+    mut zoom_state: ResMut<ZoomState>,
+    mut list_state: ResMut<AircraftListState>,
+    aircraft_query: Query<&Aircraft>,
+) {
+    let Some(link) = pending.link.clone() else {
+        return;
+    };
+    let started = *pending.started.get_or_insert_with(Instant::now);
+
+    if !pending.recentered {
+        pending.recentered = true;
+        if let Some((lat, lon)) = link.location() {
+            recenter(&mut map_state, &mut zoom_state, lat, lon, link.zoom);
+        }
+    }
+
+    let Some(ref icao) = link.icao else {
+        pending.link = None;
+        return;
+    };
+    if let Some(aircraft) = aircraft_query.iter().find(|a| &a.icao == icao) {
+        if link.location().is_none() {
+            recenter(&mut map_state, &mut zoom_state, aircraft.latitude, aircraft.longitude, link.zoom);
+        }
+        list_state.selected_icao = Some(icao.clone());
+        pending.link = None;
+    } else if started.elapsed() > AIRCRAFT_WAIT {
+        warn!("Deep link aircraft {} not seen, giving up", icao);
+        pending.link = None;
+    }
+}
+
+fn recenter(map_state: &mut MapState, zoom_state: &mut ZoomState, lat: f64, lon: f64, zoom: Option<u8>) {
+    map_state.latitude = lat;
+    map_state.longitude = lon;
+    if let Some(zoom) = zoom.and_then(|z| ZoomLevel::try_from(z).ok()) {
+        map_state.zoom_level = zoom;
+    }
+    zoom_state.camera_zoom = 1.0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deep_link_round_trips() {
+        let link = DeepLink {
+            icao: Some("ABC123".to_string()),
+            latitude: Some(47.449),
+            longitude: Some(-122.3093),
+            zoom: Some(10),
+        };
+        let text = link.to_string();
+        assert_eq!(text, "airjedi://icao=ABC123&lat=47.4490&lon=-122.3093&zoom=10");
+        assert_eq!(DeepLink::parse(&text), Some(link));
+
+        let icao_only = DeepLink::parse("airjedi://icao=abc123/").unwrap();
+        assert_eq!(icao_only.icao.as_deref(), Some("ABC123"));
+        assert!(icao_only.location().is_none());
+
+        assert!(DeepLink::parse("airjedi://lat=95&lon=0").is_none());
+        assert!(DeepLink::parse("airjedi://icao=NOTHEX").is_none());
+        assert!(DeepLink::parse("https://example.com/?icao=ABC123").is_none());
+    }
+}
@@ -8,16 +8,22 @@ use egui_tiles::{Behavior, SimplificationOptions, TabState, TileId, Tiles, UiRes
 
 use crate::aircraft::{
     AircraftDisplayList, AircraftListState, AircraftTypeInfo, CameraFollowState, DetailPanelState,
-    SessionClock, StatsPanelState, TrailHistory,
+    SessionClock, StatsPanelState, TrailHistory, TypeLookupPending,
+    altitude::AltitudeColorScheme,
+    declutter::AltitudeDeclutter,
     list_panel::render_aircraft_list_pane_content,
+    logbook::{AircraftLogbook, render_logbook_pane_content},
+    route::RouteOverlay,
     stats_panel::render_stats_pane_content,
 };
+use crate::adsb::synthetic::SyntheticTraffic;
 use crate::airspace::{AirspaceData, AirspaceDisplayState};
 use crate::bookmarks::{self, BookmarksPanelState};
 use crate::config::{self, AppConfig, SettingsUiState};
 use crate::coverage::CoverageState;
 use crate::data_sources::DataSourceManager;
 use crate::debug_panel::{self, DebugPanelState};
+use crate::event_log::{EventLog, render_event_log_pane_content};
 use crate::export::ExportState;
 use crate::inspector;
 use crate::recording::{PlaybackState, RecordingState};
@@ -48,6 +54,8 @@ pub enum DockPane {
     AircraftDetail,
     Bookmarks,
     Stats,
+    Logbook,
+    EventLog,
     Inspector,
 }
 
@@ -69,6 +77,8 @@ impl DockPane {
             DockPane::AircraftDetail => "Detail",
             DockPane::Bookmarks => "Bookmarks",
             DockPane::Stats => "Statistics",
+            DockPane::Logbook => "Logbook",
+            DockPane::EventLog => "Event Log",
         }
     }
 }
@@ -105,6 +115,8 @@ const RIGHT_PANES: &[DockPane] = &[
     DockPane::Airspace,
     DockPane::Bookmarks,
     DockPane::Stats,
+    DockPane::Logbook,
+    DockPane::EventLog,
     DockPane::Settings,
     DockPane::Ingest,
     DockPane::View3D,
@@ -133,6 +145,8 @@ impl Default for DockTreeState {
             DockPane::AircraftDetail,
             DockPane::Bookmarks,
             DockPane::Stats,
+            DockPane::Logbook,
+            DockPane::EventLog,
             DockPane::Inspector,
         ];
 
@@ -149,13 +163,15 @@ impl Default for DockTreeState {
             pane_tile_ids[&DockPane::Recording],
         ]);
 
-        // Right tabs: AircraftList, AircraftDetail, Airspace, Bookmarks, Stats, Settings, Ingest, View3D, Debug, Inspector
+        // Right tabs: AircraftList, AircraftDetail, Airspace, Bookmarks, Stats, Logbook, EventLog, Settings, Ingest, View3D, Debug, Inspector
         let right_tabs_id = tiles.insert_tab_tile(vec![
             pane_tile_ids[&DockPane::AircraftList],
             pane_tile_ids[&DockPane::AircraftDetail],
             pane_tile_ids[&DockPane::Airspace],
             pane_tile_ids[&DockPane::Bookmarks],
             pane_tile_ids[&DockPane::Stats],
+            pane_tile_ids[&DockPane::Logbook],
+            pane_tile_ids[&DockPane::EventLog],
             pane_tile_ids[&DockPane::Settings],
             pane_tile_ids[&DockPane::Ingest],
             pane_tile_ids[&DockPane::View3D],
@@ -195,6 +211,8 @@ impl Default for DockTreeState {
             DockPane::AircraftDetail,
             DockPane::Bookmarks,
             DockPane::Stats,
+            DockPane::Logbook,
+            DockPane::EventLog,
             DockPane::Settings,
             DockPane::Inspector,
         ];
@@ -328,13 +346,15 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
                         ResMut<DebugPanelState>,
                         Option<Res<MapState>>,
                         Option<Res<ZoomState>>,
+                        Option<ResMut<SyntheticTraffic>>,
                     )>::new(world);
-                    let (mut debug, map, zoom) = state.get_mut(world);
+                    let (mut debug, map, zoom, mut synthetic) = state.get_mut(world);
                     debug_panel::render_debug_pane_content(
                         ui,
                         &mut debug,
                         map.as_deref(),
                         zoom.as_deref(),
+                        synthetic.as_deref_mut(),
                     );
                 });
             }
@@ -347,8 +367,9 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
                         ResMut<AppConfig>,
                         ResMut<AppTheme>,
                         Res<ThemeRegistry>,
+                        Query<(), With<Aircraft>>,
                     )>::new(world);
-                    let (mut settings_ui, mut app_config, mut theme, theme_registry) =
+                    let (mut settings_ui, mut app_config, mut theme, theme_registry, aircraft) =
                         state.get_mut(world);
                     config::render_settings_pane_content(
                         ui,
@@ -356,6 +377,7 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
                         &mut app_config,
                         &mut theme,
                         &theme_registry,
+                        aircraft.iter().count(),
                     );
                 });
             }
@@ -370,10 +392,14 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
                         Res<AircraftDisplayList>,
                         Res<AppConfig>,
                         Res<SessionClock>,
-                        Query<(&'static Aircraft, &'static TrailHistory, Option<&'static AircraftTypeInfo>)>,
+                        Query<(&'static Aircraft, &'static TrailHistory, Option<&'static AircraftTypeInfo>, Has<TypeLookupPending>)>,
                         Res<AppTheme>,
+                        Res<AltitudeColorScheme>,
+                        ResMut<AltitudeDeclutter>,
+                        Res<DataSourceManager>,
+                        ResMut<RouteOverlay>,
                     )>::new(world);
-                    let (mut list, mut detail, mut follow, display, app_config, clock, query, theme) =
+                    let (mut list, mut detail, mut follow, display, app_config, clock, query, theme, altitude_colors, mut declutter, data_sources, mut routes) =
                         state.get_mut(world);
                     render_aircraft_list_pane_content(
                         ui,
@@ -385,6 +411,10 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
                         &clock,
                         &query,
                         &theme,
+                        &altitude_colors,
+                        &mut declutter,
+                        &data_sources,
+                        &mut routes,
                     );
                 });
             }
@@ -408,6 +438,30 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
                 });
             }
 
+            DockPane::Logbook => {
+                let world = &mut *self.world;
+                render_pane_with_bg(bg, ui, |ui| {
+                    let mut state = SystemState::<(
+                        ResMut<AircraftLogbook>,
+                        Res<AppTheme>,
+                    )>::new(world);
+                    let (mut logbook, theme) = state.get_mut(world);
+                    render_logbook_pane_content(ui, &mut logbook, &theme);
+                });
+            }
+
+            DockPane::EventLog => {
+                let world = &mut *self.world;
+                render_pane_with_bg(bg, ui, |ui| {
+                    let mut state = SystemState::<(
+                        ResMut<EventLog>,
+                        Res<AppTheme>,
+                    )>::new(world);
+                    let (mut log, theme) = state.get_mut(world);
+                    render_event_log_pane_content(ui, &mut log, &theme);
+                });
+            }
+
             DockPane::Bookmarks => {
                 let world = &mut *self.world;
                 render_pane_with_bg(bg, ui, |ui| {
@@ -438,9 +492,13 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
             DockPane::Coverage => {
                 let world = &mut *self.world;
                 render_pane_with_bg(bg, ui, |ui| {
-                    let mut state = SystemState::<ResMut<CoverageState>>::new(world);
-                    let mut coverage = state.get_mut(world);
-                    tools_window::render_coverage_tab(ui, &mut coverage);
+                    let mut state = SystemState::<(
+                        ResMut<CoverageState>,
+                        ResMut<crate::coverage::heatmap::DensityHeatmap>,
+                        Res<crate::config::AppConfig>,
+                    )>::new(world);
+                    let (mut coverage, mut heatmap, app_config) = state.get_mut(world);
+                    tools_window::render_coverage_tab(ui, &mut coverage, &mut heatmap, app_config.map.north_reference);
                 });
             }
 
@@ -495,9 +553,12 @@ impl<'a> Behavior<DockPane> for DockBehavior<'a> {
                         ResMut<TimeState>,
                         Res<SunState>,
                         Option<ResMut<crate::tiles::GridOverlay>>,
+                        ResMut<AppConfig>,
                     )>::new(world);
-                    let (mut view3d, mut terrain, mut time, sun, mut grid) = state.get_mut(world);
-                    tools_window::render_view3d_tab(ui, &mut view3d, &mut terrain, &mut time, &sun, grid.as_deref_mut());
+                    let (mut view3d, mut terrain, mut time, sun, mut grid, mut app_config) = state.get_mut(world);
+                    let mut controls = app_config.camera_3d.clone();
+                    let edit = tools_window::render_view3d_tab(ui, &mut view3d, &mut terrain, &mut time, &sun, grid.as_deref_mut(), &mut controls);
+                    tools_window::apply_camera_controls_edit(&mut app_config, controls, &view3d, &edit);
                 });
             }
 
@@ -728,6 +789,8 @@ const PANEL_DOCK_MAP: &[(PanelId, DockPane)] = &[
     (PanelId::AircraftDetail, DockPane::AircraftDetail),
     (PanelId::Bookmarks, DockPane::Bookmarks),
     (PanelId::Statistics, DockPane::Stats),
+    (PanelId::Logbook, DockPane::Logbook),
+    (PanelId::EventLog, DockPane::EventLog),
     (PanelId::Coverage, DockPane::Coverage),
     (PanelId::Airspace, DockPane::Airspace),
     (PanelId::DataSources, DockPane::DataSources),
@@ -741,6 +804,25 @@ const PANEL_DOCK_MAP: &[(PanelId, DockPane)] = &[
 // render_dock_tree - exclusive Bevy system for full World access
 // =============================================================================
 
+/// Restore open panels, theme and key bindings to their defaults.
+/// Bookmarks, recordings and the rest of the saved config are untouched;
+/// the dock tree itself is reset by the caller.
+fn reset_ui_to_defaults(world: &mut World) {
+    *world.resource_mut::<UiPanelManager>() = UiPanelManager::default();
+    *world.resource_mut::<crate::keyboard::KeyBindings>() = crate::keyboard::KeyBindings::default();
+
+    let default_theme_name = config::AppearanceConfig::default().theme;
+    if let Some(theme) = world.resource::<ThemeRegistry>().get(&default_theme_name) {
+        *world.resource_mut::<AppTheme>() = theme;
+    }
+    let mut app_config = world.resource_mut::<AppConfig>();
+    if app_config.appearance.theme != default_theme_name {
+        app_config.appearance.theme = default_theme_name;
+        config::save_config(&app_config);
+    }
+    info!("UI reset to defaults");
+}
+
 pub fn render_dock_tree(world: &mut World) {
     // 1. Clone egui context to release the world borrow
     let mut egui_context = {
@@ -759,13 +841,18 @@ pub fn render_dock_tree(world: &mut World) {
 
     // 3. Use resource_scope for DockTreeState so DockBehavior can hold &mut World
     world.resource_scope(|world, mut dock_state: Mut<DockTreeState>| {
-        // 3a. Handle layout reset request (from Settings panel button)
-        {
+        // 3a. Handle layout and full UI reset requests (from Settings panel buttons)
+        let full_reset = {
             let mut settings_ui = world.resource_mut::<SettingsUiState>();
             if settings_ui.layout_reset_requested {
                 settings_ui.layout_reset_requested = false;
                 dock_state.reset_requested = true;
             }
+            std::mem::take(&mut settings_ui.full_reset_requested)
+        };
+        if full_reset {
+            dock_state.reset_requested = true;
+            reset_ui_to_defaults(world);
         }
         if dock_state.reset_requested {
             *dock_state = DockTreeState::default();
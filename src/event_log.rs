@@ -0,0 +1,155 @@
+//! In-app history of notable events.
+//!
+//! Connection changes, emergencies, watchlist sightings and cache clears are
+//! recorded through `EventLog::record`, which logs them as before and also
+//! keeps them in a bounded buffer the Event Log panel can filter and copy.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+
+use crate::theme::{AppTheme, to_egui_color32};
+
+/// Oldest entries are dropped beyond this many
+const MAX_EVENT_LOG_ENTRIES: usize = 500;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EventCategory {
+    Connection,
+    Emergency,
+    Watchlist,
+    Cache,
+}
+
+impl EventCategory {
+    pub const ALL: [EventCategory; 4] = [
+        EventCategory::Connection,
+        EventCategory::Emergency,
+        EventCategory::Watchlist,
+        EventCategory::Cache,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EventCategory::Connection => "Connection",
+            EventCategory::Emergency => "Emergency",
+            EventCategory::Watchlist => "Watchlist",
+            EventCategory::Cache => "Cache",
+        }
+    }
+
+    /// Emergencies are logged as warnings, everything else as info
+    fn is_warning(&self) -> bool {
+        matches!(self, EventCategory::Emergency)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EventLogEntry {
+    pub time: DateTime<Local>,
+    pub category: EventCategory,
+    pub message: String,
+}
+
+impl EventLogEntry {
+    /// `HH:MM:SS [Category] message`, as shown and copied
+    pub fn line(&self) -> String {
+        format!("{} [{}] {}", self.time.format("%H:%M:%S"), self.category.label(), self.message)
+    }
+}
+
+/// Bounded buffer of notable events, newest last.
+#[derive(Resource, Default)]
+pub struct EventLog {
+    entries: VecDeque<EventLogEntry>,
+    /// Panel filter; `None` shows every category
+    pub filter: Option<EventCategory>,
+}
+
+impl EventLog {
+    /// Log an event to the console and keep it for the panel.
+    pub fn record(&mut self, category: EventCategory, message: impl Into<String>) {
+        let message = message.into();
+        if category.is_warning() {
+            warn!("{}", message);
+        } else {
+            info!("{}", message);
+        }
+        if self.entries.len() >= MAX_EVENT_LOG_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(EventLogEntry {
+            time: Local::now(),
+            category,
+            message,
+        });
+    }
+
+    /// Entries passing the category filter, oldest first
+    pub fn filtered(&self) -> impl DoubleEndedIterator<Item = &EventLogEntry> {
+        self.entries.iter().filter(|e| self.filter.is_none_or(|c| e.category == c))
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Render the event log into a bare `egui::Ui` (for dock/tab usage).
+pub fn render_event_log_pane_content(ui: &mut egui::Ui, log: &mut EventLog, theme: &AppTheme) {
+    let label_color = to_egui_color32(theme.text_dim());
+    let value_color = to_egui_color32(theme.text_primary());
+    let warn_color = to_egui_color32(theme.text_warn());
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Show:").color(label_color).size(11.0));
+        egui::ComboBox::from_id_salt("event_log_filter")
+            .selected_text(log.filter.map_or("All", |c| c.label()))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut log.filter, None, "All");
+                for category in EventCategory::ALL {
+                    ui.selectable_value(&mut log.filter, Some(category), category.label());
+                }
+            });
+        if ui.button("Copy").on_hover_text("Copy the shown entries to the clipboard").clicked() {
+            let text: Vec<String> = log.filtered().map(EventLogEntry::line).collect();
+            ui.ctx().copy_text(text.join("\n"));
+        }
+        if ui.button("Clear").clicked() {
+            log.clear();
+        }
+    });
+    ui.add_space(4.0);
+
+    if log.filtered().next().is_none() {
+        ui.label(egui::RichText::new("No events yet").color(label_color).size(10.0));
+        return;
+    }
+
+    for entry in log.filtered().rev() {
+        let color = if entry.category.is_warning() { warn_color } else { value_color };
+        ui.label(egui::RichText::new(entry.line()).color(color).size(10.0).monospace());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_keeps_a_bounded_filterable_buffer() {
+        let mut log = EventLog::default();
+        for i in 0..MAX_EVENT_LOG_ENTRIES + 5 {
+            log.record(EventCategory::Cache, format!("clear {}", i));
+        }
+        log.record(EventCategory::Emergency, "UAL1 squawking 7700");
+        assert_eq!(log.filtered().count(), MAX_EVENT_LOG_ENTRIES);
+        assert_eq!(log.filtered().next().unwrap().message, "clear 6");
+
+        log.filter = Some(EventCategory::Emergency);
+        let shown: Vec<_> = log.filtered().collect();
+        assert_eq!(shown.len(), 1);
+        assert!(shown[0].line().ends_with("[Emergency] UAL1 squawking 7700"));
+    }
+}
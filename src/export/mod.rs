@@ -1,16 +1,18 @@
 //! Export/Import Module
 //!
 //! Provides functionality to export flight data to various formats (KML, CSV)
-//! and import previously recorded sessions.
+//! and import previously recorded sessions. KML and GeoJSON tracks are thinned
+//! with the same simplification as on-map trails; CSV keeps every sample.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 use std::fs::File;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufReader, Write};
 use std::path::{Path, PathBuf};
 
-use crate::recording::RecordedFrame;
-use crate::geo::FEET_TO_METERS;
+use crate::recording::{RecordedAircraftState, RecordedFrame};
+use crate::aircraft::{simplify_track, TRAIL_SIMPLIFY_TOLERANCE_M};
+use crate::geo::{haversine_distance_nm, FEET_TO_METERS, NM_TO_KM};
 
 /// Export format options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -39,6 +41,124 @@ impl ExportFormat {
     }
 }
 
+/// Field separator for CSV export. Semicolons suit spreadsheet locales
+/// that use a decimal comma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvDelimiter {
+    #[default]
+    Comma,
+    Semicolon,
+    Tab,
+}
+
+impl CsvDelimiter {
+    pub const ALL: [CsvDelimiter; 3] = [CsvDelimiter::Comma, CsvDelimiter::Semicolon, CsvDelimiter::Tab];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CsvDelimiter::Comma => "Comma (,)",
+            CsvDelimiter::Semicolon => "Semicolon (;)",
+            CsvDelimiter::Tab => "Tab",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CsvDelimiter::Comma => ",",
+            CsvDelimiter::Semicolon => ";",
+            CsvDelimiter::Tab => "\t",
+        }
+    }
+}
+
+/// One column of the CSV export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvColumn {
+    Timestamp,
+    Icao,
+    Callsign,
+    Latitude,
+    Longitude,
+    Altitude,
+    Heading,
+    Velocity,
+    VerticalRate,
+    Squawk,
+}
+
+impl CsvColumn {
+    pub const ALL: [CsvColumn; 10] = [
+        CsvColumn::Timestamp,
+        CsvColumn::Icao,
+        CsvColumn::Callsign,
+        CsvColumn::Latitude,
+        CsvColumn::Longitude,
+        CsvColumn::Altitude,
+        CsvColumn::Heading,
+        CsvColumn::Velocity,
+        CsvColumn::VerticalRate,
+        CsvColumn::Squawk,
+    ];
+
+    pub fn header(&self) -> &'static str {
+        match self {
+            CsvColumn::Timestamp => "timestamp_ms",
+            CsvColumn::Icao => "icao",
+            CsvColumn::Callsign => "callsign",
+            CsvColumn::Latitude => "latitude",
+            CsvColumn::Longitude => "longitude",
+            CsvColumn::Altitude => "altitude_ft",
+            CsvColumn::Heading => "heading",
+            CsvColumn::Velocity => "velocity_kts",
+            CsvColumn::VerticalRate => "vertical_rate",
+            CsvColumn::Squawk => "squawk",
+        }
+    }
+
+    fn value(&self, timestamp_ms: u64, aircraft: &RecordedAircraftState) -> String {
+        match self {
+            CsvColumn::Timestamp => timestamp_ms.to_string(),
+            CsvColumn::Icao => aircraft.icao.clone(),
+            CsvColumn::Callsign => aircraft.callsign.clone().unwrap_or_default(),
+            CsvColumn::Latitude => aircraft.latitude.to_string(),
+            CsvColumn::Longitude => aircraft.longitude.to_string(),
+            CsvColumn::Altitude => aircraft.altitude.map(|a| a.to_string()).unwrap_or_default(),
+            CsvColumn::Heading => aircraft.heading.map(|h| format!("{:.1}", h)).unwrap_or_default(),
+            CsvColumn::Velocity => aircraft.velocity.map(|v| format!("{:.1}", v)).unwrap_or_default(),
+            CsvColumn::VerticalRate => aircraft.vertical_rate.map(|v| v.to_string()).unwrap_or_default(),
+            CsvColumn::Squawk => aircraft.squawk.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Delimiter and column choice for CSV export
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: CsvDelimiter,
+    /// Whether each column in `CsvColumn::ALL` is written
+    pub included: [bool; CsvColumn::ALL.len()],
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: CsvDelimiter::default(),
+            included: [true; CsvColumn::ALL.len()],
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Selected columns in export order
+    pub fn columns(&self) -> Vec<CsvColumn> {
+        CsvColumn::ALL
+            .into_iter()
+            .zip(self.included)
+            .filter_map(|(column, included)| included.then_some(column))
+            .collect()
+    }
+}
+
 /// Resource for export state
 #[derive(Resource, Default)]
 pub struct ExportState {
@@ -52,6 +172,14 @@ pub struct ExportState {
     pub status_message: Option<String>,
     /// Include trail data
     pub include_trails: bool,
+    /// CSV delimiter and columns
+    pub csv: CsvOptions,
+    /// Time into a recording (seconds) used for point snapshots
+    pub snapshot_time_secs: String,
+    /// Write a point snapshot of live traffic on the next frame
+    pub live_snapshot_requested: bool,
+    /// Write the current measurement path on the next frame
+    pub measurement_export_requested: bool,
 }
 
 /// Export flight data to KML format (for Google Earth)
@@ -117,7 +245,8 @@ pub fn export_to_kml(
         writeln!(file, "      <coordinates>")
             .map_err(|e| format!("Write error: {}", e))?;
 
-        for (lon, lat, alt, _ts) in positions {
+        let track: Vec<_> = positions.iter().map(|&(lon, lat, alt, _)| (lat, lon, Some(alt))).collect();
+        for (lon, lat, alt, _ts) in simplify_track(&track, TRAIL_SIMPLIFY_TOLERANCE_M).into_iter().map(|i| &positions[i]) {
             // KML uses meters for altitude, convert from feet
             let alt_meters = (*alt as f64) * FEET_TO_METERS;
             writeln!(file, "        {},{},{}", lon, lat, alt_meters)
@@ -141,45 +270,45 @@ pub fn export_to_kml(
     Ok(())
 }
 
-/// Export flight data to CSV format
+/// Export flight data to CSV format with the chosen delimiter and columns
 pub fn export_to_csv(
     frames: &[RecordedFrame],
     output_path: &Path,
+    options: &CsvOptions,
 ) -> Result<(), String> {
+    let columns = options.columns();
+    if columns.is_empty() {
+        return Err("Select at least one CSV column".to_string());
+    }
+
     let mut file = File::create(output_path)
         .map_err(|e| format!("Failed to create file: {}", e))?;
+    let row_count = write_csv(&mut file, frames, &columns, options.delimiter)
+        .map_err(|e| format!("Write error: {}", e))?;
 
-    // CSV header
-    writeln!(
-        file,
-        "timestamp_ms,icao,callsign,latitude,longitude,altitude_ft,heading,velocity_kts,vertical_rate,squawk"
-    )
-    .map_err(|e| format!("Write error: {}", e))?;
+    info!("Exported {} rows to CSV", row_count);
+    Ok(())
+}
+
+/// Write the header and one row per aircraft per frame. Returns the row count.
+fn write_csv(
+    out: &mut impl Write,
+    frames: &[RecordedFrame],
+    columns: &[CsvColumn],
+    delimiter: CsvDelimiter,
+) -> std::io::Result<usize> {
+    let header: Vec<&str> = columns.iter().map(CsvColumn::header).collect();
+    writeln!(out, "{}", header.join(delimiter.as_str()))?;
 
     let mut row_count = 0;
     for frame in frames {
         for aircraft in &frame.aircraft {
-            writeln!(
-                file,
-                "{},{},{},{},{},{},{},{},{},{}",
-                frame.timestamp_ms,
-                aircraft.icao,
-                aircraft.callsign.as_deref().unwrap_or(""),
-                aircraft.latitude,
-                aircraft.longitude,
-                aircraft.altitude.map(|a| a.to_string()).unwrap_or_default(),
-                aircraft.heading.map(|h| format!("{:.1}", h)).unwrap_or_default(),
-                aircraft.velocity.map(|v| format!("{:.1}", v)).unwrap_or_default(),
-                aircraft.vertical_rate.map(|v| v.to_string()).unwrap_or_default(),
-                aircraft.squawk.as_deref().unwrap_or(""),
-            )
-            .map_err(|e| format!("Write error: {}", e))?;
+            let row: Vec<String> = columns.iter().map(|c| c.value(frame.timestamp_ms, aircraft)).collect();
+            writeln!(out, "{}", row.join(delimiter.as_str()))?;
             row_count += 1;
         }
     }
-
-    info!("Exported {} rows to CSV", row_count);
-    Ok(())
+    Ok(row_count)
 }
 
 /// Export flight data to GeoJSON format
@@ -239,8 +368,10 @@ pub fn export_to_geojson(
         writeln!(file, r#"   "geometry": {{"type": "LineString", "coordinates": ["#)
             .map_err(|e| format!("Write error: {}", e))?;
 
-        for (i, (lon, lat, _alt)) in positions.iter().enumerate() {
-            let comma = if i < positions.len() - 1 { "," } else { "" };
+        let track: Vec<_> = positions.iter().map(|&(lon, lat, alt)| (lat, lon, alt)).collect();
+        let kept = simplify_track(&track, TRAIL_SIMPLIFY_TOLERANCE_M);
+        for (i, (lon, lat, _alt)) in kept.iter().map(|&k| &positions[k]).enumerate() {
+            let comma = if i < kept.len() - 1 { "," } else { "" };
             writeln!(file, "     [{}, {}]{}", lon, lat, comma)
                 .map_err(|e| format!("Write error: {}", e))?;
         }
@@ -255,23 +386,203 @@ pub fn export_to_geojson(
     Ok(())
 }
 
-/// Load recorded frames from NDJSON file
-pub fn load_recording(path: &Path) -> Result<Vec<RecordedFrame>, String> {
-    let file = File::open(path)
-        .map_err(|e| format!("Failed to open file: {}", e))?;
-    let reader = BufReader::new(file);
+/// The frame closest to `time_ms` (milliseconds since recording start).
+pub fn frame_at_time(frames: &[RecordedFrame], time_ms: u64) -> Option<&RecordedFrame> {
+    frames.iter().min_by_key(|f| f.timestamp_ms.abs_diff(time_ms))
+}
 
-    let mut frames = Vec::new();
-    for (line_num, line) in reader.lines().enumerate() {
-        let line = line.map_err(|e| format!("Read error at line {}: {}", line_num + 1, e))?;
-        if line.trim().is_empty() {
-            continue;
+/// Build a GeoJSON FeatureCollection with one `Point` per aircraft in `frame`.
+pub fn frame_to_geojson_points(frame: &RecordedFrame) -> serde_json::Value {
+    let features: Vec<serde_json::Value> = frame
+        .aircraft
+        .iter()
+        .map(|aircraft| {
+            serde_json::json!({
+                "type": "Feature",
+                "properties": {
+                    "icao": aircraft.icao,
+                    "callsign": aircraft.callsign,
+                    "altitude_ft": aircraft.altitude,
+                    "heading": aircraft.heading,
+                    "velocity_kts": aircraft.velocity,
+                },
+                "geometry": {
+                    "type": "Point",
+                    "coordinates": [aircraft.longitude, aircraft.latitude],
+                },
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "type": "FeatureCollection",
+        "properties": { "timestamp_ms": frame.timestamp_ms },
+        "features": features,
+    })
+}
+
+/// Export a single frame as GeoJSON points (for density/heatmap analysis)
+pub fn export_frame_to_geojson(
+    frame: &RecordedFrame,
+    output_path: &Path,
+) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&frame_to_geojson_points(frame))
+        .map_err(|e| format!("Serialize error: {}", e))?;
+    std::fs::write(output_path, json)
+        .map_err(|e| format!("Write error: {}", e))?;
+
+    info!("Exported {} aircraft to GeoJSON snapshot", frame.aircraft.len());
+    Ok(())
+}
+
+/// Export the recording frame nearest `time_ms` as a GeoJSON point snapshot
+pub fn export_recording_snapshot(
+    recording_path: &Path,
+    time_ms: u64,
+    output_path: &Path,
+) -> Result<(), String> {
+    let frames = load_recording(recording_path)?;
+    let frame = frame_at_time(&frames, time_ms)
+        .ok_or("Recording has no frames")?;
+    export_frame_to_geojson(frame, output_path)
+}
+
+/// System that writes a point snapshot of the live aircraft when requested
+pub fn export_live_snapshot(
+    mut export_state: ResMut<ExportState>,
+    aircraft_query: Query<&crate::Aircraft>,
+) {
+    if !export_state.live_snapshot_requested {
+        return;
+    }
+    export_state.live_snapshot_requested = false;
+
+    let frame = RecordedFrame {
+        timestamp_ms: 0,
+        aircraft: aircraft_query.iter().map(RecordedAircraftState::from).collect(),
+    };
+    let data_dir = crate::paths::data_dir();
+    let output_name = format!("snapshot_{}.geojson", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+    let output_path = data_dir.join(&output_name);
+
+    let result = std::fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))
+        .and_then(|_| export_frame_to_geojson(&frame, &output_path));
+    match result {
+        Ok(()) => {
+            export_state.status_message = Some(format!("Exported {} aircraft to {}", frame.aircraft.len(), output_name));
+            export_state.last_export_path = Some(output_path);
         }
+        Err(e) => {
+            export_state.status_message = Some(format!("Error: {}", e));
+        }
+    }
+}
+
+/// Total great-circle length of a path of (lat, lon) vertices in NM
+pub fn path_length_nm(vertices: &[(f64, f64)]) -> f64 {
+    vertices
+        .windows(2)
+        .map(|w| haversine_distance_nm(w[0].0, w[0].1, w[1].0, w[1].1))
+        .sum()
+}
+
+/// Build a GeoJSON `LineString` feature for a measured path with its total
+/// distance and any snapped vertex labels in the properties.
+pub fn measurement_path_to_geojson(vertices: &[(f64, f64)], labels: &[Option<String>]) -> serde_json::Value {
+    let distance_nm = path_length_nm(vertices);
+    let coordinates: Vec<[f64; 2]> = vertices.iter().map(|&(lat, lon)| [lon, lat]).collect();
+    serde_json::json!({
+        "type": "Feature",
+        "properties": {
+            "distance_nm": distance_nm,
+            "distance_km": distance_nm * NM_TO_KM,
+            "vertex_labels": labels,
+        },
+        "geometry": {
+            "type": "LineString",
+            "coordinates": coordinates,
+        },
+    })
+}
+
+/// Build a KML document with the measured path as a single placemark
+pub fn measurement_path_to_kml(vertices: &[(f64, f64)]) -> String {
+    let distance_nm = path_length_nm(vertices);
+    let coordinates: Vec<String> = vertices.iter().map(|&(lat, lon)| format!("{},{},0", lon, lat)).collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2">
+<Document>
+  <name>AirJedi Measurement</name>
+  <Placemark>
+    <name>{:.2} nm</name>
+    <description>{:.2} nm / {:.2} km</description>
+    <LineString>
+      <tessellate>1</tessellate>
+      <coordinates>{}</coordinates>
+    </LineString>
+  </Placemark>
+</Document>
+</kml>
+"#,
+        distance_nm,
+        distance_nm,
+        distance_nm * NM_TO_KM,
+        coordinates.join(" "),
+    )
+}
+
+/// System that writes the current measurement path when requested. KML is
+/// used when that format is selected, GeoJSON otherwise.
+pub fn export_measurement_path(
+    mut export_state: ResMut<ExportState>,
+    measurement: Res<crate::tools::MeasurementState>,
+) {
+    if !export_state.measurement_export_requested {
+        return;
+    }
+    export_state.measurement_export_requested = false;
 
-        let frame: RecordedFrame = serde_json::from_str(&line)
-            .map_err(|e| format!("Parse error at line {}: {}", line_num + 1, e))?;
-        frames.push(frame);
+    let vertices = measurement.vertices();
+    if vertices.len() < 2 {
+        export_state.status_message = Some("Error: measure a path (two points) before exporting".to_string());
+        return;
+    }
+
+    let (extension, contents) = if export_state.format == ExportFormat::KML {
+        ("kml", measurement_path_to_kml(&vertices))
+    } else {
+        let labels = [measurement.start_label.clone(), measurement.end_label.clone()];
+        let json = serde_json::to_string_pretty(&measurement_path_to_geojson(&vertices, &labels))
+            .unwrap_or_default();
+        ("geojson", json)
+    };
+
+    let data_dir = crate::paths::data_dir();
+    let output_name = format!("measurement_{}.{}", chrono::Local::now().format("%Y%m%d_%H%M%S"), extension);
+    let output_path = data_dir.join(&output_name);
+
+    let result = std::fs::create_dir_all(&data_dir)
+        .and_then(|_| std::fs::write(&output_path, contents))
+        .map_err(|e| format!("Write error: {}", e));
+    match result {
+        Ok(()) => {
+            export_state.status_message = Some(format!("Exported {:.2} nm path to {}", path_length_nm(&vertices), output_name));
+            export_state.last_export_path = Some(output_path);
+        }
+        Err(e) => {
+            export_state.status_message = Some(format!("Error: {}", e));
+        }
     }
+}
+
+/// Load recorded frames from NDJSON file.
+/// A truncated final line (from a crash mid-write) is skipped.
+pub fn load_recording(path: &Path) -> Result<Vec<RecordedFrame>, String> {
+    let file = File::open(path)
+        .map_err(|e| format!("Failed to open file: {}", e))?;
+    let frames = crate::recording::read_frames(BufReader::new(file))?;
 
     info!("Loaded {} frames from recording", frames.len());
     Ok(frames)
@@ -282,12 +593,13 @@ pub fn export_recording(
     recording_path: &Path,
     output_path: &Path,
     format: ExportFormat,
+    csv: &CsvOptions,
 ) -> Result<(), String> {
     let frames = load_recording(recording_path)?;
 
     match format {
         ExportFormat::KML => export_to_kml(&frames, output_path),
-        ExportFormat::CSV => export_to_csv(&frames, output_path),
+        ExportFormat::CSV => export_to_csv(&frames, output_path, csv),
         ExportFormat::GeoJSON => export_to_geojson(&frames, output_path),
     }
 }
@@ -322,6 +634,9 @@ pub fn render_export_panel(
                     ui.selectable_value(&mut export_state.format, ExportFormat::CSV, ExportFormat::CSV.display_name());
                     ui.selectable_value(&mut export_state.format, ExportFormat::GeoJSON, ExportFormat::GeoJSON.display_name());
                 });
+            if export_state.format == ExportFormat::CSV {
+                render_csv_options(ui, &mut export_state.csv);
+            }
 
             ui.add_space(8.0);
 
@@ -353,7 +668,7 @@ pub fn render_export_panel(
                                     .unwrap_or(Path::new("."))
                                     .join(&output_name);
 
-                                match export_recording(recording, &output_path, export_state.format) {
+                                match export_recording(recording, &output_path, export_state.format, &export_state.csv) {
                                     Ok(()) => {
                                         export_state.status_message = Some(format!(
                                             "Exported to {}",
@@ -391,6 +706,32 @@ pub fn render_export_panel(
         });
 }
 
+/// Delimiter picker and column checklist shown when CSV is selected
+pub fn render_csv_options(ui: &mut egui::Ui, csv: &mut CsvOptions) {
+    ui.horizontal(|ui| {
+        ui.label("Delimiter:");
+        egui::ComboBox::from_id_salt(ui.id().with("csv_delimiter"))
+            .selected_text(csv.delimiter.label())
+            .show_ui(ui, |ui| {
+                for delimiter in CsvDelimiter::ALL {
+                    ui.selectable_value(&mut csv.delimiter, delimiter, delimiter.label());
+                }
+            });
+    });
+    ui.label("Columns:");
+    egui::Grid::new(ui.id().with("csv_columns")).num_columns(2).show(ui, |ui| {
+        for (i, column) in CsvColumn::ALL.iter().enumerate() {
+            ui.checkbox(&mut csv.included[i], column.header());
+            if i % 2 == 1 {
+                ui.end_row();
+            }
+        }
+    });
+    if csv.columns().is_empty() {
+        ui.colored_label(egui::Color32::YELLOW, "Select at least one column");
+    }
+}
+
 /// List available recording files
 pub fn list_available_recordings() -> Vec<PathBuf> {
     let tmp_dir = crate::paths::data_dir();
@@ -419,6 +760,7 @@ pub fn list_available_recordings() -> Vec<PathBuf> {
 /// System to toggle export panel
 pub fn toggle_export_panel(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut export_state: ResMut<ExportState>,
     mut contexts: EguiContexts,
 ) {
@@ -429,7 +771,7 @@ pub fn toggle_export_panel(
     }
 
     // E - Toggle export panel
-    if keyboard.just_pressed(KeyCode::KeyE) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleExport, &keyboard) {
         export_state.panel_open = !export_state.panel_open;
     }
 }
@@ -440,7 +782,95 @@ pub struct ExportPlugin;
 impl Plugin for ExportPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ExportState>()
-            .add_systems(Update, toggle_export_panel);
+            .add_systems(Update, (toggle_export_panel, export_live_snapshot, export_measurement_path));
         // Export panel is rendered via the consolidated Tools window (tools_window.rs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aircraft(icao: &str, callsign: Option<&str>) -> RecordedAircraftState {
+        RecordedAircraftState {
+            icao: icao.to_string(),
+            callsign: callsign.map(str::to_string),
+            latitude: 37.6,
+            longitude: -97.4,
+            altitude: Some(12_000),
+            heading: Some(270.0),
+            velocity: Some(250.0),
+            vertical_rate: None,
+            squawk: None,
+        }
+    }
+
+    #[test]
+    fn csv_writes_selected_columns_with_delimiter() {
+        let frames = vec![RecordedFrame {
+            timestamp_ms: 5000,
+            aircraft: vec![aircraft("A1B2C3", Some("UAL1")), aircraft("D4E5F6", None)],
+        }];
+        let mut options = CsvOptions { delimiter: CsvDelimiter::Semicolon, ..CsvOptions::default() };
+        options.included = [false; CsvColumn::ALL.len()];
+        options.included[1] = true; // icao
+        options.included[2] = true; // callsign
+        options.included[5] = true; // altitude_ft
+        assert_eq!(options.columns(), vec![CsvColumn::Icao, CsvColumn::Callsign, CsvColumn::Altitude]);
+
+        let mut out = Vec::new();
+        let rows = write_csv(&mut out, &frames, &options.columns(), options.delimiter).unwrap();
+        assert_eq!(rows, 2);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "icao;callsign;altitude_ft\nA1B2C3;UAL1;12000\nD4E5F6;;12000\n"
+        );
+
+        options.included = [false; CsvColumn::ALL.len()];
+        let path = std::env::temp_dir().join("airjedi_csv_no_columns.csv");
+        assert!(export_to_csv(&frames, &path, &options).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn measurement_path_carries_total_distance() {
+        // One degree of latitude is 60 NM; two legs of one degree each
+        let vertices = [(37.0, -97.0), (38.0, -97.0), (39.0, -97.0)];
+        let json = measurement_path_to_geojson(&vertices, &[Some("KICT".to_string()), None]);
+        assert_eq!(json["geometry"]["type"], "LineString");
+        assert_eq!(json["geometry"]["coordinates"][1][1], 38.0);
+        let nm = json["properties"]["distance_nm"].as_f64().unwrap();
+        assert!((nm - 120.0).abs() < 0.5, "{nm}");
+        assert_eq!(json["properties"]["vertex_labels"][0], "KICT");
+
+        let kml = measurement_path_to_kml(&vertices);
+        assert!(kml.contains("-97,38,0"));
+    }
+
+    #[test]
+    fn picks_frame_nearest_requested_time() {
+        let frames: Vec<_> = [0, 1000, 2000]
+            .into_iter()
+            .map(|timestamp_ms| RecordedFrame { timestamp_ms, aircraft: vec![] })
+            .collect();
+        assert_eq!(frame_at_time(&frames, 1400).unwrap().timestamp_ms, 1000);
+        assert_eq!(frame_at_time(&frames, 99_000).unwrap().timestamp_ms, 2000);
+        assert!(frame_at_time(&[], 0).is_none());
+    }
+
+    #[test]
+    fn snapshot_has_one_point_per_aircraft() {
+        let frame = RecordedFrame {
+            timestamp_ms: 5000,
+            aircraft: vec![aircraft("A1B2C3", Some("UAL1")), aircraft("D4E5F6", None)],
+        };
+        let json = frame_to_geojson_points(&frame);
+        let features = json["features"].as_array().unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[0]["geometry"]["type"], "Point");
+        assert_eq!(features[0]["geometry"]["coordinates"][0], -97.4);
+        assert_eq!(features[0]["properties"]["callsign"], "UAL1");
+        assert!(features[1]["properties"]["callsign"].is_null());
+        assert_eq!(features[1]["properties"]["altitude_ft"], 12_000);
+    }
+}
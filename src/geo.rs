@@ -20,6 +20,9 @@ pub const FEET_TO_METERS: f64 = 0.3048;
 /// Conversion factor: nautical miles to kilometers
 pub const NM_TO_KM: f64 = 1.852;
 
+/// Conversion factor: nautical miles to statute miles
+pub const NM_TO_SM: f64 = 1.150779;
+
 /// Flight level threshold in feet (at or above 18,000 ft, altitudes
 /// are expressed as flight levels)
 pub const FL_THRESHOLD: i32 = 18000;
@@ -61,6 +64,25 @@ pub fn initial_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     (bearing + 360.0) % 360.0
 }
 
+/// Point a fraction `f` (0.0-1.0) of the way along the great circle from
+/// point 1 to point 2. Returns `(latitude, longitude)` in degrees.
+pub fn great_circle_intermediate(lat1: f64, lon1: f64, lat2: f64, lon2: f64, f: f64) -> (f64, f64) {
+    let d = haversine_distance_nm(lat1, lon1, lat2, lon2) / EARTH_RADIUS_NM;
+    if d < 1e-12 {
+        return (lat1, lon1);
+    }
+    let (lat1, lon1) = (lat1.to_radians(), lon1.to_radians());
+    let (lat2, lon2) = (lat2.to_radians(), lon2.to_radians());
+
+    let a = ((1.0 - f) * d).sin() / d.sin();
+    let b = (f * d).sin() / d.sin();
+    let x = a * lat1.cos() * lon1.cos() + b * lat2.cos() * lon2.cos();
+    let y = a * lat1.cos() * lon1.sin() + b * lat2.cos() * lon2.sin();
+    let z = a * lat1.sin() + b * lat2.sin();
+
+    (z.atan2((x * x + y * y).sqrt()).to_degrees(), y.atan2(x).to_degrees())
+}
+
 /// Predict a future position given current position, heading, speed, and time.
 /// Uses great-circle (spherical) trigonometry for accuracy over long distances.
 ///
@@ -83,8 +105,16 @@ pub fn predict_position(
     // Distance to travel in nautical miles
     let distance_nm = nm_per_minute * minutes as f64;
 
+    destination_point(lat, lon, heading_deg as f64, distance_nm)
+}
+
+/// Point reached by travelling `distance_nm` from a start position along
+/// the great circle with initial bearing `bearing_deg` (0 = north, clockwise).
+///
+/// Returns `(latitude, longitude)` in degrees.
+pub fn destination_point(lat: f64, lon: f64, bearing_deg: f64, distance_nm: f64) -> (f64, f64) {
     // Convert heading to radians (0 = north, clockwise positive)
-    let heading_rad = (heading_deg as f64).to_radians();
+    let heading_rad = bearing_deg.to_radians();
 
     // Calculate angular distance
     let angular_distance = distance_nm / EARTH_RADIUS_NM;
@@ -106,6 +136,32 @@ pub fn predict_position(
     (lat2.to_degrees(), lon2.to_degrees())
 }
 
+// =============================================================================
+// Antimeridian
+// =============================================================================
+
+/// Wrap a longitude into [-180, 180).
+pub fn wrap_longitude(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Signed shortest longitude difference `to - from`, in [-180, 180).
+/// 179 -> -179 is +2, not -358.
+pub fn longitude_delta(from: f64, to: f64) -> f64 {
+    wrap_longitude(to - from)
+}
+
+/// Width of the Web Mercator world in pixels at `zoom_level`.
+pub fn world_width_pixels(tile_size: TileSize, zoom_level: ZoomLevel) -> f64 {
+    tile_size.to_pixels() as f64 * (1u64 << zoom_level.to_u8()) as f64
+}
+
+/// Shift a horizontal pixel offset by whole world widths so it lands within
+/// half a world of zero: the nearest copy of a point across the antimeridian.
+pub fn wrap_pixel_offset(dx: f64, world_width: f64) -> f64 {
+    dx - world_width * (dx / world_width).round()
+}
+
 // =============================================================================
 // Coordinate Converter
 // =============================================================================
@@ -114,9 +170,13 @@ pub fn predict_position(
 /// conversion boilerplate. Construct one per frame/system from
 /// `SlippyTilesSettings` and `MapState`, then use `latlon_to_world` to
 /// convert geographic coordinates to Bevy world-space positions.
+///
+/// Points are placed on the copy of the world nearest the reference, so
+/// positions stay continuous across the antimeridian.
 pub struct CoordinateConverter {
     reference_pixel: (f64, f64),
     zoom_level: ZoomLevel,
+    world_width: f64,
 }
 
 impl CoordinateConverter {
@@ -134,6 +194,7 @@ impl CoordinateConverter {
         Self {
             reference_pixel,
             zoom_level,
+            world_width: world_width_pixels(crate::constants::DEFAULT_TILE_SIZE, zoom_level),
         }
     }
 
@@ -146,8 +207,317 @@ impl CoordinateConverter {
         };
         let pixel = world_coords_to_world_pixel(&ll, crate::constants::DEFAULT_TILE_SIZE, self.zoom_level);
         Vec2::new(
-            (pixel.0 - self.reference_pixel.0) as f32,
+            wrap_pixel_offset(pixel.0 - self.reference_pixel.0, self.world_width) as f32,
             (pixel.1 - self.reference_pixel.1) as f32,
         )
     }
+
+    /// Convert a Bevy world-space position back to latitude/longitude, with
+    /// the longitude wrapped into [-180, 180).
+    pub fn world_to_latlon(&self, pos: Vec2) -> (f64, f64) {
+        let ll = world_pixel_to_world_coords(
+            pos.x as f64 + self.reference_pixel.0,
+            pos.y as f64 + self.reference_pixel.1,
+            crate::constants::DEFAULT_TILE_SIZE,
+            self.zoom_level,
+        );
+        (ll.latitude, wrap_longitude(ll.longitude))
+    }
+}
+
+// =============================================================================
+// Magnetic Variation
+// =============================================================================
+
+/// Maximum spherical-harmonic degree of the bundled field model
+const MAG_MODEL_DEGREE: usize = 6;
+
+/// IGRF-13 main-field Gauss coefficients (epoch 2020.0, nT) up to degree 6,
+/// as `(n, m, g, h)`. Truncating the model this early keeps declination
+/// within a couple of degrees of the full model at populated latitudes,
+/// which is plenty for displaying bearings. Secular variation is ignored.
+const MAG_COEFFICIENTS: [(usize, usize, f64, f64); 27] = [
+    (1, 0, -29404.8, 0.0), (1, 1, -1450.9, 4652.5),
+    (2, 0, -2499.6, 0.0), (2, 1, 2982.0, -2991.6), (2, 2, 1677.0, -734.6),
+    (3, 0, 1363.2, 0.0), (3, 1, -2381.2, -82.1), (3, 2, 1236.2, 241.9), (3, 3, 525.7, -543.4),
+    (4, 0, 903.0, 0.0), (4, 1, 809.5, 281.9), (4, 2, 86.3, -158.4), (4, 3, -309.4, 199.7), (4, 4, 48.0, -349.7),
+    (5, 0, -234.3, 0.0), (5, 1, 363.2, 47.7), (5, 2, 187.8, 208.3), (5, 3, -140.7, -121.2), (5, 4, -151.2, 32.3),
+    (5, 5, 13.5, 98.9),
+    (6, 0, 66.0, 0.0), (6, 1, 65.5, -19.1), (6, 2, 72.9, 25.1), (6, 3, -121.5, 52.8), (6, 4, -36.2, -64.5),
+    (6, 5, 13.5, 8.9), (6, 6, -64.7, 68.1),
+];
+
+/// Schmidt semi-normalized associated Legendre functions P[n][m](cos θ).
+fn schmidt_legendre(x: f64) -> [[f64; MAG_MODEL_DEGREE + 1]; MAG_MODEL_DEGREE + 1] {
+    let s = (1.0 - x * x).max(0.0).sqrt();
+    let mut p = [[0.0; MAG_MODEL_DEGREE + 1]; MAG_MODEL_DEGREE + 1];
+    for m in 0..=MAG_MODEL_DEGREE {
+        let mut pmm = 1.0;
+        for k in 1..=m {
+            pmm *= (2 * k - 1) as f64 * s;
+        }
+        p[m][m] = pmm;
+        if m < MAG_MODEL_DEGREE {
+            p[m + 1][m] = x * (2 * m + 1) as f64 * pmm;
+        }
+        for n in (m + 2)..=MAG_MODEL_DEGREE {
+            p[n][m] = ((2 * n - 1) as f64 * x * p[n - 1][m] - (n + m - 1) as f64 * p[n - 2][m]) / (n - m) as f64;
+        }
+    }
+    let factorial = |k: usize| (1..=k).map(|i| i as f64).product::<f64>();
+    for (n, row) in p.iter_mut().enumerate() {
+        for (m, value) in row.iter_mut().enumerate().take(n + 1).skip(1) {
+            *value *= (2.0 * factorial(n - m) / factorial(n + m)).sqrt();
+        }
+    }
+    p
+}
+
+/// Scalar potential (over the Earth radius) at colatitude `theta` and
+/// longitude `lambda`, both in radians, on the reference sphere.
+fn magnetic_potential(theta: f64, lambda: f64) -> f64 {
+    let p = schmidt_legendre(theta.cos());
+    MAG_COEFFICIENTS
+        .iter()
+        .map(|&(n, m, g, h)| {
+            let ml = m as f64 * lambda;
+            (g * ml.cos() + h * ml.sin()) * p[n][m]
+        })
+        .sum()
+}
+
+/// Magnetic declination in degrees (east positive) at a location, from a
+/// coarse spherical-harmonic field model. Latitude is clamped short of the
+/// poles where declination is undefined.
+pub fn magnetic_declination(lat: f64, lon: f64) -> f64 {
+    let theta = (90.0 - lat.clamp(-89.0, 89.0)).to_radians();
+    let lambda = lon.to_radians();
+    let eps = 1e-5;
+    // North component: dV/dθ; east component: -(1/sin θ) dV/dλ
+    let north = (magnetic_potential(theta + eps, lambda) - magnetic_potential(theta - eps, lambda)) / (2.0 * eps);
+    let east = -(magnetic_potential(theta, lambda + eps) - magnetic_potential(theta, lambda - eps))
+        / (2.0 * eps)
+        / theta.sin();
+    east.atan2(north).to_degrees()
+}
+
+/// Which north bearings are displayed against.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NorthReference {
+    #[default]
+    True,
+    Magnetic,
+}
+
+impl NorthReference {
+    pub const ALL: [NorthReference; 2] = [NorthReference::True, NorthReference::Magnetic];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NorthReference::True => "True",
+            NorthReference::Magnetic => "Magnetic",
+        }
+    }
+
+    /// Suffix shown after bearings ("T" or "M").
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            NorthReference::True => "T",
+            NorthReference::Magnetic => "M",
+        }
+    }
+
+    /// Convert a true bearing to this reference, using the declination at
+    /// `(lat, lon)`. Result is in 0..360.
+    pub fn bearing(&self, true_bearing: f64, lat: f64, lon: f64) -> f64 {
+        match self {
+            NorthReference::True => true_bearing.rem_euclid(360.0),
+            NorthReference::Magnetic => (true_bearing - magnetic_declination(lat, lon)).rem_euclid(360.0),
+        }
+    }
+
+    /// Format a true bearing for display, e.g. `045°M`.
+    pub fn format_bearing(&self, true_bearing: f64, lat: f64, lon: f64) -> String {
+        format!("{:03.0}\u{00B0}{}", self.bearing(true_bearing, lat, lon), self.suffix())
+    }
+}
+
+/// How latitude/longitude pairs are displayed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoordinateFormat {
+    /// `47.4490, -122.3093`
+    #[default]
+    Decimal,
+    /// `N47°26.94' W122°18.56'`
+    DegreesMinutes,
+    /// `N47°26'56" W122°18'33"`
+    DegreesMinutesSeconds,
+}
+
+impl CoordinateFormat {
+    pub const ALL: [CoordinateFormat; 3] = [
+        CoordinateFormat::Decimal,
+        CoordinateFormat::DegreesMinutes,
+        CoordinateFormat::DegreesMinutesSeconds,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CoordinateFormat::Decimal => "Decimal degrees",
+            CoordinateFormat::DegreesMinutes => "Degrees, minutes",
+            CoordinateFormat::DegreesMinutesSeconds => "Degrees, minutes, seconds",
+        }
+    }
+}
+
+/// Format one coordinate with a hemisphere prefix, in degrees and decimal
+/// minutes or in degrees, minutes and seconds. Rounding is done on the
+/// smallest unit first so 59.999' carries into the degrees instead of
+/// printing as 60.00'.
+fn format_angle(value: f64, positive: char, negative: char, with_seconds: bool) -> String {
+    let hemisphere = if value < 0.0 { negative } else { positive };
+    let value = value.abs();
+    if with_seconds {
+        let seconds = (value * 3600.0).round() as u64;
+        format!("{}{}\u{00B0}{:02}'{:02}\"", hemisphere, seconds / 3600, seconds / 60 % 60, seconds % 60)
+    } else {
+        let hundredths = (value * 6000.0).round() as u64;
+        format!("{}{}\u{00B0}{:02}.{:02}'", hemisphere, hundredths / 6000, hundredths / 100 % 60, hundredths % 100)
+    }
+}
+
+/// Format a position for display in the chosen style, e.g. `47.4490, -122.3093`
+/// or `N47°26.94' W122°18.56'`.
+pub fn format_latlon(lat: f64, lon: f64, fmt: CoordinateFormat) -> String {
+    match fmt {
+        CoordinateFormat::Decimal => format!("{:.4}, {:.4}", lat, lon),
+        CoordinateFormat::DegreesMinutes | CoordinateFormat::DegreesMinutesSeconds => {
+            let with_seconds = fmt == CoordinateFormat::DegreesMinutesSeconds;
+            format!("{} {}", format_angle(lat, 'N', 'S', with_seconds), format_angle(lon, 'E', 'W', with_seconds))
+        }
+    }
+}
+
+/// Whether positions are read out as lat/lon, as bearing and distance from
+/// the receiver (home location), or both.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PositionReadout {
+    #[default]
+    LatLon,
+    FromReceiver,
+    Both,
+}
+
+impl PositionReadout {
+    pub const ALL: [PositionReadout; 3] = [
+        PositionReadout::LatLon,
+        PositionReadout::FromReceiver,
+        PositionReadout::Both,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PositionReadout::LatLon => "Lat/lon",
+            PositionReadout::FromReceiver => "Bearing/distance from receiver",
+            PositionReadout::Both => "Both",
+        }
+    }
+}
+
+/// Format a position as bearing and distance from the receiver, e.g.
+/// `045°T 12.3 NM`.
+pub fn format_from_receiver(lat: f64, lon: f64, receiver: (f64, f64), north: NorthReference) -> String {
+    let (rx_lat, rx_lon) = receiver;
+    let bearing = initial_bearing(rx_lat, rx_lon, lat, lon);
+    let distance = haversine_distance_nm(rx_lat, rx_lon, lat, lon);
+    format!("{} {:.1} NM", north.format_bearing(bearing, rx_lat, rx_lon), distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longitudes_wrap_across_the_antimeridian() {
+        assert_eq!(wrap_longitude(181.0), -179.0);
+        assert_eq!(wrap_longitude(-181.0), 179.0);
+        assert_eq!(wrap_longitude(-122.5), -122.5);
+        assert!((longitude_delta(179.0, -179.0) - 2.0).abs() < 1e-9);
+        assert!((longitude_delta(-179.0, 179.0) + 2.0).abs() < 1e-9);
+        assert!((longitude_delta(10.0, 20.0) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn converter_places_points_across_the_seam_nearby() {
+        let tile_settings = SlippyTilesSettings {
+            reference_latitude: 0.0,
+            reference_longitude: 179.0,
+            ..default()
+        };
+        let converter = CoordinateConverter::new(&tile_settings, ZoomLevel::L8);
+        let west = converter.latlon_to_world(0.0, 178.0);
+        let east = converter.latlon_to_world(0.0, -179.0);
+        // -179 is 2 degrees east of 179, not 358 degrees west
+        assert!(east.x > 0.0);
+        assert!((east.x + west.x).abs() < 1.0);
+        let two_degrees = world_width_pixels(crate::constants::DEFAULT_TILE_SIZE, ZoomLevel::L8) / 180.0;
+        assert!((east.x as f64 - two_degrees).abs() < 1.0);
+
+        let (lat, lon) = converter.world_to_latlon(east);
+        assert!(lat.abs() < 1e-6);
+        assert!((lon + 179.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn declination_matches_known_locations() {
+        // Reference values from the full model, epoch 2020
+        for (lat, lon, expected) in [
+            (37.7, -97.3, 3.3),    // Wichita
+            (40.7, -74.0, -12.9),  // New York
+            (51.5, -0.1, 0.3),     // London
+            (47.6, -122.3, 15.2),  // Seattle
+            (-33.9, 151.2, 12.8),  // Sydney
+        ] {
+            let d = magnetic_declination(lat, lon);
+            assert!((d - expected).abs() < 2.0, "({}, {}): {:.1} vs {:.1}", lat, lon, d, expected);
+        }
+    }
+
+    #[test]
+    fn magnetic_bearing_subtracts_east_declination() {
+        let (lat, lon) = (47.6, -122.3);
+        let d = magnetic_declination(lat, lon);
+        let magnetic = NorthReference::Magnetic.bearing(90.0, lat, lon);
+        assert!((magnetic - (90.0 - d)).abs() < 1e-9);
+        // Wraps below north
+        assert!(NorthReference::Magnetic.bearing(5.0, lat, lon) > 340.0);
+        assert_eq!(NorthReference::True.format_bearing(45.0, lat, lon), "045\u{00B0}T");
+    }
+
+    #[test]
+    fn format_latlon_in_each_style() {
+        let (lat, lon) = (40.446195, -79.948862);
+        assert_eq!(format_latlon(lat, lon, CoordinateFormat::Decimal), "40.4462, -79.9489");
+        assert_eq!(
+            format_latlon(lat, lon, CoordinateFormat::DegreesMinutes),
+            "N40\u{00B0}26.77' W79\u{00B0}56.93'"
+        );
+        assert_eq!(
+            format_latlon(lat, lon, CoordinateFormat::DegreesMinutesSeconds),
+            "N40\u{00B0}26'46\" W79\u{00B0}56'56\""
+        );
+        // Minutes that round up carry into the degrees
+        assert_eq!(
+            format_latlon(-33.99999, 151.0, CoordinateFormat::DegreesMinutes),
+            "S34\u{00B0}00.00' E151\u{00B0}00.00'"
+        );
+    }
+
+    #[test]
+    fn format_from_receiver_gives_bearing_and_distance() {
+        // One degree of latitude due north is 60 NM
+        assert_eq!(format_from_receiver(48.0, -122.0, (47.0, -122.0), NorthReference::True), "000\u{00B0}T 60.0 NM");
+        assert_eq!(format_from_receiver(47.0, -122.0, (47.0, -122.0), NorthReference::True), "000\u{00B0}T 0.0 NM");
+        assert!(format_from_receiver(47.0, -121.0, (47.0, -122.0), NorthReference::True).starts_with("090"));
+    }
 }
@@ -0,0 +1,182 @@
+/// Quick "go to aircraft" command box.
+///
+/// `/` opens a small text box over the map; typing an ICAO address or
+/// callsign and pressing Enter selects that aircraft and centers the map on
+/// it, the same as picking it from the list and pressing C.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::adsb::normalize_icao;
+use crate::aircraft::AircraftListState;
+use crate::dock::DockTreeState;
+use crate::keyboard::{KeyBindings, ShortcutAction};
+use crate::theme::{AppTheme, to_egui_color32, to_egui_color32_alpha};
+use crate::{Aircraft, MapState};
+
+/// Width of the command box in pixels
+const GOTO_BOX_WIDTH: f32 = 220.0;
+
+#[derive(Resource, Default)]
+pub struct GotoBoxState {
+    pub open: bool,
+    pub query: String,
+    /// Result of the last lookup, e.g. "UAL123 not visible"
+    pub message: Option<String>,
+    /// Give the text field keyboard focus on the next frame
+    focus_requested: bool,
+}
+
+/// Aircraft matching `query` as an ICAO address, or failing that as a
+/// callsign (case-insensitive, ignoring padding).
+pub fn find_aircraft<'a>(query: &str, mut aircraft: impl Iterator<Item = &'a Aircraft> + Clone) -> Option<&'a Aircraft> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+    if let Some(icao) = normalize_icao(query) {
+        if let Some(found) = aircraft.clone().find(|a| a.icao == icao) {
+            return Some(found);
+        }
+    }
+    aircraft.find(|a| a.callsign.as_deref().is_some_and(|c| c.trim().eq_ignore_ascii_case(query)))
+}
+
+/// System that opens the command box on its shortcut (`/` by default).
+pub fn open_goto_box(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut contexts: EguiContexts,
+    mut goto: ResMut<GotoBoxState>,
+) {
+    if let Ok(ctx) = contexts.ctx_mut() {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+    }
+    if bindings.just_pressed(ShortcutAction::GotoAircraft, &keyboard) {
+        goto.open = true;
+        goto.query.clear();
+        goto.message = None;
+        goto.focus_requested = true;
+    }
+}
+
+/// System that draws the command box at the top of the map and, on Enter,
+/// selects and centers the matching aircraft.
+pub fn render_goto_box(
+    mut contexts: EguiContexts,
+    mut goto: ResMut<GotoBoxState>,
+    mut list_state: ResMut<AircraftListState>,
+    mut map_state: ResMut<MapState>,
+    dock_state: Res<DockTreeState>,
+    theme: Res<AppTheme>,
+    aircraft_query: Query<&Aircraft>,
+) {
+    if !goto.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Some(map_rect) = dock_state.map_viewport_rect else {
+        return;
+    };
+
+    let bg_color = to_egui_color32_alpha(theme.bg_secondary(), 230);
+    let dim_color = to_egui_color32(theme.text_dim());
+    let warn_color = to_egui_color32(theme.text_warn());
+    let pos = egui::pos2(map_rect.center().x - GOTO_BOX_WIDTH / 2.0, map_rect.top() + 12.0);
+
+    egui::Area::new(egui::Id::new("goto_aircraft_box"))
+        .fixed_pos(pos)
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::NONE
+                .fill(bg_color)
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(egui::Margin::same(8))
+                .show(ui, |ui| {
+                    ui.set_width(GOTO_BOX_WIDTH);
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut goto.query)
+                            .hint_text("ICAO or callsign")
+                            .desired_width(f32::INFINITY),
+                    );
+                    if goto.focus_requested {
+                        response.request_focus();
+                        goto.focus_requested = false;
+                    }
+
+                    if response.lost_focus() {
+                        if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                            match find_aircraft(&goto.query, aircraft_query.iter()) {
+                                Some(aircraft) => {
+                                    list_state.selected_icao = Some(aircraft.icao.clone());
+                                    list_state.scroll_to_selected = true;
+                                    map_state.latitude = aircraft.latitude;
+                                    map_state.longitude = aircraft.longitude;
+                                    goto.open = false;
+                                }
+                                None => {
+                                    goto.message = Some(format!("{} not visible", goto.query.trim()));
+                                    goto.focus_requested = true;
+                                }
+                            }
+                        } else {
+                            // Escape or clicking away dismisses the box
+                            goto.open = false;
+                        }
+                    }
+
+                    match goto.message {
+                        Some(ref message) => {
+                            ui.label(egui::RichText::new(message).color(warn_color).size(10.0));
+                        }
+                        None => {
+                            ui.label(egui::RichText::new("Enter to go, Esc to close").color(dim_color).size(10.0));
+                        }
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aircraft(icao: &str, callsign: Option<&str>) -> Aircraft {
+        Aircraft {
+            icao: icao.to_string(),
+            callsign: callsign.map(str::to_string),
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: None,
+            barometric_altitude: None,
+            geometric_altitude: None,
+            heading: None,
+            velocity: None,
+            vertical_rate: None,
+            squawk: None,
+            is_on_ground: None,
+            alert: None,
+            emergency: None,
+            spi: None,
+            last_seen: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn finds_by_icao_then_callsign() {
+        let traffic = [
+            aircraft("A1B2C3", Some("UAL123  ")),
+            aircraft("ABCDEF", Some("A1B2C3")),
+            aircraft("123456", None),
+        ];
+        assert_eq!(find_aircraft(" a1b2c3 ", traffic.iter()).unwrap().icao, "A1B2C3");
+        assert_eq!(find_aircraft("ual123", traffic.iter()).unwrap().icao, "A1B2C3");
+        assert_eq!(find_aircraft("123456", traffic.iter()).unwrap().icao, "123456");
+        assert!(find_aircraft("DAL9", traffic.iter()).is_none());
+        assert!(find_aircraft("  ", traffic.iter()).is_none());
+    }
+}
@@ -7,6 +7,7 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
+use crate::config::AppConfig;
 use crate::dock::DockTreeState;
 use crate::theme::{AppTheme, to_egui_color32, to_egui_color32_alpha};
 use crate::view3d::View3DState;
@@ -36,6 +37,7 @@ pub fn render_camera_hud(
     dock_state: Res<DockTreeState>,
     theme: Res<AppTheme>,
     hud_state: Res<HudState>,
+    app_config: Res<AppConfig>,
 ) {
     if !view3d.is_3d_active() || !hud_state.visible {
         return;
@@ -85,6 +87,12 @@ pub fn render_camera_hud(
                                 .color(text_color)
                                 .monospace(),
                         );
+                        ui.label(
+                            egui::RichText::new("GRID NORTH")
+                                .size(LABEL_SIZE)
+                                .color(dim_color)
+                                .monospace(),
+                        );
                     });
 
                     ui.add_space(2.0);
@@ -127,6 +135,36 @@ pub fn render_camera_hud(
                                 .monospace(),
                         );
                     });
+
+                    // -- Ground point under the cursor --
+                    if let Some((lat, lon)) = view3d.cursor_ground {
+                        ui.add_space(2.0);
+                        ui.vertical_centered(|ui| {
+                            ui.label(
+                                egui::RichText::new("CURSOR")
+                                    .size(LABEL_SIZE)
+                                    .color(dim_color)
+                                    .monospace(),
+                            );
+                            // Wraps between latitude and longitude in the narrow HUD
+                            ui.add(
+                                egui::Label::new(
+                                    egui::RichText::new(app_config.map.format_position(lat, lon))
+                                        .size(LABEL_SIZE)
+                                        .color(text_color)
+                                        .monospace(),
+                                )
+                                .wrap(),
+                            );
+                            let elevation = view3d.cursor_elevation_ft.unwrap_or(view3d.ground_elevation_ft);
+                            ui.label(
+                                egui::RichText::new(format!("ELEV {} ft", format_altitude(elevation)))
+                                    .size(LABEL_SIZE)
+                                    .color(dim_color)
+                                    .monospace(),
+                            );
+                        });
+                    }
                 });
         });
 }
@@ -0,0 +1,106 @@
+//! Low-power idle mode.
+//!
+//! The winit loop normally redraws continuously while focused. With
+//! `PowerConfig::idle_mode` on, the app switches to reactive updates when
+//! the window is in the background or no aircraft has updated for a while,
+//! and goes back to full rate as soon as it is focused again or new data
+//! arrives. Input events always wake the loop immediately.
+
+use bevy::prelude::*;
+use bevy::winit::{UpdateMode, WinitSettings};
+use chrono::{DateTime, Utc};
+use std::time::{Duration, Instant};
+
+use crate::config::{AppConfig, PowerConfig};
+use crate::Aircraft;
+
+/// Redraw interval while unfocused with idle mode off: keeps ADS-B data
+/// flowing without overwhelming the GPU or triggering macOS throttling.
+pub const UNFOCUSED_FRAME_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Tracks feed activity and whether the app is currently idling.
+#[derive(Resource)]
+pub struct IdleState {
+    pub idle: bool,
+    /// Newest `last_seen` across all aircraft at the last check
+    newest_seen: Option<DateTime<Utc>>,
+    /// When the newest update last moved forward
+    last_activity: Instant,
+}
+
+impl Default for IdleState {
+    fn default() -> Self {
+        Self {
+            idle: false,
+            newest_seen: None,
+            last_activity: Instant::now(),
+        }
+    }
+}
+
+/// Whether the app should idle: only with idle mode on, and then whenever
+/// the window is unfocused or the feed has been quiet for the configured time.
+pub fn should_idle(power: &PowerConfig, focused: bool, quiet_for: Duration) -> bool {
+    power.idle_mode && (!focused || quiet_for >= Duration::from_secs(power.idle_after_secs))
+}
+
+/// System that watches focus and feed activity and switches the winit
+/// update mode between full rate and idle.
+pub fn update_idle_mode(
+    app_config: Res<AppConfig>,
+    mut state: ResMut<IdleState>,
+    mut winit: ResMut<WinitSettings>,
+    windows: Query<&Window>,
+    aircraft_query: Query<&Aircraft>,
+) {
+    let newest = aircraft_query.iter().map(|a| a.last_seen).max();
+    if newest != state.newest_seen {
+        state.newest_seen = newest;
+        state.last_activity = Instant::now();
+    }
+
+    let power = &app_config.power;
+    let focused = windows.iter().any(|w| w.focused);
+    let idle = should_idle(power, focused, state.last_activity.elapsed());
+    if idle != state.idle {
+        state.idle = idle;
+        if idle {
+            info!("Entering idle mode ({})", if focused { "feed quiet" } else { "window unfocused" });
+        } else {
+            info!("Leaving idle mode");
+        }
+    }
+
+    let idle_interval = Duration::from_millis(power.idle_frame_interval_ms);
+    let focused_mode = if idle && focused {
+        UpdateMode::reactive(idle_interval)
+    } else {
+        UpdateMode::Continuous
+    };
+    let unfocused_mode = UpdateMode::reactive(if power.idle_mode {
+        idle_interval
+    } else {
+        UNFOCUSED_FRAME_INTERVAL
+    });
+    if winit.focused_mode != focused_mode || winit.unfocused_mode != unfocused_mode {
+        winit.focused_mode = focused_mode;
+        winit.unfocused_mode = unfocused_mode;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idles_only_when_enabled_and_unfocused_or_quiet() {
+        let mut power = PowerConfig::default();
+        let quiet = Duration::from_secs(power.idle_after_secs);
+        assert!(!should_idle(&power, false, quiet));
+
+        power.idle_mode = true;
+        assert!(should_idle(&power, false, Duration::ZERO));
+        assert!(should_idle(&power, true, quiet));
+        assert!(!should_idle(&power, true, quiet - Duration::from_secs(1)));
+    }
+}
@@ -7,7 +7,7 @@ use crate::dock;
 use crate::map::{MapState, ZoomState};
 use crate::tiles::{compute_tile_radius, request_tiles_at_location};
 use crate::view3d;
-use crate::{clamp_latitude, clamp_longitude};
+use crate::{clamp_latitude, normalize_longitude};
 
 // =============================================================================
 // Resources
@@ -85,6 +85,7 @@ pub(crate) fn handle_pan_drag(
     window_query: Query<&Window>,
     egui_wants: Res<EguiWantsPointer>,
     view3d_state: Res<view3d::View3DState>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
     let Ok(window) = window_query.single() else {
         return;
@@ -160,13 +161,13 @@ pub(crate) fn handle_pan_drag(
 
                 // Update map coordinates
                 map_state.latitude = clamp_latitude(new_center_geo.latitude);
-                map_state.longitude = clamp_longitude(new_center_geo.longitude);
+                map_state.longitude = normalize_longitude(new_center_geo.longitude, app_config.map.wrap_antimeridian);
 
                 // Request tiles periodically during drag to fill visible area
                 let should_request = match drag_state.last_tile_request_coords {
                     Some((last_lat, last_lon)) => {
                         let lat_diff = (map_state.latitude - last_lat).abs();
-                        let lon_diff = (map_state.longitude - last_lon).abs();
+                        let lon_diff = crate::geo::longitude_delta(last_lon, map_state.longitude).abs();
                         lat_diff > constants::PAN_TILE_REQUEST_THRESHOLD
                             || lon_diff > constants::PAN_TILE_REQUEST_THRESHOLD
                     }
@@ -178,6 +179,7 @@ pub(crate) fn handle_pan_drag(
                         window.height(),
                         zoom_state.camera_zoom,
                         Some(&view3d_state),
+                        app_config.map.tile_detail,
                     );
                     download_events.write(DownloadSlippyTilesMessage {
                         tile_size: crate::constants::DEFAULT_TILE_SIZE,
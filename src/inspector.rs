@@ -8,14 +8,18 @@ use bevy::prelude::*;
 use bevy_egui::egui;
 use bevy_inspector_egui::bevy_inspector;
 
+use crate::aircraft::{AircraftTypeInfo, TrailHistory};
 use crate::debug_panel::DebugPanelState;
 use crate::map::{MapState, ZoomState};
 use crate::view3d::View3DState;
+use crate::Aircraft;
 
 /// Resource controlling inspector visibility (kept for compatibility).
 #[derive(Resource, Default)]
 pub struct InspectorState {
     pub open: bool,
+    /// Entity last clicked on the map, shown in the "Selected Entity" section
+    pub selected_entity: Option<Entity>,
 }
 
 /// Render inspector content into a bare `egui::Ui` (for dock/tab usage).
@@ -23,6 +27,15 @@ pub struct InspectorState {
 /// This requires `&mut World` because `bevy_inspector_egui` functions
 /// inspect and mutate ECS data directly.
 pub fn render_inspector_pane_content(world: &mut World, ui: &mut egui::Ui) {
+    // Section 0: Entity clicked on the map (open by default)
+    egui::CollapsingHeader::new("Selected Entity")
+        .default_open(true)
+        .show(ui, |ui| {
+            render_selected_entity(world, ui);
+        });
+
+    ui.separator();
+
     // Section 1: Curated app resources (open by default)
     egui::CollapsingHeader::new("App Resources")
         .default_open(true)
@@ -78,3 +91,93 @@ pub fn render_inspector_pane_content(world: &mut World, ui: &mut egui::Ui) {
             bevy_inspector::ui_for_all_assets(world, ui);
         });
 }
+
+/// Render a summary of the clicked entity's key components followed by the
+/// full component tree from `bevy_inspector_egui`.
+fn render_selected_entity(world: &mut World, ui: &mut egui::Ui) {
+    let selected = world
+        .get_resource::<InspectorState>()
+        .and_then(|state| state.selected_entity);
+
+    let Some(entity) = selected else {
+        ui.label(egui::RichText::new("Click an aircraft on the map to inspect it")
+            .color(egui::Color32::GRAY));
+        return;
+    };
+
+    if world.get_entity(entity).is_err() {
+        ui.label(egui::RichText::new(format!("{entity} has been despawned"))
+            .color(egui::Color32::GRAY));
+        if ui.button("Clear").clicked() {
+            world.resource_mut::<InspectorState>().selected_entity = None;
+        }
+        return;
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(format!("{entity}")).monospace());
+        if ui.small_button("Clear").clicked() {
+            world.resource_mut::<InspectorState>().selected_entity = None;
+        }
+    });
+
+    egui::Grid::new("inspector_selected_entity")
+        .num_columns(2)
+        .spacing([12.0, 2.0])
+        .show(ui, |ui| {
+            if let Some(aircraft) = world.get::<Aircraft>(entity) {
+                let opt = |v: Option<String>| v.unwrap_or_else(|| "-".to_string());
+                let rows = [
+                    ("ICAO", aircraft.icao.clone()),
+                    ("Callsign", opt(aircraft.callsign.clone())),
+                    ("Position", format!("{:.5}, {:.5}", aircraft.latitude, aircraft.longitude)),
+                    ("Altitude", opt(aircraft.altitude.map(|a| format!("{a} ft")))),
+                    ("Heading", opt(aircraft.heading.map(|h| format!("{h:.0}°")))),
+                    ("Speed", opt(aircraft.velocity.map(|v| format!("{v:.0} kt")))),
+                    ("Vertical rate", opt(aircraft.vertical_rate.map(|v| format!("{v} ft/min")))),
+                    ("Squawk", opt(aircraft.squawk.clone())),
+                    ("On ground", opt(aircraft.is_on_ground.map(|g| g.to_string()))),
+                    ("Last seen", aircraft.last_seen.format("%H:%M:%S UTC").to_string()),
+                ];
+                for (name, value) in rows {
+                    ui.label(name);
+                    ui.label(egui::RichText::new(value).monospace());
+                    ui.end_row();
+                }
+            }
+
+            if let Some(trail) = world.get::<TrailHistory>(entity) {
+                ui.label("Trail points");
+                ui.label(egui::RichText::new(trail.points.len().to_string()).monospace());
+                ui.end_row();
+            }
+
+            match world.get::<AircraftTypeInfo>(entity) {
+                Some(info) => {
+                    let rows = [
+                        ("Registration", &info.registration),
+                        ("Type", &info.type_code),
+                        ("Model", &info.manufacturer_model),
+                        ("Operator", &info.operator),
+                    ];
+                    for (name, value) in rows {
+                        ui.label(name);
+                        ui.label(egui::RichText::new(value.as_deref().unwrap_or("-")).monospace());
+                        ui.end_row();
+                    }
+                }
+                None => {
+                    ui.label("Type info");
+                    ui.label(egui::RichText::new("not attached").color(egui::Color32::GRAY));
+                    ui.end_row();
+                }
+            }
+        });
+
+    egui::CollapsingHeader::new("All Components")
+        .id_salt("inspector_selected_components")
+        .default_open(false)
+        .show(ui, |ui| {
+            bevy_inspector::ui_for_entity(world, entity, ui);
+        });
+}
@@ -1,13 +1,325 @@
 use bevy::prelude::*;
 use bevy_egui::EguiContexts;
 
-use crate::aircraft::{AircraftListState, DetailPanelState, CameraFollowState, StatsPanelState};
+use crate::aircraft::{AircraftDisplayList, AircraftListState, DetailPanelState, CameraFollowState, StatsPanelState};
 use crate::config::{AppConfig, SettingsUiState};
 use crate::debug_3d_hud::Debug3DHudState;
 use crate::hud::HudState;
+use crate::nearby_hud::NearbyHudState;
 use crate::ui_panels::{UiPanelManager, PanelId};
+use crate::zoom::FitAllAircraftRequest;
 use crate::{MapState, ZoomState, Aircraft};
 
+/// Feature group a shortcut is listed under in the help overlay
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShortcutGroup {
+    Panels,
+    Aircraft,
+    Map,
+    View3D,
+    Tools,
+}
+
+impl ShortcutGroup {
+    pub const ALL: &[ShortcutGroup] = &[
+        ShortcutGroup::Panels,
+        ShortcutGroup::Aircraft,
+        ShortcutGroup::Map,
+        ShortcutGroup::View3D,
+        ShortcutGroup::Tools,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShortcutGroup::Panels => "Panels",
+            ShortcutGroup::Aircraft => "Aircraft",
+            ShortcutGroup::Map => "Map",
+            ShortcutGroup::View3D => "3D View",
+            ShortcutGroup::Tools => "Tools",
+        }
+    }
+}
+
+/// Actions that can be bound to a key
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShortcutAction {
+    ToggleAircraftList,
+    ToggleDetail,
+    ToggleStatistics,
+    ToggleBookmarks,
+    ToggleExport,
+    ToggleCoverage,
+    ToggleCoverageStats,
+    ToggleAirspace,
+    ToggleDataSources,
+    ToggleIngest,
+    ToggleHelp,
+    ToggleDebugPanel,
+    ToggleInspector,
+    ToggleFocusMode,
+    ToggleRecording,
+    Cancel,
+    FollowSelected,
+    CenterSelected,
+    NextAircraft,
+    PreviousAircraft,
+    GotoAircraft,
+    ZoomIn,
+    ZoomOut,
+    ResetView,
+    FitAllAircraft,
+    ToggleAirports,
+    ToggleTrails,
+    ToggleWeather,
+    Toggle3DView,
+    TogglePlanView,
+    ToggleHud,
+    ToggleDebug3D,
+    ToggleMeasurement,
+}
+
+impl ShortcutAction {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ShortcutAction::ToggleAircraftList => "Toggle aircraft list",
+            ShortcutAction::ToggleDetail => "Toggle detail panel",
+            ShortcutAction::ToggleStatistics => "Toggle statistics",
+            ShortcutAction::ToggleBookmarks => "Toggle bookmarks",
+            ShortcutAction::ToggleExport => "Export data panel",
+            ShortcutAction::ToggleCoverage => "Toggle coverage tracking",
+            ShortcutAction::ToggleCoverageStats => "Coverage stats",
+            ShortcutAction::ToggleAirspace => "Toggle airspace",
+            ShortcutAction::ToggleDataSources => "Data sources panel",
+            ShortcutAction::ToggleIngest => "Toggle ingest panel",
+            ShortcutAction::ToggleHelp => "Toggle this help",
+            ShortcutAction::ToggleDebugPanel => "Toggle debug panel",
+            ShortcutAction::ToggleInspector => "Toggle inspector",
+            ShortcutAction::ToggleFocusMode => "Focus mode (hide all panels)",
+            ShortcutAction::ToggleRecording => "Record / stop recording",
+            ShortcutAction::Cancel => "Deselect / cancel follow",
+            ShortcutAction::FollowSelected => "Follow selected aircraft",
+            ShortcutAction::CenterSelected => "Center on selected",
+            ShortcutAction::NextAircraft => "Select next aircraft",
+            ShortcutAction::PreviousAircraft => "Select previous aircraft",
+            ShortcutAction::GotoAircraft => "Go to aircraft by ICAO / callsign",
+            ShortcutAction::ZoomIn => "Zoom in",
+            ShortcutAction::ZoomOut => "Zoom out",
+            ShortcutAction::ResetView => "Reset view",
+            ShortcutAction::FitAllAircraft => "Zoom to fit all aircraft",
+            ShortcutAction::ToggleAirports => "Toggle airports",
+            ShortcutAction::ToggleTrails => "Toggle trails",
+            ShortcutAction::ToggleWeather => "Toggle weather overlay",
+            ShortcutAction::Toggle3DView => "Toggle 3D view mode",
+            ShortcutAction::TogglePlanView => "Top-down north-up plan view (3D)",
+            ShortcutAction::ToggleHud => "Toggle camera / nearby traffic HUD",
+            ShortcutAction::ToggleDebug3D => "Toggle 3D debug overlay",
+            ShortcutAction::ToggleMeasurement => "Measurement mode",
+        }
+    }
+
+    pub fn group(&self) -> ShortcutGroup {
+        match self {
+            ShortcutAction::ToggleAircraftList
+            | ShortcutAction::ToggleDetail
+            | ShortcutAction::ToggleStatistics
+            | ShortcutAction::ToggleBookmarks
+            | ShortcutAction::ToggleDataSources
+            | ShortcutAction::ToggleIngest
+            | ShortcutAction::ToggleHelp
+            | ShortcutAction::ToggleDebugPanel
+            | ShortcutAction::ToggleInspector
+            | ShortcutAction::ToggleFocusMode => ShortcutGroup::Panels,
+            ShortcutAction::Cancel
+            | ShortcutAction::FollowSelected
+            | ShortcutAction::CenterSelected
+            | ShortcutAction::NextAircraft
+            | ShortcutAction::PreviousAircraft
+            | ShortcutAction::GotoAircraft => ShortcutGroup::Aircraft,
+            ShortcutAction::ZoomIn
+            | ShortcutAction::ZoomOut
+            | ShortcutAction::ResetView
+            | ShortcutAction::FitAllAircraft
+            | ShortcutAction::ToggleAirports
+            | ShortcutAction::ToggleTrails
+            | ShortcutAction::ToggleWeather
+            | ShortcutAction::ToggleAirspace
+            | ShortcutAction::ToggleCoverage
+            | ShortcutAction::ToggleCoverageStats => ShortcutGroup::Map,
+            ShortcutAction::Toggle3DView
+            | ShortcutAction::TogglePlanView
+            | ShortcutAction::ToggleHud
+            | ShortcutAction::ToggleDebug3D => ShortcutGroup::View3D,
+            ShortcutAction::ToggleMeasurement
+            | ShortcutAction::ToggleExport
+            | ShortcutAction::ToggleRecording => ShortcutGroup::Tools,
+        }
+    }
+}
+
+/// A key plus the modifiers that must be held with it. Modifiers must
+/// match exactly, so `V` and `Shift+V` are distinct chords.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyChord {
+    pub key: KeyCode,
+    pub shift: bool,
+    pub ctrl: bool,
+}
+
+impl KeyChord {
+    pub const fn key(key: KeyCode) -> Self {
+        Self { key, shift: false, ctrl: false }
+    }
+
+    pub const fn shift(key: KeyCode) -> Self {
+        Self { key, shift: true, ctrl: false }
+    }
+
+    pub const fn ctrl(key: KeyCode) -> Self {
+        Self { key, shift: false, ctrl: true }
+    }
+
+    pub fn just_pressed(&self, keyboard: &ButtonInput<KeyCode>) -> bool {
+        let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+        let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+        shift == self.shift && ctrl == self.ctrl && keyboard.just_pressed(self.key)
+    }
+
+    /// Human-readable form, e.g. "Shift+A", "Ctrl+R", "?".
+    pub fn label(&self) -> String {
+        if self.shift && self.key == KeyCode::Slash {
+            return if self.ctrl { "Ctrl+?".to_string() } else { "?".to_string() };
+        }
+        let key = match self.key {
+            KeyCode::Escape => "Esc".to_string(),
+            KeyCode::Equal => "=".to_string(),
+            KeyCode::Minus => "-".to_string(),
+            KeyCode::Slash => "/".to_string(),
+            KeyCode::Backquote => "`".to_string(),
+            KeyCode::NumpadAdd => "Num+".to_string(),
+            KeyCode::NumpadSubtract => "Num-".to_string(),
+            other => {
+                let name = format!("{:?}", other);
+                name.strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .map(str::to_string)
+                    .unwrap_or(name)
+            }
+        };
+        let mut label = String::new();
+        if self.ctrl {
+            label.push_str("Ctrl+");
+        }
+        if self.shift {
+            label.push_str("Shift+");
+        }
+        label.push_str(&key);
+        label
+    }
+}
+
+/// Runtime keyboard bindings. Every shortcut handler reads from this
+/// resource, so the help overlay always lists the bindings actually in effect.
+#[derive(Resource)]
+pub struct KeyBindings {
+    bindings: Vec<(ShortcutAction, Vec<KeyChord>)>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        use ShortcutAction as A;
+        Self {
+            bindings: vec![
+                (A::ToggleAircraftList, vec![KeyChord::key(KeyCode::KeyL)]),
+                (A::ToggleDetail, vec![KeyChord::key(KeyCode::KeyD)]),
+                (A::ToggleStatistics, vec![KeyChord::key(KeyCode::KeyS)]),
+                (A::ToggleBookmarks, vec![KeyChord::key(KeyCode::KeyB)]),
+                (A::ToggleDataSources, vec![KeyChord::shift(KeyCode::KeyD)]),
+                (A::ToggleIngest, vec![KeyChord::key(KeyCode::KeyI)]),
+                (A::ToggleHelp, vec![KeyChord::shift(KeyCode::Slash), KeyChord::key(KeyCode::F1)]),
+                (A::ToggleDebugPanel, vec![KeyChord::key(KeyCode::Backquote)]),
+                (A::ToggleInspector, vec![KeyChord::key(KeyCode::F12)]),
+                (A::ToggleFocusMode, vec![KeyChord::key(KeyCode::F11)]),
+                (A::Cancel, vec![KeyChord::key(KeyCode::Escape)]),
+                (A::FollowSelected, vec![KeyChord::key(KeyCode::KeyF)]),
+                (A::CenterSelected, vec![KeyChord::key(KeyCode::KeyC)]),
+                (A::NextAircraft, vec![KeyChord::key(KeyCode::Tab)]),
+                (A::PreviousAircraft, vec![KeyChord::shift(KeyCode::Tab)]),
+                (A::GotoAircraft, vec![KeyChord::key(KeyCode::Slash)]),
+                (A::ZoomIn, vec![
+                    KeyChord::key(KeyCode::Equal),
+                    KeyChord::shift(KeyCode::Equal),
+                    KeyChord::key(KeyCode::NumpadAdd),
+                ]),
+                (A::ZoomOut, vec![KeyChord::key(KeyCode::Minus), KeyChord::key(KeyCode::NumpadSubtract)]),
+                (A::ResetView, vec![KeyChord::key(KeyCode::KeyR)]),
+                (A::FitAllAircraft, vec![KeyChord::key(KeyCode::KeyZ)]),
+                (A::ToggleAirports, vec![KeyChord::key(KeyCode::KeyA)]),
+                (A::ToggleTrails, vec![KeyChord::key(KeyCode::KeyT)]),
+                (A::ToggleWeather, vec![KeyChord::key(KeyCode::KeyW)]),
+                (A::ToggleAirspace, vec![KeyChord::shift(KeyCode::KeyA)]),
+                (A::ToggleCoverage, vec![KeyChord::key(KeyCode::KeyV)]),
+                (A::ToggleCoverageStats, vec![KeyChord::shift(KeyCode::KeyV)]),
+                (A::Toggle3DView, vec![KeyChord::key(KeyCode::Digit3)]),
+                (A::TogglePlanView, vec![KeyChord::key(KeyCode::KeyP)]),
+                (A::ToggleHud, vec![KeyChord::key(KeyCode::KeyH)]),
+                (A::ToggleDebug3D, vec![KeyChord::key(KeyCode::F10)]),
+                (A::ToggleMeasurement, vec![KeyChord::key(KeyCode::KeyM)]),
+                (A::ToggleExport, vec![KeyChord::key(KeyCode::KeyE)]),
+                (A::ToggleRecording, vec![KeyChord::ctrl(KeyCode::KeyR)]),
+            ],
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Chords bound to `action` (empty if unbound).
+    pub fn chords(&self, action: ShortcutAction) -> &[KeyChord] {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, chords)| chords.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Whether any chord bound to `action` was pressed this frame.
+    pub fn just_pressed(&self, action: ShortcutAction, keyboard: &ButtonInput<KeyCode>) -> bool {
+        self.chords(action).iter().any(|chord| chord.just_pressed(keyboard))
+    }
+
+    /// Replace the chords bound to `action`.
+    pub fn rebind(&mut self, action: ShortcutAction, chords: Vec<KeyChord>) {
+        match self.bindings.iter_mut().find(|(a, _)| *a == action) {
+            Some((_, existing)) => *existing = chords,
+            None => self.bindings.push((action, chords)),
+        }
+    }
+
+    /// All bindings in `group`, in registration order.
+    pub fn in_group(&self, group: ShortcutGroup) -> impl Iterator<Item = (ShortcutAction, &[KeyChord])> {
+        self.bindings
+            .iter()
+            .filter(move |(action, chords)| action.group() == group && !chords.is_empty())
+            .map(|(action, chords)| (*action, chords.as_slice()))
+    }
+
+    /// Help text listing every active binding, grouped by feature.
+    pub fn help_text(&self) -> String {
+        let mut text = String::from("Keyboard Shortcuts\n");
+        for &group in ShortcutGroup::ALL {
+            let entries: Vec<_> = self.in_group(group).collect();
+            if entries.is_empty() {
+                continue;
+            }
+            text.push_str(&format!("\n{}\n", group.label()));
+            for (action, chords) in entries {
+                let keys = chords.iter().map(KeyChord::label).collect::<Vec<_>>().join(" / ");
+                text.push_str(&format!("  {:<10} {}\n", keys, action.description()));
+            }
+        }
+        text
+    }
+}
+
 /// Resource for help overlay visibility
 #[derive(Resource, Default)]
 pub struct HelpOverlayState {
@@ -33,7 +345,10 @@ pub fn handle_keyboard_shortcuts(
     aircraft_query: Query<&Aircraft>,
     mut contexts: EguiContexts,
     mut hud_state: ResMut<HudState>,
+    mut nearby_hud: ResMut<NearbyHudState>,
     mut debug_3d_hud: ResMut<Debug3DHudState>,
+    bindings: Res<KeyBindings>,
+    mut fit_requests: MessageWriter<FitAllAircraftRequest>,
 ) {
     // Check if egui wants keyboard input (e.g., typing in a text field)
     if let Ok(ctx) = contexts.ctx_mut() {
@@ -42,96 +357,98 @@ pub fn handle_keyboard_shortcuts(
         }
     }
 
-    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
-    let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+    let pressed = |action| bindings.just_pressed(action, &keyboard);
 
     // L - Toggle aircraft list
-    if keyboard.just_pressed(KeyCode::KeyL) {
+    if pressed(ShortcutAction::ToggleAircraftList) {
         panels.toggle_panel(PanelId::AircraftList);
     }
 
     // D - Toggle detail panel (if aircraft selected)
-    if keyboard.just_pressed(KeyCode::KeyD) && !shift {
+    if pressed(ShortcutAction::ToggleDetail) {
         if list_state.selected_icao.is_some() {
             panels.toggle_panel(PanelId::AircraftDetail);
         }
     }
 
     // S - Toggle statistics panel
-    if keyboard.just_pressed(KeyCode::KeyS) {
+    if pressed(ShortcutAction::ToggleStatistics) {
         panels.toggle_panel(PanelId::Statistics);
     }
 
     // B - Toggle bookmarks
-    if keyboard.just_pressed(KeyCode::KeyB) {
+    if pressed(ShortcutAction::ToggleBookmarks) {
         panels.toggle_panel(PanelId::Bookmarks);
     }
 
     // M - Toggle measurement mode
-    if keyboard.just_pressed(KeyCode::KeyM) {
+    if pressed(ShortcutAction::ToggleMeasurement) {
         panels.toggle_panel(PanelId::Measurement);
     }
 
     // E - Toggle export
-    if keyboard.just_pressed(KeyCode::KeyE) {
+    if pressed(ShortcutAction::ToggleExport) {
         panels.toggle_panel(PanelId::Export);
     }
 
     // V - Toggle coverage
-    if keyboard.just_pressed(KeyCode::KeyV) && !shift {
+    if pressed(ShortcutAction::ToggleCoverage) {
         panels.toggle_panel(PanelId::Coverage);
     }
 
-    // A - Toggle airports / Shift+A - Airspace
-    if keyboard.just_pressed(KeyCode::KeyA) {
-        if shift {
-            panels.toggle_panel(PanelId::Airspace);
-        }
-        // Non-shift A (airports toggle) is handled by toggle_overlays_keyboard
+    // Shift+A - Airspace (A alone toggles airports in toggle_overlays_keyboard)
+    if pressed(ShortcutAction::ToggleAirspace) {
+        panels.toggle_panel(PanelId::Airspace);
     }
 
     // Shift+D - Data sources
-    if keyboard.just_pressed(KeyCode::KeyD) && shift {
+    if pressed(ShortcutAction::ToggleDataSources) {
         panels.toggle_panel(PanelId::DataSources);
     }
 
     // I - Toggle ingest panel
-    if keyboard.just_pressed(KeyCode::KeyI) {
+    if pressed(ShortcutAction::ToggleIngest) {
         panels.toggle_panel(PanelId::Ingest);
     }
 
     // H - Toggle camera HUD
-    if keyboard.just_pressed(KeyCode::KeyH) && !shift {
+    if pressed(ShortcutAction::ToggleHud) {
         hud_state.visible = !hud_state.visible;
+        nearby_hud.visible = hud_state.visible;
     }
 
     // F10 - Toggle 3D debug overlay
-    if keyboard.just_pressed(KeyCode::F10) {
+    if pressed(ShortcutAction::ToggleDebug3D) {
         debug_3d_hud.visible = !debug_3d_hud.visible;
     }
 
-    // ? (Shift+/) - Toggle help overlay
-    if keyboard.just_pressed(KeyCode::Slash) && shift {
+    // ? (Shift+/) or F1 - Toggle help overlay
+    if pressed(ShortcutAction::ToggleHelp) {
         panels.toggle_panel(PanelId::Help);
     }
 
     // ` (backtick) - Toggle debug panel
-    if keyboard.just_pressed(KeyCode::Backquote) {
+    if pressed(ShortcutAction::ToggleDebugPanel) {
         panels.toggle_panel(PanelId::Debug);
     }
 
     // F12 - Toggle inspector
-    if keyboard.just_pressed(KeyCode::F12) {
+    if pressed(ShortcutAction::ToggleInspector) {
         panels.toggle_panel(PanelId::Inspector);
     }
 
+    // F11 - Toggle focus mode (map only)
+    if pressed(ShortcutAction::ToggleFocusMode) {
+        panels.toggle_focus_mode();
+    }
+
     // Ctrl+R - Toggle recording
-    if ctrl && keyboard.just_pressed(KeyCode::KeyR) {
+    if pressed(ShortcutAction::ToggleRecording) {
         panels.toggle_panel(PanelId::Recording);
     }
 
     // Escape - Deselect aircraft, cancel follow, close panels (cascading)
-    if keyboard.just_pressed(KeyCode::Escape) {
+    if pressed(ShortcutAction::Cancel) {
         if follow_state.following_icao.is_some() {
             follow_state.following_icao = None;
         } else if list_state.selected_icao.is_some() {
@@ -145,7 +462,7 @@ pub fn handle_keyboard_shortcuts(
     }
 
     // F - Follow selected aircraft
-    if keyboard.just_pressed(KeyCode::KeyF) {
+    if pressed(ShortcutAction::FollowSelected) {
         if let Some(ref icao) = list_state.selected_icao {
             if follow_state.following_icao.as_ref() == Some(icao) {
                 follow_state.following_icao = None;
@@ -156,7 +473,7 @@ pub fn handle_keyboard_shortcuts(
     }
 
     // C - Center on selected aircraft (one-time center, not follow)
-    if keyboard.just_pressed(KeyCode::KeyC) {
+    if pressed(ShortcutAction::CenterSelected) {
         if let Some(ref icao) = list_state.selected_icao {
             if let Some(aircraft) = aircraft_query.iter().find(|a| &a.icao == icao) {
                 map_state.latitude = aircraft.latitude;
@@ -166,24 +483,90 @@ pub fn handle_keyboard_shortcuts(
     }
 
     // + or = (same key, shift for +) - Zoom in
-    if keyboard.just_pressed(KeyCode::Equal) || keyboard.just_pressed(KeyCode::NumpadAdd) {
+    if pressed(ShortcutAction::ZoomIn) {
         zoom_state.camera_zoom = (zoom_state.camera_zoom * 1.2)
             .clamp(zoom_state.min_zoom, zoom_state.max_zoom);
     }
 
     // - (minus) - Zoom out
-    if keyboard.just_pressed(KeyCode::Minus) || keyboard.just_pressed(KeyCode::NumpadSubtract) {
+    if pressed(ShortcutAction::ZoomOut) {
         zoom_state.camera_zoom = (zoom_state.camera_zoom / 1.2)
             .clamp(zoom_state.min_zoom, zoom_state.max_zoom);
     }
 
-    // R - Reset view to default (only when Ctrl is NOT pressed, so Ctrl+R goes to recording)
-    if keyboard.just_pressed(KeyCode::KeyR) && !ctrl {
+    // R - Reset view to default (Ctrl+R is a distinct chord and goes to recording)
+    if pressed(ShortcutAction::ResetView) {
         map_state.latitude = app_config.map.default_latitude;
         map_state.longitude = app_config.map.default_longitude;
         zoom_state.camera_zoom = 1.0;
         follow_state.following_icao = None;
     }
+
+    // Z - Zoom to fit every listed aircraft
+    if pressed(ShortcutAction::FitAllAircraft) {
+        fit_requests.write(FitAllAircraftRequest);
+    }
+}
+
+/// ICAO of the aircraft after (or before) `current` in the display list,
+/// wrapping around at either end. Starts from the first (or last) entry when
+/// nothing is selected or the selection is no longer in the list.
+pub fn cycle_icao(list: &AircraftDisplayList, current: Option<&str>, forward: bool) -> Option<String> {
+    let len = list.aircraft.len();
+    if len == 0 {
+        return None;
+    }
+    let position = current.and_then(|icao| list.aircraft.iter().position(|a| a.icao == icao));
+    let index = match (position, forward) {
+        (Some(i), true) => (i + 1) % len,
+        (Some(i), false) => (i + len - 1) % len,
+        (None, true) => 0,
+        (None, false) => len - 1,
+    };
+    Some(list.aircraft[index].icao.clone())
+}
+
+/// System to cycle the selection through the aircraft list with Tab / Shift+Tab.
+///
+/// Uses the list's current sort and filters. If an aircraft is being followed
+/// the follow moves to the new selection, otherwise the map centers on it.
+pub fn cycle_aircraft_selection(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut list_state: ResMut<AircraftListState>,
+    mut follow_state: ResMut<CameraFollowState>,
+    mut map_state: ResMut<MapState>,
+    display_list: Res<AircraftDisplayList>,
+    aircraft_query: Query<&Aircraft>,
+    mut contexts: EguiContexts,
+    bindings: Res<KeyBindings>,
+) {
+    if let Ok(ctx) = contexts.ctx_mut() {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+    }
+
+    let forward = if bindings.just_pressed(ShortcutAction::NextAircraft, &keyboard) {
+        true
+    } else if bindings.just_pressed(ShortcutAction::PreviousAircraft, &keyboard) {
+        false
+    } else {
+        return;
+    };
+
+    let Some(icao) = cycle_icao(&display_list, list_state.selected_icao.as_deref(), forward) else {
+        return;
+    };
+
+    if follow_state.following_icao.is_some() {
+        follow_state.following_icao = Some(icao.clone());
+    } else if let Some(aircraft) = aircraft_query.iter().find(|a| a.icao == icao) {
+        map_state.latitude = aircraft.latitude;
+        map_state.longitude = aircraft.longitude;
+    }
+
+    list_state.selected_icao = Some(icao);
+    list_state.scroll_to_selected = true;
 }
 
 /// System to toggle overlay settings with keyboard (airports, trails)
@@ -192,6 +575,7 @@ pub fn toggle_overlays_keyboard(
     mut airport_state: Option<ResMut<crate::aviation::AirportRenderState>>,
     mut trail_config: Option<ResMut<crate::aircraft::TrailConfig>>,
     mut contexts: EguiContexts,
+    bindings: Res<KeyBindings>,
 ) {
     // Check if egui wants keyboard input
     if let Ok(ctx) = contexts.ctx_mut() {
@@ -200,17 +584,15 @@ pub fn toggle_overlays_keyboard(
         }
     }
 
-    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
-
-    // A - Toggle airports (only without Shift; Shift+A is airspace, handled above)
-    if keyboard.just_pressed(KeyCode::KeyA) && !shift {
+    // A - Toggle airports (Shift+A is airspace, handled above)
+    if bindings.just_pressed(ShortcutAction::ToggleAirports, &keyboard) {
         if let Some(ref mut state) = airport_state {
             state.show_airports = !state.show_airports;
         }
     }
 
     // T - Toggle trails
-    if keyboard.just_pressed(KeyCode::KeyT) {
+    if bindings.just_pressed(ShortcutAction::ToggleTrails, &keyboard) {
         if let Some(ref mut config) = trail_config {
             config.enabled = !config.enabled;
         }
@@ -304,59 +686,29 @@ fn sync_one(panels: &mut UiPanelManager, id: PanelId, resource_open: bool) {
     }
 }
 
-/// System to create/update help overlay
+/// System to create/update help overlay. The text is generated from
+/// `KeyBindings`, and the overlay is rebuilt whenever the bindings change.
 pub fn update_help_overlay(
     mut commands: Commands,
     help_state: Res<HelpOverlayState>,
+    bindings: Res<KeyBindings>,
     existing_overlay: Query<Entity, With<HelpOverlay>>,
     theme: Res<crate::theme::AppTheme>,
 ) {
-    // Remove existing overlay if not visible
-    if !help_state.visible {
+    // Remove existing overlay if not visible or out of date
+    if !help_state.visible || bindings.is_changed() {
         for entity in existing_overlay.iter() {
             commands.entity(entity).despawn();
         }
+        if !help_state.visible {
+            return;
+        }
+    } else if !existing_overlay.is_empty() {
+        // Overlay already exists and is current, leave it
         return;
     }
 
-    // If overlay already exists, leave it
-    if !existing_overlay.is_empty() {
-        return;
-    }
-
-    // Create help overlay
-    let help_text = "\
-Keyboard Shortcuts
-------------------
-L     Toggle aircraft list
-D     Toggle detail panel
-S     Toggle statistics
-B     Toggle bookmarks
-M     Measurement mode
-E     Export data panel
-V     Toggle coverage tracking
-3     Toggle 3D view mode
-H     Toggle camera HUD (3D)
-F10   Toggle 3D debug overlay
-Esc   Deselect / cancel follow
-F     Follow selected aircraft
-C     Center on selected
-+/-   Zoom in / out
-?     Toggle this help
-`     Toggle debug panel
-F12   Toggle inspector
-R     Reset view
-A     Toggle airports
-T     Toggle trails
-W     Toggle weather overlay
-
-Shift+ Modifiers:
-Shift+A  Toggle airspace
-Shift+D  Data sources panel
-Shift+V  Coverage stats
-
-Ctrl+R  Record/Stop recording
-";
+    let help_text = bindings.help_text();
 
     let bg_color = theme.bg_secondary().with_alpha(0.95);
     let text_color = theme.text_primary();
@@ -364,14 +716,13 @@ Ctrl+R  Record/Stop recording
     commands.spawn((
         Node {
             position_type: PositionType::Absolute,
-            top: Val::Percent(50.0),
+            top: Val::Px(60.0),
             left: Val::Percent(50.0),
             margin: UiRect {
-                left: Val::Px(-150.0),
-                top: Val::Px(-180.0),
+                left: Val::Px(-170.0),
                 ..default()
             },
-            width: Val::Px(300.0),
+            width: Val::Px(340.0),
             padding: UiRect::all(Val::Px(20.0)),
             flex_direction: FlexDirection::Column,
             ..default()
@@ -389,3 +740,65 @@ Ctrl+R  Record/Stop recording
         ));
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display_list(icaos: &[&str]) -> AircraftDisplayList {
+        AircraftDisplayList {
+            aircraft: icaos
+                .iter()
+                .map(|icao| crate::aircraft::AircraftDisplayData {
+                    icao: icao.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn cycle_wraps_in_both_directions() {
+        let list = display_list(&["A1", "B2", "C3"]);
+        assert_eq!(cycle_icao(&list, Some("B2"), true).as_deref(), Some("C3"));
+        assert_eq!(cycle_icao(&list, Some("C3"), true).as_deref(), Some("A1"));
+        assert_eq!(cycle_icao(&list, Some("A1"), false).as_deref(), Some("C3"));
+        assert_eq!(cycle_icao(&list, None, true).as_deref(), Some("A1"));
+        assert_eq!(cycle_icao(&list, Some("GONE"), false).as_deref(), Some("C3"));
+        assert_eq!(cycle_icao(&display_list(&[]), None, true), None);
+    }
+
+    #[test]
+    fn default_bindings_have_no_conflicts() {
+        let bindings = KeyBindings::default();
+        let all: Vec<(ShortcutAction, KeyChord)> = bindings
+            .bindings
+            .iter()
+            .flat_map(|(action, chords)| chords.iter().map(move |c| (*action, *c)))
+            .collect();
+        for (i, (action, chord)) in all.iter().enumerate() {
+            for (other_action, other_chord) in &all[i + 1..] {
+                assert!(chord != other_chord, "{:?} and {:?} share {}", action, other_action, chord.label());
+            }
+        }
+    }
+
+    #[test]
+    fn help_text_reflects_rebinding() {
+        let mut bindings = KeyBindings::default();
+        assert!(bindings.help_text().contains("Shift+A"));
+        bindings.rebind(ShortcutAction::ToggleAirspace, vec![KeyChord::ctrl(KeyCode::KeyP)]);
+        let text = bindings.help_text();
+        assert!(text.contains("Ctrl+P"));
+        assert!(!text.contains("Shift+A"));
+    }
+
+    #[test]
+    fn chord_requires_exact_modifiers() {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::ShiftLeft);
+        keyboard.press(KeyCode::KeyV);
+        assert!(KeyChord::shift(KeyCode::KeyV).just_pressed(&keyboard));
+        assert!(!KeyChord::key(KeyCode::KeyV).just_pressed(&keyboard));
+    }
+}
@@ -17,6 +17,7 @@ mod bookmarks;
 mod recording;
 mod tools;
 mod coverage;
+mod range_rings;
 mod airspace;
 mod data_sources;
 mod export;
@@ -37,6 +38,12 @@ mod input;
 mod zoom;
 mod camera;
 mod hud;
+mod nearby_hud;
+mod deeplink;
+mod event_log;
+mod goto;
+mod maintenance;
+mod idle;
 mod debug_3d_hud;
 mod build_info;
 pub(crate) mod theme;
@@ -52,7 +59,7 @@ pub(crate) use map::{MapState, ZoomState};
 pub(crate) use camera::{MapCamera, AircraftCamera};
 pub(crate) use render_layers::RenderCategory;
 use config::ConfigPlugin;
-use keyboard::{HelpOverlayState, handle_keyboard_shortcuts, toggle_overlays_keyboard, update_help_overlay, sync_panel_manager_to_resources, sync_resources_to_panel_manager};
+use keyboard::{HelpOverlayState, KeyBindings, cycle_aircraft_selection, handle_keyboard_shortcuts, toggle_overlays_keyboard, update_help_overlay, sync_panel_manager_to_resources, sync_resources_to_panel_manager};
 use bevy_egui::{EguiGlobalSettings, PrimaryEguiContext};
 
 // ADS-B client types
@@ -95,6 +102,15 @@ pub(crate) mod constants {
     pub const ZOOM_SENSITIVITY_LINE: f32 = 0.1;  // Mouse wheel
     pub const ZOOM_SENSITIVITY_PIXEL: f32 = 0.002;  // Trackpad
 
+    // Zoom snapping: idle time after the last zoom input, and easing rate (1/s)
+    pub const ZOOM_SNAP_IDLE_SECS: f32 = 0.2;
+    pub const ZOOM_SNAP_RATE: f32 = 12.0;
+
+    // Fit all aircraft: margin on each side (fraction of the viewport), and
+    // the closest tile zoom it will pick for a lone aircraft
+    pub const FIT_ALL_MARGIN: f32 = 0.08;
+    pub const FIT_ALL_MAX_ZOOM: u8 = 12;
+
     // Movement threshold for tile requests (degrees, ~100m at equator)
     pub const PAN_TILE_REQUEST_THRESHOLD: f64 = 0.001;
 
@@ -105,11 +121,26 @@ pub(crate) mod constants {
     pub const BUTTON_FONT_SIZE: f32 = 16.0;
 
     // Tile fade/despawn timing
+    // Default 2D tile fade-in rate (opacity/s); see MapConfig::tile_fade_speed
     pub const TILE_FADE_SPEED: f32 = 3.0;
+    // Accepted fade-in rates; hand-edited values outside are clamped
+    pub const TILE_FADE_SPEED_MIN: f32 = 0.5;
+    pub const TILE_FADE_SPEED_MAX: f32 = 10.0;
+    // Old-zoom tiles are despawned once a new tile at least this opaque
+    // covers their cell; cells are this many world pixels across
+    pub const TILE_COVERAGE_OPACITY: f32 = 1.0;
+    pub const TILE_COVERAGE_CELL_PIXELS: f32 = DEFAULT_TILE_PIXELS;
+    // Superseded old-zoom tiles are despawned after this long even when the
+    // coverage check never matches (e.g. rescaled tiles straddling cells)
+    pub const TILE_SUPERSEDED_DESPAWN_SECS: f32 = 4.0;
 
     // Z-layers
     pub const TILE_Z_LAYER: f32 = 0.0;
     pub const AIRCRAFT_Z_LAYER: f32 = 10.0;
+    // Extra 2D Z for aircraft at AIRCRAFT_Z_BIAS_CEILING_FT and above, so
+    // higher traffic draws on top; stays well under LABEL_Z_LAYER
+    pub const AIRCRAFT_Z_BIAS_MAX: f32 = 0.5;
+    pub const AIRCRAFT_Z_BIAS_CEILING_FT: f32 = 50000.0;
     pub const LABEL_Z_LAYER: f32 = 11.0;
 
     // 3D model scale: model is ~4 units across, target is 32 world units (AIRCRAFT_MARKER_RADIUS * 4)
@@ -144,6 +175,16 @@ pub(crate) fn clamp_longitude(lon: f64) -> f64 {
     lon.clamp(-180.0, 180.0)
 }
 
+/// Bring a map center longitude back into range: wrap across the
+/// antimeridian when `wrap` (`MapConfig::wrap_antimeridian`), else clamp.
+pub(crate) fn normalize_longitude(lon: f64, wrap: bool) -> f64 {
+    if wrap {
+        geo::wrap_longitude(lon)
+    } else {
+        clamp_longitude(lon)
+    }
+}
+
 fn main() {
     paths::init_from_args();
 
@@ -199,24 +240,31 @@ fn main() {
             view3d::View3DPlugin,
             adsb::AdsbPlugin,
         ))
-        .add_plugins((bevy_obj::ObjPlugin, bevy_inspector_egui::DefaultInspectorConfigPlugin, data_ingest::DataIngestPlugin))
-        // Full speed when focused; ~4 FPS when unfocused to keep ADS-B data
-        // flowing without overwhelming the GPU or triggering macOS throttling.
+        .add_plugins((bevy_obj::ObjPlugin, bevy_inspector_egui::DefaultInspectorConfigPlugin, data_ingest::DataIngestPlugin, range_rings::RangeRingsPlugin))
+        .add_plugins(bevy::diagnostic::FrameTimeDiagnosticsPlugin::default())
+        // Full speed when focused; ~4 FPS when unfocused. `idle::update_idle_mode`
+        // lowers both further when low-power idle mode is enabled.
         .insert_resource(ClearColor(Color::srgb(20.0 / 255.0, 21.0 / 255.0, 24.0 / 255.0)))
         .insert_resource(bevy::winit::WinitSettings {
             focused_mode: bevy::winit::UpdateMode::Continuous,
-            unfocused_mode: bevy::winit::UpdateMode::reactive(
-                std::time::Duration::from_millis(250),
-            ),
+            unfocused_mode: bevy::winit::UpdateMode::reactive(idle::UNFOCUSED_FRAME_INTERVAL),
         })
+        .init_resource::<idle::IdleState>()
         .init_resource::<HelpOverlayState>()
+        .init_resource::<KeyBindings>()
         .init_resource::<ui_panels::UiPanelManager>()
         .init_resource::<tools_window::ToolsWindowState>()
         .init_resource::<debug_panel::DebugPanelState>()
+        .init_resource::<event_log::EventLog>()
         .init_resource::<dock::DockTreeState>()
         .init_resource::<inspector::InspectorState>()
         .init_resource::<statusbar::StatusBarState>()
         .init_resource::<hud::HudState>()
+        .init_resource::<nearby_hud::NearbyHudState>()
+        .init_resource::<goto::GotoBoxState>()
+        .init_resource::<maintenance::MaintenanceState>()
+        .add_message::<maintenance::MaintenanceRequest>()
+        .insert_resource(deeplink::PendingDeepLink::from_args())
         .register_type::<MapState>()
         .register_type::<ZoomState>()
         .insert_resource(ZoomState::new())
@@ -243,10 +291,17 @@ fn main() {
         .add_systems(Update, show_window_after_init)
         .add_systems(Update, handle_keyboard_shortcuts)
         .add_systems(Update, toggle_overlays_keyboard)
+        .add_systems(Update, cycle_aircraft_selection.after(handle_keyboard_shortcuts))
         .add_systems(Update, sync_resources_to_panel_manager.after(handle_keyboard_shortcuts))
         .add_systems(Update, sync_panel_manager_to_resources.after(sync_resources_to_panel_manager))
         .add_systems(Update, update_help_overlay)
+        .add_systems(Update, nearby_hud::render_nearby_hud)
+        .add_systems(Update, (goto::open_goto_box, goto::render_goto_box.after(goto::open_goto_box)))
+        .add_systems(Update, maintenance::apply_maintenance_requests)
+        .add_systems(Update, deeplink::apply_deep_link)
+        .add_systems(Update, idle::update_idle_mode)
         .add_systems(Update, debug_panel::update_debug_metrics)
+        .add_systems(Update, debug_panel::render_zoom_overlay)
         .add_systems(Update, heartbeat_diagnostic);
 
     #[cfg(feature = "brp")]
@@ -492,9 +547,11 @@ pub(crate) fn setup_map(
     tile_cache::remove_invalid_tiles();
 
     // Update SlippyTilesSettings from config
-    tile_settings.endpoint = app_config.map.basemap_style.endpoint_url().to_string();
-    tile_settings.tile_format = app_config.map.basemap_style.tile_format();
-    tile_settings.reverse_axes = app_config.map.basemap_style.reverse_axes();
+    let tile_source = app_config.map.tile_source();
+    tile_settings.endpoint = tile_source.endpoints[0].clone();
+    tile_settings.tile_format = tile_source.tile_format;
+    tile_settings.reverse_axes = tile_source.reverse_axes;
+    tile_settings.query = tile_source.query.clone();
     tile_settings.reference_latitude = app_config.map.default_latitude;
     tile_settings.reference_longitude = app_config.map.default_longitude;
 
@@ -0,0 +1,158 @@
+/// One-click maintenance actions.
+///
+/// Trails, coverage, the tile cache and lost-aircraft ghosts all build up
+/// over a long session. The toolbar's maintenance menu offers a reset for
+/// each; every action asks for confirmation before a `MaintenanceRequest`
+/// is sent and applied by `apply_maintenance_requests`.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use bevy_slippy_tiles::{DownloadSlippyTilesMessage, MapTile, SlippyTileDownloadStatus};
+
+use crate::aircraft::{LostTrails, TrailHistory};
+use crate::coverage::CoverageState;
+use crate::event_log::{EventCategory, EventLog};
+use crate::tiles::ClearZoomTilesRequest;
+use crate::MapState;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaintenanceAction {
+    ClearTrails,
+    ClearCoverage,
+    ClearTileCache,
+    ClearGhosts,
+}
+
+impl MaintenanceAction {
+    pub const ALL: [MaintenanceAction; 4] = [
+        MaintenanceAction::ClearTrails,
+        MaintenanceAction::ClearCoverage,
+        MaintenanceAction::ClearTileCache,
+        MaintenanceAction::ClearGhosts,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MaintenanceAction::ClearTrails => "Clear all trails",
+            MaintenanceAction::ClearCoverage => "Clear coverage",
+            MaintenanceAction::ClearTileCache => "Clear tile cache",
+            MaintenanceAction::ClearGhosts => "Clear lost-aircraft ghosts",
+        }
+    }
+
+    /// Question shown in the confirmation window
+    fn confirm_text(&self) -> &'static str {
+        match self {
+            MaintenanceAction::ClearTrails => "Erase the trail history of every tracked aircraft?",
+            MaintenanceAction::ClearCoverage => "Reset the receiver coverage polygon and statistics?",
+            MaintenanceAction::ClearTileCache => "Delete all cached map tiles and download them again?",
+            MaintenanceAction::ClearGhosts => "Remove the fading trails of lost aircraft?",
+        }
+    }
+}
+
+/// Request to run a confirmed maintenance action
+#[derive(Message)]
+pub struct MaintenanceRequest(pub MaintenanceAction);
+
+#[derive(Resource, Default)]
+pub struct MaintenanceState {
+    /// Action waiting for the user to confirm or cancel
+    pub pending: Option<MaintenanceAction>,
+}
+
+/// Render the maintenance menu entries. Picking one asks for confirmation;
+/// clearing the current zoom level's tiles is quick to undo and runs at once.
+pub fn render_maintenance_menu(
+    ui: &mut egui::Ui,
+    state: &mut MaintenanceState,
+    zoom: u8,
+    clear_zoom_events: &mut MessageWriter<ClearZoomTilesRequest>,
+) {
+    for action in MaintenanceAction::ALL {
+        if ui.button(format!("{}\u{2026}", action.label())).clicked() {
+            state.pending = Some(action);
+            ui.close();
+        }
+    }
+    ui.separator();
+    if ui.button(format!("Clear current zoom tiles (z{})", zoom)).clicked() {
+        clear_zoom_events.write(ClearZoomTilesRequest { zoom });
+        ui.close();
+    }
+}
+
+/// Render the confirmation window for the pending action, if any.
+pub fn render_maintenance_confirm(
+    ctx: &egui::Context,
+    state: &mut MaintenanceState,
+    requests: &mut MessageWriter<MaintenanceRequest>,
+) {
+    let Some(action) = state.pending else {
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new(action.label())
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.label(action.confirm_text());
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("Clear").clicked() {
+                    requests.write(MaintenanceRequest(action));
+                    state.pending = None;
+                }
+                if ui.button("Cancel").clicked() {
+                    state.pending = None;
+                }
+            });
+        });
+    if !open {
+        state.pending = None;
+    }
+}
+
+/// System that applies confirmed maintenance actions.
+pub fn apply_maintenance_requests(
+    mut commands: Commands,
+    mut requests: MessageReader<MaintenanceRequest>,
+    mut trail_query: Query<&mut TrailHistory>,
+    mut coverage: ResMut<CoverageState>,
+    mut lost_trails: ResMut<LostTrails>,
+    tile_query: Query<Entity, With<MapTile>>,
+    mut download_status: ResMut<SlippyTileDownloadStatus>,
+    mut download_events: MessageWriter<DownloadSlippyTilesMessage>,
+    map_state: Res<MapState>,
+    mut events: ResMut<EventLog>,
+) {
+    for MaintenanceRequest(action) in requests.read() {
+        match action {
+            MaintenanceAction::ClearTrails => {
+                for mut trail in trail_query.iter_mut() {
+                    trail.points.clear();
+                }
+            }
+            MaintenanceAction::ClearCoverage => coverage.reset(),
+            MaintenanceAction::ClearTileCache => {
+                download_status.0.clear();
+                for entity in tile_query.iter() {
+                    commands.entity(entity).despawn();
+                }
+                crate::clear_tile_cache();
+                crate::tiles::request_tiles_at_location(
+                    &mut download_events,
+                    map_state.latitude,
+                    map_state.longitude,
+                    map_state.zoom_level,
+                    false,
+                );
+            }
+            MaintenanceAction::ClearGhosts => lost_trails.trails.clear(),
+        }
+        events.record(EventCategory::Cache, format!("{} via maintenance menu", action.label()));
+    }
+}
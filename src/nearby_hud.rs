@@ -0,0 +1,226 @@
+/// Nearby traffic HUD for follow mode.
+///
+/// While following an aircraft, lists the nearest other aircraft with
+/// bearing relative to the followed aircraft's track, distance, and altitude
+/// difference in the top-left corner of the map viewport.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::aircraft::CameraFollowState;
+use crate::dock::DockTreeState;
+use crate::geo::{haversine_distance_nm, initial_bearing};
+use crate::theme::{AppTheme, to_egui_color32, to_egui_color32_alpha};
+use crate::Aircraft;
+
+#[derive(Resource)]
+pub struct NearbyHudState {
+    pub visible: bool,
+    /// Number of nearest aircraft listed
+    pub count: usize,
+}
+
+impl Default for NearbyHudState {
+    fn default() -> Self {
+        Self { visible: true, count: 5 }
+    }
+}
+
+const HUD_MARGIN: f32 = 12.0;
+const HUD_PADDING: f32 = 8.0;
+const LABEL_SIZE: f32 = 10.0;
+const VALUE_SIZE: f32 = 11.0;
+
+/// One row of the nearby traffic list
+pub struct NearbyTraffic {
+    pub name: String,
+    pub distance_nm: f64,
+    /// Bearing from the followed aircraft, relative to its track when known
+    /// (0 = dead ahead, clockwise), otherwise true bearing
+    pub bearing_deg: f64,
+    /// Whether `bearing_deg` is relative to the followed aircraft's track
+    pub relative_to_track: bool,
+    pub altitude_diff_ft: Option<i32>,
+}
+
+/// Distance, bearing and altitude difference from `from` to `to`. The bearing
+/// is relative to `from`'s heading when it has one.
+pub fn relative_position(from: &Aircraft, to: &Aircraft) -> (f64, f64, Option<i32>) {
+    let distance = haversine_distance_nm(from.latitude, from.longitude, to.latitude, to.longitude);
+    let true_bearing = initial_bearing(from.latitude, from.longitude, to.latitude, to.longitude);
+    let bearing = match from.heading {
+        Some(heading) => (true_bearing - heading as f64).rem_euclid(360.0),
+        None => true_bearing,
+    };
+    let altitude_diff = from.altitude.zip(to.altitude).map(|(a, b)| b - a);
+    (distance, bearing, altitude_diff)
+}
+
+/// The `count` aircraft closest to `target`, nearest first
+pub fn nearest_traffic<'a>(
+    target: &Aircraft,
+    others: impl Iterator<Item = &'a Aircraft>,
+    count: usize,
+) -> Vec<NearbyTraffic> {
+    let mut traffic: Vec<NearbyTraffic> = others
+        .filter(|other| other.icao != target.icao)
+        .map(|other| {
+            let (distance_nm, bearing_deg, altitude_diff_ft) = relative_position(target, other);
+            let name = other.callsign.as_deref().map(str::trim).filter(|c| !c.is_empty())
+                .unwrap_or(&other.icao)
+                .to_string();
+            NearbyTraffic {
+                name,
+                distance_nm,
+                bearing_deg,
+                relative_to_track: target.heading.is_some(),
+                altitude_diff_ft,
+            }
+        })
+        .collect();
+    traffic.sort_by(|a, b| a.distance_nm.total_cmp(&b.distance_nm));
+    traffic.truncate(count);
+    traffic
+}
+
+pub fn render_nearby_hud(
+    mut contexts: EguiContexts,
+    follow_state: Res<CameraFollowState>,
+    hud_state: Res<NearbyHudState>,
+    dock_state: Res<DockTreeState>,
+    theme: Res<AppTheme>,
+    app_config: Res<crate::config::AppConfig>,
+    aircraft_query: Query<&Aircraft>,
+) {
+    if !hud_state.visible {
+        return;
+    }
+    let Some(ref following_icao) = follow_state.following_icao else {
+        return;
+    };
+    let Some(target) = aircraft_query.iter().find(|a| &a.icao == following_icao) else {
+        return;
+    };
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Some(map_rect) = dock_state.map_viewport_rect else {
+        return;
+    };
+
+    let traffic = nearest_traffic(target, aircraft_query.iter(), hud_state.count);
+
+    let bg_color = to_egui_color32_alpha(theme.bg_secondary(), 200);
+    let text_color = to_egui_color32(theme.text_primary());
+    let dim_color = to_egui_color32(theme.text_dim());
+    let accent_color = to_egui_color32(theme.accent_primary());
+
+    let north_reference = app_config.map.north_reference;
+    let target_name = target.callsign.as_deref().map(str::trim).unwrap_or(&target.icao);
+
+    egui::Area::new(egui::Id::new("nearby_hud"))
+        .fixed_pos(map_rect.left_top() + egui::vec2(HUD_MARGIN, HUD_MARGIN))
+        .order(egui::Order::Middle)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::NONE
+                .fill(bg_color)
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(HUD_PADDING)
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!("NEAR {}", target_name))
+                            .size(LABEL_SIZE)
+                            .color(accent_color)
+                            .monospace(),
+                    );
+                    if traffic.is_empty() {
+                        ui.label(
+                            egui::RichText::new("No other traffic")
+                                .size(LABEL_SIZE)
+                                .color(dim_color)
+                                .monospace(),
+                        );
+                        return;
+                    }
+                    egui::Grid::new("nearby_hud_grid")
+                        .num_columns(4)
+                        .spacing(egui::vec2(8.0, 2.0))
+                        .show(ui, |ui| {
+                            for header in ["", "BRG", "DIST", "ALT"] {
+                                ui.label(egui::RichText::new(header).size(LABEL_SIZE).color(dim_color).monospace());
+                            }
+                            ui.end_row();
+                            for row in &traffic {
+                                let alt = match row.altitude_diff_ft {
+                                    Some(diff) => format!("{:+}", diff / 100),
+                                    None => "--".to_string(),
+                                };
+                                // Track-relative bearings have no north reference
+                                let bearing = if row.relative_to_track {
+                                    format!("{:03.0}\u{00B0}", row.bearing_deg)
+                                } else {
+                                    north_reference.format_bearing(row.bearing_deg, target.latitude, target.longitude)
+                                };
+                                for value in [
+                                    row.name.clone(),
+                                    bearing,
+                                    format!("{:.1}", row.distance_nm),
+                                    alt,
+                                ] {
+                                    ui.label(egui::RichText::new(value).size(VALUE_SIZE).color(text_color).monospace());
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aircraft(icao: &str, latitude: f64, longitude: f64, altitude: Option<i32>, heading: Option<f32>) -> Aircraft {
+        Aircraft {
+            icao: icao.to_string(),
+            callsign: None,
+            latitude,
+            longitude,
+            altitude,
+            barometric_altitude: altitude,
+            geometric_altitude: None,
+            heading,
+            velocity: None,
+            vertical_rate: None,
+            squawk: None,
+            is_on_ground: None,
+            alert: None,
+            emergency: None,
+            spi: None,
+            last_seen: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn nearest_traffic_is_sorted_relative_to_track() {
+        let target = aircraft("AAAAAA", 40.0, -100.0, Some(10_000), Some(90.0));
+        let others = [
+            aircraft("AAAAAA", 40.0, -100.0, Some(10_000), Some(90.0)),
+            aircraft("BBBBBB", 40.0, -99.0, Some(12_000), None),
+            aircraft("CCCCCC", 40.5, -100.0, None, None),
+            aircraft("DDDDDD", 42.0, -100.0, Some(8_000), None),
+        ];
+        let traffic = nearest_traffic(&target, others.iter(), 2);
+        assert_eq!(traffic.len(), 2);
+        assert_eq!(traffic[0].name, "CCCCCC");
+        assert!(traffic[0].relative_to_track);
+        assert!(traffic[0].altitude_diff_ft.is_none());
+        // Due north while tracking east is off the left wing
+        assert!((traffic[0].bearing_deg - 270.0).abs() < 0.5);
+        assert_eq!(traffic[1].name, "BBBBBB");
+        assert_eq!(traffic[1].altitude_diff_ft, Some(2_000));
+        assert!(traffic[1].bearing_deg < 1.0 || traffic[1].bearing_deg > 359.0);
+    }
+}
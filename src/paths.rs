@@ -63,7 +63,7 @@ pub fn os_defaults() -> AppPaths {
 const HELP_TEXT: &str = "\
 AirJedi - Aircraft Map Tracker
 
-USAGE: airjedi [OPTIONS]
+USAGE: airjedi [OPTIONS] [airjedi://LINK]
 
 OPTIONS:
     --base-dir <PATH>    Base directory for all app data (config/cache/data/logs)
@@ -72,6 +72,10 @@ OPTIONS:
     --data-dir <PATH>    Data directory for recordings/exports (default: OS standard)
     --log-dir <PATH>     Log file directory (default: OS standard)
     -h, --help           Print this help message
+
+LINK:
+    airjedi://icao=ABC123&lat=47.4490&lon=-122.3093&zoom=10
+                         Recenter on a location and/or select an aircraft
 ";
 
 /// Parse command-line arguments and initialize application paths.
@@ -137,6 +141,8 @@ fn parse_args(args: Vec<String>) -> Result<AppPaths, String> {
                     iter.next().ok_or("--log-dir requires a path argument")?,
                 ));
             }
+            // Deep links are picked up by `deeplink::PendingDeepLink`
+            other if other.starts_with(crate::deeplink::SCHEME) => {}
             other => {
                 return Err(format!("Unknown argument: {other}"));
             }
@@ -350,6 +356,16 @@ mod tests {
         assert!(result.unwrap_err().contains("Unknown argument"));
     }
 
+    #[test]
+    fn test_parse_args_ignores_deep_link() {
+        let paths = parse_args(vec![
+            "airjedi://icao=ABC123".to_string(),
+            "--config-dir".to_string(),
+            "/tmp/c".to_string(),
+        ]).unwrap();
+        assert_eq!(paths.config, PathBuf::from("/tmp/c"));
+    }
+
     #[test]
     fn test_parse_args_missing_value_errors() {
         let result = parse_args(vec!["--base-dir".to_string()]);
@@ -0,0 +1,129 @@
+//! Fixed distance rings around the receiver location.
+//!
+//! Independent of coverage tracking: these are plain reference circles at
+//! configured radii (e.g. 50/100/150 NM), labeled with their distance.
+
+use bevy::prelude::*;
+use bevy::camera::visibility::RenderLayers;
+use bevy_slippy_tiles::SlippyTilesSettings;
+
+use crate::config::AppConfig;
+use crate::coverage::CoverageState;
+use crate::geo::{destination_point, CoordinateConverter};
+use crate::view3d::View3DState;
+use crate::{MapState, RenderCategory, ZoomState};
+
+/// Number of segments used to approximate each ring
+const RING_SEGMENTS: usize = 96;
+
+const RING_COLOR: Color = Color::srgba(0.6, 0.8, 1.0, 0.45);
+
+/// Distance label for one range ring
+#[derive(Component)]
+pub struct RangeRingLabel {
+    pub radius_nm: f64,
+}
+
+/// Draw range rings as geodesic circles so they stay true at any radius.
+pub fn draw_range_rings(
+    mut gizmos: Gizmos,
+    app_config: Res<AppConfig>,
+    coverage: Res<CoverageState>,
+    map_state: Res<MapState>,
+    tile_settings: Res<SlippyTilesSettings>,
+    view3d_state: Res<View3DState>,
+) {
+    let overlays = &app_config.overlays;
+    if !overlays.show_range_rings || view3d_state.is_3d_active() {
+        return;
+    }
+
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let (lat, lon) = coverage.receiver_location;
+
+    for &radius_nm in &overlays.range_ring_radii_nm {
+        let points = (0..=RING_SEGMENTS).map(|i| {
+            let bearing = i as f64 * 360.0 / RING_SEGMENTS as f64;
+            let (plat, plon) = destination_point(lat, lon, bearing, radius_nm);
+            converter.latlon_to_world(plat, plon)
+        });
+        gizmos.linestrip_2d(points, RING_COLOR);
+    }
+}
+
+/// Respawn ring labels when the configured rings change.
+pub fn sync_range_ring_labels(
+    mut commands: Commands,
+    app_config: Res<AppConfig>,
+    label_query: Query<Entity, With<RangeRingLabel>>,
+) {
+    if !app_config.is_changed() {
+        return;
+    }
+
+    for entity in label_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let overlays = &app_config.overlays;
+    if !overlays.show_range_rings {
+        return;
+    }
+
+    for &radius_nm in &overlays.range_ring_radii_nm {
+        commands.spawn((
+            Name::new(format!("Range Ring Label: {} NM", radius_nm)),
+            Text2d::new(format!("{} NM", radius_nm)),
+            TextFont {
+                font_size: crate::constants::BASE_FONT_SIZE,
+                ..default()
+            },
+            TextColor(RING_COLOR.with_alpha(0.9)),
+            Transform::from_xyz(0.0, 0.0, crate::constants::LABEL_Z_LAYER),
+            RangeRingLabel { radius_nm },
+            RenderLayers::layer(RenderCategory::LABELS),
+        ));
+    }
+}
+
+/// Keep ring labels at the top of their ring with constant screen size.
+pub fn update_range_ring_labels(
+    coverage: Res<CoverageState>,
+    map_state: Res<MapState>,
+    zoom_state: Res<ZoomState>,
+    tile_settings: Res<SlippyTilesSettings>,
+    view3d_state: Res<View3DState>,
+    mut label_query: Query<(&RangeRingLabel, &mut Transform, &mut Visibility)>,
+) {
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let (lat, lon) = coverage.receiver_location;
+    let hidden = view3d_state.is_3d_active();
+    let scale = 1.0 / zoom_state.camera_zoom;
+
+    for (label, mut transform, mut visibility) in label_query.iter_mut() {
+        let target = if hidden { Visibility::Hidden } else { Visibility::Inherited };
+        if *visibility != target {
+            *visibility = target;
+        }
+        if hidden {
+            continue;
+        }
+        let (plat, plon) = destination_point(lat, lon, 0.0, label.radius_nm);
+        let pos = converter.latlon_to_world(plat, plon);
+        transform.translation.x = pos.x;
+        transform.translation.y = pos.y + 8.0 * scale;
+        transform.scale = Vec3::splat(scale);
+    }
+}
+
+pub struct RangeRingsPlugin;
+
+impl Plugin for RangeRingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            draw_range_rings.after(crate::ZoomSet::Change),
+            sync_range_ring_labels,
+            update_range_ring_labels.after(crate::ZoomSet::Change),
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+use bevy::prelude::*;
+use bevy::camera::visibility::RenderLayers;
+use bevy_slippy_tiles::SlippyTilesSettings;
+use std::collections::HashMap;
+
+use super::player::PlaybackState;
+use super::recorder::RecordedAircraftState;
+use crate::adsb::AircraftModelRegistry;
+use crate::camera::{marker_rotation_2d, BASE_ROT_YUP};
+use crate::geo::CoordinateConverter;
+use crate::view3d::View3DState;
+use crate::{constants, MapCamera, MapState, RenderCategory, ZoomState};
+
+/// Tint applied to ghost markers and their labels
+const GHOST_COLOR: Color = Color::srgba(0.75, 0.5, 1.0, 0.6);
+
+/// A recorded aircraft replayed alongside live traffic.
+///
+/// Deliberately not an `Aircraft`: ghosts share world space and the marker
+/// model with live aircraft but never enter the list, trails or ADS-B sync.
+#[derive(Component)]
+pub struct GhostAircraft {
+    pub state: RecordedAircraftState,
+}
+
+/// "REC" badge label following a ghost marker
+#[derive(Component)]
+pub struct GhostLabel {
+    pub ghost_entity: Entity,
+}
+
+/// Shared translucent material swapped onto ghost model meshes
+#[derive(Resource)]
+pub struct GhostMaterial(Handle<StandardMaterial>);
+
+impl FromWorld for GhostMaterial {
+    fn from_world(world: &mut World) -> Self {
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        Self(materials.add(StandardMaterial {
+            base_color: GHOST_COLOR,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }))
+    }
+}
+
+fn ghost_label_text(state: &RecordedAircraftState) -> String {
+    let name = state.callsign.as_deref().map(str::trim).unwrap_or(&state.icao);
+    match state.altitude {
+        Some(alt) => format!("REC {}\n{} ft", name, alt),
+        None => format!("REC {}", name),
+    }
+}
+
+/// System that drives ghost entities from `PlaybackState` while playback is
+/// overlaid on live traffic, and clears them when it is not.
+pub fn playback_ghosts(
+    mut commands: Commands,
+    mut playback: ResMut<PlaybackState>,
+    model_registry: Option<Res<AircraftModelRegistry>>,
+    mut ghost_query: Query<(Entity, &mut GhostAircraft)>,
+    mut label_query: Query<(Entity, &GhostLabel, &mut Text2d)>,
+) {
+    if !playback.overlay_live || !playback.is_playing {
+        for (entity, _) in ghost_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        for (entity, _, _) in label_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let Some(states) = playback.advance() else {
+        return;
+    };
+    let Some(model_registry) = model_registry else {
+        return;
+    };
+
+    let mut existing: HashMap<String, Entity> = ghost_query
+        .iter()
+        .map(|(entity, ghost)| (ghost.state.icao.clone(), entity))
+        .collect();
+
+    for state in states {
+        if let Some(entity) = existing.remove(&state.icao) {
+            if let Ok((_, mut ghost)) = ghost_query.get_mut(entity) {
+                ghost.state = state;
+            }
+            continue;
+        }
+
+        let label_text = ghost_label_text(&state);
+        let ghost_entity = commands.spawn((
+            Name::new(format!("Ghost: {}", state.icao)),
+            SceneRoot(model_registry.get_model(None)),
+            Transform::from_xyz(0.0, 0.0, constants::AIRCRAFT_Z_LAYER),
+            GhostAircraft { state },
+        )).id();
+        commands.spawn((
+            Name::new("Ghost Label"),
+            Text2d::new(label_text),
+            TextFont {
+                font_size: constants::BASE_FONT_SIZE,
+                ..default()
+            },
+            TextColor(GHOST_COLOR.with_alpha(0.9)),
+            Transform::from_xyz(0.0, 0.0, constants::LABEL_Z_LAYER),
+            GhostLabel { ghost_entity },
+            RenderLayers::layer(RenderCategory::LABELS),
+        ));
+    }
+
+    // Refresh label text and drop ghosts that left the recording
+    for (label_entity, label, mut text) in label_query.iter_mut() {
+        if existing.values().any(|&e| e == label.ghost_entity) {
+            commands.entity(label_entity).despawn();
+        } else if let Ok((_, ghost)) = ghost_query.get(label.ghost_entity) {
+            text.0 = ghost_label_text(&ghost.state);
+        }
+    }
+    for entity in existing.into_values() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Position, orient and scale ghost markers the same way as live aircraft,
+/// in both 2D and 3D. Ghost labels follow in 2D and are hidden in 3D.
+pub fn place_ghost_markers(
+    map_state: Res<MapState>,
+    tile_settings: Res<SlippyTilesSettings>,
+    zoom_state: Res<ZoomState>,
+    view3d_state: Res<View3DState>,
+    app_config: Res<crate::config::AppConfig>,
+    camera_query: Query<&Transform, (With<MapCamera>, Without<GhostAircraft>, Without<GhostLabel>)>,
+    mut ghost_query: Query<(&GhostAircraft, &mut Transform), Without<GhostLabel>>,
+    mut label_query: Query<(&GhostLabel, &mut Transform, &mut Visibility), Without<GhostAircraft>>,
+) {
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let marker_size = app_config.appearance.marker_size;
+    let is_3d = view3d_state.is_3d_active();
+
+    for (ghost, mut transform) in ghost_query.iter_mut() {
+        let state = &ghost.state;
+        let pos = converter.latlon_to_world(state.latitude, state.longitude);
+        if is_3d {
+            transform.translation = Vec3::new(pos.x, view3d_state.aircraft_height(state.altitude), -pos.y);
+            transform.rotation = match state.heading {
+                Some(heading) => Quat::from_rotation_y((-heading).to_radians()) * BASE_ROT_YUP,
+                None => BASE_ROT_YUP,
+            };
+            transform.scale = Vec3::splat(marker_size * 10.0);
+        } else {
+            transform.translation = Vec3::new(pos.x, pos.y, crate::camera::aircraft_z_2d(state.altitude));
+            transform.rotation = marker_rotation_2d(state.heading);
+            transform.scale = Vec3::splat(marker_size / zoom_state.camera_zoom);
+        }
+    }
+
+    let camera_rotation = if is_3d || view3d_state.is_transitioning() {
+        Quat::IDENTITY
+    } else {
+        camera_query.single().map(|t| t.rotation).unwrap_or(Quat::IDENTITY)
+    };
+    let appearance = &app_config.appearance;
+    let world_space_offset = appearance.label_anchor.offset(appearance.label_offset) / zoom_state.camera_zoom;
+    let offset = camera_rotation * world_space_offset.extend(0.0);
+
+    for (label, mut label_transform, mut visibility) in label_query.iter_mut() {
+        let Ok((_, ghost_transform)) = ghost_query.get(label.ghost_entity) else {
+            continue;
+        };
+        *visibility = if is_3d { Visibility::Hidden } else { Visibility::Inherited };
+        label_transform.translation.x = ghost_transform.translation.x + offset.x;
+        label_transform.translation.y = ghost_transform.translation.y + offset.y;
+        label_transform.rotation = camera_rotation;
+        label_transform.scale = Vec3::splat(1.0 / zoom_state.camera_zoom);
+    }
+}
+
+/// Swap the ghost material onto every mesh of each ghost's model once the
+/// scene has spawned, so ghosts read clearly apart from live traffic.
+pub fn tint_ghost_markers(
+    mut commands: Commands,
+    ghost_material: Res<GhostMaterial>,
+    ghost_query: Query<Entity, With<GhostAircraft>>,
+    children_query: Query<&Children>,
+    mesh_query: Query<&MeshMaterial3d<StandardMaterial>>,
+) {
+    for ghost in ghost_query.iter() {
+        for descendant in children_query.iter_descendants(ghost) {
+            let Ok(material) = mesh_query.get(descendant) else {
+                continue;
+            };
+            if material.0 != ghost_material.0 {
+                commands.entity(descendant).insert(MeshMaterial3d(ghost_material.0.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ghost_label_carries_rec_badge() {
+        let mut state = RecordedAircraftState {
+            icao: "A1B2C3".to_string(),
+            callsign: Some("UAL123  ".to_string()),
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: Some(12_000),
+            heading: None,
+            velocity: None,
+            vertical_rate: None,
+            squawk: None,
+        };
+        assert_eq!(ghost_label_text(&state), "REC UAL123\n12000 ft");
+        state.callsign = None;
+        state.altitude = None;
+        assert_eq!(ghost_label_text(&state), "REC A1B2C3");
+    }
+}
@@ -1,5 +1,6 @@
 mod recorder;
 mod player;
+pub mod ghost;
 
 pub use recorder::*;
 pub use player::*;
@@ -12,10 +13,14 @@ impl Plugin for RecordingPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<RecordingState>()
             .init_resource::<PlaybackState>()
+            .init_resource::<ghost::GhostMaterial>()
             .add_systems(Update, (
                 record_frame,
                 playback_frame,
                 toggle_recording,
+                ghost::playback_ghosts,
+                ghost::place_ghost_markers.after(ghost::playback_ghosts),
+                ghost::tint_ghost_markers,
             ));
     }
 }
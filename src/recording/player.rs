@@ -1,13 +1,70 @@
 use bevy::prelude::*;
-use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 use std::time::Instant;
 
-use super::recorder::RecordedFrame;
+use super::recorder::{RecordedAircraftState, RecordedFrame};
 use crate::Aircraft;
 use crate::aircraft::TrailHistory;
+use crate::aircraft::emergency::EmergencyType;
+
+/// Window over which aircraft counts are compared when looking for spikes
+const SPIKE_WINDOW_MS: u64 = 60_000;
+/// Minimum growth in aircraft count (absolute) for a traffic spike marker
+const SPIKE_MIN_INCREASE: usize = 5;
+/// Minimum growth in aircraft count (relative) for a traffic spike marker
+const SPIKE_MIN_RATIO: f32 = 1.25;
+
+/// Kind of event flagged on the playback timeline
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimelineMarkerKind {
+    /// An aircraft started squawking 7500/7600/7700
+    Emergency(EmergencyType),
+    /// The aircraft count grew sharply
+    TrafficSpike,
+}
+
+/// A notable moment in a loaded recording
+#[derive(Clone, Debug)]
+pub struct TimelineMarker {
+    pub time_ms: u64,
+    pub kind: TimelineMarkerKind,
+    pub label: String,
+}
+
+/// Parse NDJSON recording frames.
+///
+/// A recording cut short by a crash may end in a partially written line; an
+/// unparsable *final* line is dropped with a warning so every complete frame
+/// before it still plays. Corruption anywhere else is reported as an error.
+pub fn read_frames<R: BufRead>(reader: R) -> Result<Vec<RecordedFrame>, String> {
+    let mut frames = Vec::new();
+    let mut pending_error: Option<(usize, serde_json::Error)> = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| format!("Failed to read line: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // A bad line followed by more data is real corruption, not truncation
+        if let Some((bad_line, e)) = pending_error.take() {
+            return Err(format!("Failed to parse frame on line {}: {}", bad_line + 1, e));
+        }
+
+        match serde_json::from_str::<RecordedFrame>(&line) {
+            Ok(frame) => frames.push(frame),
+            Err(e) => pending_error = Some((index, e)),
+        }
+    }
+
+    if let Some((bad_line, e)) = pending_error {
+        warn!("Ignoring truncated final line {} of recording: {}", bad_line + 1, e);
+    }
+
+    Ok(frames)
+}
 
 /// Playback state resource
 #[derive(Resource, Default)]
@@ -32,6 +89,13 @@ pub struct PlaybackState {
     pause_time: Option<Instant>,
     /// Accumulated pause duration
     accumulated_pause_ms: u64,
+    /// Set by `seek` so the new position is applied even while paused
+    seek_pending: bool,
+    /// Notable events found when the recording was loaded
+    pub markers: Vec<TimelineMarker>,
+    /// Replay as ghost markers on top of live traffic instead of driving
+    /// the `Aircraft` entities (see `ghost::playback_ghosts`)
+    pub overlay_live: bool,
 }
 
 impl PlaybackState {
@@ -44,25 +108,14 @@ impl PlaybackState {
         let file = File::open(path)
             .map_err(|e| format!("Failed to open file: {}", e))?;
 
-        let reader = BufReader::new(file);
-        let mut frames = Vec::new();
-
-        for line in reader.lines() {
-            let line = line.map_err(|e| format!("Failed to read line: {}", e))?;
-            if line.trim().is_empty() {
-                continue;
-            }
-
-            let frame: RecordedFrame = serde_json::from_str(&line)
-                .map_err(|e| format!("Failed to parse frame: {}", e))?;
-            frames.push(frame);
-        }
+        let frames = read_frames(BufReader::new(file))?;
 
         if frames.is_empty() {
             return Err("Recording file is empty".to_string());
         }
 
         self.total_duration_ms = frames.last().map(|f| f.timestamp_ms).unwrap_or(0);
+        self.markers = compute_timeline_markers(&frames);
         self.frames = frames;
         self.current_frame_index = 0;
         self.current_time_ms = 0;
@@ -72,8 +125,10 @@ impl PlaybackState {
         self.playback_start = Some(Instant::now());
         self.pause_time = None;
         self.accumulated_pause_ms = 0;
+        self.seek_pending = false;
 
-        info!("Loaded recording with {} frames, duration {} ms", self.frames.len(), self.total_duration_ms);
+        info!("Loaded recording with {} frames, duration {} ms, {} markers",
+            self.frames.len(), self.total_duration_ms, self.markers.len());
         Ok(())
     }
 
@@ -82,11 +137,19 @@ impl PlaybackState {
         self.is_playing = false;
         self.is_paused = false;
         self.frames.clear();
+        self.markers.clear();
         self.current_frame_index = 0;
         self.current_time_ms = 0;
         self.playback_start = None;
         self.pause_time = None;
         self.accumulated_pause_ms = 0;
+        self.seek_pending = false;
+    }
+
+    /// Whether playback is driving the `Aircraft` entities rather than
+    /// overlaying ghosts on live traffic
+    pub fn drives_aircraft(&self) -> bool {
+        self.is_playing && !self.overlay_live
     }
 
     /// Pause playback
@@ -111,11 +174,30 @@ impl PlaybackState {
     pub fn seek(&mut self, time_ms: u64) {
         self.current_time_ms = time_ms.min(self.total_duration_ms);
 
-        // Find the frame closest to this time
+        // Find the last frame at or before this time
         self.current_frame_index = self.frames
             .iter()
-            .position(|f| f.timestamp_ms >= self.current_time_ms)
-            .unwrap_or(self.frames.len().saturating_sub(1));
+            .rposition(|f| f.timestamp_ms <= self.current_time_ms)
+            .unwrap_or(0);
+
+        // Re-anchor the playback clock so advance() continues from here
+        let now = Instant::now();
+        let offset = std::time::Duration::from_millis((self.current_time_ms as f32 / self.speed.max(0.01)) as u64);
+        self.playback_start = Some(now.checked_sub(offset).unwrap_or(now));
+        self.accumulated_pause_ms = 0;
+        if self.is_paused {
+            self.pause_time = Some(now);
+        }
+        self.seek_pending = true;
+    }
+
+    /// Seek to the newest recorded frame and pause there
+    pub fn jump_to_newest(&mut self) {
+        if !self.is_playing {
+            return;
+        }
+        self.pause();
+        self.seek(self.total_duration_ms);
     }
 
     /// Get the current frame, if any
@@ -123,9 +205,14 @@ impl PlaybackState {
         self.frames.get(self.current_frame_index)
     }
 
-    /// Advance playback and return the current frame if one should be applied
-    pub fn advance(&mut self) -> Option<&RecordedFrame> {
-        if !self.is_playing || self.is_paused || self.frames.is_empty() {
+    /// Advance playback and return the aircraft states for the current
+    /// playback time, interpolated between the bracketing recorded frames.
+    pub fn advance(&mut self) -> Option<Vec<RecordedAircraftState>> {
+        if !self.is_playing || self.frames.is_empty() {
+            return None;
+        }
+        let seek_pending = std::mem::take(&mut self.seek_pending);
+        if self.is_paused && !seek_pending {
             return None;
         }
 
@@ -133,28 +220,146 @@ impl PlaybackState {
             return None;
         };
 
-        // Calculate current playback time
-        let real_elapsed_ms = start.elapsed().as_millis() as u64 - self.accumulated_pause_ms;
-        self.current_time_ms = (real_elapsed_ms as f32 * self.speed) as u64;
+        if !self.is_paused {
+            // Calculate current playback time
+            let real_elapsed_ms = (start.elapsed().as_millis() as u64).saturating_sub(self.accumulated_pause_ms);
+            self.current_time_ms = (real_elapsed_ms as f32 * self.speed) as u64;
 
-        // Check if we've reached the end
-        if self.current_time_ms >= self.total_duration_ms {
-            self.stop();
+            // Check if we've run past the end
+            if self.current_time_ms > self.total_duration_ms {
+                self.stop();
+                return None;
+            }
+        }
+
+        // Move to the last frame at or before the current time
+        while self.current_frame_index + 1 < self.frames.len()
+            && self.frames[self.current_frame_index + 1].timestamp_ms <= self.current_time_ms
+        {
+            self.current_frame_index += 1;
+        }
+
+        let prev = &self.frames[self.current_frame_index];
+        if prev.timestamp_ms > self.current_time_ms {
+            // Before the first recorded frame
             return None;
         }
+        match self.frames.get(self.current_frame_index + 1) {
+            Some(next) => Some(interpolate_frames(prev, next, self.current_time_ms)),
+            None => Some(prev.aircraft.clone()),
+        }
+    }
+}
 
-        // Find the appropriate frame
-        while self.current_frame_index < self.frames.len() {
-            let frame = &self.frames[self.current_frame_index];
-            if frame.timestamp_ms <= self.current_time_ms {
-                self.current_frame_index += 1;
-                return Some(frame);
+/// Scan loaded frames once for emergency squawks and aircraft-count spikes.
+pub fn compute_timeline_markers(frames: &[RecordedFrame]) -> Vec<TimelineMarker> {
+    let mut markers = Vec::new();
+    let mut emergencies: std::collections::HashMap<&str, EmergencyType> = std::collections::HashMap::new();
+    let mut window_start = 0;
+    let mut last_spike_ms: Option<u64> = None;
+
+    for (i, frame) in frames.iter().enumerate() {
+        // Emergency squawks: flag the first frame each aircraft enters a new emergency
+        let mut current: std::collections::HashMap<&str, EmergencyType> = std::collections::HashMap::new();
+        for ac in &frame.aircraft {
+            let Some(kind) = ac.squawk.as_deref().and_then(EmergencyType::from_squawk) else {
+                continue;
+            };
+            if emergencies.get(ac.icao.as_str()) != Some(&kind) {
+                markers.push(TimelineMarker {
+                    time_ms: frame.timestamp_ms,
+                    label: format!(
+                        "{} {}",
+                        kind.description(),
+                        ac.callsign.as_deref().map(str::trim).unwrap_or(&ac.icao),
+                    ),
+                    kind: TimelineMarkerKind::Emergency(kind.clone()),
+                });
             }
-            break;
+            current.insert(ac.icao.as_str(), kind);
+        }
+        emergencies = current;
+
+        // Traffic spikes: compare against the count one window earlier
+        while frames[window_start].timestamp_ms + SPIKE_WINDOW_MS < frame.timestamp_ms {
+            window_start += 1;
+        }
+        if window_start == i {
+            continue;
         }
+        let before = frames[window_start].aircraft.len();
+        let now = frame.aircraft.len();
+        let cooled_down = last_spike_ms.is_none_or(|t| frame.timestamp_ms >= t + SPIKE_WINDOW_MS);
+        if cooled_down
+            && now >= before + SPIKE_MIN_INCREASE
+            && now as f32 >= before as f32 * SPIKE_MIN_RATIO
+        {
+            markers.push(TimelineMarker {
+                time_ms: frame.timestamp_ms,
+                kind: TimelineMarkerKind::TrafficSpike,
+                label: format!("Traffic {} \u{2192} {}", before, now),
+            });
+            last_spike_ms = Some(frame.timestamp_ms);
+        }
+    }
 
-        None
+    markers.sort_by_key(|m| m.time_ms);
+    markers
+}
+
+/// Interpolate aircraft states at `time_ms` between two recorded frames.
+///
+/// Aircraft are matched by ICAO. Those present in both frames have their
+/// position, altitude and heading lerped; aircraft only in `prev` keep their
+/// recorded state until `next` takes effect.
+pub fn interpolate_frames(prev: &RecordedFrame, next: &RecordedFrame, time_ms: u64) -> Vec<RecordedAircraftState> {
+    let span = next.timestamp_ms.saturating_sub(prev.timestamp_ms);
+    if span == 0 {
+        return next.aircraft.clone();
     }
+    let t = (time_ms.saturating_sub(prev.timestamp_ms) as f64 / span as f64).clamp(0.0, 1.0);
+
+    let next_by_icao: std::collections::HashMap<&str, &RecordedAircraftState> = next
+        .aircraft
+        .iter()
+        .map(|a| (a.icao.as_str(), a))
+        .collect();
+
+    prev.aircraft
+        .iter()
+        .map(|a| {
+            let Some(b) = next_by_icao.get(a.icao.as_str()) else {
+                return a.clone();
+            };
+            let mut state = a.clone();
+            state.latitude = a.latitude + (b.latitude - a.latitude) * t;
+            // Take the short way across the antimeridian
+            let mut dlon = b.longitude - a.longitude;
+            if dlon > 180.0 {
+                dlon -= 360.0;
+            } else if dlon < -180.0 {
+                dlon += 360.0;
+            }
+            state.longitude = a.longitude + dlon * t;
+            if state.longitude > 180.0 {
+                state.longitude -= 360.0;
+            } else if state.longitude < -180.0 {
+                state.longitude += 360.0;
+            }
+            state.altitude = match (a.altitude, b.altitude) {
+                (Some(x), Some(y)) => Some(x + ((y - x) as f64 * t).round() as i32),
+                (x, _) => x,
+            };
+            state.heading = match (a.heading, b.heading) {
+                (Some(x), Some(y)) => {
+                    let diff = (y - x + 540.0).rem_euclid(360.0) - 180.0;
+                    Some((x + diff * t as f32).rem_euclid(360.0))
+                }
+                (x, _) => x,
+            };
+            state
+        })
+        .collect()
 }
 
 /// System to apply playback frames to aircraft entities
@@ -166,9 +371,13 @@ pub fn playback_frame(
     mut materials: ResMut<Assets<ColorMaterial>>,
     asset_server: Res<AssetServer>,
 ) {
-    // Get the current frame to apply
-    let frame = playback.advance();
-    let Some(frame) = frame else {
+    // Overlay mode is driven by playback_ghosts instead
+    if playback.overlay_live {
+        return;
+    }
+
+    // Get the interpolated aircraft states to apply
+    let Some(states) = playback.advance() else {
         return;
     };
 
@@ -179,13 +388,15 @@ pub fn playback_frame(
         .collect();
 
     // Apply frame data
-    for state in &frame.aircraft {
+    for state in &states {
         if let Some(entity) = existing_aircraft.remove(&state.icao) {
             // Update existing aircraft
             if let Ok((_, mut aircraft, _)) = aircraft_query.get_mut(entity) {
                 aircraft.latitude = state.latitude;
                 aircraft.longitude = state.longitude;
+                // Recordings hold a single (effective) altitude
                 aircraft.altitude = state.altitude;
+                aircraft.barometric_altitude = state.altitude;
                 aircraft.heading = state.heading;
                 aircraft.velocity = state.velocity;
                 aircraft.vertical_rate = state.vertical_rate;
@@ -203,6 +414,8 @@ pub fn playback_frame(
                     latitude: state.latitude,
                     longitude: state.longitude,
                     altitude: state.altitude,
+                    barometric_altitude: state.altitude,
+                    geometric_altitude: None,
                     heading: state.heading,
                     velocity: state.velocity,
                     vertical_rate: state.vertical_rate,
@@ -213,9 +426,7 @@ pub fn playback_frame(
                     spi: None,
                     last_seen: chrono::Utc::now(),
                 },
-                TrailHistory {
-                    points: VecDeque::new(),
-                },
+                TrailHistory::default(),
                 Transform::default(),
                 Visibility::default(),
             ));
@@ -227,3 +438,95 @@ pub fn playback_frame(
         commands.entity(entity).despawn();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(icao: &str, lon: f64, heading: f32) -> RecordedAircraftState {
+        RecordedAircraftState {
+            icao: icao.to_string(),
+            callsign: None,
+            latitude: 40.0,
+            longitude: lon,
+            altitude: Some(10_000),
+            heading: Some(heading),
+            velocity: None,
+            vertical_rate: None,
+            squawk: None,
+        }
+    }
+
+    #[test]
+    fn interpolates_matched_aircraft_between_frames() {
+        let prev = RecordedFrame { timestamp_ms: 0, aircraft: vec![state("A1", -100.0, 350.0), state("B2", 0.0, 90.0)] };
+        let next = RecordedFrame { timestamp_ms: 1000, aircraft: vec![state("A1", -99.0, 10.0)] };
+
+        let states = interpolate_frames(&prev, &next, 500);
+        assert_eq!(states.len(), 2);
+        assert!((states[0].longitude - -99.5).abs() < 1e-9);
+        // Heading wraps through north rather than swinging through south
+        assert!(states[0].heading.unwrap().abs() < 1e-3 || (states[0].heading.unwrap() - 360.0).abs() < 1e-3);
+        // Unmatched aircraft keep their recorded state
+        assert_eq!(states[1].longitude, 0.0);
+    }
+
+    #[test]
+    fn markers_flag_new_emergencies_and_spikes() {
+        let mut squawking = state("A1", 0.0, 0.0);
+        squawking.squawk = Some("7700".to_string());
+        let crowd: Vec<_> = (0..10).map(|i| state(&format!("C{}", i), 0.0, 0.0)).collect();
+
+        let frames = vec![
+            RecordedFrame { timestamp_ms: 0, aircraft: vec![state("A1", 0.0, 0.0)] },
+            RecordedFrame { timestamp_ms: 1000, aircraft: vec![squawking.clone()] },
+            RecordedFrame { timestamp_ms: 2000, aircraft: vec![squawking.clone()] },
+            RecordedFrame { timestamp_ms: 3000, aircraft: crowd },
+        ];
+        let markers = compute_timeline_markers(&frames);
+        assert_eq!(markers.len(), 2);
+        assert_eq!(markers[0].time_ms, 1000);
+        assert_eq!(markers[0].kind, TimelineMarkerKind::Emergency(EmergencyType::General));
+        assert_eq!(markers[1].time_ms, 3000);
+        assert_eq!(markers[1].kind, TimelineMarkerKind::TrafficSpike);
+    }
+
+    #[test]
+    fn truncated_final_line_keeps_complete_frames() {
+        let frames = [
+            RecordedFrame { timestamp_ms: 0, aircraft: vec![state("A1", 0.0, 0.0)] },
+            RecordedFrame { timestamp_ms: 1000, aircraft: vec![state("A1", 0.1, 0.0)] },
+        ];
+        let mut data = String::new();
+        for frame in &frames {
+            data.push_str(&serde_json::to_string(frame).unwrap());
+            data.push('\n');
+        }
+        let partial = serde_json::to_string(&frames[1]).unwrap();
+        data.push_str(&partial[..partial.len() / 2]);
+
+        let path = std::env::temp_dir().join(format!("airjedi_truncated_{}.ndjson", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+        let loaded = read_frames(BufReader::new(File::open(&path).unwrap()));
+        let _ = std::fs::remove_file(&path);
+
+        let loaded = loaded.expect("truncated tail should not fail the load");
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[1].timestamp_ms, 1000);
+    }
+
+    #[test]
+    fn corrupt_line_mid_file_is_an_error() {
+        let frame = serde_json::to_string(&RecordedFrame { timestamp_ms: 0, aircraft: vec![] }).unwrap();
+        let data = format!("{}\n{{garbage\n{}\n", frame, frame);
+        assert!(read_frames(data.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn interpolation_crosses_antimeridian() {
+        let prev = RecordedFrame { timestamp_ms: 0, aircraft: vec![state("A1", 179.5, 90.0)] };
+        let next = RecordedFrame { timestamp_ms: 1000, aircraft: vec![state("A1", -179.5, 90.0)] };
+        let states = interpolate_frames(&prev, &next, 500);
+        assert!((states[0].longitude.abs() - 180.0).abs() < 1e-9);
+    }
+}
@@ -152,7 +152,9 @@ impl RecordingState {
         if let Some(ref mut writer) = self.writer {
             match serde_json::to_string(&frame) {
                 Ok(json) => {
-                    if writeln!(writer, "{}", json).is_ok() {
+                    // Flush every frame so an abrupt exit leaves a replayable
+                    // file; at ~1 FPS the extra syscall is negligible.
+                    if writeln!(writer, "{}", json).and_then(|_| writer.flush()).is_ok() {
                         self.frame_count += 1;
                         self.last_frame_time = Some(Instant::now());
                     }
@@ -192,6 +194,7 @@ pub fn record_frame(
 /// System to toggle recording with a keyboard shortcut
 pub fn toggle_recording(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut recording: ResMut<RecordingState>,
     mut contexts: EguiContexts,
 ) {
@@ -203,14 +206,12 @@ pub fn toggle_recording(
     }
 
     // Ctrl+R to toggle recording
-    if keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight) {
-        if keyboard.just_pressed(KeyCode::KeyR) {
-            if recording.is_recording {
-                recording.stop();
-            } else {
-                if let Err(e) = recording.start() {
-                    error!("Failed to start recording: {}", e);
-                }
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleRecording, &keyboard) {
+        if recording.is_recording {
+            recording.stop();
+        } else {
+            if let Err(e) = recording.start() {
+                error!("Failed to start recording: {}", e);
             }
         }
     }
@@ -3,8 +3,11 @@ use bevy_egui::{egui, EguiContexts};
 
 use crate::adsb::AdsbAircraftData;
 use crate::aircraft::stats_panel::StatsPanelState;
+use crate::config::AppConfig;
+use crate::geo::format_latlon;
 use crate::recording::RecordingState;
 use crate::theme::{AppTheme, to_egui_color32};
+use crate::ui_panels::UiPanelManager;
 use crate::MapState;
 
 /// FPS smoothing state using exponential moving average.
@@ -37,8 +40,10 @@ pub fn render_statusbar(
     stats: Res<StatsPanelState>,
     recording: Res<RecordingState>,
     map_state: Res<MapState>,
+    app_config: Res<AppConfig>,
     time: Res<Time>,
     mut state: ResMut<StatusBarState>,
+    panels: Res<UiPanelManager>,
 ) {
     // Update FPS with exponential moving average
     let dt = time.delta_secs();
@@ -51,6 +56,9 @@ pub fn render_statusbar(
         }
     }
 
+    if panels.is_focus_mode() {
+        return;
+    }
     let Ok(ctx) = contexts.ctx_mut() else {
         return;
     };
@@ -141,9 +149,8 @@ pub fn render_statusbar(
                     // Map position + zoom
                     ui.label(
                         egui::RichText::new(format!(
-                            "{:.4}, {:.4}  Z{}",
-                            map_state.latitude,
-                            map_state.longitude,
+                            "{}  Z{}",
+                            format_latlon(map_state.latitude, map_state.longitude, app_config.map.coordinate_format),
                             map_state.zoom_level.to_u8(),
                         ))
                         .size(FONT_SIZE)
@@ -165,6 +165,17 @@ impl HeightmapCache {
         removed
     }
 
+    /// Lowest elevation (meters) across cached heightmaps at `zoom`, or
+    /// `None` when none are loaded yet.
+    pub(crate) fn min_elevation_at_zoom(&self, zoom: u8) -> Option<f32> {
+        self.cache
+            .iter()
+            .filter(|((z, _, _), _)| *z == zoom)
+            .map(|(_, data)| data.min_elevation)
+            .filter(|m| m.is_finite())
+            .reduce(f32::min)
+    }
+
     /// Number of cached heightmaps.
     pub(crate) fn len(&self) -> usize {
         self.cache.len()
@@ -233,6 +233,12 @@ impl Plugin for TerrainPlugin {
                 update_ground_elevation
                     .after(heightmap::poll_heightmap_completions),
             )
+            .add_systems(
+                Update,
+                update_cursor_elevation
+                    .after(heightmap::poll_heightmap_completions)
+                    .after(view3d::update_cursor_ground_position),
+            )
             .add_systems(
                 Update,
                 animate_terrain_displacement
@@ -390,10 +396,30 @@ fn evict_terrain_caches(
     }
 }
 
+/// Sample the heightmap at the ground point under the cursor for the 3D
+/// camera HUD readout. Cleared when terrain is off or no heightmap covers
+/// the point, in which case the HUD shows the flat ground elevation.
+fn update_cursor_elevation(
+    terrain_state: Res<TerrainState>,
+    mut view3d_state: ResMut<View3DState>,
+    map_state: Res<MapState>,
+    heightmap_cache: Res<HeightmapCache>,
+) {
+    let elevation_ft = view3d_state
+        .cursor_ground
+        .filter(|_| terrain_state.enabled)
+        .and_then(|(lat, lon)| heightmap_cache.sample_elevation(lat, lon, map_state.zoom_level))
+        .map(|m| (m as f64 / crate::geo::FEET_TO_METERS) as i32);
+    if view3d_state.cursor_elevation_ft != elevation_ft {
+        view3d_state.cursor_elevation_ft = elevation_ft;
+    }
+}
+
 /// Sample the heightmap at the camera's map center position and update
-/// `View3DState::ground_elevation_ft`. Only runs when terrain is enabled
-/// and 3D mode is active. Falls back to the existing airport-based detection
-/// when no heightmap data is available at the current position.
+/// `View3DState::ground_elevation_ft`, and anchor the flat ground plane to
+/// the lowest loaded terrain via `terrain_floor_ft`. Only runs when terrain
+/// is enabled and 3D mode is active. Falls back to the existing airport-based
+/// detection when no heightmap data is available at the current position.
 fn update_ground_elevation(
     terrain_state: Res<TerrainState>,
     mut view3d_state: ResMut<View3DState>,
@@ -401,6 +427,9 @@ fn update_ground_elevation(
     mut heightmap_cache: ResMut<HeightmapCache>,
 ) {
     if !terrain_state.enabled || !view3d_state.is_3d_active() {
+        if view3d_state.terrain_floor_ft.is_some() {
+            view3d_state.terrain_floor_ft = None;
+        }
         return;
     }
 
@@ -423,9 +452,16 @@ fn update_ground_elevation(
         map_state.longitude,
         zoom_level,
     ) {
-        let elevation_ft = (elevation_m * 3.28084) as i32;
+        let elevation_ft = (elevation_m as f64 / crate::geo::FEET_TO_METERS) as i32;
         view3d_state.ground_elevation_ft = elevation_ft;
     }
+
+    let floor_ft = heightmap_cache
+        .min_elevation_at_zoom(zoom)
+        .map(|m| (m as f64 / crate::geo::FEET_TO_METERS).floor() as i32);
+    if view3d_state.terrain_floor_ft != floor_ft {
+        view3d_state.terrain_floor_ft = floor_ft;
+    }
 }
 
 /// Animate terrain displacement during 2D↔3D transitions.
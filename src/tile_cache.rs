@@ -109,6 +109,39 @@ pub fn clear_tile_cache() {
     info!("Cleared {} tile(s) from cache at {:?}", deleted_count, cache_dir);
 }
 
+/// Clears cached tile files for a single zoom level. Tile filenames are
+/// `{zoom}.{x}.{y}.{size}.tile.{ext}`, so the zoom is the leading component.
+pub fn clear_tile_cache_for_zoom(zoom: u8) {
+    let cache_dir = tile_cache_dir();
+
+    if !cache_dir.exists() {
+        warn!("Tile cache directory not found at {:?}", cache_dir);
+        return;
+    }
+
+    let prefix = format!("{}.", zoom);
+    let mut deleted_count = 0;
+
+    if let Ok(entries) = fs::read_dir(&cache_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_file() {
+                if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
+                    if filename.starts_with(&prefix) && filename.contains(".tile.") {
+                        if let Err(e) = fs::remove_file(&path) {
+                            warn!("Failed to delete tile {:?}: {}", path, e);
+                        } else {
+                            deleted_count += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    info!("Cleared {} zoom {} tile(s) from cache at {:?}", deleted_count, zoom, cache_dir);
+}
+
 /// Also clear any legacy tiles sitting directly in `assets/` from before
 /// the centralized cache was introduced.
 pub fn clear_legacy_tiles() {
@@ -9,8 +9,9 @@ use crate::map::{MapState, ZoomState};
 use crate::tile_cache;
 use crate::view3d;
 use crate::camera::MapCamera;
+use crate::config::{AppConfig, TileDetail};
 use crate::RenderCategory;
-use crate::{clamp_latitude, clamp_longitude, ZoomDebugLogger, ZoomSet};
+use crate::{clamp_latitude, ZoomDebugLogger, ZoomSet};
 use bevy::camera::visibility::RenderLayers;
 
 // =============================================================================
@@ -23,6 +24,15 @@ pub(crate) struct TileFadeState {
     pub(crate) alpha: f32,
     /// The zoom level this tile was spawned for
     pub(crate) tile_zoom: u8,
+    /// Seconds this tile has been outside the active zoom band
+    pub(crate) superseded_secs: f32,
+}
+
+/// Request to discard one zoom level's tiles (entities and disk cache) and
+/// re-download them, leaving other zoom levels untouched.
+#[derive(Message)]
+pub(crate) struct ClearZoomTilesRequest {
+    pub(crate) zoom: u8,
 }
 
 /// Links a tile entity to its 3D mesh quad companion (used in 3D mode only).
@@ -55,6 +65,31 @@ impl Default for Tile3DRefreshTimer {
     }
 }
 
+impl Tile3DRefreshTimer {
+    /// Make the next tick fire so the multi-band request runs this frame.
+    pub(crate) fn force(&mut self) {
+        let duration = self.0.duration();
+        self.0.set_elapsed(duration);
+    }
+}
+
+/// Fraction of the near band's tiles that must be loaded before the 3D
+/// refresh also requests the far and horizon bands. Requesting everything at
+/// once queues hundreds of coarse tiles ahead of the ones under the camera.
+const NEAR_BAND_READY_FRACTION: f32 = 0.5;
+
+/// Refresh ticks the far bands may be deferred in a row, so a near band that
+/// can never fill (e.g. off the edge of the map) doesn't starve them.
+const MAX_FAR_BAND_DEFERRALS: u8 = 3;
+
+/// Zoom offset from the base zoom at which bands count as "far".
+const FAR_BAND_ZOOM_OFFSET: u8 = 2;
+
+/// Seconds after a window mode change (entering/leaving fullscreen) before
+/// tiles are requested again. The OS animates the transition, so the first
+/// resize event can report an intermediate size.
+const WINDOW_MODE_SETTLE_SECS: f32 = 0.5;
+
 /// Tracks the previous 3D zoom level so tile transforms can be rescaled
 /// when the zoom level changes. Without this, tiles spawned at zoom N
 /// stay at zoom-N pixel coordinates while the camera and entities move
@@ -100,6 +135,11 @@ pub(crate) struct GridOverlay {
     pub texture: Handle<Image>,
 }
 
+/// Distance the map center may drift from the projection origin before the
+/// origin is moved to the center. Keeps world-space pixel coordinates small
+/// enough for f32 precision after long pans.
+const REANCHOR_DISTANCE_NM: f64 = 300.0;
+
 /// Fixed emissive boost for 3D tile materials. With emissive_exposure_weight=0.0
 /// the emissive bypasses both per-material and global exposure in the tonemapping
 /// pass, so a fixed multiplier gives consistent brightness at any EV.
@@ -118,13 +158,17 @@ impl Plugin for TilesPlugin {
             .init_resource::<AltitudeChangeTracker>()
             .init_resource::<Previous3DZoom>()
             .register_type::<GridOverlay>()
+            .add_message::<ClearZoomTilesRequest>()
             .add_systems(Startup, (setup_tile_quad_mesh, setup_grid_overlay))
             .add_systems(Update, toggle_grid_overlay)
+            .add_systems(PreUpdate, reanchor_projection_origin)
             .add_systems(Update, handle_basemap_change)
+            .add_systems(Update, handle_clear_zoom_tiles)
             .add_systems(Update, handle_window_resize)
             .add_systems(Update, handle_3d_view_tile_refresh)
             .add_systems(Update, request_3d_tiles_continuous
                 .after(handle_3d_view_tile_refresh)
+                .after(handle_window_resize)
                 .in_set(ZoomSet::Change))
             .add_systems(Update, rescale_tiles_on_zoom_change
                 .after(ZoomSet::Change))
@@ -252,7 +296,13 @@ fn raw_altitude_to_zoom(altitude_ft: f32) -> f32 {
 /// Uses asymmetric hysteresis: 0.7 to upgrade (biased toward staying at
 /// lower zoom), 0.6 to downgrade, preventing the rapid 14↔15 oscillation
 /// seen at altitude boundaries.
-pub(crate) fn altitude_to_zoom_level(altitude_ft: f32, current_zoom: u8) -> u8 {
+///
+/// Contract: the result is always within 8..=18. At or below 5,000 ft the
+/// target is zoom 16, and it drops 1.5 levels per doubling of altitude
+/// (120,000 ft targets zoom 9). A target more than one level from
+/// `current_zoom` is returned directly; otherwise the result moves at most
+/// one level from `current_zoom`.
+pub fn altitude_to_zoom_level(altitude_ft: f32, current_zoom: u8) -> u8 {
     let raw = raw_altitude_to_zoom(altitude_ft);
     let target = raw.round() as u8;
     let current = current_zoom as f32;
@@ -282,11 +332,18 @@ pub(crate) fn altitude_to_zoom_level(altitude_ft: f32, current_zoom: u8) -> u8 {
 /// In 2D (orthographic): each tile occupies `256 * camera_zoom` screen pixels.
 /// In 3D (perspective): the tilted camera sees a larger ground footprint, so we
 /// estimate the visible ground extent from the camera distance, pitch, and FOV.
-pub(crate) fn compute_tile_radius(
+/// The result is scaled by the user's `TileDetail` setting.
+///
+/// Contract: before the detail scaling the radius is clamped to 3..=8 tiles
+/// in 2D and 3..=12 in 3D (`view3d_state` in or entering 3D); `None` or a
+/// 2D state uses the orthographic estimate. The scaled result is never
+/// below 2.
+pub fn compute_tile_radius(
     window_width: f32,
     window_height: f32,
     camera_zoom: f32,
     view3d_state: Option<&view3d::View3DState>,
+    detail: TileDetail,
 ) -> u8 {
     // Check if we're in 3D perspective mode
     if let Some(state) = view3d_state {
@@ -315,7 +372,7 @@ pub(crate) fn compute_tile_radius(
             let max_ground_extent = far_ground_dist.max(half_width);
             let tile_world_size = constants::DEFAULT_TILE_PIXELS;
             let tiles_needed = (max_ground_extent / tile_world_size).ceil() as u8;
-            return tiles_needed.clamp(3, 12);
+            return detail.scale_radius(tiles_needed.clamp(3, 12));
         }
     }
 
@@ -323,7 +380,7 @@ pub(crate) fn compute_tile_radius(
     let tile_screen_px = constants::DEFAULT_TILE_PIXELS * camera_zoom;
     let half_tiles_x = (window_width / (2.0 * tile_screen_px)).ceil() as u8;
     let half_tiles_y = (window_height / (2.0 * tile_screen_px)).ceil() as u8;
-    half_tiles_x.max(half_tiles_y).clamp(3, 8)
+    detail.scale_radius(half_tiles_x.max(half_tiles_y).clamp(3, 8))
 }
 
 /// Send a tile download request for the current map location.
@@ -357,19 +414,19 @@ fn handle_basemap_change(
     mut download_status: ResMut<SlippyTileDownloadStatus>,
     mut download_events: MessageWriter<DownloadSlippyTilesMessage>,
     map_state: Res<MapState>,
-    mut last_style: Local<Option<crate::config::BasemapStyle>>,
+    mut last_revision: Local<Option<u32>>,
 ) {
-    let current = basemap_state.style;
-    if *last_style == Some(current) {
+    let current = basemap_state.revision;
+    if *last_revision == Some(current) {
         return;
     }
-    let is_first_run = last_style.is_none();
-    *last_style = Some(current);
+    let is_first_run = last_revision.is_none();
+    *last_revision = Some(current);
     if is_first_run {
         return;
     }
 
-    info!("Basemap changed to {:?} - clearing all tile entities", current);
+    info!("Basemap changed to {:?} - clearing all tile entities", basemap_state.style);
 
     for (entity, mesh_quad) in tile_query.iter() {
         if let Some(quad) = mesh_quad {
@@ -390,6 +447,112 @@ fn handle_basemap_change(
     );
 }
 
+/// Move the projection origin (`SlippyTilesSettings` reference point) to the
+/// map center once it drifts more than `REANCHOR_DISTANCE_NM` away.
+///
+/// Aircraft, overlays and the camera are re-projected from lat/lon every frame,
+/// so they follow the new origin without jumping. Tiles bake their position at
+/// spawn time, so they are cleared and re-requested (served from the disk
+/// cache). Runs in `PreUpdate` so every `Update` system sees one origin per frame.
+/// Skipped in 3D, where the saved 2D center is stored relative to the origin.
+fn reanchor_projection_origin(
+    mut commands: Commands,
+    mut tile_settings: ResMut<SlippyTilesSettings>,
+    map_state: Res<MapState>,
+    view3d_state: Res<view3d::View3DState>,
+    tile_query: Query<(Entity, Option<&TileMeshQuad>), With<MapTile>>,
+    mut spawned_tiles: ResMut<SpawnedTiles>,
+    mut download_status: ResMut<SlippyTileDownloadStatus>,
+    mut download_events: MessageWriter<DownloadSlippyTilesMessage>,
+) {
+    if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        return;
+    }
+
+    let drift_nm = crate::geo::haversine_distance_nm(
+        tile_settings.reference_latitude,
+        tile_settings.reference_longitude,
+        map_state.latitude,
+        map_state.longitude,
+    );
+    if drift_nm < REANCHOR_DISTANCE_NM {
+        return;
+    }
+
+    info!(
+        "Re-anchoring projection origin to ({:.4}, {:.4}) after {:.0} NM drift",
+        map_state.latitude, map_state.longitude, drift_nm
+    );
+    tile_settings.reference_latitude = map_state.latitude;
+    tile_settings.reference_longitude = map_state.longitude;
+
+    for (entity, mesh_quad) in tile_query.iter() {
+        if let Some(quad) = mesh_quad {
+            commands.entity(quad.0).despawn();
+        }
+        commands.entity(entity).despawn();
+    }
+    spawned_tiles.positions.clear();
+    download_status.0.clear();
+
+    request_tiles_at_location(
+        &mut download_events,
+        map_state.latitude,
+        map_state.longitude,
+        map_state.zoom_level,
+        true,
+    );
+}
+
+/// Despawn and delete cached tiles for a single zoom level, then re-request it.
+fn handle_clear_zoom_tiles(
+    mut commands: Commands,
+    mut requests: MessageReader<ClearZoomTilesRequest>,
+    tile_query: Query<(Entity, &TileFadeState, Option<&TileMeshQuad>), With<MapTile>>,
+    mut spawned_tiles: ResMut<SpawnedTiles>,
+    mut download_status: ResMut<SlippyTileDownloadStatus>,
+    mut download_events: MessageWriter<DownloadSlippyTilesMessage>,
+    map_state: Res<MapState>,
+    mut events: ResMut<crate::event_log::EventLog>,
+) {
+    for request in requests.read() {
+        let zoom = request.zoom;
+        let mut despawned = 0;
+        for (entity, fade, mesh_quad) in tile_query.iter() {
+            if fade.tile_zoom != zoom {
+                continue;
+            }
+            if let Some(quad) = mesh_quad {
+                commands.entity(quad.0).despawn();
+            }
+            commands.entity(entity).despawn();
+            despawned += 1;
+        }
+        spawned_tiles.positions.retain(|&(_, _, z)| z != zoom);
+
+        // Download status is not keyed in a way we can filter by zoom, so
+        // reset it; other levels are served from the disk cache on re-request.
+        download_status.0.clear();
+
+        tile_cache::clear_tile_cache_for_zoom(zoom);
+
+        if map_state.zoom_level.to_u8() == zoom {
+            request_tiles_at_location(
+                &mut download_events,
+                map_state.latitude,
+                map_state.longitude,
+                map_state.zoom_level,
+                false,
+            );
+        }
+
+        events.record(
+            crate::event_log::EventCategory::Cache,
+            format!("Cleared zoom {} tiles ({} entities)", zoom, despawned),
+        );
+    }
+}
+
 /// When a tile image fails to load, check if the cached file is corrupt and remove it.
 /// The tile will be re-requested automatically by bevy_slippy_tiles on the next frame.
 fn handle_tile_load_failures(
@@ -426,29 +589,69 @@ fn handle_tile_load_failures(
     }
 }
 
-/// Request tiles when the window is resized or maximized so newly exposed areas are filled.
+/// Request tiles when the window is resized, maximized, or enters/leaves
+/// fullscreen so newly exposed areas are filled.
+///
+/// In 3D the single center request would leave the perspective footprint
+/// uncovered, so the multi-band `request_3d_tiles_continuous` pass is forced
+/// to run instead. Window mode changes request again once the transition
+/// has settled.
 fn handle_window_resize(
     mut resize_events: MessageReader<bevy::window::WindowResized>,
     mut download_events: MessageWriter<DownloadSlippyTilesMessage>,
+    mut refresh_timer: ResMut<Tile3DRefreshTimer>,
     map_state: Res<MapState>,
     zoom_state: Res<ZoomState>,
     view3d_state: Res<view3d::View3DState>,
+    app_config: Res<AppConfig>,
+    time: Res<Time>,
+    window_query: Query<&Window>,
+    mut last_mode: Local<Option<bevy::window::WindowMode>>,
+    mut mode_settle: Local<Option<Timer>>,
 ) {
-    for event in resize_events.read() {
-        let radius = compute_tile_radius(
-            event.width,
-            event.height,
-            zoom_state.camera_zoom,
-            Some(&view3d_state),
-        );
-        download_events.write(DownloadSlippyTilesMessage {
-            tile_size: constants::DEFAULT_TILE_SIZE,
-            zoom_level: map_state.zoom_level,
-            coordinates: Coordinates::from_latitude_longitude(map_state.latitude, map_state.longitude),
-            radius: Radius(radius),
-            use_cache: true,
-        });
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+
+    let mut resized = resize_events.read().count() > 0;
+
+    if last_mode.is_some_and(|mode| mode != window.mode) {
+        debug!("Window mode changed to {:?}, refreshing tiles", window.mode);
+        *mode_settle = Some(Timer::from_seconds(WINDOW_MODE_SETTLE_SECS, TimerMode::Once));
+        resized = true;
     }
+    *last_mode = Some(window.mode);
+
+    if let Some(settle) = mode_settle.as_mut() {
+        if settle.tick(time.delta()).just_finished() {
+            *mode_settle = None;
+            resized = true;
+        }
+    }
+
+    if !resized {
+        return;
+    }
+
+    if view3d_state.is_3d_active() {
+        refresh_timer.force();
+        return;
+    }
+
+    let radius = compute_tile_radius(
+        window.width(),
+        window.height(),
+        zoom_state.camera_zoom,
+        Some(&view3d_state),
+        app_config.map.tile_detail,
+    );
+    download_events.write(DownloadSlippyTilesMessage {
+        tile_size: constants::DEFAULT_TILE_SIZE,
+        zoom_level: map_state.zoom_level,
+        coordinates: Coordinates::from_latitude_longitude(map_state.latitude, map_state.longitude),
+        radius: Radius(radius),
+        use_cache: true,
+    });
 }
 
 /// Re-request tiles when 3D view state changes (entering/exiting 3D, orbit, pitch, distance)
@@ -461,6 +664,7 @@ fn handle_3d_view_tile_refresh(
     zoom_state: Res<ZoomState>,
     window_query: Query<&Window>,
     mut spawned_tiles: ResMut<SpawnedTiles>,
+    app_config: Res<AppConfig>,
 ) {
     if !view3d_state.is_changed() {
         return;
@@ -490,6 +694,7 @@ fn handle_3d_view_tile_refresh(
         window.height(),
         zoom_state.camera_zoom,
         Some(&view3d_state),
+        app_config.map.tile_detail,
     );
     download_events.write(DownloadSlippyTilesMessage {
         tile_size: constants::DEFAULT_TILE_SIZE,
@@ -512,6 +717,11 @@ fn handle_3d_view_tile_refresh(
 /// Mid and far bands are offset in the camera look direction so tiles load ahead
 /// of where the user is looking. Band radii adapt to pitch: low pitch (looking
 /// toward horizon) favours far tiles; high pitch (looking down) favours near tiles.
+///
+/// Bands are requested nearest first against `MapConfig::tile_budget_3d`, so
+/// the horizon is what gets dropped when the budget runs out. Far and horizon
+/// bands also wait until the near band is mostly loaded. Per-tick counts are
+/// published to the debug panel.
 fn request_3d_tiles_continuous(
     mut commands: Commands,
     mut timer: ResMut<Tile3DRefreshTimer>,
@@ -521,7 +731,11 @@ fn request_3d_tiles_continuous(
     tile_settings: Res<SlippyTilesSettings>,
     mut download_events: MessageWriter<DownloadSlippyTilesMessage>,
     mut spawned_tiles: ResMut<SpawnedTiles>,
-    tile_query: Query<(Entity, &TileFadeState, Option<&TileMeshQuad>), With<MapTile>>,
+    tile_query: Query<(Entity, &TileFadeState, Option<&TileMeshQuad>, &Sprite), With<MapTile>>,
+    app_config: Res<AppConfig>,
+    asset_server: Res<AssetServer>,
+    debug: Option<ResMut<crate::debug_panel::DebugPanelState>>,
+    mut far_deferrals: Local<u8>,
 ) {
     if !view3d_state.is_3d_active() {
         return;
@@ -561,7 +775,7 @@ fn request_3d_tiles_continuous(
             let min_band = new_z.saturating_sub(4);
             spawned_tiles.positions.retain(|&(_, _, z)| z >= min_band && z <= new_z);
             let mut despawned = 0u32;
-            for (entity, fade_state, mesh_quad) in tile_query.iter() {
+            for (entity, fade_state, mesh_quad, _) in tile_query.iter() {
                 if fade_state.tile_zoom > new_z || fade_state.tile_zoom < min_band {
                     // Despawn the mesh quad companion first to prevent orphans
                     if let Some(quad) = mesh_quad {
@@ -586,10 +800,28 @@ fn request_3d_tiles_continuous(
     // pitch_factor: 0.0 = low pitch (horizon), 1.0 = high pitch (looking down)
     let pitch_factor = ((pitch - 15.0) / (89.0 - 15.0)).clamp(0.0, 1.0);
 
-    // Adaptive band radii based on pitch
-    let near_radius = 3 + (3.0 * pitch_factor) as u8;          // 3-6
-    let mid_radius  = 3 + (2.0 * (1.0 - pitch_factor)) as u8;  // 3-5
-    let far_radius  = 2 + (3.0 * (1.0 - pitch_factor)) as u8;  // 2-5
+    // Adaptive band radii based on pitch, scaled by the tile detail setting
+    let detail = app_config.map.tile_detail;
+    let near_radius = detail.scale_radius(3 + (3.0 * pitch_factor) as u8);          // 3-6
+    let mid_radius  = detail.scale_radius(3 + (2.0 * (1.0 - pitch_factor)) as u8);  // 3-5
+    let far_radius  = detail.scale_radius(2 + (3.0 * (1.0 - pitch_factor)) as u8);  // 2-5
+    let sides = detail.side_requests();
+    let budget = app_config.map.tile_budget_3d;
+    let wrap_antimeridian = app_config.map.wrap_antimeridian;
+    let band_tiles = |radius: u8| (2 * radius as usize + 1).pow(2);
+
+    // Hold back the far bands until the near band is mostly on screen
+    let near_loaded = tile_query
+        .iter()
+        .filter(|(_, fade, _, sprite)| fade.tile_zoom == base_zoom && asset_server.is_loaded(&sprite.image))
+        .count();
+    let near_ready = near_loaded as f32 >= band_tiles(near_radius) as f32 * NEAR_BAND_READY_FRACTION;
+    let defer_far = !near_ready && *far_deferrals < MAX_FAR_BAND_DEFERRALS;
+    *far_deferrals = if defer_far { *far_deferrals + 1 } else { 0 };
+
+    let mut requests = 1usize;
+    let mut tiles = band_tiles(near_radius);
+    let mut skipped = 0usize;
 
     // --- Near band: current zoom level, centered on map position ---
     download_events.write(DownloadSlippyTilesMessage {
@@ -606,6 +838,11 @@ fn request_3d_tiles_continuous(
         if base_zoom < zoom_offset {
             return;
         }
+        let cost = band_tiles(radius);
+        if (defer_far && zoom_offset >= FAR_BAND_ZOOM_OFFSET) || tiles + cost > budget {
+            skipped += 1;
+            return;
+        }
         let z = base_zoom - zoom_offset;
         let Ok(zoom) = ZoomLevel::try_from(z) else {
             return;
@@ -622,41 +859,59 @@ fn request_3d_tiles_continuous(
             zoom_level: zoom,
             coordinates: Coordinates::from_latitude_longitude(
                 clamp_latitude(lat + offset_lat),
-                clamp_longitude(lon + offset_lon),
+                crate::normalize_longitude(lon + offset_lon, wrap_antimeridian),
             ),
             radius: Radius(radius),
             use_cache: true,
         });
+        requests += 1;
+        tiles += cost;
     };
 
     // --- Mid band: zoom_level - 1 ---
     request_band(1, 3.0, 0.0, mid_radius);
-    request_band(1, 2.0, -4.0, mid_radius);
-    request_band(1, 2.0, 4.0, mid_radius);
+    if sides {
+        request_band(1, 2.0, -4.0, mid_radius);
+        request_band(1, 2.0, 4.0, mid_radius);
+    }
 
     // --- Far band: zoom_level - 2 ---
     request_band(2, 4.0, 0.0, far_radius);
-    request_band(2, 3.0, -5.0, far_radius);
-    request_band(2, 3.0, 5.0, far_radius);
+    if sides {
+        request_band(2, 3.0, -5.0, far_radius);
+        request_band(2, 3.0, 5.0, far_radius);
+    }
 
     // --- Horizon bands: zoom_level - 3 and - 4 ---
-    let hr = 4 + (3.0 * (1.0 - pitch_factor)) as u8; // 4-7
+    let hr = detail.scale_radius(4 + (3.0 * (1.0 - pitch_factor)) as u8); // 4-7
+
+    let sweeps = app_config.map.max_horizon_sweeps as usize;
 
     // zoom-3: sweep at multiple forward distances
-    for &fwd in &[2.0, 5.0, 8.0] {
+    for &fwd in detail.horizon_sweeps().iter().take(sweeps) {
         request_band(3, fwd, 0.0, hr);
-        let spread = fwd * 1.5 + 4.0;
-        request_band(3, fwd, -spread, hr);
-        request_band(3, fwd, spread, hr);
+        if sides {
+            let spread = fwd * 1.5 + 4.0;
+            request_band(3, fwd, -spread, hr);
+            request_band(3, fwd, spread, hr);
+        }
     }
 
     // zoom-4: coarser tiles for the far horizon, even wider sweep
-    let ur = 4 + (2.0 * (1.0 - pitch_factor)) as u8; // 4-6
-    for &fwd in &[2.0, 5.0, 8.0] {
+    let ur = detail.scale_radius(4 + (2.0 * (1.0 - pitch_factor)) as u8); // 4-6
+    for &fwd in detail.horizon_sweeps().iter().take(sweeps) {
         request_band(4, fwd, 0.0, ur);
-        let spread = fwd * 2.0 + 5.0;
-        request_band(4, fwd, -spread, ur);
-        request_band(4, fwd, spread, ur);
+        if sides {
+            let spread = fwd * 2.0 + 5.0;
+            request_band(4, fwd, -spread, ur);
+            request_band(4, fwd, spread, ur);
+        }
+    }
+
+    if let Some(mut debug) = debug {
+        debug.tile_requests_3d = requests;
+        debug.tiles_requested_3d = tiles;
+        debug.tile_bands_skipped_3d = skipped;
     }
 }
 
@@ -745,12 +1000,16 @@ fn display_tiles_filtered(
     view3d_state: Res<view3d::View3DState>,
     grid: Option<Res<GridOverlay>>,
     basemap_state: Res<crate::config::CurrentBasemapState>,
+    app_config: Res<AppConfig>,
 ) {
     let current_zoom = map_state.zoom_level.to_u8();
+    // With the fade disabled, 2D tiles appear fully opaque at once. 3D keeps
+    // its short fade so textures get a frame to load.
+    let initial_alpha = if app_config.map.tile_fade || view3d_state.is_3d_active() { 0.0 } else { 1.0 };
 
     // Scale factor to compensate when the tile server returns smaller images
     // than the requested tile size (e.g. ESRI returns 256px for @2x/512 requests).
-    let native_px = basemap_state.style.native_tile_pixels() as f32;
+    let native_px = basemap_state.source.native_tile_pixels as f32;
     let requested_px = constants::DEFAULT_TILE_SIZE.to_pixels() as f32;
     let tile_scale = requested_px / native_px;
 
@@ -791,7 +1050,10 @@ fn display_tiles_filtered(
         let tile_center_x = tile_x + half_tile;
         let tile_center_y = tile_y - half_tile;
 
-        let mut transform_x = (tile_center_x - ref_x) as f32;
+        // Nearest copy across the antimeridian, matching CoordinateConverter;
+        // this also keeps the dedup key below identical for both sides of the seam
+        let world_width = crate::geo::world_width_pixels(event.tile_size, event.zoom_level);
+        let mut transform_x = crate::geo::wrap_pixel_offset(tile_center_x - ref_x, world_width) as f32;
         let mut transform_y = (tile_center_y - ref_y) as f32;
 
         // In 3D mode, lower-zoom tiles are in a different pixel coordinate
@@ -838,7 +1100,7 @@ fn display_tiles_filtered(
             Name::new(format!("Map Tile z{}", event_zoom)),
             Sprite {
                 image: display_handle,
-                color: Color::srgba(1.0, 1.0, 1.0, 0.0),
+                color: Color::srgba(1.0, 1.0, 1.0, initial_alpha),
                 custom_size: if tile_scale != 1.0 {
                     Some(Vec2::splat(requested_px))
                 } else {
@@ -851,8 +1113,9 @@ fn display_tiles_filtered(
                 .with_scale(Vec3::splat(rescale)),
             MapTile,
             TileFadeState {
-                alpha: 0.0,
+                alpha: initial_alpha,
                 tile_zoom: event_zoom,
+                superseded_secs: 0.0,
             },
             RenderLayers::layer(RenderCategory::TILES_2D),
         ));
@@ -869,14 +1132,15 @@ fn display_tiles_filtered(
 /// directional requests) can generate 800-1200 tiles at steady state.
 /// The budget must exceed this to prevent a spawn-cull-respawn cycle
 /// where culled tiles are re-requested every 300ms, causing flashing
-/// as they respawn at alpha 0 and fade back in.
-fn max_tile_entities(view3d_state: Option<&view3d::View3DState>) -> usize {
+/// as they respawn at alpha 0 and fade back in. Scaled with `TileDetail`
+/// alongside the band requests so the budget keeps that margin.
+fn max_tile_entities(view3d_state: Option<&view3d::View3DState>, detail: TileDetail) -> usize {
     if let Some(state) = view3d_state {
         if state.is_3d_active() {
-            return 1500;
+            return detail.scale_budget(1500);
         }
     }
-    400 // 2D limit
+    detail.scale_budget(400) // 2D limit
 }
 
 /// Despawn tile entities that are far outside the visible viewport.
@@ -890,6 +1154,7 @@ fn cull_offscreen_tiles(
     mut spawned_tiles: ResMut<SpawnedTiles>,
     view3d_state: Res<view3d::View3DState>,
     alt_tracker: Res<AltitudeChangeTracker>,
+    app_config: Res<AppConfig>,
 ) {
     let Ok((camera_tf, projection)) = camera_query.single() else {
         return;
@@ -977,7 +1242,7 @@ fn cull_offscreen_tiles(
 
     // Second pass: if still over budget, cull farthest tiles.
     // Raise the limit during active altitude changes to avoid thrashing.
-    let base_limit = max_tile_entities(Some(&view3d_state));
+    let base_limit = max_tile_entities(Some(&view3d_state), app_config.map.tile_detail);
     let tile_limit = if view3d_state.is_3d_active() && alt_tracker.idle_secs < 0.5 {
         base_limit + 200
     } else {
@@ -997,7 +1262,16 @@ fn cull_offscreen_tiles(
     }
 }
 
-/// Animate tile fade-in and despawn old tiles only when covered by fully-loaded new tiles.
+/// Quantize a tile position to a coverage grid cell of `cell_pixels`.
+fn coverage_cell(translation: Vec3, cell_pixels: f32) -> (i32, i32) {
+    (
+        (translation.x / cell_pixels).round() as i32,
+        (translation.y / cell_pixels).round() as i32,
+    )
+}
+
+/// Animate tile fade-in and despawn old tiles when covered by loaded new
+/// tiles, or once they have been superseded for too long regardless.
 fn animate_tile_fades(
     mut commands: Commands,
     time: Res<Time>,
@@ -1005,6 +1279,7 @@ fn animate_tile_fades(
     mut tile_query: Query<(Entity, &mut TileFadeState, &mut Sprite, &Transform), With<MapTile>>,
     mut spawned_tiles: ResMut<SpawnedTiles>,
     view3d_state: Res<view3d::View3DState>,
+    app_config: Res<AppConfig>,
 ) {
     let delta = time.delta_secs();
     let current_zoom = map_state.zoom_level.to_u8();
@@ -1013,10 +1288,10 @@ fn animate_tile_fades(
     // current_zoom - 4) are intentional and should NOT be treated as "old."
     let is_3d = view3d_state.is_3d_active();
 
-    // Collect grid cells covered by fully-opaque new tiles.
-    // Quantize positions to 256px cells so old (rescaled) tiles can be matched.
+    // Collect grid cells covered by sufficiently opaque new tiles.
+    // Quantize positions to coverage cells so old (rescaled) tiles can be matched.
     let mut loaded_cells: std::collections::HashSet<(i32, i32)> = std::collections::HashSet::new();
-    let mut old_tiles: Vec<(Entity, i32, i32, u8)> = Vec::new();
+    let mut old_tiles: Vec<(Entity, (i32, i32), (i32, i32), u8, bool)> = Vec::new();
 
     for (entity, mut fade_state, mut sprite, transform) in tile_query.iter_mut() {
         let dominated = if is_3d {
@@ -1030,40 +1305,49 @@ fn animate_tile_fades(
         };
 
         if !dominated {
+            fade_state.superseded_secs = 0.0;
             // Current / active tile: fade in.
             // In 3D mode, use fast fade (appear in ~2 frames) to minimize
             // gaps during zoom transitions while still giving textures a
             // frame to load (prevents Bevy's default magenta showing).
             if fade_state.alpha < 1.0 {
-                let speed = if is_3d { 30.0 } else { constants::TILE_FADE_SPEED };
-                fade_state.alpha += speed * delta;
-                fade_state.alpha = fade_state.alpha.min(1.0);
+                fade_state.alpha = if is_3d {
+                    (fade_state.alpha + 30.0 * delta).min(1.0)
+                } else if app_config.map.tile_fade {
+                    let speed = app_config.map.tile_fade_speed
+                        .clamp(constants::TILE_FADE_SPEED_MIN, constants::TILE_FADE_SPEED_MAX);
+                    (fade_state.alpha + speed * delta).min(1.0)
+                } else {
+                    1.0
+                };
                 sprite.color = Color::srgba(1.0, 1.0, 1.0, fade_state.alpha);
             }
-            // Track fully-opaque tiles by grid cell
-            if fade_state.alpha >= 1.0 {
-                let cell = (
-                    (transform.translation.x / constants::DEFAULT_TILE_PIXELS).round() as i32,
-                    (transform.translation.y / constants::DEFAULT_TILE_PIXELS).round() as i32,
-                );
-                loaded_cells.insert(cell);
+            // Track opaque-enough tiles by coverage cell
+            if fade_state.alpha >= constants::TILE_COVERAGE_OPACITY {
+                loaded_cells.insert(coverage_cell(transform.translation, constants::TILE_COVERAGE_CELL_PIXELS));
             }
         } else {
-            // Old-zoom tile: record for coverage check
-            let cell = (
-                (transform.translation.x / constants::DEFAULT_TILE_PIXELS).round() as i32,
-                (transform.translation.y / constants::DEFAULT_TILE_PIXELS).round() as i32,
-            );
-            old_tiles.push((entity, cell.0, cell.1, fade_state.tile_zoom));
+            // Old-zoom tile: record for coverage check, with a time-based
+            // fallback for tiles the coverage check never matches
+            fade_state.superseded_secs += delta;
+            let expired = fade_state.superseded_secs >= constants::TILE_SUPERSEDED_DESPAWN_SECS;
+            old_tiles.push((
+                entity,
+                coverage_cell(transform.translation, constants::TILE_COVERAGE_CELL_PIXELS),
+                coverage_cell(transform.translation, constants::DEFAULT_TILE_PIXELS),
+                fade_state.tile_zoom,
+                expired,
+            ));
         }
     }
 
-    // Despawn old tiles whose grid cell is covered by a fully-loaded new tile.
-    // Remove from spawned_tiles so the position can be re-used.
-    for (entity, cx, cy, zoom) in old_tiles {
-        if loaded_cells.contains(&(cx, cy)) {
-            let tx = (cx as f32 * constants::DEFAULT_TILE_PIXELS) as i32;
-            let ty = (cy as f32 * constants::DEFAULT_TILE_PIXELS) as i32;
+    // Despawn old tiles whose cell is covered by a loaded new tile, or that
+    // have been superseded for too long. Remove from spawned_tiles so the
+    // position can be re-used.
+    for (entity, cell, (px, py), zoom, expired) in old_tiles {
+        if expired || loaded_cells.contains(&cell) {
+            let tx = (px as f32 * constants::DEFAULT_TILE_PIXELS) as i32;
+            let ty = (py as f32 * constants::DEFAULT_TILE_PIXELS) as i32;
             spawned_tiles.positions.remove(&(tx, ty, zoom));
             commands.entity(entity).despawn();
         }
@@ -1248,3 +1532,53 @@ fn cleanup_orphaned_tile_quads(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn altitude_to_zoom_level_hits_reference_altitudes() {
+        // Far from the current zoom, the target is returned directly
+        assert_eq!(altitude_to_zoom_level(5_000.0, 10), 16);
+        assert_eq!(altitude_to_zoom_level(1_000.0, 10), 16);
+        assert_eq!(altitude_to_zoom_level(120_000.0, 16), 9);
+        assert_eq!(altitude_to_zoom_level(10_000_000.0, 16), 8);
+
+        // Within a level, hysteresis holds the current zoom
+        let raw = raw_altitude_to_zoom(10_000.0);
+        assert!(raw > 14.0 && raw < 15.0);
+        assert_eq!(altitude_to_zoom_level(10_000.0, 14), 14);
+        assert_eq!(altitude_to_zoom_level(10_000.0, 15), 15);
+        assert_eq!(altitude_to_zoom_level(5_000.0, 15), 16);
+    }
+
+    #[test]
+    fn compute_tile_radius_stays_within_clamps() {
+        let detail = TileDetail::Medium;
+        // 2D: zoomed far out hits the upper clamp, zoomed in the lower one
+        assert_eq!(compute_tile_radius(1920.0, 1080.0, 0.1, None, detail), 8);
+        assert_eq!(compute_tile_radius(1920.0, 1080.0, 4.0, None, detail), 3);
+        assert_eq!(
+            compute_tile_radius(1920.0, 1080.0, 0.1, Some(&view3d::View3DState::default()), detail),
+            8
+        );
+
+        for pitch in [15.0, 45.0, 89.0] {
+            for altitude in [1_000.0, 10_000.0, 60_000.0] {
+                let state = view3d::View3DState {
+                    mode: view3d::ViewMode::Perspective3D,
+                    camera_pitch: pitch,
+                    camera_altitude: altitude,
+                    ..Default::default()
+                };
+                let radius = compute_tile_radius(1920.0, 1080.0, 1.0, Some(&state), detail);
+                assert!((3..=12).contains(&radius), "pitch {} alt {} -> {}", pitch, altitude, radius);
+            }
+        }
+
+        // Detail scaling applies on top of the clamps, never below 2
+        assert_eq!(compute_tile_radius(1920.0, 1080.0, 4.0, None, TileDetail::Low), 2);
+        assert_eq!(compute_tile_radius(1920.0, 1080.0, 0.1, None, TileDetail::High), 11);
+    }
+}
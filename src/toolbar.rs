@@ -5,13 +5,16 @@
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
-use bevy_slippy_tiles::{MapTile, SlippyTileDownloadStatus, DownloadSlippyTilesMessage};
 
 use egui_phosphor::regular;
 
 use crate::ui_panels::{UiPanelManager, PanelId};
 use crate::theme::{AppTheme, to_egui_color32, to_egui_color32_alpha};
 use crate::MapState;
+use crate::maintenance::{MaintenanceRequest, MaintenanceState, render_maintenance_confirm, render_maintenance_menu};
+use crate::tiles::ClearZoomTilesRequest;
+use crate::zoom::FitAllAircraftRequest;
+use crate::view3d::{Toggle3DViewRequest, View3DState, ViewMode};
 
 /// Width of the toolbar in pixels.
 const TOOLBAR_WIDTH: f32 = 44.0;
@@ -21,12 +24,18 @@ pub fn render_toolbar(
     mut contexts: EguiContexts,
     mut panels: ResMut<UiPanelManager>,
     map_state: Res<MapState>,
-    mut download_events: MessageWriter<DownloadSlippyTilesMessage>,
-    mut commands: Commands,
-    tile_query: Query<Entity, With<MapTile>>,
-    mut slippy_tile_download_status: ResMut<SlippyTileDownloadStatus>,
+    mut maintenance: ResMut<MaintenanceState>,
+    mut maintenance_requests: MessageWriter<MaintenanceRequest>,
+    mut clear_zoom_events: MessageWriter<ClearZoomTilesRequest>,
+    mut fit_requests: MessageWriter<FitAllAircraftRequest>,
+    mut view_toggle_requests: MessageWriter<Toggle3DViewRequest>,
+    view3d_state: Res<View3DState>,
     theme: Res<AppTheme>,
 ) {
+    // Focus mode leaves only the map on screen
+    if panels.is_focus_mode() {
+        return;
+    }
     let Ok(ctx) = contexts.ctx_mut() else {
         return;
     };
@@ -54,6 +63,8 @@ pub fn render_toolbar(
                 toolbar_button(ui, &mut panels, PanelId::AircraftList, regular::AIRPLANE_TILT, "Aircraft List (L)", active_color, inactive_color, active_bg);
                 toolbar_button(ui, &mut panels, PanelId::Bookmarks, regular::STAR, "Bookmarks (B)", active_color, inactive_color, active_bg);
                 toolbar_button(ui, &mut panels, PanelId::Statistics, regular::CHART_BAR, "Statistics (S)", active_color, inactive_color, active_bg);
+                toolbar_button(ui, &mut panels, PanelId::Logbook, regular::BOOK_OPEN, "Aircraft logbook", active_color, inactive_color, active_bg);
+                toolbar_button(ui, &mut panels, PanelId::EventLog, regular::SCROLL, "Event log", active_color, inactive_color, active_bg);
 
                 ui.separator();
 
@@ -72,40 +83,48 @@ pub fn render_toolbar(
                 toolbar_button(ui, &mut panels, PanelId::Inspector, regular::MAGNIFYING_GLASS, "Inspector (F12)", active_color, inactive_color, active_bg);
                 toolbar_button(ui, &mut panels, PanelId::Help, regular::QUESTION, "Help (?)", active_color, inactive_color, active_bg);
 
-                // -- Clear Cache button (action, not a panel toggle) --
+                // -- Action buttons (not panel toggles) --
                 let icon_dim = to_egui_color32(theme.text_dim());
-                let clear_btn = ui.add(
+
+                // 2D/3D mode indicator; shows transition progress while animating
+                let (mode_text, mode_hover) = match (view3d_state.transition_progress(), view3d_state.mode) {
+                    (Some(progress), _) => (format!("{:.0}%", progress * 100.0), "Switching view..."),
+                    (None, ViewMode::Map2D) => ("2D".to_string(), "2D map. Click to switch to 3D (3)"),
+                    (None, ViewMode::Perspective3D) => ("3D".to_string(), "3D view. Click to switch to 2D (3)"),
+                };
+                let mode_color = if view3d_state.mode == ViewMode::Perspective3D || view3d_state.is_transitioning() {
+                    active_color
+                } else {
+                    icon_dim
+                };
+                let mode_btn = ui.add(
+                    egui::Button::new(egui::RichText::new(mode_text).size(11.0).strong().color(mode_color))
+                        .min_size(egui::vec2(28.0, 22.0))
+                ).on_hover_text(mode_hover);
+                if mode_btn.clicked() {
+                    view_toggle_requests.write(Toggle3DViewRequest);
+                }
+
+                let fit_btn = ui.add(
                     egui::Button::new(
-                        egui::RichText::new(regular::X)
+                        egui::RichText::new(regular::ARROWS_OUT)
                             .font(crate::theme::icon_font_id(16.0, ctx))
                             .color(icon_dim),
                     )
                     .min_size(egui::vec2(28.0, 22.0))
-                ).on_hover_text("Clear tile cache");
-
-                if clear_btn.clicked() {
-                    // Clear download status tracking
-                    slippy_tile_download_status.0.clear();
-
-                    // Despawn all tile entities
-                    for entity in tile_query.iter() {
-                        commands.entity(entity).despawn();
-                    }
-
-                    // Clear tile cache from disk
-                    crate::clear_tile_cache();
-
-                    // Request fresh tiles
-                    crate::tiles::request_tiles_at_location(
-                        &mut download_events,
-                        map_state.latitude,
-                        map_state.longitude,
-                        map_state.zoom_level,
-                        false,
-                    );
-
-                    info!("Tile cache cleared via toolbar");
+                ).on_hover_text("Fit all aircraft (Z)");
+                if fit_btn.clicked() {
+                    fit_requests.write(FitAllAircraftRequest);
                 }
+
+                // Maintenance menu: clear trails, coverage, tiles and ghosts
+                let zoom = map_state.zoom_level.to_u8();
+                ui.menu_button(
+                    egui::RichText::new(regular::BROOM)
+                        .font(crate::theme::icon_font_id(16.0, ctx))
+                        .color(icon_dim),
+                    |ui| render_maintenance_menu(ui, &mut maintenance, zoom, &mut clear_zoom_events),
+                ).response.on_hover_text("Maintenance");
             });
         });
 
@@ -121,6 +140,8 @@ pub fn render_toolbar(
     );
     ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, "toolbar_gap".into()))
         .rect_filled(gap_rect, 0.0, panel_bg);
+
+    render_maintenance_confirm(ctx, &mut maintenance, &mut maintenance_requests);
 }
 
 /// Render a toolbar toggle button that highlights when its panel is open.
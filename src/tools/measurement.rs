@@ -1,9 +1,13 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
-use bevy_slippy_tiles::{LatitudeLongitudeCoordinates, world_coords_to_world_pixel, world_pixel_to_world_coords};
+use bevy_slippy_tiles::SlippyTilesSettings;
 
-use crate::{MapState, ZoomState};
-use crate::geo::{haversine_distance_nm, initial_bearing, NM_TO_KM};
+use crate::{Aircraft, MapState, ZoomState};
+use crate::aviation::{AirportMarker, AviationData};
+use crate::geo::{haversine_distance_nm, initial_bearing, CoordinateConverter, NM_TO_KM, NM_TO_SM};
+
+/// Screen-space radius (pixels) within which a click snaps to an aircraft or airport
+const SNAP_RADIUS_PX: f32 = 20.0;
 
 /// State for the measurement tool
 #[derive(Resource, Default)]
@@ -16,6 +20,10 @@ pub struct MeasurementState {
     pub end_point: Option<(f64, f64)>,
     /// Current cursor position in lat/lon (for rubber-band line)
     pub cursor_latlon: Option<(f64, f64)>,
+    /// Callsign/ident of the entity the start point snapped to
+    pub start_label: Option<String>,
+    /// Callsign/ident of the entity the end point snapped to
+    pub end_label: Option<String>,
 }
 
 impl MeasurementState {
@@ -24,6 +32,13 @@ impl MeasurementState {
         self.start_point = None;
         self.end_point = None;
         self.cursor_latlon = None;
+        self.start_label = None;
+        self.end_label = None;
+    }
+
+    /// Placed vertices of the measured path, in order (excludes the cursor)
+    pub fn vertices(&self) -> Vec<(f64, f64)> {
+        self.start_point.into_iter().chain(self.end_point).collect()
     }
 
     /// Get distance in nautical miles between start and end (or cursor)
@@ -38,6 +53,11 @@ impl MeasurementState {
         self.distance_nm().map(|nm| nm * NM_TO_KM)
     }
 
+    /// Get distance in statute miles
+    pub fn distance_sm(&self) -> Option<f64> {
+        self.distance_nm().map(|nm| nm * NM_TO_SM)
+    }
+
     /// Get bearing from start to end (or cursor) in degrees
     pub fn bearing(&self) -> Option<f64> {
         let start = self.start_point?;
@@ -46,6 +66,31 @@ impl MeasurementState {
     }
 }
 
+/// A map entity a measurement vertex can snap to
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapTarget {
+    /// World position of the entity's marker
+    pub world_pos: Vec2,
+    /// Exact geographic position (lat, lon)
+    pub latlon: (f64, f64),
+    /// Callsign or airport ident shown next to the vertex
+    pub label: String,
+}
+
+/// Pick the snap target closest to `world_pos` within `radius` world units.
+pub fn nearest_snap_target(
+    world_pos: Vec2,
+    radius: f32,
+    candidates: impl IntoIterator<Item = SnapTarget>,
+) -> Option<SnapTarget> {
+    candidates
+        .into_iter()
+        .map(|target| (world_pos.distance(target.world_pos), target))
+        .filter(|(distance, _)| *distance < radius)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, target)| target)
+}
+
 /// Component for measurement line entity
 #[derive(Component)]
 pub struct MeasurementLine;
@@ -54,11 +99,19 @@ pub struct MeasurementLine;
 #[derive(Component)]
 pub struct MeasurementPoint {
     pub is_start: bool,
+    /// Position of the point (lat, lon); the marker is re-projected from it
+    pub latlon: (f64, f64),
 }
 
+/// Snapped entity's callsign/ident shown beside a measurement point,
+/// counter-scaled to a constant screen size like aircraft labels
+#[derive(Component)]
+pub struct MeasurementLabel;
+
 /// Toggle measurement mode with 'M' key
 pub fn toggle_measurement_mode(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut state: ResMut<MeasurementState>,
     mut contexts: EguiContexts,
     mut commands: Commands,
@@ -72,8 +125,8 @@ pub fn toggle_measurement_mode(
         }
     }
 
-    // M key or Escape to toggle/cancel
-    if keyboard.just_pressed(KeyCode::KeyM) {
+    // M key or the cancel key (Escape) to toggle/cancel
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleMeasurement, &keyboard) {
         if state.active {
             // Deactivate and clear
             state.active = false;
@@ -91,7 +144,7 @@ pub fn toggle_measurement_mode(
             state.reset();
             info!("Measurement mode enabled - click to set start point");
         }
-    } else if keyboard.just_pressed(KeyCode::Escape) && state.active {
+    } else if bindings.just_pressed(crate::keyboard::ShortcutAction::Cancel, &keyboard) && state.active {
         state.active = false;
         state.reset();
         // Remove visual elements
@@ -113,6 +166,10 @@ pub fn handle_measurement_clicks(
     camera_query: Query<(&Camera, &GlobalTransform), With<crate::MapCamera>>,
     map_state: Res<MapState>,
     zoom_state: Res<ZoomState>,
+    tile_settings: Res<SlippyTilesSettings>,
+    aircraft_query: Query<(&Aircraft, &Transform, &Visibility)>,
+    airport_query: Query<(&AirportMarker, &Transform, &Visibility)>,
+    aviation_data: Res<AviationData>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
@@ -147,70 +204,125 @@ pub fn handle_measurement_clicks(
     };
 
     // Convert world position to lat/lon
-    let center_coords = LatitudeLongitudeCoordinates {
-        latitude: map_state.latitude,
-        longitude: map_state.longitude,
-    };
-    let (cx, cy) = world_coords_to_world_pixel(&center_coords, crate::constants::DEFAULT_TILE_SIZE, map_state.zoom_level);
-
-    // Calculate offset from center in world pixels
-    let offset_x = world_pos.x as f64 / zoom_state.camera_zoom as f64;
-    let offset_y = -world_pos.y as f64 / zoom_state.camera_zoom as f64; // Y is inverted
-
-    let cursor_geo = world_pixel_to_world_coords(
-        cx + offset_x,
-        cy + offset_y,
-        crate::constants::DEFAULT_TILE_SIZE,
-        map_state.zoom_level,
-    );
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let cursor_latlon = converter.world_to_latlon(world_pos);
 
     // Update cursor position for rubber-band line
-    state.cursor_latlon = Some((cursor_geo.latitude, cursor_geo.longitude));
+    state.cursor_latlon = Some(cursor_latlon);
 
     // Handle clicks
     if mouse_button.just_pressed(MouseButton::Left) {
-        if state.start_point.is_none() {
-            // Set start point
-            state.start_point = Some((cursor_geo.latitude, cursor_geo.longitude));
-            info!("Measurement start: {:.4}, {:.4}", cursor_geo.latitude, cursor_geo.longitude);
-
-            // Spawn start point marker
-            commands.spawn((
-                Mesh2d(meshes.add(Circle::new(5.0))),
-                MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgb(0.0, 1.0, 0.0)))),
-                Transform::from_xyz(world_pos.x, world_pos.y, 15.0),
-                MeasurementPoint { is_start: true },
-            ));
-        } else if state.end_point.is_none() {
-            // Set end point
-            state.end_point = Some((cursor_geo.latitude, cursor_geo.longitude));
-            info!("Measurement end: {:.4}, {:.4}", cursor_geo.latitude, cursor_geo.longitude);
-
-            // Spawn end point marker
-            commands.spawn((
-                Mesh2d(meshes.add(Circle::new(5.0))),
-                MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgb(1.0, 0.0, 0.0)))),
-                Transform::from_xyz(world_pos.x, world_pos.y, 15.0),
-                MeasurementPoint { is_start: false },
+        if state.end_point.is_some() {
+            // Reset for new measurement
+            state.reset();
+            return;
+        }
+
+        // Snap to the nearest visible aircraft or airport under the cursor
+        let snap_radius = SNAP_RADIUS_PX / zoom_state.camera_zoom;
+        let aircraft_targets = aircraft_query
+            .iter()
+            .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+            .map(|(aircraft, transform, _)| SnapTarget {
+                world_pos: transform.translation.truncate(),
+                latlon: (aircraft.latitude, aircraft.longitude),
+                label: aircraft.callsign.clone().unwrap_or_else(|| aircraft.icao.clone()),
+            });
+        let airport_targets = airport_query
+            .iter()
+            .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+            .filter_map(|(marker, transform, _)| {
+                let airport = aviation_data.airports.iter().find(|a| a.id == marker.airport_id)?;
+                Some(SnapTarget {
+                    world_pos: transform.translation.truncate(),
+                    latlon: (airport.latitude_deg, airport.longitude_deg),
+                    label: airport.ident.clone(),
+                })
+            });
+        let snap = nearest_snap_target(world_pos, snap_radius, aircraft_targets.chain(airport_targets));
+
+        let (point, marker_pos, label) = match snap {
+            Some(target) => (target.latlon, target.world_pos, Some(target.label)),
+            None => (cursor_latlon, world_pos, None),
+        };
+        let is_start = state.start_point.is_none();
+
+        if is_start {
+            state.start_point = Some(point);
+            state.start_label = label.clone();
+            info!("Measurement start: {:.4}, {:.4}", point.0, point.1);
+        } else {
+            state.end_point = Some(point);
+            state.end_label = label.clone();
+            info!("Measurement end: {:.4}, {:.4}", point.0, point.1);
+        }
+
+        // Spawn point marker (green start, red end), labelled when snapped
+        let color = if is_start {
+            Color::srgb(0.0, 1.0, 0.0)
+        } else {
+            Color::srgb(1.0, 0.0, 0.0)
+        };
+        let mut point_entity = commands.spawn((
+            Mesh2d(meshes.add(Circle::new(5.0))),
+            MeshMaterial2d(materials.add(ColorMaterial::from(color))),
+            Transform::from_xyz(marker_pos.x, marker_pos.y, 15.0),
+            MeasurementPoint { is_start, latlon: point },
+        ));
+        if let Some(label) = label {
+            point_entity.with_child((
+                Text2d::new(label),
+                TextFont {
+                    font_size: crate::constants::BASE_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(color),
+                Transform::from_xyz(0.0, 14.0, 0.0).with_scale(Vec3::splat(1.0 / zoom_state.camera_zoom)),
+                MeasurementLabel,
             ));
+        }
 
+        if !is_start {
             // Log result
             if let (Some(dist_nm), Some(bearing)) = (state.distance_nm(), state.bearing()) {
                 let dist_km = state.distance_km().unwrap_or(0.0);
                 info!("Distance: {:.2} nm ({:.2} km), Bearing: {:.0}", dist_nm, dist_km, bearing);
             }
-        } else {
-            // Reset for new measurement
-            state.reset();
         }
     }
 }
 
+/// Keep snapped-point labels at a constant screen size as the map zooms
+pub fn scale_measurement_labels(
+    zoom_state: Res<ZoomState>,
+    mut label_query: Query<&mut Transform, With<MeasurementLabel>>,
+) {
+    let scale = Vec3::splat(1.0 / zoom_state.camera_zoom);
+    for mut transform in label_query.iter_mut() {
+        transform.scale = scale;
+    }
+}
+
+/// Keep point markers on their lat/lon as the zoom level or the projection
+/// origin changes
+pub fn update_measurement_points(
+    map_state: Res<MapState>,
+    tile_settings: Res<SlippyTilesSettings>,
+    mut point_query: Query<(&MeasurementPoint, &mut Transform)>,
+) {
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    for (point, mut transform) in point_query.iter_mut() {
+        let pos = converter.latlon_to_world(point.latlon.0, point.latlon.1);
+        transform.translation.x = pos.x;
+        transform.translation.y = pos.y;
+    }
+}
+
 /// Update measurement line visual
 pub fn update_measurement_line(
     state: Res<MeasurementState>,
     map_state: Res<MapState>,
-    zoom_state: Res<ZoomState>,
+    tile_settings: Res<SlippyTilesSettings>,
     mut commands: Commands,
     mut line_query: Query<(Entity, &mut Transform), With<MeasurementLine>>,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -231,38 +343,14 @@ pub fn update_measurement_line(
         return;
     };
 
-    // Convert lat/lon to screen coordinates
-    let center_coords = LatitudeLongitudeCoordinates {
-        latitude: map_state.latitude,
-        longitude: map_state.longitude,
-    };
-    let (cx, cy) = world_coords_to_world_pixel(&center_coords, crate::constants::DEFAULT_TILE_SIZE, map_state.zoom_level);
-
-    let start_coords = LatitudeLongitudeCoordinates {
-        latitude: start.0,
-        longitude: start.1,
-    };
-    let (sx, sy) = world_coords_to_world_pixel(&start_coords, crate::constants::DEFAULT_TILE_SIZE, map_state.zoom_level);
-
-    let end_coords = LatitudeLongitudeCoordinates {
-        latitude: end.0,
-        longitude: end.1,
-    };
-    let (ex, ey) = world_coords_to_world_pixel(&end_coords, crate::constants::DEFAULT_TILE_SIZE, map_state.zoom_level);
-
-    // Calculate screen positions
-    let start_screen = Vec2::new(
-        (sx - cx) as f32 * zoom_state.camera_zoom,
-        -(sy - cy) as f32 * zoom_state.camera_zoom,
-    );
-    let end_screen = Vec2::new(
-        (ex - cx) as f32 * zoom_state.camera_zoom,
-        -(ey - cy) as f32 * zoom_state.camera_zoom,
-    );
+    // Convert lat/lon to world positions
+    let converter = CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+    let start_pos = converter.latlon_to_world(start.0, start.1);
+    let end_pos = converter.latlon_to_world(end.0, end.1);
 
     // Calculate line properties
-    let midpoint = (start_screen + end_screen) / 2.0;
-    let delta = end_screen - start_screen;
+    let midpoint = (start_pos + end_pos) / 2.0;
+    let delta = end_pos - start_pos;
     let length = delta.length();
     let angle = delta.y.atan2(delta.x);
 
@@ -289,6 +377,7 @@ pub fn update_measurement_line(
 /// Render measurement tooltip with distance and bearing
 pub fn render_measurement_tooltip(
     state: Res<MeasurementState>,
+    app_config: Res<crate::config::AppConfig>,
     mut contexts: EguiContexts,
     window_query: Query<&Window>,
 ) {
@@ -332,7 +421,20 @@ pub fn render_measurement_tooltip(
                         .show(ui, |ui| {
                             if let Some(dist_nm) = state.distance_nm() {
                                 let dist_km = state.distance_km().unwrap_or(0.0);
+                                let dist_sm = state.distance_sm().unwrap_or(0.0);
                                 let bearing = state.bearing().unwrap_or(0.0);
+                                // Declination is taken at the start point
+                                let (start_lat, start_lon) = state.start_point.unwrap_or_default();
+
+                                if state.start_label.is_some() || state.end_label.is_some() {
+                                    let start = state.start_label.as_deref().unwrap_or("point");
+                                    let end = state.end_label.as_deref().unwrap_or("point");
+                                    ui.label(
+                                        egui::RichText::new(format!("{} \u{2192} {}", start, end))
+                                            .color(egui::Color32::YELLOW)
+                                            .size(12.0)
+                                    );
+                                }
 
                                 ui.label(
                                     egui::RichText::new(format!("{:.2} nm", dist_nm))
@@ -340,14 +442,22 @@ pub fn render_measurement_tooltip(
                                         .strong()
                                 );
                                 ui.label(
-                                    egui::RichText::new(format!("{:.2} km", dist_km))
+                                    egui::RichText::new(format!("{:.2} km \u{00B7} {:.2} mi", dist_km, dist_sm))
                                         .color(egui::Color32::LIGHT_GRAY)
                                         .size(12.0)
                                 );
                                 ui.label(
-                                    egui::RichText::new(format!("BRG {:03.0}", bearing))
+                                    egui::RichText::new(format!("BRG {}", app_config.map.north_reference.format_bearing(bearing, start_lat, start_lon)))
                                         .color(egui::Color32::LIGHT_BLUE)
                                 );
+                                if let Some((lat, lon)) = state.end_point.or(state.cursor_latlon) {
+                                    ui.label(
+                                        egui::RichText::new(app_config.map.format_position(lat, lon))
+                                            .color(egui::Color32::LIGHT_GRAY)
+                                            .size(11.0)
+                                            .monospace()
+                                    );
+                                }
                             } else {
                                 ui.label("Click to set start point");
                             }
@@ -356,3 +466,31 @@ pub fn render_measurement_tooltip(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(x: f32, label: &str) -> SnapTarget {
+        SnapTarget {
+            world_pos: Vec2::new(x, 0.0),
+            latlon: (0.0, x as f64),
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn snaps_to_closest_target_within_radius() {
+        let snap = nearest_snap_target(
+            Vec2::ZERO,
+            20.0,
+            [target(15.0, "KSFO"), target(5.0, "UAL123"), target(50.0, "KOAK")],
+        );
+        assert_eq!(snap.map(|t| t.label), Some("UAL123".to_string()));
+    }
+
+    #[test]
+    fn no_snap_outside_radius() {
+        assert!(nearest_snap_target(Vec2::ZERO, 20.0, [target(25.0, "KSFO")]).is_none());
+    }
+}
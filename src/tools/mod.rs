@@ -13,6 +13,8 @@ impl Plugin for ToolsPlugin {
                 toggle_measurement_mode,
                 handle_measurement_clicks,
                 update_measurement_line,
+                update_measurement_points,
+                scale_measurement_labels.after(crate::ZoomSet::Change),
                 render_measurement_tooltip,
             ));
     }
@@ -7,11 +7,14 @@ use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 use std::path::Path;
 
+use crate::aircraft::WakeCategory;
 use crate::coverage::CoverageState;
+use crate::coverage::heatmap::DensityHeatmap;
 use crate::airspace::{AirspaceDisplayState, AirspaceData};
 use crate::data_sources::DataSourceManager;
 use crate::export::{ExportState, ExportFormat};
-use crate::recording::{RecordingState, PlaybackState};
+use crate::geo::NorthReference;
+use crate::recording::{RecordingState, PlaybackState, TimelineMarkerKind};
 use crate::view3d::{View3DState, ViewMode, sky::{TimeState, SunState}};
 use crate::terrain::TerrainState;
 use crate::tiles::GridOverlay;
@@ -45,7 +48,7 @@ pub struct ToolsWindowState {
 pub fn render_tools_window(
     mut contexts: EguiContexts,
     mut tools_state: ResMut<ToolsWindowState>,
-    mut coverage: ResMut<CoverageState>,
+    (mut coverage, mut heatmap): (ResMut<CoverageState>, ResMut<DensityHeatmap>),
     mut airspace_display: ResMut<AirspaceDisplayState>,
     mut airspace_data: ResMut<AirspaceData>,
     mut datasource_mgr: ResMut<DataSourceManager>,
@@ -119,12 +122,16 @@ pub fn render_tools_window(
                 .auto_shrink([false, false])
                 .show(ui, |ui| {
                     match tools_state.active_tab {
-                        ToolsTab::Coverage => render_coverage_tab(ui, &mut coverage),
+                        ToolsTab::Coverage => render_coverage_tab(ui, &mut coverage, &mut heatmap, app_config.map.north_reference),
                         ToolsTab::Airspace => render_airspace_tab(ui, &mut airspace_display, &mut airspace_data),
                         ToolsTab::DataSources => render_data_sources_tab(ui, &mut datasource_mgr),
                         ToolsTab::Export => render_export_tab(ui, &mut export_state),
                         ToolsTab::Recording => render_recording_tab(ui, &mut recording, &mut playback),
-                        ToolsTab::View3D => render_view3d_tab(ui, &mut view3d_state, &mut terrain_state, &mut time_state, &sun_state, grid_overlay.as_deref_mut()),
+                        ToolsTab::View3D => {
+                            let mut controls = app_config.camera_3d.clone();
+                            let edit = render_view3d_tab(ui, &mut view3d_state, &mut terrain_state, &mut time_state, &sun_state, grid_overlay.as_deref_mut(), &mut controls);
+                            apply_camera_controls_edit(&mut app_config, controls, &view3d_state, &edit);
+                        }
                         ToolsTab::Ingest => render_ingest_tab(ui, ingest_status.as_deref(), &mut app_config, ingest_ui.as_deref_mut()),
                     }
                 });
@@ -136,7 +143,12 @@ pub fn render_tools_window(
     }
 }
 
-pub fn render_coverage_tab(ui: &mut egui::Ui, coverage: &mut CoverageState) {
+pub fn render_coverage_tab(
+    ui: &mut egui::Ui,
+    coverage: &mut CoverageState,
+    heatmap: &mut DensityHeatmap,
+    north: NorthReference,
+) {
     let stats = coverage.get_stats();
 
     ui.horizontal(|ui| {
@@ -177,6 +189,10 @@ pub fn render_coverage_tab(ui: &mut egui::Ui, coverage: &mut CoverageState) {
 
     ui.separator();
 
+    crate::coverage::render_sector_analysis(ui, coverage, north);
+
+    ui.separator();
+
     ui.horizontal(|ui| {
         let enable_text = if coverage.enabled { "Disable" } else { "Enable" };
         if ui.button(enable_text).clicked() {
@@ -192,6 +208,70 @@ pub fn render_coverage_tab(ui: &mut egui::Ui, coverage: &mut CoverageState) {
         ui.label("Receiver:");
         ui.label(format!("{:.4}, {:.4}", coverage.receiver_location.0, coverage.receiver_location.1));
     });
+
+    ui.collapsing("Radio Horizon", |ui| {
+        ui.checkbox(&mut coverage.show_horizon, "Show theoretical horizon");
+        ui.add(egui::Slider::new(&mut coverage.antenna_height_ft, 0.0..=2000.0)
+            .step_by(5.0)
+            .suffix(" ft")
+            .text("Antenna height"));
+        ui.add(egui::Slider::new(&mut coverage.horizon_target_altitude_ft, 1000.0..=45_000.0)
+            .step_by(1000.0)
+            .suffix(" ft")
+            .text("Target altitude"));
+        ui.label(format!(
+            "Horizon {:.0} NM; {}/{} sectors reach half of it",
+            coverage.horizon_nm(),
+            coverage.sectors_near_horizon(),
+            stats.total_sectors,
+        ));
+        ui.label(
+            egui::RichText::new("Sectors well short of the horizon suggest terrain or obstruction blockage")
+                .size(10.0)
+                .color(egui::Color32::GRAY),
+        );
+    });
+
+    let filter_title = if coverage.filter.is_active() { "Filter (active)" } else { "Filter" };
+    ui.collapsing(filter_title, |ui| {
+        ui.add(egui::Slider::new(&mut coverage.filter.min_altitude_ft, 0..=40_000)
+            .step_by(1000.0)
+            .suffix(" ft")
+            .text("Min altitude"));
+        ui.label("Categories (none checked = all):");
+        ui.horizontal_wrapped(|ui| {
+            for category in [WakeCategory::Light, WakeCategory::Medium, WakeCategory::Heavy, WakeCategory::Super] {
+                let mut checked = coverage.filter.categories.contains(&category);
+                if ui.checkbox(&mut checked, category.label()).changed() {
+                    if checked {
+                        coverage.filter.categories.push(category);
+                    } else {
+                        coverage.filter.categories.retain(|c| *c != category);
+                    }
+                }
+            }
+        });
+        ui.label(
+            egui::RichText::new("Applies to new observations; Reset to rebuild the map")
+                .size(10.0)
+                .color(egui::Color32::GRAY),
+        );
+    });
+
+    ui.collapsing("Density Heatmap", |ui| {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut heatmap.enabled, "Show traffic density");
+            if ui.button("Reset").clicked() {
+                heatmap.reset(coverage.receiver_location);
+            }
+        });
+        ui.label(format!("{} position samples", heatmap.total_samples));
+        ui.label(
+            egui::RichText::new("Samples every aircraft every few seconds while shown; blue is sparse, red is busy")
+                .size(10.0)
+                .color(egui::Color32::GRAY),
+        );
+    });
 }
 
 pub fn render_airspace_tab(
@@ -242,6 +322,26 @@ pub fn render_airspace_tab(
         ui.separator();
         ui.checkbox(&mut display_state.show_labels, "Show Labels");
     }
+
+    ui.separator();
+    ui.collapsing("Load FAA NASR\u{2026}", |ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut airspace_data.nasr_path)
+                .hint_text("Shape_Files/Class_Airspace.shp")
+                .desired_width(220.0),
+        );
+        if ui.button("Load").clicked() {
+            airspace_data.load_nasr_from_path();
+        }
+        if let Some(ref err) = airspace_data.load_error {
+            ui.colored_label(egui::Color32::from_rgb(255, 120, 100), err);
+        }
+        ui.label(
+            egui::RichText::new("Class B/C/D boundaries from the NASR subscription's\nAdditional_Data/Shape_Files (the .dbf must sit alongside)")
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+        );
+    });
 }
 
 pub fn render_data_sources_tab(ui: &mut egui::Ui, manager: &mut DataSourceManager) {
@@ -262,6 +362,11 @@ pub fn render_data_sources_tab(ui: &mut egui::Ui, manager: &mut DataSourceManage
         ui.horizontal(|ui| {
             let enabled_icon = if source.enabled { "\u{25CF}" } else { "\u{25CB}" };
             ui.label(enabled_icon);
+            if manager.trails_by_source() {
+                // Legend swatch matching this source's trail color
+                let color = crate::theme::to_egui_color32(manager.source_color(&source.name));
+                ui.label(egui::RichText::new("\u{25A0}").color(color));
+            }
             ui.label(&source.name);
             ui.label(
                 egui::RichText::new(&status_text)
@@ -276,6 +381,17 @@ pub fn render_data_sources_tab(ui: &mut egui::Ui, manager: &mut DataSourceManage
                 .color(egui::Color32::from_rgb(150, 150, 150)),
         );
     }
+
+    ui.separator();
+    ui.checkbox(&mut manager.color_trails_by_source, "Color trails by source")
+        .on_hover_text("Shows where one feed hands over to another along a track");
+    if manager.color_trails_by_source && !manager.is_multi_source() {
+        ui.label(
+            egui::RichText::new("Needs two or more enabled sources")
+                .size(10.0)
+                .color(egui::Color32::GRAY),
+        );
+    }
 }
 
 pub fn render_export_tab(ui: &mut egui::Ui, export_state: &mut ExportState) {
@@ -290,6 +406,9 @@ pub fn render_export_tab(ui: &mut egui::Ui, export_state: &mut ExportState) {
             ui.selectable_value(&mut export_state.format, ExportFormat::CSV, ExportFormat::CSV.display_name());
             ui.selectable_value(&mut export_state.format, ExportFormat::GeoJSON, ExportFormat::GeoJSON.display_name());
         });
+    if export_state.format == ExportFormat::CSV {
+        crate::export::render_csv_options(ui, &mut export_state.csv);
+    }
 
     ui.add_space(8.0);
     ui.label("Available Recordings:");
@@ -301,6 +420,12 @@ pub fn render_export_tab(ui: &mut egui::Ui, export_state: &mut ExportState) {
                 .color(egui::Color32::GRAY),
         );
     } else {
+        ui.horizontal(|ui| {
+            ui.label("Snapshot at (s):");
+            ui.add(egui::TextEdit::singleline(&mut export_state.snapshot_time_secs)
+                .desired_width(60.0)
+                .hint_text("0"));
+        });
         for recording in &recordings {
             let name = recording.file_name()
                 .unwrap_or_default()
@@ -308,6 +433,31 @@ pub fn render_export_tab(ui: &mut egui::Ui, export_state: &mut ExportState) {
 
             ui.horizontal(|ui| {
                 ui.label(&*name);
+                if ui.button("Snapshot").on_hover_text("Export the aircraft at one instant as GeoJSON points").clicked() {
+                    // An empty field means the start, as the hint shows
+                    let input = export_state.snapshot_time_secs.trim();
+                    let time = if input.is_empty() { Ok(0.0) } else { input.parse::<f64>() };
+                    match time.ok().filter(|t| *t >= 0.0) {
+                        Some(secs) => {
+                            let output_name = format!("{}_{:.0}s.geojson", name.trim_end_matches(".ndjson"), secs);
+                            let output_path = recording.parent()
+                                .unwrap_or(Path::new("."))
+                                .join(&output_name);
+                            match crate::export::export_recording_snapshot(recording, (secs * 1000.0) as u64, &output_path) {
+                                Ok(()) => {
+                                    export_state.status_message = Some(format!("Exported to {}", output_name));
+                                    export_state.last_export_path = Some(output_path);
+                                }
+                                Err(e) => {
+                                    export_state.status_message = Some(format!("Error: {}", e));
+                                }
+                            }
+                        }
+                        None => {
+                            export_state.status_message = Some("Error: snapshot time must be a non-negative number of seconds".to_string());
+                        }
+                    }
+                }
                 if ui.button("Export").clicked() {
                     let output_name = format!(
                         "{}.{}",
@@ -318,7 +468,7 @@ pub fn render_export_tab(ui: &mut egui::Ui, export_state: &mut ExportState) {
                         .unwrap_or(Path::new("."))
                         .join(&output_name);
 
-                    match crate::export::export_recording(recording, &output_path, export_state.format) {
+                    match crate::export::export_recording(recording, &output_path, export_state.format, &export_state.csv) {
                         Ok(()) => {
                             export_state.status_message = Some(format!("Exported to {}", output_name));
                             export_state.last_export_path = Some(output_path);
@@ -332,6 +482,14 @@ pub fn render_export_tab(ui: &mut egui::Ui, export_state: &mut ExportState) {
         }
     }
 
+    ui.add_space(8.0);
+    if ui.button("Snapshot Live Traffic").on_hover_text("Export current aircraft as GeoJSON points").clicked() {
+        export_state.live_snapshot_requested = true;
+    }
+    if ui.button("Export Measured Path").on_hover_text("Export the current measurement as a line (KML when selected, otherwise GeoJSON)").clicked() {
+        export_state.measurement_export_requested = true;
+    }
+
     if let Some(ref msg) = export_state.status_message {
         ui.separator();
         let color = if msg.starts_with("Error") {
@@ -343,7 +501,22 @@ pub fn render_export_tab(ui: &mut egui::Ui, export_state: &mut ExportState) {
     }
 }
 
-pub fn render_view3d_tab(ui: &mut egui::Ui, state: &mut View3DState, terrain: &mut TerrainState, time_state: &mut TimeState, sun_state: &SunState, mut grid_overlay: Option<&mut GridOverlay>) {
+/// Edits the 3D tab made this frame to settings persisted in `AppConfig`:
+/// the camera controls copy and the fog settings on `View3DState`
+#[derive(Default)]
+pub struct CameraControlsEdit {
+    /// A camera control changed and should be written back to `AppConfig`
+    pub changed: bool,
+    /// A fog setting changed and should be written back to `AppConfig`
+    pub fog_changed: bool,
+    /// An edit was completed and the config should be saved
+    pub commit: bool,
+}
+
+/// Render the 3D view tab. Camera controls are edited on a copy so the
+/// caller only touches `AppConfig` (and its change detection) when the
+/// returned edit says so.
+pub fn render_view3d_tab(ui: &mut egui::Ui, state: &mut View3DState, terrain: &mut TerrainState, time_state: &mut TimeState, sun_state: &SunState, mut grid_overlay: Option<&mut GridOverlay>, controls: &mut crate::config::CameraControlsConfig) -> CameraControlsEdit {
     ui.colored_label(egui::Color32::YELLOW, "This feature is in research/prototype stage");
     ui.separator();
 
@@ -374,12 +547,41 @@ pub fn render_view3d_tab(ui: &mut egui::Ui, state: &mut View3DState, terrain: &m
     });
     ui.horizontal(|ui| {
         ui.label("Yaw:");
-        ui.add(egui::Slider::new(&mut state.camera_yaw, 0.0..=360.0).suffix("\u{00B0}"));
+        ui.add(egui::Slider::new(&mut state.camera_yaw, 0.0..=360.0).suffix("\u{00B0} grid"))
+            .on_hover_text("Degrees clockwise from grid north (map up). On the Mercator tile grid this matches true north.");
     });
     ui.horizontal(|ui| {
         ui.label("Alt Scale:");
         ui.add(egui::Slider::new(&mut state.altitude_scale, 0.1..=100.0));
     });
+    let plan_label = if state.plan_view { "Exit plan view" } else { "Plan view" };
+    if ui.button(plan_label).on_hover_text("Top-down, north-up view without leaving 3D (P)").clicked() {
+        state.toggle_plan_view();
+    }
+
+    ui.separator();
+    ui.label("Controls:");
+
+    // Saved once a slider is released (or typed into), not on every drag step
+    let mut edit = CameraControlsEdit::default();
+    for (label, value, range, hint) in [
+        ("Orbit speed:", &mut controls.orbit_sensitivity, 0.05..=1.5, "Degrees per pixel of Shift+drag"),
+        ("Pan speed:", &mut controls.pan_sensitivity, 0.0005..=0.02, "Map movement per pixel of drag"),
+        ("Pitch scroll:", &mut controls.pitch_scroll_sensitivity, 0.01..=0.5, "Degrees per point of Shift+scroll"),
+        ("Zoom scroll:", &mut controls.altitude_scroll_sensitivity, 100.0..=5000.0, "Feet of altitude per scroll line"),
+    ] {
+        ui.horizontal(|ui| {
+            ui.label(label);
+            let response = ui.add(egui::Slider::new(value, range).logarithmic(true)).on_hover_text(hint);
+            edit.changed |= response.changed();
+            edit.commit |= response.drag_stopped() || (response.changed() && !response.dragged());
+        });
+    }
+    if ui.button("Reset controls").clicked() {
+        *controls = crate::config::CameraControlsConfig::default();
+        edit.changed = true;
+        edit.commit = true;
+    }
 
     ui.separator();
     ui.label("Ground Elevation:");
@@ -401,7 +603,7 @@ pub fn render_view3d_tab(ui: &mut egui::Ui, state: &mut View3DState, terrain: &m
     ui.horizontal(|ui| {
         ui.label("Elevation:");
         let mut elev = state.ground_elevation_ft as f32;
-        if ui.add(egui::Slider::new(&mut elev, 0.0..=15000.0).suffix(" ft")).changed() {
+        if ui.add(egui::Slider::new(&mut elev, -1500.0..=15000.0).suffix(" ft")).changed() {
             state.ground_elevation_ft = elev as i32;
         }
     });
@@ -410,6 +612,11 @@ pub fn render_view3d_tab(ui: &mut egui::Ui, state: &mut View3DState, terrain: &m
     ui.label("Atmosphere:");
 
     ui.checkbox(&mut state.atmosphere_enabled, "Enable atmosphere effects");
+    ui.checkbox(&mut state.show_ground_grid, "Show ground grid");
+    ui.checkbox(&mut state.show_labels_3d, "Show aircraft labels");
+    ui.checkbox(&mut state.show_ground_markers, "Show ground markers")
+        .on_hover_text("Constant-size ring on the ground beneath each aircraft");
+    ui.checkbox(&mut state.show_cursor_coords, "Show coordinates under cursor");
 
     if state.atmosphere_enabled {
         ui.horizontal(|ui| {
@@ -418,6 +625,40 @@ pub fn render_view3d_tab(ui: &mut egui::Ui, state: &mut View3DState, terrain: &m
                 .suffix(" units")
                 .logarithmic(true));
         });
+        ui.horizontal(|ui| {
+            ui.label("Fog:");
+            egui::ComboBox::from_id_salt("fog_curve")
+                .selected_text(state.fog_curve.label())
+                .show_ui(ui, |ui| {
+                    for curve in crate::view3d::FogCurve::ALL {
+                        if ui.selectable_value(&mut state.fog_curve, curve, curve.label()).changed() {
+                            edit.fog_changed = true;
+                            edit.commit = true;
+                        }
+                    }
+                });
+        });
+        match state.fog_curve {
+            crate::view3d::FogCurve::Linear => {
+                ui.horizontal(|ui| {
+                    ui.label("Fog start:");
+                    let response = ui.add(egui::Slider::new(&mut state.fog_start_fraction, 0.0..=0.95)
+                        .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)));
+                    edit.fog_changed |= response.changed();
+                    edit.commit |= response.drag_stopped() || (response.changed() && !response.dragged());
+                });
+            }
+            crate::view3d::FogCurve::ExponentialSquared => {
+                ui.horizontal(|ui| {
+                    ui.label("Density:");
+                    let response = ui.add(egui::Slider::new(&mut state.fog_density_scale, 0.25..=4.0)
+                        .suffix("x")
+                        .logarithmic(true));
+                    edit.fog_changed |= response.changed();
+                    edit.commit |= response.drag_stopped() || (response.changed() && !response.dragged());
+                });
+            }
+        }
     }
 
     ui.separator();
@@ -450,6 +691,32 @@ pub fn render_view3d_tab(ui: &mut egui::Ui, state: &mut View3DState, terrain: &m
 
     ui.separator();
     crate::view3d::render_time_of_day_section(ui, time_state, sun_state);
+
+    edit
+}
+
+/// Write back and save camera control and fog edits from
+/// `render_view3d_tab`. `AppConfig` is only borrowed mutably when a value
+/// actually changed.
+pub fn apply_camera_controls_edit(
+    app_config: &mut ResMut<crate::config::AppConfig>,
+    controls: crate::config::CameraControlsConfig,
+    state: &View3DState,
+    edit: &CameraControlsEdit,
+) {
+    if edit.changed {
+        app_config.camera_3d = controls;
+    }
+    if edit.fog_changed {
+        app_config.fog = crate::config::FogConfig {
+            curve: state.fog_curve,
+            start_fraction: state.fog_start_fraction,
+            density_scale: state.fog_density_scale,
+        };
+    }
+    if edit.commit {
+        crate::config::save_config(app_config);
+    }
 }
 
 pub fn render_recording_tab(
@@ -505,6 +772,9 @@ pub fn render_recording_tab(
             if ui.button("Stop").clicked() {
                 playback.stop();
             }
+            if ui.button("Jump to newest").on_hover_text("Seek to the last recorded frame").clicked() {
+                playback.jump_to_newest();
+            }
         });
 
         // Speed controls
@@ -522,6 +792,7 @@ pub fn render_recording_tab(
         if playback.total_duration_ms > 0 {
             let progress = playback.current_time_ms as f32 / playback.total_duration_ms as f32;
             ui.add(egui::ProgressBar::new(progress).show_percentage());
+            render_timeline_markers(ui, playback);
 
             let current_secs = playback.current_time_ms / 1000;
             let total_secs = playback.total_duration_ms / 1000;
@@ -531,6 +802,8 @@ pub fn render_recording_tab(
             ));
         }
     } else {
+        ui.checkbox(&mut playback.overlay_live, "Overlay on live traffic")
+            .on_hover_text("Replay as REC ghost markers alongside live aircraft");
         if ui.button("Load Recording...").clicked() {
             // List available recordings
             let data_dir = crate::paths::data_dir();
@@ -554,6 +827,46 @@ pub fn render_recording_tab(
     }
 }
 
+/// Clickable event ticks under the playback progress bar.
+fn render_timeline_markers(ui: &mut egui::Ui, playback: &mut PlaybackState) {
+    if playback.markers.is_empty() {
+        return;
+    }
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 12.0), egui::Sense::hover());
+    ui.painter().line_segment(
+        [rect.left_center(), rect.right_center()],
+        egui::Stroke::new(1.0, egui::Color32::from_gray(70)),
+    );
+
+    let total = playback.total_duration_ms.max(1) as f32;
+    let mut seek_to = None;
+    for (i, marker) in playback.markers.iter().enumerate() {
+        let x = rect.left() + rect.width() * (marker.time_ms as f32 / total);
+        let color = match marker.kind {
+            TimelineMarkerKind::Emergency(ref kind) => {
+                let [r, g, b, _] = kind.color().to_srgba().to_u8_array();
+                egui::Color32::from_rgb(r, g, b)
+            }
+            TimelineMarkerKind::TrafficSpike => egui::Color32::from_rgb(100, 180, 255),
+        };
+        let tick = egui::Rect::from_center_size(egui::pos2(x, rect.center().y), egui::vec2(5.0, rect.height()));
+        let response = ui.interact(tick, ui.id().with(("timeline_marker", i)), egui::Sense::click());
+        let width = if response.hovered() { 4.0 } else { 2.0 };
+        ui.painter().line_segment([tick.center_top(), tick.center_bottom()], egui::Stroke::new(width, color));
+
+        let secs = marker.time_ms / 1000;
+        if response
+            .on_hover_text(format!("{}:{:02} {}", secs / 60, secs % 60, marker.label))
+            .clicked()
+        {
+            seek_to = Some(marker.time_ms);
+        }
+    }
+    if let Some(time_ms) = seek_to {
+        playback.seek(time_ms);
+    }
+}
+
 pub fn render_ingest_tab(
     ui: &mut egui::Ui,
     ingest_status: Option<&crate::data_ingest::IngestStatus>,
@@ -852,12 +1165,13 @@ mod tests {
     use crate::view3d::{View3DState, ViewMode};
 
     /// Bundled state for view3d tab tests since render_view3d_tab now
-    /// requires View3DState, TimeState, and SunState parameters.
+    /// requires View3DState, TimeState, SunState and camera controls.
     struct View3DTabTestState {
         view3d: View3DState,
         terrain: crate::terrain::TerrainState,
         time: TimeState,
         sun: SunState,
+        camera_controls: crate::config::CameraControlsConfig,
     }
 
     impl Default for View3DTabTestState {
@@ -867,6 +1181,7 @@ mod tests {
                 terrain: crate::terrain::TerrainState::default(),
                 time: TimeState::default(),
                 sun: SunState::default(),
+                camera_controls: crate::config::CameraControlsConfig::default(),
             }
         }
     }
@@ -874,10 +1189,10 @@ mod tests {
     #[test]
     fn test_coverage_tab_shows_inactive() {
         let harness = Harness::new_ui_state(
-            |ui, state: &mut CoverageState| {
-                render_coverage_tab(ui, state);
+            |ui, (coverage, heatmap): &mut (CoverageState, DensityHeatmap)| {
+                render_coverage_tab(ui, coverage, heatmap, NorthReference::True);
             },
-            CoverageState::default(),
+            (CoverageState::default(), DensityHeatmap::default()),
         );
 
         harness.get_by_label("INACTIVE");
@@ -886,10 +1201,10 @@ mod tests {
     #[test]
     fn test_coverage_tab_shows_enable_button() {
         let harness = Harness::new_ui_state(
-            |ui, state: &mut CoverageState| {
-                render_coverage_tab(ui, state);
+            |ui, (coverage, heatmap): &mut (CoverageState, DensityHeatmap)| {
+                render_coverage_tab(ui, coverage, heatmap, NorthReference::True);
             },
-            CoverageState::default(),
+            (CoverageState::default(), DensityHeatmap::default()),
         );
 
         harness.get_by_label("Enable");
@@ -899,7 +1214,7 @@ mod tests {
     fn test_view3d_tab_shows_pitch_label() {
         let harness = Harness::new_ui_state(
             |ui, state: &mut View3DTabTestState| {
-                render_view3d_tab(ui, &mut state.view3d, &mut state.terrain, &mut state.time, &state.sun, None);
+                render_view3d_tab(ui, &mut state.view3d, &mut state.terrain, &mut state.time, &state.sun, None, &mut state.camera_controls);
             },
             View3DTabTestState::default(),
         );
@@ -911,7 +1226,7 @@ mod tests {
     fn test_view3d_tab_shows_altitude_label() {
         let harness = Harness::new_ui_state(
             |ui, state: &mut View3DTabTestState| {
-                render_view3d_tab(ui, &mut state.view3d, &mut state.terrain, &mut state.time, &state.sun, None);
+                render_view3d_tab(ui, &mut state.view3d, &mut state.terrain, &mut state.time, &state.sun, None, &mut state.camera_controls);
             },
             View3DTabTestState::default(),
         );
@@ -923,7 +1238,7 @@ mod tests {
     fn test_view3d_tab_shows_mode_selectable_labels() {
         let harness = Harness::new_ui_state(
             |ui, state: &mut View3DTabTestState| {
-                render_view3d_tab(ui, &mut state.view3d, &mut state.terrain, &mut state.time, &state.sun, None);
+                render_view3d_tab(ui, &mut state.view3d, &mut state.terrain, &mut state.time, &state.sun, None, &mut state.camera_controls);
             },
             View3DTabTestState::default(),
         );
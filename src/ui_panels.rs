@@ -15,6 +15,8 @@ pub enum PanelId {
     AircraftDetail,
     Bookmarks,
     Statistics,
+    Logbook,
+    EventLog,
     Recording,
     Measurement,
     Export,
@@ -37,6 +39,8 @@ impl PanelId {
             PanelId::AircraftDetail => "D",
             PanelId::Bookmarks => "B",
             PanelId::Statistics => "S",
+            PanelId::Logbook => "",
+            PanelId::EventLog => "",
             PanelId::Recording => "Ctrl+R",
             PanelId::Measurement => "M",
             PanelId::Export => "E",
@@ -59,6 +63,8 @@ impl PanelId {
             PanelId::AircraftDetail => "Aircraft Detail",
             PanelId::Bookmarks => "Bookmarks",
             PanelId::Statistics => "Statistics",
+            PanelId::Logbook => "Logbook",
+            PanelId::EventLog => "Event Log",
             PanelId::Recording => "Recording",
             PanelId::Measurement => "Measurement",
             PanelId::Export => "Export",
@@ -81,6 +87,8 @@ impl PanelId {
             PanelId::AircraftDetail => regular::LIST_DASHES,
             PanelId::Bookmarks => regular::STAR,
             PanelId::Statistics => regular::CHART_BAR,
+            PanelId::Logbook => regular::BOOK_OPEN,
+            PanelId::EventLog => regular::SCROLL,
             PanelId::Recording => regular::RECORD,
             PanelId::Measurement => regular::RULER,
             PanelId::Export => regular::DOWNLOAD_SIMPLE,
@@ -100,6 +108,9 @@ impl PanelId {
 #[derive(Resource, Default)]
 pub struct UiPanelManager {
     open_panels: HashSet<PanelId>,
+    /// Panels that were open when focus mode was entered. `Some` while focus
+    /// mode is active; restored verbatim when it is toggled off.
+    focus_snapshot: Option<HashSet<PanelId>>,
 }
 
 impl UiPanelManager {
@@ -109,13 +120,15 @@ impl UiPanelManager {
             self.open_panels.remove(&panel);
             false
         } else {
-            self.open_panels.insert(panel);
+            self.open_panel(panel);
             true
         }
     }
 
-    /// Open a specific panel.
+    /// Open a specific panel. Opening a panel while in focus mode leaves focus
+    /// mode, restoring the saved layout alongside the new panel.
     pub fn open_panel(&mut self, panel: PanelId) {
+        self.exit_focus_mode();
         self.open_panels.insert(panel);
     }
 
@@ -133,6 +146,29 @@ impl UiPanelManager {
     pub fn close_all(&mut self) {
         self.open_panels.clear();
     }
+
+    /// Whether focus mode (map only, all panels hidden) is active.
+    pub fn is_focus_mode(&self) -> bool {
+        self.focus_snapshot.is_some()
+    }
+
+    /// Toggle focus mode. Entering saves the open panels and closes them all;
+    /// leaving restores exactly the saved set. Returns the new state.
+    pub fn toggle_focus_mode(&mut self) -> bool {
+        if self.is_focus_mode() {
+            self.exit_focus_mode();
+            false
+        } else {
+            self.focus_snapshot = Some(std::mem::take(&mut self.open_panels));
+            true
+        }
+    }
+
+    fn exit_focus_mode(&mut self) {
+        if let Some(snapshot) = self.focus_snapshot.take() {
+            self.open_panels.extend(snapshot);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -277,4 +313,30 @@ mod tests {
         mgr.toggle_panel(PanelId::Inspector);
         assert!(mgr.is_open(PanelId::Inspector));
     }
+
+    #[test]
+    fn focus_mode_hides_and_restores_exact_layout() {
+        let mut mgr = UiPanelManager::default();
+        mgr.open_panel(PanelId::AircraftList);
+        mgr.open_panel(PanelId::Statistics);
+        assert!(mgr.toggle_focus_mode());
+        assert!(mgr.is_focus_mode());
+        assert!(!mgr.is_open(PanelId::AircraftList));
+        assert!(!mgr.is_open(PanelId::Statistics));
+        assert!(!mgr.toggle_focus_mode());
+        assert!(mgr.is_open(PanelId::AircraftList));
+        assert!(mgr.is_open(PanelId::Statistics));
+        assert!(!mgr.is_open(PanelId::Help));
+    }
+
+    #[test]
+    fn opening_panel_leaves_focus_mode() {
+        let mut mgr = UiPanelManager::default();
+        mgr.open_panel(PanelId::AircraftList);
+        mgr.toggle_focus_mode();
+        mgr.toggle_panel(PanelId::Help);
+        assert!(!mgr.is_focus_mode());
+        assert!(mgr.is_open(PanelId::Help));
+        assert!(mgr.is_open(PanelId::AircraftList));
+    }
 }
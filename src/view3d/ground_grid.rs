@@ -0,0 +1,77 @@
+//! Faint ground reference grid for 3D mode.
+//!
+//! Lines fade with distance from the camera on the same curve as the
+//! `DistanceFog` falloff and `fade_distant_sprites`, so the grid recedes
+//! into the fog wall instead of ending abruptly.
+
+use bevy::prelude::*;
+
+use super::View3DState;
+
+/// Grid lines drawn on each side of the camera's ground position.
+const GRID_HALF_LINES: i32 = 20;
+
+/// Peak line opacity close to the camera.
+const GRID_BASE_ALPHA: f32 = 0.25;
+
+/// Height above the ground plane so lines aren't z-fought by tiles.
+const GRID_GROUND_OFFSET: f32 = 0.5;
+
+/// Draw the ground grid beneath the 3D camera.
+pub fn draw_ground_grid(
+    state: Res<View3DState>,
+    camera_query: Query<&Transform, With<crate::MapCamera>>,
+    mut gizmos: Gizmos,
+) {
+    if !state.is_3d_active() || !state.show_ground_grid {
+        return;
+    }
+    let Ok(cam_transform) = camera_query.single() else {
+        return;
+    };
+
+    let cam_pos = cam_transform.translation;
+    let ground_z = state.altitude_to_z(state.ground_elevation_ft) + GRID_GROUND_OFFSET;
+
+    // Spacing scales with visibility so the grid always reaches the fog wall.
+    // Snapping the origin to the spacing keeps lines fixed to the ground
+    // while the camera moves.
+    let spacing = state.visibility_range / GRID_HALF_LINES as f32;
+    if spacing <= 0.0 {
+        return;
+    }
+    let origin_x = (cam_pos.x / spacing).round() * spacing;
+    let origin_y = (cam_pos.y / spacing).round() * spacing;
+    let extent = spacing * GRID_HALF_LINES as f32;
+
+    let color_at = |p: Vec3| {
+        Color::srgba(0.8, 0.85, 0.9, GRID_BASE_ALPHA * state.fog_alpha(cam_pos.distance(p)))
+    };
+
+    // Each line is split into spacing-length pieces with per-vertex colors so
+    // the fade follows distance along the line.
+    for i in -GRID_HALF_LINES..=GRID_HALF_LINES {
+        let offset = i as f32 * spacing;
+        for j in -GRID_HALF_LINES..GRID_HALF_LINES {
+            let a = -extent + (j + GRID_HALF_LINES) as f32 * spacing;
+            let b = a + spacing;
+
+            // Line of constant x (north-south)
+            let start = Vec3::new(origin_x + offset, origin_y + a, ground_z);
+            let end = Vec3::new(origin_x + offset, origin_y + b, ground_z);
+            draw_faded_segment(&mut gizmos, start, end, color_at(start), color_at(end));
+
+            // Line of constant y (east-west)
+            let start = Vec3::new(origin_x + a, origin_y + offset, ground_z);
+            let end = Vec3::new(origin_x + b, origin_y + offset, ground_z);
+            draw_faded_segment(&mut gizmos, start, end, color_at(start), color_at(end));
+        }
+    }
+}
+
+fn draw_faded_segment(gizmos: &mut Gizmos, start: Vec3, end: Vec3, start_color: Color, end_color: Color) {
+    if start_color.alpha() <= 0.0 && end_color.alpha() <= 0.0 {
+        return;
+    }
+    gizmos.line_gradient(start, end, start_color, end_color);
+}
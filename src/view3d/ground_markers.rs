@@ -0,0 +1,70 @@
+//! Constant-size ground markers for 3D mode.
+//!
+//! Aircraft models and sprites shrink with distance in perspective, so from
+//! high up the ground track of distant traffic is hard to read. Each
+//! aircraft gets a small ring on the ground plane directly beneath it, sized
+//! from the camera depth so it covers the same number of pixels at any
+//! distance, and faded with the same curve as the distance fog.
+
+use bevy::prelude::*;
+
+use super::{yup_to_zup, View3DState};
+use crate::aircraft::declutter::AltitudeHidden;
+
+/// On-screen ring radius in pixels
+const MARKER_RADIUS_PX: f32 = 6.0;
+
+/// Peak ring opacity close to the camera
+const MARKER_BASE_ALPHA: f32 = 0.8;
+
+/// Height above the ground plane so rings aren't z-fought by tiles; slightly
+/// above the ground grid so rings draw over it.
+const MARKER_GROUND_OFFSET: f32 = 0.8;
+
+/// Draw a ground ring beneath every visible aircraft.
+pub fn draw_ground_markers(
+    state: Res<View3DState>,
+    window_query: Query<&Window>,
+    camera_query: Query<(&Transform, &Projection), With<crate::MapCamera>>,
+    aircraft_query: Query<(&Transform, &Visibility), (With<crate::Aircraft>, Without<AltitudeHidden>)>,
+    mut gizmos: Gizmos,
+) {
+    if !state.is_3d_active() || !state.show_ground_markers {
+        return;
+    }
+    let Ok((cam_transform, Projection::Perspective(perspective))) = camera_query.single() else {
+        return;
+    };
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    if window.height() <= 0.0 {
+        return;
+    }
+
+    // World units per screen pixel at unit depth
+    let units_per_px = 2.0 * (perspective.fov / 2.0).tan() / window.height();
+    let forward = cam_transform.forward();
+    let ground_z = state.altitude_to_z(state.ground_elevation_ft) + MARKER_GROUND_OFFSET;
+
+    for (transform, visibility) in aircraft_query.iter() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        // Aircraft transforms are Y-up in 3D; gizmos draw through Camera2d in Z-up
+        let above = yup_to_zup(transform.translation);
+        let ground = Vec3::new(above.x, above.y, ground_z);
+        let depth = (ground - cam_transform.translation).dot(*forward);
+        if depth <= 0.0 {
+            continue;
+        }
+        let alpha = MARKER_BASE_ALPHA * state.fog_alpha(cam_transform.translation.distance(ground));
+        if alpha <= 0.0 {
+            continue;
+        }
+        let radius = depth * units_per_px * MARKER_RADIUS_PX;
+        let color = Color::srgba(0.4, 0.9, 1.0, alpha);
+        gizmos.circle(Isometry3d::from_translation(ground), radius, color);
+        gizmos.circle(Isometry3d::from_translation(ground), radius * 0.25, color);
+    }
+}
@@ -5,6 +5,8 @@
 //! all existing 2D content (tiles, trails, sprites) renders correctly.
 //! Aircraft altitude is shown by adjusting sprite Z positions.
 
+pub mod ground_grid;
+pub mod ground_markers;
 pub mod sky;
 
 use bevy::prelude::*;
@@ -33,6 +35,11 @@ use bevy_egui::{egui, EguiContexts};
 // Constants for 3D view
 const TRANSITION_DURATION: f32 = 2.0;
 const DEFAULT_PITCH: f32 = 25.0;
+/// Camera pitch for the top-down plan view (just short of straight down,
+/// where the orbit basis stays well defined)
+const PLAN_VIEW_PITCH: f32 = 89.0;
+/// Seconds for the eased move into or out of the plan view
+const PLAN_VIEW_DURATION: f32 = 0.6;
 const DEFAULT_CAMERA_ALTITUDE: f32 = 10000.0;
 const MIN_PITCH: f32 = -89.9;
 const MAX_PITCH: f32 = 89.9;
@@ -47,6 +54,47 @@ pub(crate) const CHASE_TRANSITION_DURATION: f32 = 2.0;
 /// Scale factor to convert altitude/distance values to pixel-space.
 pub(crate) const PIXEL_SCALE: f32 = 20.0;
 
+/// Lowest altitude placed in 3D. The Dead Sea shore (~-1,400 ft) is the
+/// lowest real terrain; anything below is a pressure-altitude artifact.
+pub(crate) const MIN_PLAUSIBLE_ALTITUDE_FT: i32 = -1500;
+
+/// Clearance (pixel units) kept between aircraft and the tile ground plane.
+/// Clears depth precision limits at grazing angles on Metal (~4 units at FL300).
+const AIRCRAFT_GROUND_CLEARANCE: f32 = 10.0;
+
+/// Pitch beyond which the orbit camera derives its up vector from yaw.
+const STEEP_PITCH_DEG: f32 = 80.0;
+
+/// Default fraction of `visibility_range` at which linear fog and distance
+/// fades begin.
+pub(crate) const FOG_START_FRACTION: f32 = 0.4;
+
+/// Remaining contrast at `visibility_range` for exponential fog (matches
+/// `FogFalloff::from_visibility_squared`).
+const FOG_VISIBILITY_CONTRAST: f32 = 0.05;
+
+/// How fog thickens with distance in 3D
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, serde::Serialize, serde::Deserialize)]
+pub enum FogCurve {
+    /// Clear up to the fog start, then a straight ramp to a hard cutoff at
+    /// `visibility_range`
+    #[default]
+    Linear,
+    /// Haze that thickens gradually from the camera, without a hard edge
+    ExponentialSquared,
+}
+
+impl FogCurve {
+    pub const ALL: [FogCurve; 2] = [FogCurve::Linear, FogCurve::ExponentialSquared];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FogCurve::Linear => "Linear",
+            FogCurve::ExponentialSquared => "Exponential",
+        }
+    }
+}
+
 /// View mode for the application
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 pub enum ViewMode {
@@ -64,6 +112,22 @@ pub enum TransitionState {
     TransitioningTo2D { progress: f32 },
 }
 
+/// Eased camera pitch/yaw move, used by the plan view toggle
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct CameraEase {
+    pub from_pitch: f32,
+    pub from_yaw: f32,
+    pub to_pitch: f32,
+    pub to_yaw: f32,
+    /// 0.0 to 1.0, before easing
+    pub progress: f32,
+}
+
+/// Request to switch between 2D and 3D, as if the toggle key were pressed
+/// (sent by the toolbar mode button).
+#[derive(Message)]
+pub struct Toggle3DViewRequest;
+
 /// Resource for 3D view state
 #[derive(Resource, Reflect)]
 #[reflect(Resource)]
@@ -78,12 +142,34 @@ pub struct View3DState {
     pub saved_2d_center: Vec2,
     /// Ground plane elevation in feet ASL (from nearest airport)
     pub ground_elevation_ft: i32,
+    /// Lowest loaded terrain elevation in feet ASL while 3D terrain is on.
+    /// The flat ground plane drops to this so it never hides valleys.
+    pub terrain_floor_ft: Option<i32>,
     /// Name of the detected nearest airport (for UI display)
     pub detected_airport_name: Option<String>,
     /// Distance (world units) before fog reaches full opacity
     pub visibility_range: f32,
+    /// Fog falloff curve
+    pub fog_curve: FogCurve,
+    /// Linear fog: fraction of `visibility_range` where the fog starts
+    pub fog_start_fraction: f32,
+    /// Exponential fog: density multiplier (1.0 = 5% contrast left at
+    /// `visibility_range`)
+    pub fog_density_scale: f32,
     /// Whether atmosphere effects (scattering, fog, exposure) are enabled
     pub atmosphere_enabled: bool,
+    /// Whether the faint ground reference grid is drawn in 3D
+    pub show_ground_grid: bool,
+    /// Whether aircraft labels stay visible in 3D as screen-facing billboards
+    pub show_labels_3d: bool,
+    /// Whether each aircraft gets a constant-size ring on the ground beneath it
+    pub show_ground_markers: bool,
+    /// Whether the camera HUD shows the coordinates under the cursor
+    pub show_cursor_coords: bool,
+    /// Lat/lon of the ground point under the cursor, updated each frame in 3D
+    pub cursor_ground: Option<(f64, f64)>,
+    /// Terrain elevation in feet at `cursor_ground`, when a heightmap covers it
+    pub cursor_elevation_ft: Option<i32>,
     /// Accumulated drag distance since mouse-down (for click vs drag disambiguation)
     #[reflect(ignore)]
     pub drag_accumulated: f32,
@@ -104,6 +190,13 @@ pub struct View3DState {
     pub pre_chase_altitude: f32,
     /// User orbited/scrolled during chase — keep position tracking but stop heading tracking
     pub chase_orbit_override: bool,
+    /// Whether the camera is in the top-down north-up plan view
+    pub plan_view: bool,
+    /// Orbit pitch and yaw from before the plan view, restored when it is toggled off
+    pub pre_plan_pitch: f32,
+    pub pre_plan_yaw: f32,
+    /// Running pitch/yaw ease into or out of the plan view
+    pub camera_ease: Option<CameraEase>,
 }
 
 /// Minimum mouse movement (pixels) before a click becomes a drag.
@@ -121,9 +214,19 @@ impl Default for View3DState {
             altitude_scale: ALTITUDE_EXAGGERATION,
             saved_2d_center: Vec2::ZERO,
             ground_elevation_ft: 0,
+            terrain_floor_ft: None,
             detected_airport_name: None,
             visibility_range: 5000.0,
+            fog_curve: FogCurve::Linear,
+            fog_start_fraction: FOG_START_FRACTION,
+            fog_density_scale: 1.0,
             atmosphere_enabled: true,
+            show_ground_grid: true,
+            show_labels_3d: false,
+            show_ground_markers: false,
+            show_cursor_coords: true,
+            cursor_ground: None,
+            cursor_elevation_ft: None,
             drag_accumulated: 0.0,
             drag_active: false,
             follow_altitude_ft: None,
@@ -134,6 +237,10 @@ impl Default for View3DState {
             pre_chase_yaw: 0.0,
             pre_chase_altitude: DEFAULT_CAMERA_ALTITUDE,
             chase_orbit_override: false,
+            plan_view: false,
+            pre_plan_pitch: DEFAULT_PITCH,
+            pre_plan_yaw: 0.0,
+            camera_ease: None,
         }
     }
 }
@@ -148,13 +255,102 @@ impl View3DState {
         !matches!(self.transition, TransitionState::Idle)
     }
 
-    /// Convert altitude in feet to pixel-space Z offset
+    /// Ease into the top-down north-up plan view, or back to the pitch and
+    /// yaw from before it.
+    pub fn toggle_plan_view(&mut self) {
+        let (to_pitch, to_yaw) = if self.plan_view {
+            (self.pre_plan_pitch, self.pre_plan_yaw)
+        } else {
+            self.pre_plan_pitch = self.camera_pitch;
+            self.pre_plan_yaw = self.camera_yaw;
+            (PLAN_VIEW_PITCH, 0.0)
+        };
+        self.plan_view = !self.plan_view;
+        // Chase would otherwise turn the camera straight back to the heading
+        if self.chase_active {
+            self.chase_orbit_override = true;
+        }
+        self.camera_ease = Some(CameraEase {
+            from_pitch: self.camera_pitch,
+            from_yaw: self.camera_yaw,
+            to_pitch,
+            to_yaw,
+            progress: 0.0,
+        });
+    }
+
+    /// Advance the plan view ease by `dt` seconds
+    fn step_camera_ease(&mut self, dt: f32) {
+        let Some(mut ease) = self.camera_ease else {
+            return;
+        };
+        ease.progress = (ease.progress + dt / PLAN_VIEW_DURATION).min(1.0);
+        let t = smooth_step(ease.progress);
+        self.camera_pitch = ease.from_pitch + (ease.to_pitch - ease.from_pitch) * t;
+        // Turn the short way round
+        let yaw_delta = (ease.to_yaw - ease.from_yaw + 540.0).rem_euclid(360.0) - 180.0;
+        self.camera_yaw = (ease.from_yaw + yaw_delta * t).rem_euclid(360.0);
+        self.camera_ease = (ease.progress < 1.0).then_some(ease);
+    }
+
+    /// Progress (0..1) of the running 2D/3D transition, if any
+    pub fn transition_progress(&self) -> Option<f32> {
+        match self.transition {
+            TransitionState::Idle => None,
+            TransitionState::TransitioningTo3D { progress }
+            | TransitionState::TransitioningTo2D { progress } => Some(progress),
+        }
+    }
+
+    /// Convert altitude in feet to pixel-space Z offset.
+    /// Negative altitudes map below sea level, down to `MIN_PLAUSIBLE_ALTITUDE_FT`.
     pub fn altitude_to_z(&self, altitude_feet: i32) -> f32 {
         // Convert feet to km, then scale to pixel space
-        let alt_km = altitude_feet as f32 * 0.3048 / 1000.0;
+        let alt_km = altitude_feet.max(MIN_PLAUSIBLE_ALTITUDE_FT) as f32 * 0.3048 / 1000.0;
         alt_km * PIXEL_SCALE * self.altitude_scale
     }
 
+    /// Height of an aircraft above the 3D scene origin. Aircraft at or below
+    /// the ground plane (negative pressure altitudes, below-sea-level
+    /// airfields, missing altitude) sit just above it so opaque tiles never
+    /// occlude them.
+    pub fn aircraft_height(&self, altitude_feet: Option<i32>) -> f32 {
+        let ground = self.altitude_to_z(self.ground_elevation_ft);
+        let alt = altitude_feet.unwrap_or(self.ground_elevation_ft);
+        self.altitude_to_z(alt).max(ground + AIRCRAFT_GROUND_CLEARANCE)
+    }
+
+    /// `DistanceFog` falloff for the current fog settings.
+    pub fn fog_falloff(&self) -> FogFalloff {
+        match self.fog_curve {
+            FogCurve::Linear => FogFalloff::Linear {
+                start: self.visibility_range * self.fog_start_fraction,
+                end: self.visibility_range,
+            },
+            FogCurve::ExponentialSquared => FogFalloff::ExponentialSquared {
+                density: self.fog_density(),
+            },
+        }
+    }
+
+    /// Exponential-squared fog density derived from `visibility_range`
+    fn fog_density(&self) -> f32 {
+        (-FOG_VISIBILITY_CONTRAST.ln()).sqrt() / self.visibility_range.max(1.0) * self.fog_density_scale
+    }
+
+    /// Opacity of sprites and overlays at `distance` from the camera,
+    /// following the same curve as the scene fog.
+    pub fn fog_alpha(&self, distance: f32) -> f32 {
+        match self.fog_curve {
+            FogCurve::Linear => linear_fog_alpha(
+                distance,
+                self.visibility_range * self.fog_start_fraction,
+                self.visibility_range,
+            ),
+            FogCurve::ExponentialSquared => (-(distance * self.fog_density()).powi(2)).exp(),
+        }
+    }
+
     /// Convert camera altitude in feet to pixel-space vertical height
     pub fn altitude_to_distance(&self) -> f32 {
         let alt_km = self.camera_altitude * 0.3048 / 1000.0;
@@ -190,7 +386,7 @@ impl View3DState {
             center.z + horizontal_dist * yaw_rad.cos(),
         );
 
-        Transform::from_translation(camera_pos).looking_at(center, Vec3::Y)
+        Transform::from_translation(camera_pos).looking_at(center, orbit_up_vector(self.camera_pitch, self.camera_yaw))
     }
 
     /// Calculate chase camera transform in Y-up space.
@@ -223,24 +419,26 @@ impl View3DState {
     }
 }
 
-/// System to toggle 3D view mode with smooth transition
+/// System to toggle 3D view mode with smooth transition, from the toggle key
+/// or a `Toggle3DViewRequest`
 pub fn toggle_3d_view(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut state: ResMut<View3DState>,
     mut contexts: EguiContexts,
+    mut requests: MessageReader<Toggle3DViewRequest>,
     camera_query: Query<&Transform, With<crate::MapCamera>>,
     map_state: Res<crate::MapState>,
     aviation_data: Res<crate::aviation::AviationData>,
 ) {
+    let requested = requests.read().count() > 0;
     let egui_wants_input = contexts.ctx_mut()
         .map(|ctx| ctx.wants_keyboard_input())
         .unwrap_or(false);
+    let pressed = !egui_wants_input
+        && bindings.just_pressed(crate::keyboard::ShortcutAction::Toggle3DView, &keyboard);
 
-    if egui_wants_input {
-        return;
-    }
-
-    if keyboard.just_pressed(KeyCode::Digit3) {
+    if requested || pressed {
         // Don't start new transition if one is in progress
         if state.is_transitioning() {
             return;
@@ -273,6 +471,31 @@ pub fn toggle_3d_view(
     }
 }
 
+/// System that toggles the top-down plan view from its shortcut (3D only).
+pub fn toggle_plan_view(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
+    mut state: ResMut<View3DState>,
+    mut contexts: EguiContexts,
+) {
+    if contexts.ctx_mut().is_ok_and(|ctx| ctx.wants_keyboard_input()) {
+        return;
+    }
+    if !matches!(state.mode, ViewMode::Perspective3D) || state.is_transitioning() {
+        return;
+    }
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::TogglePlanView, &keyboard) {
+        state.toggle_plan_view();
+    }
+}
+
+/// System that advances the plan view pitch/yaw ease.
+pub fn animate_camera_ease(time: Res<Time>, mut state: ResMut<View3DState>) {
+    if state.camera_ease.is_some() {
+        state.step_camera_ease(time.delta_secs());
+    }
+}
+
 /// Find the nearest airport to the current map center and set ground elevation.
 fn detect_ground_elevation(
     state: &mut View3DState,
@@ -325,7 +548,7 @@ pub fn render_time_of_day_section(
             let offset_secs = (time_state.utc_offset_hours * 3600.0) as i32;
             let offset = chrono::FixedOffset::east_opt(offset_secs)
                 .unwrap_or(chrono::FixedOffset::east_opt(0).unwrap());
-            time_state.override_time = Some(chrono::Utc::now().with_timezone(&offset));
+            time_state.set_override(chrono::Utc::now().with_timezone(&offset).fixed_offset());
         } else {
             time_state.reset_to_live();
         }
@@ -350,6 +573,17 @@ pub fn render_time_of_day_section(
                 time_state.set_hour(hour);
             }
         });
+
+        let mut mode = time_state.manual_mode;
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut mode, sky::ManualTimeMode::Frozen, "Frozen")
+                .on_hover_text("Hold the sun at the chosen time");
+            ui.radio_value(&mut mode, sky::ManualTimeMode::Advancing, "Advancing")
+                .on_hover_text("Run the clock forward from the chosen time");
+        });
+        if mode != time_state.manual_mode {
+            time_state.set_manual_mode(mode);
+        }
     } else {
         use chrono::Timelike;
         let now = time_state.current_datetime();
@@ -590,11 +824,6 @@ fn smooth_step(t: f32) -> f32 {
     t * t * (3.0 - 2.0 * t)
 }
 
-const ORBIT_SENSITIVITY: f32 = 0.3;
-const PAN_3D_SENSITIVITY: f32 = 0.003;
-const PITCH_SCROLL_SENSITIVITY: f32 = 2.0;
-const ALTITUDE_SCROLL_SENSITIVITY: f32 = 1000.0;
-
 /// System to handle 3D camera controls.
 ///
 /// - **Click+drag**: Pan (translate camera and target in XY, no rotation)
@@ -602,6 +831,8 @@ const ALTITUDE_SCROLL_SENSITIVITY: f32 = 1000.0;
 /// - **Scroll**: Change camera altitude (zoom in/out)
 /// - **Shift+scroll**: Change camera pitch
 /// - **Pinch**: Change camera altitude
+///
+/// Drag and scroll rates come from `AppConfig::camera_3d`.
 pub fn handle_3d_camera_controls(
     mouse_button: Res<ButtonInput<MouseButton>>,
     keyboard: Res<ButtonInput<KeyCode>>,
@@ -614,6 +845,7 @@ pub fn handle_3d_camera_controls(
     tile_settings: Res<bevy_slippy_tiles::SlippyTilesSettings>,
     mut contexts: EguiContexts,
     dock_state: Res<crate::dock::DockTreeState>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
     // Only active in 3D mode
     if !matches!(state.mode, ViewMode::Perspective3D) {
@@ -623,6 +855,8 @@ pub fn handle_3d_camera_controls(
         return;
     }
 
+    let sensitivity = &app_config.camera_3d;
+
     // Read shift state from egui's input (bevy_egui absorbs modifier keys from ButtonInput)
     let shift_held = contexts.ctx_mut()
         .map(|ctx| ctx.input(|i| i.modifiers.shift))
@@ -668,10 +902,10 @@ pub fn handle_3d_camera_controls(
                 if state.chase_active && !state.chase_orbit_override {
                     state.chase_orbit_override = true;
                 }
-                state.camera_yaw += event.delta.x * ORBIT_SENSITIVITY;
+                state.camera_yaw += event.delta.x * sensitivity.orbit_sensitivity;
                 if state.camera_yaw < 0.0 { state.camera_yaw += 360.0; }
                 if state.camera_yaw >= 360.0 { state.camera_yaw -= 360.0; }
-                state.camera_pitch = (state.camera_pitch - event.delta.y * ORBIT_SENSITIVITY)
+                state.camera_pitch = (state.camera_pitch - event.delta.y * sensitivity.orbit_sensitivity)
                     .clamp(MIN_PITCH, MAX_PITCH);
             } else {
                 // Plain drag = Pan (translate XY only, no rotation)
@@ -679,7 +913,7 @@ pub fn handle_3d_camera_controls(
                     follow_state.following_icao = None;
                 }
 
-                let pan_speed = state.altitude_to_distance() * PAN_3D_SENSITIVITY;
+                let pan_speed = state.altitude_to_distance() * sensitivity.pan_sensitivity;
                 let yaw_rad = state.camera_yaw.to_radians();
 
                 // Camera basis vectors projected onto the ground plane.
@@ -698,7 +932,7 @@ pub fn handle_3d_camera_controls(
                 state.saved_2d_center.x += dx * cam_right_x + dy * cam_fwd_x;
                 state.saved_2d_center.y += dx * cam_right_y + dy * cam_fwd_y;
 
-                sync_center_to_map_state(&state, &tile_settings, &mut map_state);
+                sync_center_to_map_state(&mut state, &tile_settings, &mut map_state, app_config.map.wrap_antimeridian);
             }
         }
     } else {
@@ -722,7 +956,7 @@ pub fn handle_3d_camera_controls(
                 if state.chase_active && !state.chase_orbit_override {
                     state.chase_orbit_override = true;
                 }
-                let pitch_delta = scroll_y * 0.05;
+                let pitch_delta = scroll_y * sensitivity.pitch_scroll_sensitivity;
                 state.camera_pitch = (state.camera_pitch + pitch_delta)
                     .clamp(MIN_PITCH, MAX_PITCH);
             }
@@ -737,7 +971,7 @@ pub fn handle_3d_camera_controls(
             if state.chase_active && !state.chase_orbit_override {
                 state.chase_orbit_override = true;
             }
-            state.camera_altitude = (state.camera_altitude - scroll_y * ALTITUDE_SCROLL_SENSITIVITY)
+            state.camera_altitude = (state.camera_altitude - scroll_y * sensitivity.altitude_scroll_sensitivity)
                 .clamp(MIN_CAMERA_ALTITUDE, MAX_CAMERA_ALTITUDE);
         }
     }
@@ -755,10 +989,16 @@ pub fn handle_3d_camera_controls(
 
 /// Convert saved_2d_center (pixel-space offset from tile reference point) back to
 /// geographic coordinates and update the shared map state so tiles are loaded.
+///
+/// Panning past the Web Mercator latitude limit (~85°) pulls the pan target
+/// back onto the projection edge, so the camera can't drift away from the
+/// clamped map center near the poles. Longitude wraps across the
+/// antimeridian when `wrap_longitude` is set, else it is clamped too.
 fn sync_center_to_map_state(
-    state: &View3DState,
+    state: &mut View3DState,
     tile_settings: &bevy_slippy_tiles::SlippyTilesSettings,
     map_state: &mut crate::MapState,
+    wrap_longitude: bool,
 ) {
     use bevy_slippy_tiles::*;
 
@@ -780,7 +1020,31 @@ fn sync_center_to_map_state(
     );
 
     map_state.latitude = crate::clamp_latitude(center_geo.latitude);
-    map_state.longitude = crate::clamp_longitude(center_geo.longitude);
+    map_state.longitude = crate::normalize_longitude(center_geo.longitude, wrap_longitude);
+
+    // Inside the projection the round trip is sub-pixel; a larger gap means
+    // the center was clamped and the pan target must follow it.
+    let converter = crate::geo::CoordinateConverter::new(tile_settings, map_state.zoom_level);
+    let clamped_center = converter.latlon_to_world(map_state.latitude, map_state.longitude);
+    if clamped_center.distance(state.saved_2d_center) > 0.5 {
+        state.saved_2d_center = clamped_center;
+    }
+}
+
+/// Up vector for the orbit camera's `looking_at`.
+///
+/// World up is parallel to the view direction when looking straight down,
+/// which leaves the camera roll undefined and makes it spin near ±90° pitch.
+/// Steep views use the horizontal forward direction instead: screen-up stays
+/// along the camera yaw, pointing at grid north when yaw is 0.
+fn orbit_up_vector(pitch_deg: f32, yaw_deg: f32) -> Vec3 {
+    if pitch_deg.abs() < STEEP_PITCH_DEG {
+        return Vec3::Y;
+    }
+    // The camera always sits above the orbit center (see
+    // calculate_camera_transform_yup), so forward is the same for ±pitch.
+    let yaw_rad = yaw_deg.to_radians();
+    Vec3::new(yaw_rad.sin(), 0.0, -yaw_rad.cos())
 }
 
 /// System to raise map tiles to ground elevation in 3D mode.
@@ -815,21 +1079,17 @@ pub fn update_aircraft_3d_transform(
     state: Res<View3DState>,
     mut aircraft_query: Query<(&crate::Aircraft, &mut Transform), Without<crate::AircraftLabel>>,
     mut label_query: Query<(&crate::AircraftLabel, &mut Visibility)>,
+    declutter_hidden: Query<(), With<crate::aircraft::declutter::AltitudeHidden>>,
 ) {
     if state.is_3d_active() {
-        // Tile mesh quads sit at ground_y in Y-up space. Aircraft must always
-        // render above tiles to avoid being occluded by opaque tile depth writes.
-        // Add a small offset (10 units) above ground to clear depth precision
-        // limits at grazing angles on Metal (~4 units at FL300).
-        let ground_y = state.altitude_to_z(state.ground_elevation_ft);
-        let min_aircraft_y = ground_y + 10.0;
-
+        // Tile mesh quads sit at ground level in Y-up space. Aircraft must always
+        // render above tiles to avoid being occluded by opaque tile depth writes;
+        // aircraft_height keeps them a small clearance above the ground plane.
         for (aircraft, mut transform) in aircraft_query.iter_mut() {
             // Read pixel positions set by update_aircraft_positions (Z-up)
             let px = transform.translation.x;
             let py = transform.translation.y;
-            let alt = aircraft.altitude.unwrap_or(0);
-            let alt_y = state.altitude_to_z(alt).max(min_aircraft_y);
+            let alt_y = state.aircraft_height(aircraft.altitude);
 
             // Remap to Y-up: (px, py, alt_z) -> (px, alt_y, -py)
             transform.translation = Vec3::new(px, alt_y, -py);
@@ -843,22 +1103,136 @@ pub fn update_aircraft_3d_transform(
                 transform.rotation = base_rot;
             }
         }
-        // Hide text labels in 3D mode (they don't position well in perspective)
-        for (_label, mut vis) in label_query.iter_mut() {
-            *vis = Visibility::Hidden;
+        // Labels are hidden in 3D unless billboarding is enabled, in which
+        // case billboard_labels_3d positions them
+        for (label, mut vis) in label_query.iter_mut() {
+            let show = state.show_labels_3d && declutter_hidden.get(label.aircraft_entity).is_err();
+            *vis = if show { Visibility::Inherited } else { Visibility::Hidden };
         }
     } else if !state.is_transitioning() {
-        for (_aircraft, mut transform) in aircraft_query.iter_mut() {
-            transform.translation.z = crate::constants::AIRCRAFT_Z_LAYER;
+        for (aircraft, mut transform) in aircraft_query.iter_mut() {
+            transform.translation.z = crate::camera::aircraft_z_2d(aircraft.altitude);
         }
-        for (_label, mut vis) in label_query.iter_mut() {
-            if *vis == Visibility::Hidden {
+        for (label, mut vis) in label_query.iter_mut() {
+            // Labels of aircraft hidden by the map declutter band stay hidden
+            if *vis == Visibility::Hidden && declutter_hidden.get(label.aircraft_entity).is_err() {
                 *vis = Visibility::Inherited;
             }
         }
     }
 }
 
+/// Place labels at their aircraft's 3D position as screen-facing billboards.
+///
+/// Labels render through Camera2d (perspective, Z-up in 3D mode), so each
+/// label copies the camera rotation to face the screen and is scaled by its
+/// depth so text keeps a constant on-screen size. The 2D label offset is
+/// applied along the camera's right/up axes.
+pub fn billboard_labels_3d(
+    state: Res<View3DState>,
+    app_config: Res<crate::config::AppConfig>,
+    window_query: Query<&Window>,
+    camera_query: Query<(&Transform, &Projection), (With<crate::MapCamera>, Without<crate::AircraftLabel>)>,
+    aircraft_query: Query<&Transform, (With<crate::Aircraft>, Without<crate::AircraftLabel>, Without<crate::MapCamera>)>,
+    mut label_query: Query<(&crate::AircraftLabel, &mut Transform), (Without<crate::Aircraft>, Without<crate::MapCamera>)>,
+) {
+    if !state.is_3d_active() || !state.show_labels_3d {
+        return;
+    }
+    let Ok((cam_transform, Projection::Perspective(perspective))) = camera_query.single() else {
+        return;
+    };
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    if window.height() <= 0.0 {
+        return;
+    }
+
+    // World units per screen pixel at unit depth
+    let units_per_px = 2.0 * (perspective.fov / 2.0).tan() / window.height();
+    let forward = cam_transform.forward();
+    let appearance = &app_config.appearance;
+    let screen_offset = appearance.label_anchor.offset(appearance.label_offset);
+    let offset_dir = *cam_transform.right() * screen_offset.x + *cam_transform.up() * screen_offset.y;
+
+    for (label, mut label_transform) in label_query.iter_mut() {
+        let Ok(aircraft_transform) = aircraft_query.get(label.aircraft_entity) else {
+            continue;
+        };
+        // Aircraft transforms are Y-up in 3D; Camera2d works in Z-up
+        let anchor = yup_to_zup(aircraft_transform.translation);
+        let depth = (anchor - cam_transform.translation).dot(*forward);
+        if depth <= 0.0 {
+            continue;
+        }
+        let scale = depth * units_per_px;
+        label_transform.translation = anchor + offset_dir * scale;
+        label_transform.rotation = cam_transform.rotation;
+        label_transform.scale = Vec3::splat(scale);
+    }
+}
+
+/// Point where a ray meets the horizontal plane `y = ground_y` (Y-up space),
+/// or None when the ray points level or away from it.
+pub(crate) fn ray_ground_intersection(origin: Vec3, direction: Vec3, ground_y: f32) -> Option<Vec3> {
+    if direction.y.abs() < 1e-6 {
+        return None;
+    }
+    let t = (ground_y - origin.y) / direction.y;
+    (t > 0.0).then(|| origin + direction * t)
+}
+
+/// Cast the cursor through the 3D camera onto the ground plane and record
+/// the geographic point it lands on for the camera HUD readout.
+pub fn update_cursor_ground_position(
+    mut state: ResMut<View3DState>,
+    window_query: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<crate::AircraftCamera>>,
+    dock_state: Res<crate::dock::DockTreeState>,
+    tile_settings: Res<bevy_slippy_tiles::SlippyTilesSettings>,
+    map_state: Res<crate::MapState>,
+) {
+    let ground = if !state.is_3d_active() || !state.show_cursor_coords {
+        None
+    } else {
+        cursor_ground_point(&state, &window_query, &camera_query, &dock_state, &tile_settings, &map_state)
+    };
+    if state.cursor_ground != ground {
+        state.cursor_ground = ground;
+    }
+}
+
+fn cursor_ground_point(
+    state: &View3DState,
+    window_query: &Query<&Window>,
+    camera_query: &Query<(&Camera, &GlobalTransform), With<crate::AircraftCamera>>,
+    dock_state: &crate::dock::DockTreeState,
+    tile_settings: &bevy_slippy_tiles::SlippyTilesSettings,
+    map_state: &crate::MapState,
+) -> Option<(f64, f64)> {
+    let window = window_query.single().ok()?;
+    let cursor_pos = window.cursor_position()?;
+    // Ignore the cursor while it is over a dock panel
+    if let Some(map_rect) = dock_state.map_viewport_rect {
+        if !map_rect.contains(egui::pos2(cursor_pos.x, cursor_pos.y)) {
+            return None;
+        }
+    }
+    let (camera, cam_gtf) = camera_query.single().ok()?;
+    let ray = camera.viewport_to_world(cam_gtf, cursor_pos).ok()?;
+    let ground_y = state.altitude_to_z(state.ground_elevation_ft);
+    let hit = ray_ground_intersection(ray.origin, *ray.direction, ground_y)?;
+    // Only report points inside the fogged scene, not the far horizon
+    if hit.distance(ray.origin) > state.visibility_range * 2.0 {
+        return None;
+    }
+    let converter = crate::geo::CoordinateConverter::new(tile_settings, map_state.zoom_level);
+    let ground = yup_to_zup(hit);
+    let (lat, lon) = converter.world_to_latlon(Vec2::new(ground.x, ground.y));
+    (lat.abs() <= crate::constants::MERCATOR_LAT_LIMIT).then_some((lat, lon))
+}
+
 /// Fade aircraft sprites based on distance from Camera2d in 3D mode.
 /// Tiles are fogged by DistanceFog via their 3D mesh quad companions.
 pub fn fade_distant_sprites(
@@ -880,30 +1254,31 @@ pub fn fade_distant_sprites(
 
     let cam_pos = cam_transform.translation;
 
-    // Fade range matches the fog: starts at 40% of visibility_range, fully gone at 100%
-    let fade_start = state.visibility_range * 0.4;
-    let fade_end = state.visibility_range;
-    let fade_range = fade_end - fade_start;
-
-    if fade_range <= 0.0 {
+    if state.visibility_range <= 0.0 {
         return;
     }
 
     // Fade aircraft
     for (transform, mut sprite) in aircraft_query.iter_mut() {
         let dist = cam_pos.distance(transform.translation);
-        let alpha = if dist <= fade_start {
-            1.0
-        } else if dist >= fade_end {
-            0.0
-        } else {
-            1.0 - ((dist - fade_start) / fade_range)
-        };
+        let alpha = state.fog_alpha(dist);
         sprite.color = Color::srgba(1.0, 1.0, 1.0, alpha);
     }
 }
 
-/// Update DistanceFog falloff when visibility_range changes.
+/// Opacity at `distance` on a linear fog ramp: fully opaque up to
+/// `fade_start`, fading linearly to nothing at `fade_end`.
+fn linear_fog_alpha(distance: f32, fade_start: f32, fade_end: f32) -> f32 {
+    if distance <= fade_start {
+        1.0
+    } else if distance >= fade_end {
+        0.0
+    } else {
+        1.0 - (distance - fade_start) / (fade_end - fade_start)
+    }
+}
+
+/// Update DistanceFog falloff when the visibility or fog settings change.
 fn update_distance_fog(
     state: Res<View3DState>,
     mut fog_query: Query<&mut DistanceFog, With<Camera3d>>,
@@ -914,10 +1289,7 @@ fn update_distance_fog(
     let Ok(mut fog) = fog_query.single_mut() else {
         return;
     };
-    fog.falloff = FogFalloff::Linear {
-        start: state.visibility_range * 0.4,
-        end: state.visibility_range,
-    };
+    fog.falloff = state.fog_falloff();
 }
 
 /// Fix aircraft model materials for the current view mode.
@@ -982,9 +1354,12 @@ impl Plugin for View3DPlugin {
             .init_resource::<sky::SunState>()
             .init_resource::<sky::MoonState>()
             .init_resource::<sky::TimeState>()
+            .add_message::<Toggle3DViewRequest>()
             .add_systems(Startup, sky::setup_sky)
             .add_systems(Update, (
                 toggle_3d_view,
+                toggle_plan_view,
+                animate_camera_ease.before(update_3d_camera),
                 animate_view_transition,
                 handle_3d_camera_controls,
                 update_3d_camera
@@ -996,6 +1371,11 @@ impl Plugin for View3DPlugin {
                 .after(crate::ZoomSet::Change))
             .add_systems(Update, update_aircraft_3d_transform
                 .after(crate::camera::update_aircraft_positions))
+            .add_systems(Update, billboard_labels_3d
+                .after(update_aircraft_3d_transform)
+                .after(update_3d_camera)
+                .after(crate::camera::update_aircraft_labels)
+                .after(crate::camera::scale_aircraft_and_labels))
             .add_systems(Update, fix_aircraft_model_materials)
             .add_systems(Update, sky::update_sky_visibility)
             .add_systems(Update, sky::sync_sky_camera.after(update_3d_camera))
@@ -1003,6 +1383,7 @@ impl Plugin for View3DPlugin {
             .add_systems(Update, sky::update_sun_position.after(sky::sync_time_offset))
             .add_systems(Update, sky::update_moon_position.after(sky::sync_time_offset))
             .add_systems(Update, sky::update_star_visibility)
+            .add_systems(Update, sky::update_2d_tint.after(sky::update_sun_position))
             .add_systems(Update, sky::manage_camera_mode
                 .after(animate_view_transition)
                 .after(update_3d_camera)
@@ -1018,9 +1399,143 @@ impl Plugin for View3DPlugin {
                 .after(update_tile_elevation))
             .add_systems(Update, update_distance_fog
                 .after(animate_view_transition))
+            .add_systems(Update, ground_grid::draw_ground_grid
+                .after(update_3d_camera))
+            .add_systems(Update, ground_markers::draw_ground_markers
+                .after(update_3d_camera)
+                .after(update_aircraft_3d_transform))
+            .add_systems(Update, update_cursor_ground_position
+                .after(update_3d_camera))
             .add_systems(Update, crate::hud::render_camera_hud)
             .init_resource::<crate::debug_3d_hud::Debug3DHudState>()
             .add_systems(Update, crate::debug_3d_hud::render_debug_3d_hud);
         // 3D view settings panel is rendered via the consolidated Tools window (tools_window.rs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn altitude_to_z_handles_negative_altitudes() {
+        let state = View3DState::default();
+        assert_eq!(state.altitude_to_z(0), 0.0);
+        assert!(state.altitude_to_z(-100) < 0.0);
+        assert!(state.altitude_to_z(-1000) < state.altitude_to_z(-100));
+        // Implausible pressure altitudes are clamped rather than sinking forever
+        assert_eq!(state.altitude_to_z(-50_000), state.altitude_to_z(MIN_PLAUSIBLE_ALTITUDE_FT));
+    }
+
+    #[test]
+    fn aircraft_stay_above_below_sea_level_ground() {
+        let state = View3DState {
+            ground_elevation_ft: -11, // Amsterdam Schiphol
+            ..Default::default()
+        };
+        let ground = state.altitude_to_z(state.ground_elevation_ft);
+        assert!(ground < 0.0);
+        for alt in [Some(-300), Some(-11), Some(0), None] {
+            assert!(state.aircraft_height(alt) > ground, "{:?} should sit above ground", alt);
+        }
+        assert_eq!(state.aircraft_height(Some(10_000)), state.altitude_to_z(10_000));
+    }
+
+    #[test]
+    fn fog_fade_alpha_matches_fog_ramp() {
+        let state = View3DState::default();
+        assert_eq!(state.visibility_range, 5000.0);
+        assert_eq!(state.fog_alpha(0.0), 1.0);
+        assert_eq!(state.fog_alpha(2000.0), 1.0);
+        assert!((state.fog_alpha(3500.0) - 0.5).abs() < 1e-6);
+        assert_eq!(state.fog_alpha(5000.0), 0.0);
+        assert_eq!(state.fog_alpha(9000.0), 0.0);
+    }
+
+    #[test]
+    fn exponential_fog_thins_smoothly_to_visibility_range() {
+        let state = View3DState {
+            fog_curve: FogCurve::ExponentialSquared,
+            ..Default::default()
+        };
+        assert_eq!(state.fog_alpha(0.0), 1.0);
+        assert!(state.fog_alpha(1000.0) < 1.0);
+        assert!((state.fog_alpha(state.visibility_range) - FOG_VISIBILITY_CONTRAST).abs() < 1e-4);
+        assert!(matches!(state.fog_falloff(), FogFalloff::ExponentialSquared { .. }));
+    }
+
+    #[test]
+    fn cursor_ray_meets_ground_plane() {
+        let hit = ray_ground_intersection(Vec3::new(0.0, 100.0, 0.0), Vec3::new(1.0, -1.0, 0.0), 0.0).unwrap();
+        assert!((hit - Vec3::new(100.0, 0.0, 0.0)).length() < 1e-4);
+        // Level or upward rays never reach the ground
+        assert!(ray_ground_intersection(Vec3::new(0.0, 100.0, 0.0), Vec3::X, 0.0).is_none());
+        assert!(ray_ground_intersection(Vec3::new(0.0, 100.0, 0.0), Vec3::Y, 0.0).is_none());
+    }
+
+    #[test]
+    fn top_down_orbit_keeps_grid_north_up() {
+        for (yaw, expected_up) in [(0.0, Vec3::NEG_Z), (90.0, Vec3::X)] {
+            let state = View3DState {
+                camera_pitch: MAX_PITCH,
+                camera_yaw: yaw,
+                ..Default::default()
+            };
+            let tf = state.calculate_camera_transform_yup(Vec3::new(1000.0, 0.0, -2000.0));
+            assert!(tf.translation.is_finite() && tf.rotation.is_finite());
+            assert!(tf.up().dot(expected_up) > 0.99, "yaw {yaw}: up {:?}", tf.up());
+        }
+    }
+
+    #[test]
+    fn panning_past_mercator_limit_at_high_latitude_is_clamped() {
+        let tile_settings = bevy_slippy_tiles::SlippyTilesSettings {
+            reference_latitude: 82.0,
+            reference_longitude: 15.0,
+            ..default()
+        };
+        let mut map_state = crate::MapState {
+            latitude: 82.0,
+            longitude: 15.0,
+            ..Default::default()
+        };
+        let converter = crate::geo::CoordinateConverter::new(&tile_settings, map_state.zoom_level);
+        let edge = converter.latlon_to_world(crate::constants::MERCATOR_LAT_LIMIT, 15.0);
+
+        // Drag far beyond the northern edge of the projection
+        let mut state = View3DState {
+            saved_2d_center: Vec2::new(0.0, edge.y * 4.0 + edge.y.signum() * 1.0e6),
+            ..Default::default()
+        };
+        sync_center_to_map_state(&mut state, &tile_settings, &mut map_state, false);
+
+        assert!(map_state.latitude.is_finite());
+        assert!(map_state.latitude <= crate::constants::MERCATOR_LAT_LIMIT);
+        assert!(map_state.latitude > 84.0);
+        let resynced = converter.latlon_to_world(map_state.latitude, map_state.longitude);
+        assert!((state.saved_2d_center - resynced).length() < 1.0);
+    }
+
+    #[test]
+    fn plan_view_eases_overhead_and_back() {
+        let mut state = View3DState {
+            camera_pitch: 30.0,
+            camera_yaw: 300.0,
+            ..Default::default()
+        };
+        state.toggle_plan_view();
+        state.step_camera_ease(PLAN_VIEW_DURATION / 2.0);
+        // Halfway round the short way (through north), not back through south
+        assert!(state.camera_yaw > 300.0 || state.camera_yaw < 1.0, "{}", state.camera_yaw);
+        state.step_camera_ease(PLAN_VIEW_DURATION);
+        assert!(state.camera_ease.is_none());
+        assert_eq!(state.camera_pitch, PLAN_VIEW_PITCH);
+        assert_eq!(state.camera_yaw, 0.0);
+
+        state.toggle_plan_view();
+        state.step_camera_ease(PLAN_VIEW_DURATION);
+        assert!(!state.plan_view);
+        assert!((state.camera_pitch - 30.0).abs() < 1e-4);
+        assert!((state.camera_yaw - 300.0).abs() < 1e-3);
+    }
+}
@@ -12,6 +12,7 @@ use bevy::camera::{CameraOutputMode, Exposure};
 use bevy::camera::visibility::RenderLayers;
 use bevy::pbr::{DistanceFog, FogFalloff, StandardMaterial};
 use bevy::render::render_resource::BlendState;
+use bevy::render::renderer::RenderAdapterInfo;
 
 use super::View3DState;
 use crate::map::MapState;
@@ -20,10 +21,21 @@ use crate::RenderCategory;
 /// Z-depth for the star field sprite (behind tiles at z=0.1)
 const STAR_Z: f32 = -1.0;
 
+/// Z-depth for the 2D day/night tint sprite (above tiles, below aircraft)
+const TINT_Z: f32 = 5.0;
+/// Peak tint alpha at full night before the intensity multiplier
+const NIGHT_TINT_ALPHA: f32 = 0.45;
+/// Peak tint alpha of the golden-hour cue before the intensity multiplier
+const GOLDEN_TINT_ALPHA: f32 = 0.12;
+
 /// Marker component for the star field sprite
 #[derive(Component)]
 pub struct StarField;
 
+/// Marker component for the full-viewport 2D day/night tint sprite
+#[derive(Component)]
+pub struct DayNightTint;
+
 /// Marker component for the ground plane mesh
 #[derive(Component)]
 pub struct GroundPlane;
@@ -58,12 +70,28 @@ impl Default for SunState {
     }
 }
 
+/// How a manual time override behaves as wall-clock time passes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum ManualTimeMode {
+    /// The override is a fixed instant; the sun stays put.
+    #[default]
+    Frozen,
+    /// The override is an offset from real time; the clock keeps running
+    /// from the chosen start.
+    Advancing,
+}
+
 /// Controls whether the app uses real wall-clock time or a manual override.
 #[derive(Resource, Reflect)]
 #[reflect(Resource)]
 pub struct TimeState {
     #[reflect(ignore)]
     pub override_time: Option<chrono::DateTime<chrono::FixedOffset>>,
+    /// Wall-clock instant at which `override_time` was set. Used to advance
+    /// the override in `ManualTimeMode::Advancing`.
+    #[reflect(ignore)]
+    pub override_set_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub manual_mode: ManualTimeMode,
     pub utc_offset_hours: f32,
     /// Set via BRP to override the local hour (0.0-24.0). Calls set_hour()
     /// internally. Resets to -1 after applying.
@@ -74,6 +102,8 @@ impl Default for TimeState {
     fn default() -> Self {
         Self {
             override_time: None,
+            override_set_at: None,
+            manual_mode: ManualTimeMode::default(),
             utc_offset_hours: 0.0,
             override_hour: -1.0,
         }
@@ -82,8 +112,31 @@ impl Default for TimeState {
 
 impl TimeState {
     pub fn current_datetime(&self) -> chrono::DateTime<chrono::FixedOffset> {
-        self.override_time
-            .unwrap_or_else(|| chrono::Utc::now().fixed_offset())
+        let now = chrono::Utc::now();
+        match (self.override_time, self.manual_mode, self.override_set_at) {
+            (Some(dt), ManualTimeMode::Advancing, Some(set_at)) => dt + (now - set_at),
+            (Some(dt), _, _) => dt,
+            (None, _, _) => now.fixed_offset(),
+        }
+    }
+
+    /// Set the manual override, anchoring it to the current wall-clock time.
+    pub fn set_override(&mut self, datetime: chrono::DateTime<chrono::FixedOffset>) {
+        self.override_time = Some(datetime);
+        self.override_set_at = Some(chrono::Utc::now());
+    }
+
+    /// Switch between frozen and advancing manual time without jumping:
+    /// the displayed time is re-anchored at the moment of the switch.
+    pub fn set_manual_mode(&mut self, mode: ManualTimeMode) {
+        if mode == self.manual_mode {
+            return;
+        }
+        if self.is_manual() {
+            let current = self.current_datetime();
+            self.set_override(current);
+        }
+        self.manual_mode = mode;
     }
 
     pub fn is_manual(&self) -> bool {
@@ -105,15 +158,52 @@ impl TimeState {
             .and_then(|d| d.with_minute(m.min(59)))
             .and_then(|d| d.with_second(0))
         {
-            self.override_time = Some(dt.fixed_offset());
+            self.set_override(dt.fixed_offset());
         }
     }
 
     pub fn reset_to_live(&mut self) {
         self.override_time = None;
+        self.override_set_at = None;
     }
 }
 
+/// Apparent position of the sun or moon for an observer on the ground.
+///
+/// Angles are in degrees: `elevation` above the horizon (negative when
+/// below) and `azimuth` clockwise from true north (0 = N, 90 = E).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CelestialPosition {
+    pub elevation: f32,
+    pub azimuth: f32,
+    /// Lunar phase as a fraction of the synodic month: 0.0 = new,
+    /// 0.5 = full. `None` for the sun.
+    pub phase: Option<f32>,
+}
+
+/// Sun position at `datetime` for an observer at `latitude`/`longitude`
+/// (degrees, east positive). Uses the NREL SPA with standard refraction.
+pub fn sun_position_at(
+    latitude: f64,
+    longitude: f64,
+    datetime: &chrono::DateTime<chrono::FixedOffset>,
+) -> CelestialPosition {
+    let (elevation, azimuth) = compute_sun_position_at(latitude, longitude, datetime);
+    CelestialPosition { elevation, azimuth, phase: None }
+}
+
+/// Moon position and phase at `datetime` for an observer at
+/// `latitude`/`longitude` (degrees, east positive). Geocentric low-precision
+/// model: expect 2-5 degrees of error in position.
+pub fn moon_position_at(
+    latitude: f64,
+    longitude: f64,
+    datetime: &chrono::DateTime<chrono::FixedOffset>,
+) -> CelestialPosition {
+    let (elevation, azimuth, phase) = compute_moon_position(latitude, longitude, datetime);
+    CelestialPosition { elevation, azimuth, phase: Some(phase) }
+}
+
 /// Compute sun elevation and azimuth using the NREL Solar Position Algorithm.
 /// Accuracy: ~0.0003 degrees. Handles polar day/night edge cases.
 pub fn compute_sun_position(latitude: f64, longitude: f64) -> (f32, f32) {
@@ -209,8 +299,14 @@ fn compute_moon_position(
         + lat_rad.cos() * declination.cos() * hour_angle.cos();
     let elevation = sin_alt.asin();
 
-    let cos_az = (declination.sin() - lat_rad.sin() * sin_alt)
-        / (lat_rad.cos() * elevation.cos());
+    // Azimuth is undefined at the poles and at the zenith; report north
+    // rather than dividing by ~0 and producing NaN.
+    let az_denominator = lat_rad.cos() * elevation.cos();
+    let cos_az = if az_denominator.abs() < 1e-9 {
+        1.0
+    } else {
+        (declination.sin() - lat_rad.sin() * sin_alt) / az_denominator
+    };
     let mut azimuth = cos_az.clamp(-1.0, 1.0).acos();
     if hour_angle.sin() > 0.0 {
         azimuth = std::f64::consts::TAU - azimuth;
@@ -229,19 +325,57 @@ fn compute_moon_position(
     )
 }
 
-/// Toggle sky dome visibility based on 3D mode.
+/// Whether the GPU backend is Metal, where the sky dome workaround is kept.
+/// Falls back to the target OS before the render adapter is known.
+fn is_metal_backend(adapter: Option<&RenderAdapterInfo>) -> bool {
+    match adapter {
+        Some(info) => info.backend.to_str() == "metal",
+        None => cfg!(target_os = "macos"),
+    }
+}
+
+/// Plain sky color for the clear-color path, from night navy to day blue.
+fn clear_sky_color(sun_elevation: f32) -> Color {
+    let day = ((sun_elevation + 12.0) / 22.0).clamp(0.0, 1.0);
+    let night = Vec3::new(0.02, 0.03, 0.06);
+    let noon = Vec3::new(0.53, 0.70, 0.90);
+    let c = night.lerp(noon, day);
+    Color::srgb(c.x, c.y, c.z)
+}
+
+/// Toggle sky dome visibility based on 3D mode and the configured sky
+/// rendering path. On the clear-color path the dome stays hidden and
+/// Camera2d's clear color provides the sky behind the tiles; on the dome
+/// path the clear color is reset to the default.
 pub fn update_sky_visibility(
     state: Res<View3DState>,
+    app_config: Res<crate::config::AppConfig>,
+    sun_state: Res<SunState>,
+    adapter: Option<Res<RenderAdapterInfo>>,
     mut sky_query: Query<&mut Visibility, With<SkyDome>>,
+    mut camera_2d: Query<&mut Camera, With<crate::MapCamera>>,
 ) {
     let Ok(mut vis) = sky_query.single_mut() else {
         return;
     };
-    *vis = if state.is_3d_active() {
+    let use_dome = app_config.appearance.sky_rendering.uses_sky_dome(is_metal_backend(adapter.as_deref()));
+    *vis = if state.is_3d_active() && use_dome {
         Visibility::Inherited
     } else {
         Visibility::Hidden
     };
+
+    if !state.is_3d_active() {
+        return;
+    }
+    if let Ok(mut cam2d) = camera_2d.single_mut() {
+        if !use_dome {
+            cam2d.clear_color = ClearColorConfig::Custom(clear_sky_color(sun_state.elevation));
+        } else if !matches!(cam2d.clear_color, ClearColorConfig::Default) {
+            // Switched to the dome while in 3D: drop the clear-color sky
+            cam2d.clear_color = ClearColorConfig::Default;
+        }
+    }
 }
 
 /// Keep star field sprite centered on Camera2d and scaled to fill the viewport.
@@ -280,6 +414,64 @@ pub fn sync_sky_camera(
     }
 }
 
+/// Tint color and alpha for the 2D map at a given sun elevation, before the
+/// user's intensity multiplier. Night fades in through civil and nautical
+/// twilight; a warm golden-hour cue peaks just above the horizon.
+pub fn day_night_tint(sun_elevation: f32, night_color: [u8; 3]) -> (Color, f32) {
+    if sun_elevation < 0.0 {
+        let night = (-sun_elevation / 12.0).clamp(0.0, 1.0);
+        let [r, g, b] = night_color;
+        (Color::srgb_u8(r, g, b), NIGHT_TINT_ALPHA * night)
+    } else {
+        let golden = (1.0 - (sun_elevation - 3.0).abs() / 7.0).clamp(0.0, 1.0);
+        (Color::srgb(1.0, 0.6, 0.2), GOLDEN_TINT_ALPHA * golden)
+    }
+}
+
+/// Keep the day/night tint sprite over the 2D viewport and update its color
+/// from the sun elevation and the configured intensity. Hidden in 3D, where
+/// the sky, fog and exposure carry the time of day.
+pub fn update_2d_tint(
+    state: Res<View3DState>,
+    sun_state: Res<SunState>,
+    app_config: Res<crate::config::AppConfig>,
+    zoom_state: Res<crate::ZoomState>,
+    window_query: Query<&Window>,
+    camera_query: Query<&Transform, (With<crate::MapCamera>, Without<DayNightTint>)>,
+    mut tint_query: Query<(&mut Sprite, &mut Transform, &mut Visibility), With<DayNightTint>>,
+) {
+    let Ok((mut sprite, mut transform, mut visibility)) = tint_query.single_mut() else {
+        return;
+    };
+
+    let appearance = &app_config.appearance;
+    let (color, alpha) = day_night_tint(sun_state.elevation, appearance.night_tint_color);
+    let alpha = (alpha * appearance.day_night_tint_intensity).clamp(0.0, 1.0);
+    let show = !state.is_3d_active() && !state.is_transitioning() && alpha > 0.001;
+    let target_visibility = if show { Visibility::Inherited } else { Visibility::Hidden };
+    if *visibility != target_visibility {
+        *visibility = target_visibility;
+    }
+    if !show {
+        return;
+    }
+
+    let tinted = color.with_alpha(alpha);
+    if sprite.color != tinted {
+        sprite.color = tinted;
+    }
+
+    let Ok(camera_tf) = camera_query.single() else {
+        return;
+    };
+    transform.translation = Vec3::new(camera_tf.translation.x, camera_tf.translation.y, TINT_Z);
+    if let Ok(window) = window_query.single() {
+        // Diagonal covers the viewport at any track-up rotation
+        let diagonal = window.width().hypot(window.height()) / zoom_state.camera_zoom;
+        transform.scale = Vec3::new(diagonal, diagonal, 1.0);
+    }
+}
+
 /// Spawn the star field as a 2D sprite and the ground plane mesh.
 pub fn setup_sky(
     mut commands: Commands,
@@ -303,6 +495,20 @@ pub fn setup_sky(
         RenderLayers::layer(RenderCategory::SKY),
     ));
 
+    commands.spawn((
+        Name::new("Day/Night Tint"),
+        DayNightTint,
+        Sprite {
+            color: Color::NONE,
+            custom_size: Some(Vec2::ONE),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, TINT_Z),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        RenderLayers::layer(RenderCategory::OVERLAYS_2D),
+    ));
+
     // Spawn ground plane mesh (hidden until 3D mode activates).
     // Color matches dark CartoDB basemap tiles so the transition
     // from tiles to ground plane is seamless at distance.
@@ -588,7 +794,7 @@ pub fn update_sun_position(
     mut ambient: ResMut<GlobalAmbientLight>,
 ) {
     let datetime = time_state.current_datetime();
-    let (elevation, azimuth) = compute_sun_position_at(
+    let CelestialPosition { elevation, azimuth, .. } = sun_position_at(
         map_state.latitude,
         map_state.longitude,
         &datetime,
@@ -653,11 +859,13 @@ pub fn update_moon_position(
     mut moon_query: Query<(&mut DirectionalLight, &mut Transform), With<MoonLight>>,
 ) {
     let datetime = time_state.current_datetime();
-    let (elevation, azimuth, phase) = compute_moon_position(
+    let moon = moon_position_at(
         map_state.latitude,
         map_state.longitude,
         &datetime,
     );
+    let (elevation, azimuth) = (moon.elevation, moon.azimuth);
+    let phase = moon.phase.unwrap_or_default();
 
     // Only update when position changes meaningfully.
     let elev_changed = (moon_state.elevation - elevation).abs() > 0.05;
@@ -732,10 +940,7 @@ pub fn manage_camera_mode(
     };
 
     if state.is_3d_active() {
-        fog.falloff = FogFalloff::Linear {
-            start: state.visibility_range * 0.4,
-            end: state.visibility_range,
-        };
+        fog.falloff = state.fog_falloff();
 
         // Camera ordering: Camera2d base (order 0), Camera3d overlay (order 1)
         cam2d.order = 0;
@@ -857,7 +1062,12 @@ pub fn sync_ground_plane(
         // Place the ground plane slightly below the tile mesh quads so tiles
         // always render on top. Lower-zoom tiles can be up to 0.2 units below
         // the base ground level (zoom_diff * 0.05), so offset by 1.0 to clear all.
-        let ground_alt = state.altitude_to_z(state.ground_elevation_ft) - 1.0;
+        // With terrain on, drop to the lowest loaded terrain instead so the
+        // plane never cuts through valleys below the center elevation.
+        let floor_ft = state
+            .terrain_floor_ft
+            .map_or(state.ground_elevation_ft, |f| f.min(state.ground_elevation_ft));
+        let ground_alt = state.altitude_to_z(floor_ft) - 1.0;
         let pos_yup = super::zup_to_yup(Vec3::new(
             state.saved_2d_center.x,
             state.saved_2d_center.y,
@@ -921,3 +1131,62 @@ pub fn update_ground_plane_color(
     material.base_color = Color::srgb(r, g, b);
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> chrono::DateTime<chrono::FixedOffset> {
+        chrono::DateTime::parse_from_rfc3339(s).unwrap()
+    }
+
+    /// Great-circle angle between two sky positions, in degrees.
+    fn separation(a: CelestialPosition, b: CelestialPosition) -> f32 {
+        let (e1, e2) = (a.elevation.to_radians(), b.elevation.to_radians());
+        let daz = (a.azimuth - b.azimuth).to_radians();
+        (e1.sin() * e2.sin() + e1.cos() * e2.cos() * daz.cos()).clamp(-1.0, 1.0).acos().to_degrees()
+    }
+
+    #[test]
+    fn day_night_tint_ramps_through_twilight_and_golden_hour() {
+        let night = [5, 10, 40];
+        assert_eq!(day_night_tint(45.0, night).1, 0.0);
+        assert!((day_night_tint(3.0, night).1 - GOLDEN_TINT_ALPHA).abs() < 1e-6);
+        assert!((day_night_tint(-6.0, night).1 - NIGHT_TINT_ALPHA * 0.5).abs() < 1e-6);
+        assert_eq!(day_night_tint(-30.0, night), (Color::srgb_u8(5, 10, 40), NIGHT_TINT_ALPHA));
+    }
+
+    #[test]
+    fn sun_at_greenwich_june_solstice_noon() {
+        // Almanac: noon altitude = 90 - 51.48 + 23.44 = 61.96 degrees, due south
+        let sun = sun_position_at(51.4769, 0.0, &utc("2024-06-20T12:02:00Z"));
+        assert!((sun.elevation - 61.96).abs() < 0.2, "elevation {}", sun.elevation);
+        assert!((sun.azimuth - 180.0).abs() < 1.5, "azimuth {}", sun.azimuth);
+        assert_eq!(sun.phase, None);
+    }
+
+    #[test]
+    fn sun_below_horizon_at_greenwich_december_midnight() {
+        let sun = sun_position_at(51.4769, 0.0, &utc("2024-12-21T00:00:00Z"));
+        assert!((sun.elevation + 61.96).abs() < 0.5, "elevation {}", sun.elevation);
+    }
+
+    #[test]
+    fn moon_covers_sun_during_2024_eclipse_totality() {
+        // Dallas, TX at totality of the 2024-04-08 total solar eclipse
+        let when = utc("2024-04-08T18:40:00Z");
+        let sun = sun_position_at(32.78, -96.80, &when);
+        let moon = moon_position_at(32.78, -96.80, &when);
+        assert!(separation(sun, moon) < 5.0, "sun {:?} moon {:?}", sun, moon);
+
+        // New moon: phase wraps around 0/1
+        let phase = moon.phase.unwrap();
+        assert!(phase.min(1.0 - phase) < 0.03, "phase {}", phase);
+    }
+
+    #[test]
+    fn moon_phase_full_on_known_full_moon() {
+        // Full moon 2024-04-23 23:49 UTC
+        let moon = moon_position_at(0.0, 0.0, &utc("2024-04-23T23:49:00Z"));
+        assert!((moon.phase.unwrap() - 0.5).abs() < 0.03);
+    }
+}
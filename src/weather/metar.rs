@@ -510,9 +510,10 @@ pub fn update_weather_indicator_positions(
     }
 }
 
-/// Toggle weather overlay with 'W' key
+/// Toggle weather overlay with the weather shortcut ('W' by default)
 pub fn toggle_weather_overlay(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<crate::keyboard::KeyBindings>,
     mut weather_state: ResMut<WeatherState>,
     mut contexts: EguiContexts,
 ) {
@@ -523,7 +524,7 @@ pub fn toggle_weather_overlay(
         }
     }
 
-    if keyboard.just_pressed(KeyCode::KeyW) {
+    if bindings.just_pressed(crate::keyboard::ShortcutAction::ToggleWeather, &keyboard) {
         weather_state.enabled = !weather_state.enabled;
         info!("Weather overlay: {}", if weather_state.enabled { "enabled" } else { "disabled" });
     }
@@ -4,14 +4,20 @@ use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
 use bevy_egui::EguiContexts;
 use bevy_slippy_tiles::*;
+use std::time::Instant;
 
-use crate::constants::{self, ZOOM_DOWNGRADE_THRESHOLD, ZOOM_UPGRADE_THRESHOLD};
+use crate::aircraft::{AircraftDisplayList, CameraFollowState};
+use crate::constants::{
+    self, FIT_ALL_MARGIN, FIT_ALL_MAX_ZOOM, ZOOM_DOWNGRADE_THRESHOLD, ZOOM_SNAP_IDLE_SECS, ZOOM_SNAP_RATE,
+    ZOOM_UPGRADE_THRESHOLD,
+};
 use crate::dock;
+use crate::geo::{longitude_delta, world_width_pixels, wrap_longitude};
 use crate::map::{MapState, ZoomState};
 use crate::view3d;
 use crate::tiles::{request_tiles_at_location, SpawnedTiles, TileFadeState};
 use crate::camera::MapCamera;
-use crate::{clamp_latitude, clamp_longitude, ZoomDebugLogger};
+use crate::{clamp_latitude, normalize_longitude, Aircraft, ZoomDebugLogger};
 
 pub(crate) struct ZoomPlugin;
 
@@ -20,7 +26,10 @@ impl Plugin for ZoomPlugin {
         app.add_systems(Update, handle_zoom)
             .add_systems(Update, handle_pinch_zoom)
             .add_systems(Update, ApplyDeferred.after(handle_zoom))
-            .add_systems(Update, apply_camera_zoom.after(ApplyDeferred));
+            .add_systems(Update, snap_zoom_to_tile_scale.after(handle_zoom).after(handle_pinch_zoom))
+            .add_systems(Update, fit_all_aircraft.before(snap_zoom_to_tile_scale))
+            .add_systems(Update, apply_camera_zoom.after(ApplyDeferred).after(snap_zoom_to_tile_scale))
+            .add_message::<FitAllAircraftRequest>();
     }
 }
 
@@ -187,6 +196,7 @@ pub(crate) fn handle_zoom(
     dock_state: Res<dock::DockTreeState>,
     mut spawned_tiles: ResMut<SpawnedTiles>,
     view3d_state: Res<view3d::View3DState>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
     // In 3D mode, scroll is handled by handle_3d_camera_controls
     if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
@@ -292,7 +302,7 @@ pub(crate) fn handle_zoom(
             map_state.zoom_level,
         );
         map_state.latitude = clamp_latitude(new_lat);
-        map_state.longitude = clamp_longitude(new_lon);
+        map_state.longitude = normalize_longitude(new_lon, app_config.map.wrap_antimeridian);
         log_info!("  Map center updated: ({:.6}, {:.6}) -> ({:.6}, {:.6})", old_lat, old_lon, map_state.latitude, map_state.longitude);
 
         // === Handle zoom level transition (scale old tiles, request new) ===
@@ -325,6 +335,7 @@ pub(crate) fn handle_pinch_zoom(
     dock_state: Res<dock::DockTreeState>,
     mut spawned_tiles: ResMut<SpawnedTiles>,
     view3d_state: Res<view3d::View3DState>,
+    app_config: Res<crate::config::AppConfig>,
 ) {
     // In 3D mode, zoom is handled by handle_3d_camera_controls
     if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
@@ -373,7 +384,7 @@ pub(crate) fn handle_pinch_zoom(
                 map_state.zoom_level,
             );
             map_state.latitude = clamp_latitude(new_lat);
-            map_state.longitude = clamp_longitude(new_lon);
+            map_state.longitude = normalize_longitude(new_lon, app_config.map.wrap_antimeridian);
 
             if zoom_level_changed {
                 apply_zoom_level_transition(
@@ -402,3 +413,203 @@ pub(crate) fn apply_camera_zoom(
         }
     }
 }
+
+/// Nearest camera zoom at which tiles render at a whole-pixel scale: whole
+/// multiples when zoomed in (1x, 2x, ...) and whole fractions when zoomed out
+/// (1/2, 1/3, ...), kept within the camera zoom bounds. Between tile level
+/// transitions this is almost always 1.0.
+pub(crate) fn snap_target(camera_zoom: f32, min_zoom: f32, max_zoom: f32) -> f32 {
+    let target = if camera_zoom >= 1.0 {
+        camera_zoom.round()
+    } else {
+        1.0 / (1.0 / camera_zoom).round()
+    };
+    target.clamp(min_zoom, max_zoom)
+}
+
+/// Tracks zoom input so snapping only starts once the user stops zooming.
+#[derive(Default)]
+pub(crate) struct ZoomSnapTracker {
+    /// Camera zoom as last seen (or set) by the snap system
+    last_zoom: f32,
+    /// When the zoom was last changed by something other than the snap
+    last_change: Option<Instant>,
+}
+
+/// Ease the 2D camera zoom to the nearest whole-pixel tile scale once zoom
+/// input has been idle for `ZOOM_SNAP_IDLE_SECS`. Enabled by
+/// `MapConfig::snap_zoom`; any new zoom input cancels an in-progress snap.
+pub(crate) fn snap_zoom_to_tile_scale(
+    mut tracker: Local<ZoomSnapTracker>,
+    mut zoom_state: ResMut<ZoomState>,
+    view3d_state: Res<view3d::View3DState>,
+    app_config: Res<crate::config::AppConfig>,
+    time: Res<Time>,
+) {
+    if zoom_state.camera_zoom != tracker.last_zoom {
+        tracker.last_zoom = zoom_state.camera_zoom;
+        tracker.last_change = Some(Instant::now());
+        return;
+    }
+    if !app_config.map.snap_zoom || view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        return;
+    }
+    let Some(last_change) = tracker.last_change else {
+        return;
+    };
+    if last_change.elapsed().as_secs_f32() < ZOOM_SNAP_IDLE_SECS {
+        return;
+    }
+
+    let target = snap_target(zoom_state.camera_zoom, zoom_state.min_zoom, zoom_state.max_zoom);
+    let t = (ZOOM_SNAP_RATE * time.delta_secs()).min(1.0);
+    let mut new_zoom = zoom_state.camera_zoom + (target - zoom_state.camera_zoom) * t;
+    if (target - new_zoom).abs() < 1e-3 {
+        new_zoom = target;
+        tracker.last_change = None;
+    }
+    zoom_state.camera_zoom = new_zoom;
+    tracker.last_zoom = new_zoom;
+}
+
+// =============================================================================
+// Fit All Aircraft
+// =============================================================================
+
+/// Request to zoom the 2D map so every listed aircraft is in view. Sent by
+/// the toolbar button and the `FitAllAircraft` shortcut.
+#[derive(Message)]
+pub(crate) struct FitAllAircraftRequest;
+
+/// Map center, tile zoom level and camera zoom that fit `points` (lat, lon)
+/// into a viewport of `viewport` pixels with `margin` (fraction of the
+/// viewport) left free on each side. The tile zoom is capped at
+/// `max_tile_zoom` so a lone aircraft doesn't zoom to street level, and is
+/// picked so the camera zoom stays between the level transition thresholds.
+/// Longitudes are unwrapped around the first point, so a cluster straddling
+/// the antimeridian fits as one group.
+pub(crate) fn fit_view(
+    points: &[(f64, f64)],
+    viewport: Vec2,
+    margin: f32,
+    max_tile_zoom: u8,
+) -> Option<(f64, f64, ZoomLevel, f32)> {
+    let &(_, first_lon) = points.first()?;
+    let z0_width = world_width_pixels(constants::DEFAULT_TILE_SIZE, ZoomLevel::L0);
+    let y_at = |lat: f64| {
+        let ll = LatitudeLongitudeCoordinates { latitude: lat, longitude: 0.0 };
+        world_coords_to_world_pixel(&ll, constants::DEFAULT_TILE_SIZE, ZoomLevel::L0).1
+    };
+
+    let (mut west, mut east) = (f64::MAX, f64::MIN);
+    let (mut top, mut bottom) = (f64::MAX, f64::MIN);
+    for &(lat, lon) in points {
+        let lon = first_lon + longitude_delta(first_lon, lon);
+        west = west.min(lon);
+        east = east.max(lon);
+        let y = y_at(lat);
+        top = top.min(y);
+        bottom = bottom.max(y);
+    }
+
+    let center_lon = wrap_longitude((west + east) / 2.0);
+    let center_lat = world_pixel_to_world_coords(0.0, (top + bottom) / 2.0, constants::DEFAULT_TILE_SIZE, ZoomLevel::L0).latitude;
+
+    // Scale relative to zoom 0 at which the box fills the usable viewport
+    let usable = viewport * (1.0 - 2.0 * margin);
+    let span_x = (east - west) / 360.0 * z0_width;
+    let span_y = bottom - top;
+    let scale_x = if span_x > 0.0 { usable.x as f64 / span_x } else { f64::MAX };
+    let scale_y = if span_y > 0.0 { usable.y as f64 / span_y } else { f64::MAX };
+    let scale = scale_x.min(scale_y);
+
+    let ideal_zoom = scale.log2().round();
+    let (tile_zoom, camera_zoom) = if ideal_zoom >= max_tile_zoom as f64 {
+        (max_tile_zoom, 1.0)
+    } else {
+        let tile_zoom = ideal_zoom.max(0.0) as u8;
+        (tile_zoom, (scale / (1u64 << tile_zoom) as f64) as f32)
+    };
+    let zoom_level = ZoomLevel::try_from(tile_zoom).ok()?;
+    Some((center_lat, center_lon, zoom_level, camera_zoom))
+}
+
+/// System that fits the 2D map to the aircraft in the (filtered) list, or to
+/// every aircraft when the list is empty. Ignored in 3D, where the camera
+/// altitude rather than the tile zoom sets the view.
+pub(crate) fn fit_all_aircraft(
+    mut requests: MessageReader<FitAllAircraftRequest>,
+    mut map_state: ResMut<MapState>,
+    mut zoom_state: ResMut<ZoomState>,
+    mut follow_state: ResMut<CameraFollowState>,
+    display_list: Res<AircraftDisplayList>,
+    aircraft_query: Query<&Aircraft>,
+    view3d_state: Res<view3d::View3DState>,
+    dock_state: Res<dock::DockTreeState>,
+    window_query: Query<&Window>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    if view3d_state.is_3d_active() || view3d_state.is_transitioning() {
+        return;
+    }
+
+    let listed: std::collections::HashSet<&str> = display_list.aircraft.iter().map(|a| a.icao.as_str()).collect();
+    let points: Vec<(f64, f64)> = aircraft_query
+        .iter()
+        .filter(|a| listed.is_empty() || listed.contains(a.icao.as_str()))
+        .map(|a| (a.latitude, a.longitude))
+        .collect();
+
+    let viewport = match (dock_state.map_viewport_rect, window_query.single()) {
+        (Some(rect), _) => Vec2::new(rect.width(), rect.height()),
+        (None, Ok(window)) => Vec2::new(window.width(), window.height()),
+        (None, Err(_)) => return,
+    };
+    let Some((lat, lon, zoom_level, camera_zoom)) = fit_view(&points, viewport, FIT_ALL_MARGIN, FIT_ALL_MAX_ZOOM) else {
+        return;
+    };
+
+    map_state.latitude = clamp_latitude(lat);
+    map_state.longitude = lon;
+    map_state.zoom_level = zoom_level;
+    zoom_state.camera_zoom = camera_zoom.clamp(zoom_state.min_zoom, zoom_state.max_zoom);
+    follow_state.following_icao = None;
+    info!("Fit {} aircraft at zoom {}", points.len(), zoom_level.to_u8());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_target_picks_whole_pixel_scale() {
+        assert_eq!(snap_target(1.3, 0.1, 10.0), 1.0);
+        assert_eq!(snap_target(0.8, 0.1, 10.0), 1.0);
+        assert_eq!(snap_target(0.45, 0.1, 10.0), 0.5);
+        assert_eq!(snap_target(2.6, 0.1, 10.0), 3.0);
+        assert_eq!(snap_target(12.0, 0.1, 10.0), 10.0);
+    }
+
+    #[test]
+    fn fit_view_frames_points_across_the_antimeridian() {
+        let viewport = Vec2::new(1000.0, 800.0);
+        assert!(fit_view(&[], viewport, 0.1, 12).is_none());
+
+        // A lone aircraft is centered at the capped zoom
+        let (lat, lon, zoom, camera_zoom) = fit_view(&[(47.5, -122.3)], viewport, 0.1, 12).unwrap();
+        assert!((lat - 47.5).abs() < 1e-6 && (lon + 122.3).abs() < 1e-6);
+        assert_eq!((zoom.to_u8(), camera_zoom), (12, 1.0));
+
+        // Two aircraft 2 degrees apart either side of the antimeridian
+        let points = [(0.0, 179.0), (0.0, -179.0)];
+        let (lat, lon, zoom, camera_zoom) = fit_view(&points, viewport, 0.1, 12).unwrap();
+        assert!(lat.abs() < 1e-9);
+        assert!((lon.abs() - 180.0).abs() < 1e-9);
+        // The box must fit inside the usable 800 px width at the chosen scale
+        let width = 2.0 / 360.0 * world_width_pixels(constants::DEFAULT_TILE_SIZE, zoom) * camera_zoom as f64;
+        assert!(width <= 800.0 + 1e-6, "{} px", width);
+        assert!(width > 400.0, "{} px", width);
+    }
+}